@@ -1,3 +1,173 @@
-fn main() {
-    println!("Hello, world!");
+use anyhow::{Context, Result, bail};
+use clap::{Parser, ValueEnum};
+use csaf::CsafDocument;
+use csaf::converter::{ConverterOptions, convert_2_0_to_2_1};
+use csaf::csaf2_0::loader::load_document_from_str;
+use csaf::schema::csaf2_1::schema::{CategoryOfPublisher, LabelOfTlp};
+use csaf::vex::cyclonedx::{self, CycloneDxVex};
+use csaf::vex::import::ImportOptions;
+use csaf::vex::openvex::{self, OpenVexDocument};
+use std::path::Path;
+use std::str::FromStr;
+
+/// A converter for CSAF 2.0 documents into CSAF 2.1 documents and CSAF VEX documents from and into OpenVEX and CycloneDX VEX
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+struct Args {
+    /// Path to the document to convert
+    #[arg()]
+    path: String,
+
+    /// Format to convert from, OpenVEX and CycloneDX VEX are imported as CSAF 2.1 documents with category csaf_vex
+    #[arg(long, value_enum, default_value_t = Source::Csaf)]
+    from: Source,
+
+    /// Format to convert to, OpenVEX and CycloneDX VEX require a document with category csaf_vex
+    #[arg(long, value_enum, default_value_t = Target::Csaf21)]
+    to: Target,
+
+    /// Path of the converted document, printed to stdout if not given
+    #[arg(short, long)]
+    output: Option<String>,
+
+    /// TLP label to use if the document does not have one (AMBER, AMBER+STRICT, CLEAR, GREEN, RED), required for
+    /// imported VEX documents
+    #[arg(long)]
+    default_tlp_label: Option<String>,
+
+    /// Namespace of the publisher of imported VEX documents
+    #[arg(long)]
+    publisher_namespace: Option<String>,
+
+    /// Name of the publisher of imported VEX documents, defaults to the author of the VEX document
+    #[arg(long)]
+    publisher_name: Option<String>,
+
+    /// Category of the publisher of imported VEX documents
+    #[arg(long, default_value = "vendor")]
+    publisher_category: String,
+
+    /// Tracking ID of imported VEX documents, defaults to the ID of the VEX document
+    #[arg(long)]
+    tracking_id: Option<String>,
+}
+
+/// Formats documents can be converted from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Source {
+    Csaf,
+    Openvex,
+    CyclonedxVex,
+}
+
+/// Formats documents can be converted to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Target {
+    #[value(name = "csaf-2.1")]
+    Csaf21,
+    Openvex,
+    CyclonedxVex,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    if args.from != Source::Csaf {
+        return import_vex(&args);
+    }
+    if args.to != Target::Csaf21 {
+        return export_vex(&args);
+    }
+
+    let default_tlp_label = tlp_label(&args)?;
+
+    let content = std::fs::read_to_string(&args.path).with_context(|| format!("Failed to read {}", args.path))?;
+    let document = load_document_from_str(&content).with_context(|| format!("Failed to load {}", args.path))?;
+
+    let conversion = convert_2_0_to_2_1(&document, &ConverterOptions { default_tlp_label })?;
+    for note in &conversion.notes {
+        eprintln!("{note}");
+    }
+
+    write_output(&args, serde_json::to_string_pretty(conversion.document.get_json())?)
+}
+
+/// Parses the TLP label given by `--default-tlp-label`.
+fn tlp_label(args: &Args) -> Result<Option<LabelOfTlp>> {
+    match &args.default_tlp_label {
+        Some(label) => match LabelOfTlp::from_str(label) {
+            Ok(label) => Ok(Some(label)),
+            Err(_) => bail!("Invalid TLP label: {label}"),
+        },
+        None => Ok(None),
+    }
+}
+
+/// Imports an OpenVEX or CycloneDX VEX document as CSAF 2.1 document.
+fn import_vex(args: &Args) -> Result<()> {
+    if args.to != Target::Csaf21 {
+        bail!("VEX documents can only be converted to CSAF 2.1");
+    }
+    let Some(tlp_label) = tlp_label(args)? else {
+        bail!("--default-tlp-label is required to import VEX documents");
+    };
+    let Some(publisher_namespace) = args.publisher_namespace.clone() else {
+        bail!("--publisher-namespace is required to import VEX documents");
+    };
+    let Ok(publisher_category) = CategoryOfPublisher::from_str(&args.publisher_category) else {
+        bail!("Invalid publisher category: {}", args.publisher_category);
+    };
+    let options = ImportOptions {
+        publisher_name: args.publisher_name.clone(),
+        publisher_namespace,
+        publisher_category,
+        tracking_id: args.tracking_id.clone(),
+        tlp_label,
+    };
+
+    let content = std::fs::read_to_string(&args.path).with_context(|| format!("Failed to read {}", args.path))?;
+    let import = match args.from {
+        Source::Openvex => {
+            let document: OpenVexDocument =
+                serde_json::from_str(&content).with_context(|| format!("Failed to load {}", args.path))?;
+            openvex::import(&document, &options)?
+        },
+        _ => {
+            let document: CycloneDxVex =
+                serde_json::from_str(&content).with_context(|| format!("Failed to load {}", args.path))?;
+            cyclonedx::import(&document, &options)?
+        },
+    };
+    for warning in &import.warnings {
+        eprintln!("{warning}");
+    }
+    write_output(args, serde_json::to_string_pretty(&import.document)?)
+}
+
+/// Exports a CSAF VEX document of any version as OpenVEX or CycloneDX VEX.
+fn export_vex(args: &Args) -> Result<()> {
+    let document =
+        CsafDocument::load(Path::new(&args.path)).with_context(|| format!("Failed to load {}", args.path))?;
+    let (json, warnings) = match args.to {
+        Target::Openvex => {
+            let export = openvex::export(&document)?;
+            (serde_json::to_string_pretty(&export.document)?, export.warnings)
+        },
+        _ => {
+            let export = cyclonedx::export(&document)?;
+            (serde_json::to_string_pretty(&export.document)?, export.warnings)
+        },
+    };
+    for warning in &warnings {
+        eprintln!("{warning}");
+    }
+    write_output(args, json)
+}
+
+/// Writes the converted document to the output file or stdout.
+fn write_output(args: &Args, json: String) -> Result<()> {
+    match &args.output {
+        Some(output) => std::fs::write(output, json + "\n").with_context(|| format!("Failed to write {output}"))?,
+        None => println!("{json}"),
+    }
+    Ok(())
 }
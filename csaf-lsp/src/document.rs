@@ -0,0 +1,353 @@
+//! Open documents and the results of their analysis.
+
+use std::collections::{BTreeMap, HashSet};
+
+use csaf::csaf::raw::{HasParsed, RawDocument};
+use csaf::csaf::span::{Span, SpanIndex};
+use csaf::csaf_traits::{CsafTrait, ProductGroupTrait, ProductTrait, ProductTreeTrait};
+use csaf::validation::{TestResultStatus, Validatable, ValidationPreset, ValidationResult, validate_by_preset};
+use lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Position, Range};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::line_index::LineIndex;
+
+/// Source of the diagnostics published by the server
+const DIAGNOSTIC_SOURCE: &str = "csaf";
+
+/// An open CSAF document, analyzed after every change.
+pub struct Document {
+    pub text: String,
+    pub line_index: LineIndex,
+    pub spans: SpanIndex,
+    /// CSAF version of the document, if it is supported
+    pub csaf_version: Option<String>,
+    /// Product and group IDs of the document
+    pub products: ProductIndex,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Definition of a product ID by a full product name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProductDefinition {
+    /// JSON pointer of the full product name
+    pub pointer: String,
+    /// Name of the product
+    pub name: String,
+}
+
+/// Definitions and references of product and group IDs.
+#[derive(Debug, Clone, Default)]
+pub struct ProductIndex {
+    /// Defined product IDs
+    pub products: BTreeMap<String, ProductDefinition>,
+    /// Defined product group IDs and the JSON pointer of their group
+    pub groups: BTreeMap<String, String>,
+    /// JSON pointers of all product ID references
+    pub product_references: HashSet<String>,
+    /// JSON pointers of all group ID references
+    pub group_references: HashSet<String>,
+}
+
+impl ProductIndex {
+    pub fn new(doc: &impl CsafTrait) -> Self {
+        let mut index = ProductIndex {
+            product_references: doc
+                .get_all_product_references()
+                .into_iter()
+                .map(|(_, pointer)| pointer)
+                .collect(),
+            group_references: doc
+                .get_all_group_references()
+                .into_iter()
+                .map(|(_, pointer)| pointer)
+                .collect(),
+            ..Default::default()
+        };
+        if let Some(product_tree) = doc.get_product_tree() {
+            product_tree.visit_all_products(&mut |product, pointer| {
+                index.products.insert(
+                    product.get_product_id().to_owned(),
+                    ProductDefinition {
+                        pointer: pointer.to_string(),
+                        name: product.get_name().to_string(),
+                    },
+                );
+            });
+            for (i, group) in product_tree.get_product_groups().iter().enumerate() {
+                index.groups.insert(
+                    group.get_group_id().to_owned(),
+                    format!("/product_tree/product_groups/{i}"),
+                );
+            }
+        }
+        index
+    }
+}
+
+impl Document {
+    /// Analyzes the text of a document.
+    ///
+    /// If the document cannot be parsed, the product IDs of the previous version of the document
+    /// are kept, so that they can still be completed while the document is being edited.
+    pub fn new(text: String, preset: ValidationPreset, previous: Option<Document>) -> Self {
+        let mut document = Document {
+            line_index: LineIndex::new(&text),
+            spans: SpanIndex::new(&text),
+            text,
+            csaf_version: None,
+            products: previous.map(|previous| previous.products).unwrap_or_default(),
+            diagnostics: Vec::new(),
+        };
+
+        let json: Value = match serde_json::from_str(&document.text) {
+            Ok(json) => json,
+            Err(err) => {
+                document.diagnostics = vec![document.syntax_diagnostic(&err)];
+                return document;
+            },
+        };
+        let version = json
+            .pointer("/document/csaf_version")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        let (result, products) = match version.as_str() {
+            "2.0" => analyze(
+                RawDocument::<csaf::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework>::new(json),
+                &version,
+                preset,
+            ),
+            "2.1" => analyze(
+                RawDocument::<csaf::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>::new(json),
+                &version,
+                preset,
+            ),
+            _ => {
+                let span = document.spans.locate("/document/csaf_version").copied();
+                document.diagnostics = vec![document.diagnostic(
+                    span.as_ref(),
+                    DiagnosticSeverity::ERROR,
+                    None,
+                    format!("Unsupported or missing CSAF version \"{version}\", expected \"2.0\" or \"2.1\""),
+                )];
+                return document;
+            },
+        };
+
+        document.csaf_version = Some(version);
+        if let Some(products) = products {
+            document.products = products;
+        }
+        document.diagnostics = document.validation_diagnostics(&result);
+        document
+    }
+
+    /// Returns the byte offset of an LSP position.
+    pub fn offset(&self, position: Position) -> usize {
+        self.line_index.offset(&self.text, position)
+    }
+
+    /// Returns the LSP range of a span.
+    pub fn range(&self, span: &Span) -> Range {
+        Range::new(
+            self.line_index.position(&self.text, span.start),
+            self.line_index.position(&self.text, span.end),
+        )
+    }
+
+    /// Returns the string at the span, if the span contains a JSON string.
+    pub fn string_at(&self, span: &Span) -> Option<String> {
+        serde_json::from_str(&self.text[span.start..span.end]).ok()
+    }
+
+    fn syntax_diagnostic(&self, err: &serde_json::Error) -> Diagnostic {
+        // serde_json counts columns in bytes
+        let line_start = self
+            .line_index
+            .offset(&self.text, Position::new(err.line().saturating_sub(1) as u32, 0));
+        let mut offset = (line_start + err.column().saturating_sub(1)).min(self.text.len());
+        while !self.text.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let position = self.line_index.position(&self.text, offset);
+        Diagnostic {
+            range: Range::new(position, position),
+            severity: Some(DiagnosticSeverity::ERROR),
+            source: Some(DIAGNOSTIC_SOURCE.to_string()),
+            message: format!("Invalid JSON: {err}"),
+            ..Default::default()
+        }
+    }
+
+    fn validation_diagnostics(&self, result: &ValidationResult) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        for test_result in &result.test_results {
+            let TestResultStatus::Failure {
+                errors,
+                warnings,
+                infos,
+            } = &test_result.status
+            else {
+                continue;
+            };
+            let findings = [
+                (DiagnosticSeverity::ERROR, errors),
+                (DiagnosticSeverity::WARNING, warnings),
+                (DiagnosticSeverity::INFORMATION, infos),
+            ];
+            for (severity, findings) in findings {
+                for finding in findings {
+                    diagnostics.push(self.diagnostic(
+                        self.spans.locate(&finding.instance_path),
+                        severity,
+                        Some(&test_result.test_id),
+                        finding.message.clone(),
+                    ));
+                }
+            }
+        }
+        diagnostics
+    }
+
+    /// Creates a diagnostic for the value at the span.
+    ///
+    /// Objects and arrays are only marked up to the end of their first line, to not mark large parts
+    /// of the document.
+    fn diagnostic(
+        &self,
+        span: Option<&Span>,
+        severity: DiagnosticSeverity,
+        test_id: Option<&str>,
+        message: String,
+    ) -> Diagnostic {
+        let range = match span {
+            Some(span) => {
+                let line_end = self.line_index.line_end(&self.text, span.start);
+                self.range(&Span {
+                    end: span.end.min(line_end),
+                    ..*span
+                })
+            },
+            None => Range::default(),
+        };
+        Diagnostic {
+            range,
+            severity: Some(severity),
+            code: test_id.map(|id| NumberOrString::String(id.to_string())),
+            source: Some(DIAGNOSTIC_SOURCE.to_string()),
+            message,
+            ..Default::default()
+        }
+    }
+}
+
+/// Validates a document and indexes its product IDs, if it can be parsed.
+fn analyze<T>(doc: RawDocument<T>, version: &str, preset: ValidationPreset) -> (ValidationResult, Option<ProductIndex>)
+where
+    T: CsafTrait + DeserializeOwned,
+    RawDocument<T>: Validatable,
+{
+    let result = validate_by_preset(&doc, version, preset);
+    let products = doc.get_parsed().as_ref().ok().map(ProductIndex::new);
+    (result, products)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// A CSAF 2.0 document with two products, one product group and a vulnerability referencing them.
+    pub(crate) const DOCUMENT: &str = r#"{
+  "document": {
+    "category": "csaf_base",
+    "csaf_version": "2.0",
+    "distribution": { "tlp": { "label": "WHITE" } },
+    "publisher": {
+      "category": "vendor",
+      "name": "Example Company",
+      "namespace": "https://example.com"
+    },
+    "title": "Language server test",
+    "tracking": {
+      "current_release_date": "2024-01-24T10:00:00.000Z",
+      "id": "LSP-TEST",
+      "initial_release_date": "2024-01-24T10:00:00.000Z",
+      "revision_history": [
+        { "date": "2024-01-24T10:00:00.000Z", "number": "1", "summary": "Initial version." }
+      ],
+      "status": "final",
+      "version": "1"
+    }
+  },
+  "product_tree": {
+    "full_product_names": [
+      { "name": "Product A", "product_id": "CSAFPID-0001" },
+      { "name": "Product B", "product_id": "CSAFPID-0002" }
+    ],
+    "product_groups": [
+      { "group_id": "CSAFGID-0001", "product_ids": ["CSAFPID-0001", "CSAFPID-0002"] }
+    ]
+  },
+  "vulnerabilities": [
+    {
+      "product_status": { "known_affected": ["CSAFPID-0001", "CSAFPID-9999"] },
+      "remediations": [
+        { "category": "vendor_fix", "details": "Update.", "group_ids": ["CSAFGID-0001"] }
+      ]
+    }
+  ]
+}"#;
+
+    #[test]
+    fn test_analysis() {
+        let document = Document::new(DOCUMENT.to_string(), ValidationPreset::Basic, None);
+        assert_eq!(document.csaf_version.as_deref(), Some("2.0"));
+        assert_eq!(document.products.products.len(), 2);
+        assert_eq!(
+            document.products.products["CSAFPID-0002"].pointer,
+            "/product_tree/full_product_names/1"
+        );
+        assert_eq!(
+            document.products.groups["CSAFGID-0001"],
+            "/product_tree/product_groups/0"
+        );
+        assert!(
+            document
+                .products
+                .product_references
+                .contains("/vulnerabilities/0/product_status/known_affected/1")
+        );
+
+        // 6.1.1 reports the undefined product ID at the reference
+        let diagnostic = document
+            .diagnostics
+            .iter()
+            .find(|d| d.code == Some(NumberOrString::String("6.1.1".to_string())))
+            .unwrap();
+        let line = DOCUMENT.lines().position(|l| l.contains("CSAFPID-9999")).unwrap() as u32;
+        assert_eq!(diagnostic.range.start.line, line);
+        assert_eq!(diagnostic.range.end.line, line);
+        assert_eq!(diagnostic.severity, Some(DiagnosticSeverity::ERROR));
+    }
+
+    #[test]
+    fn test_invalid_json_keeps_products() {
+        let document = Document::new(DOCUMENT.to_string(), ValidationPreset::Basic, None);
+        let broken = DOCUMENT.replacen("\"title\":", "\"title\"", 1);
+        let document = Document::new(broken, ValidationPreset::Basic, Some(document));
+
+        assert_eq!(document.diagnostics.len(), 1);
+        assert!(document.diagnostics[0].message.starts_with("Invalid JSON"));
+        assert_eq!(document.diagnostics[0].range.start.line, 10);
+        assert_eq!(document.products.products.len(), 2);
+    }
+
+    #[test]
+    fn test_unsupported_version() {
+        let document = Document::new(DOCUMENT.replace("\"2.0\"", "\"1.2\""), ValidationPreset::Basic, None);
+        assert_eq!(document.csaf_version, None);
+        assert_eq!(document.diagnostics.len(), 1);
+        assert_eq!(document.diagnostics[0].range.start, Position::new(3, 20));
+    }
+}
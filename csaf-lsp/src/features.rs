@@ -0,0 +1,227 @@
+//! Hover, completion and go-to-definition for CSAF documents.
+
+use csaf::csaf::json_schema::schema_documentation;
+use lsp_types::{
+    CompletionItem, CompletionItemKind, CompletionTextEdit, Hover, HoverContents, Location, MarkupContent, MarkupKind,
+    Position, Range, TextEdit, Uri,
+};
+
+use crate::document::Document;
+
+/// Returns the schema documentation of the property or value at the position.
+///
+/// For product and group ID references, the referenced product or group is included.
+pub fn hover(document: &Document, position: Position) -> Option<Hover> {
+    let version = document.csaf_version.as_deref()?;
+    let offset = document.offset(position);
+    let (pointer, span) = document
+        .spans
+        .key_at(offset)
+        .or_else(|| document.spans.value_at(offset))?;
+    let documentation = schema_documentation(version, pointer)?;
+
+    let mut sections = Vec::new();
+    if let Some(title) = documentation.title {
+        sections.push(format!("**{title}**"));
+    }
+    if let Some(description) = documentation.description {
+        sections.push(description);
+    }
+    if !documentation.enum_values.is_empty() {
+        let values: Vec<String> = documentation.enum_values.iter().map(|v| format!("`{v}`")).collect();
+        sections.push(format!("Allowed values: {}", values.join(", ")));
+    }
+    if let Some(id) = document.spans.get(pointer).and_then(|value| document.string_at(value)) {
+        if document.products.product_references.contains(pointer)
+            && let Some(definition) = document.products.products.get(&id)
+        {
+            sections.push(format!("Product `{id}`: {}", definition.name));
+        } else if document.products.group_references.contains(pointer) && document.products.groups.contains_key(&id) {
+            sections.push(format!("Product group `{id}`"));
+        }
+    }
+    if sections.is_empty() {
+        return None;
+    }
+
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: sections.join("\n\n"),
+        }),
+        range: Some(document.range(span)),
+    })
+}
+
+/// Returns completions for the string value at the position.
+///
+/// Values of enumerations are completed from the schema, product and group ID references from the
+/// IDs defined in the product tree.
+pub fn completion(document: &Document, position: Position) -> Vec<CompletionItem> {
+    let offset = document.offset(position);
+    let Some((pointer, span)) = document.spans.value_at(offset) else {
+        return vec![];
+    };
+    if !document.text[span.start..span.end].starts_with('"') {
+        return vec![];
+    }
+    // The content of the string is replaced, keeping the quotes
+    let range = Range::new(
+        document.line_index.position(&document.text, span.start + 1),
+        document.line_index.position(&document.text, span.end - 1),
+    );
+    let item = |label: &str, kind, detail: Option<String>| CompletionItem {
+        label: label.to_string(),
+        kind: Some(kind),
+        detail,
+        text_edit: Some(CompletionTextEdit::Edit(TextEdit::new(range, label.to_string()))),
+        ..Default::default()
+    };
+
+    let mut items = Vec::new();
+    if let Some(version) = document.csaf_version.as_deref()
+        && let Some(documentation) = schema_documentation(version, pointer)
+    {
+        items.extend(
+            documentation
+                .enum_values
+                .iter()
+                .map(|value| item(value, CompletionItemKind::ENUM_MEMBER, None)),
+        );
+    }
+    if is_product_reference(document, pointer) {
+        items.extend(
+            document
+                .products
+                .products
+                .iter()
+                .map(|(id, definition)| item(id, CompletionItemKind::REFERENCE, Some(definition.name.clone()))),
+        );
+    }
+    if is_group_reference(document, pointer) {
+        items.extend(
+            document
+                .products
+                .groups
+                .keys()
+                .map(|id| item(id, CompletionItemKind::REFERENCE, None)),
+        );
+    }
+    items
+}
+
+/// Returns the full product name or product group defining the ID referenced at the position.
+pub fn definition(document: &Document, uri: &Uri, position: Position) -> Option<Location> {
+    let offset = document.offset(position);
+    let (pointer, span) = document.spans.value_at(offset)?;
+    let id = document.string_at(span)?;
+
+    let target = if is_product_reference(document, pointer) {
+        &document.products.products.get(&id)?.pointer
+    } else if is_group_reference(document, pointer) {
+        document.products.groups.get(&id)?
+    } else {
+        return None;
+    };
+    let target_span = document.spans.get(target)?;
+    Some(Location::new(uri.clone(), document.range(target_span)))
+}
+
+/// Whether the value at the JSON pointer references a product ID.
+///
+/// Besides the references found in the last parsable version of the document, new elements of
+/// product ID lists are recognized by their property name, since they are typically added while
+/// the document cannot be parsed.
+fn is_product_reference(document: &Document, pointer: &str) -> bool {
+    if document.products.product_references.contains(pointer) {
+        return true;
+    }
+    let mut segments = pointer.rsplit('/');
+    let last = segments.next().unwrap_or_default();
+    let parent = segments.next().unwrap_or_default();
+    let grandparent = segments.next().unwrap_or_default();
+    matches!(last, "product_reference" | "relates_to_product_reference")
+        || (last.parse::<usize>().is_ok()
+            && (matches!(parent, "product_ids" | "products") || grandparent == "product_status"))
+}
+
+/// Whether the value at the JSON pointer references a product group ID.
+fn is_group_reference(document: &Document, pointer: &str) -> bool {
+    if document.products.group_references.contains(pointer) {
+        return true;
+    }
+    let mut segments = pointer.rsplit('/');
+    let last = segments.next().unwrap_or_default();
+    last.parse::<usize>().is_ok() && segments.next() == Some("group_ids")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::tests::DOCUMENT;
+    use csaf::validation::ValidationPreset;
+    use std::str::FromStr;
+
+    fn document() -> Document {
+        Document::new(DOCUMENT.to_string(), ValidationPreset::Basic, None)
+    }
+
+    /// Returns the position of the first character after the first occurrence of `text`.
+    fn position_after(text: &str) -> Position {
+        let offset = DOCUMENT.find(text).unwrap() + text.len();
+        let line = DOCUMENT[..offset].matches('\n').count() as u32;
+        let line_start = DOCUMENT[..offset].rfind('\n').map_or(0, |i| i + 1);
+        Position::new(line, (offset - line_start) as u32)
+    }
+
+    fn hover_text(hover: Hover) -> String {
+        match hover.contents {
+            HoverContents::Markup(markup) => markup.value,
+            _ => panic!("hover is not markup"),
+        }
+    }
+
+    #[test]
+    fn test_hover() {
+        let document = document();
+        let text = hover_text(hover(&document, position_after("\"tlp\": { \"la")).unwrap());
+        assert!(text.starts_with("**Label of TLP**"));
+        assert!(text.contains("`AMBER`"));
+
+        let text = hover_text(hover(&document, position_after("\"known_affected\": [\"CSAF")).unwrap());
+        assert!(text.contains("Product `CSAFPID-0001`: Product A"));
+    }
+
+    #[test]
+    fn test_completion() {
+        let document = document();
+        let items = completion(&document, position_after("{ \"category\": \"vendor"));
+        assert!(items.iter().any(|item| item.label == "workaround"));
+
+        let items = completion(&document, position_after("\"known_affected\": [\"CSAF"));
+        let labels: Vec<&str> = items.iter().map(|item| item.label.as_str()).collect();
+        assert_eq!(labels, vec!["CSAFPID-0001", "CSAFPID-0002"]);
+        assert_eq!(items[0].detail.as_deref(), Some("Product A"));
+
+        let items = completion(&document, position_after("\"group_ids\": [\"CSAF"));
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].label, "CSAFGID-0001");
+
+        assert!(completion(&document, position_after("\"title\": \"Language")).is_empty());
+    }
+
+    #[test]
+    fn test_definition() {
+        let document = document();
+        let uri = Uri::from_str("file:///advisory.json").unwrap();
+
+        let location = definition(&document, &uri, position_after("\"known_affected\": [\"CSAF")).unwrap();
+        assert_eq!(location.range.start, Position::new(24, 6));
+
+        let location = definition(&document, &uri, position_after("\"group_ids\": [\"CSAF")).unwrap();
+        assert_eq!(location.range.start, Position::new(28, 6));
+
+        // Undefined product IDs have no definition
+        assert_eq!(definition(&document, &uri, position_after("\"CSAFPID-9")), None);
+    }
+}
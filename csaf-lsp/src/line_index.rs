@@ -0,0 +1,81 @@
+//! Conversion between byte offsets and LSP positions.
+
+use lsp_types::Position;
+
+/// Start offsets of the lines of a text, used to convert byte offsets into LSP positions and back.
+///
+/// LSP positions count characters in UTF-16 code units, which is the default position encoding of
+/// the protocol.
+#[derive(Debug, Clone)]
+pub struct LineIndex {
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+    pub fn new(text: &str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+        LineIndex { line_starts }
+    }
+
+    /// Returns the position of the byte offset in the text.
+    pub fn position(&self, text: &str, offset: usize) -> Position {
+        let offset = offset.min(text.len());
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        let line_start = self.line_starts[line];
+        let character = text[line_start..offset].encode_utf16().count();
+        Position::new(line as u32, character as u32)
+    }
+
+    /// Returns the byte offset of the position in the text.
+    ///
+    /// Positions after the end of a line are moved to the end of the line.
+    pub fn offset(&self, text: &str, position: Position) -> usize {
+        let Some(&line_start) = self.line_starts.get(position.line as usize) else {
+            return text.len();
+        };
+        let line_end = self
+            .line_starts
+            .get(position.line as usize + 1)
+            .map_or(text.len(), |next_start| next_start - 1);
+
+        let mut remaining = position.character as usize;
+        for (index, c) in text[line_start..line_end].char_indices() {
+            if remaining == 0 {
+                return line_start + index;
+            }
+            remaining = remaining.saturating_sub(c.len_utf16());
+        }
+        line_end
+    }
+
+    /// Returns the byte offset at which the line ends, excluding the line break.
+    pub fn line_end(&self, text: &str, offset: usize) -> usize {
+        let line = self.line_starts.partition_point(|&start| start <= offset);
+        self.line_starts
+            .get(line)
+            .map_or(text.len(), |next_start| next_start - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_positions() {
+        let text = "{\n  \"title\": \"𝔘ber\",\n  \"x\": 1\n}";
+        let index = LineIndex::new(text);
+
+        let offset = text.find("ber").unwrap();
+        // The mathematical U is a single character, but two UTF-16 code units
+        assert_eq!(index.position(text, offset), Position::new(1, 14));
+        assert_eq!(index.offset(text, Position::new(1, 14)), offset);
+
+        assert_eq!(index.position(text, 0), Position::new(0, 0));
+        assert_eq!(index.offset(text, Position::new(2, 100)), text.find("1\n").unwrap() + 1);
+        assert_eq!(index.offset(text, Position::new(10, 0)), text.len());
+        assert_eq!(index.line_end(text, offset), text.find(",\n").unwrap() + 1);
+    }
+}
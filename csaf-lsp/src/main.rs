@@ -0,0 +1,319 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use csaf::validation::ValidationPreset;
+use document::Document;
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, LogMessage, Notification as _, PublishDiagnostics,
+};
+use lsp_types::request::{Completion, GotoDefinition, HoverRequest, Request as _};
+use lsp_types::{
+    CompletionOptions, CompletionParams, CompletionResponse, DidChangeTextDocumentParams, DidCloseTextDocumentParams,
+    DidOpenTextDocumentParams, GotoDefinitionParams, GotoDefinitionResponse, HoverParams, HoverProviderCapability,
+    InitializeParams, LogMessageParams, MessageType, OneOf, PublishDiagnosticsParams, ServerCapabilities,
+    TextDocumentSyncCapability, TextDocumentSyncKind, Uri,
+};
+use serde::de::DeserializeOwned;
+
+mod document;
+mod features;
+mod line_index;
+
+/// Time without further changes after which a changed document is analyzed
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// A language server for CSAF documents, communicating over stdio.
+///
+/// The validation preset used for diagnostics can be set with the initialization option `preset`
+/// (`basic`, `extended` or `full`), it defaults to `basic`.
+fn main() -> Result<()> {
+    let (connection, io_threads) = Connection::stdio();
+
+    let capabilities = serde_json::to_value(server_capabilities())?;
+    let params: InitializeParams = serde_json::from_value(connection.initialize(capabilities)?)?;
+    let preset = params
+        .initialization_options
+        .as_ref()
+        .and_then(|options| options.get("preset"))
+        .and_then(|preset| preset.as_str())
+        .map(|preset| {
+            ValidationPreset::from_str(preset).map_err(|_| anyhow::anyhow!("Invalid validation preset: {preset}"))
+        })
+        .transpose()?
+        .unwrap_or(ValidationPreset::Basic);
+
+    // The server owns the connection, which must be dropped before joining the I/O threads
+    Server::new(connection, preset).run()?;
+
+    io_threads.join()?;
+    Ok(())
+}
+
+fn server_capabilities() -> ServerCapabilities {
+    ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(vec!["\"".to_string()]),
+            ..Default::default()
+        }),
+        definition_provider: Some(OneOf::Left(true)),
+        ..Default::default()
+    }
+}
+
+struct Server {
+    connection: Connection,
+    preset: ValidationPreset,
+    documents: HashMap<Uri, Document>,
+    /// Version and text of changed documents that are not analyzed yet
+    pending: HashMap<Uri, (i32, String)>,
+    /// When the pending documents are analyzed, [DEBOUNCE] after the last change
+    deadline: Option<Instant>,
+}
+
+impl Server {
+    fn new(connection: Connection, preset: ValidationPreset) -> Self {
+        Server {
+            connection,
+            preset,
+            documents: HashMap::new(),
+            pending: HashMap::new(),
+            deadline: None,
+        }
+    }
+
+    /// Handles messages until the client shuts the server down.
+    ///
+    /// Changed documents are analyzed once the client stops sending changes for [DEBOUNCE], or
+    /// before the next request, so that typing is not slowed down by the validation.
+    fn run(mut self) -> Result<()> {
+        loop {
+            let message = match self.deadline {
+                Some(deadline) => {
+                    let timeout = deadline.saturating_duration_since(Instant::now());
+                    match self.connection.receiver.recv_timeout(timeout) {
+                        Ok(message) => message,
+                        Err(err) if err.is_timeout() => {
+                            self.analyze_pending()?;
+                            continue;
+                        },
+                        Err(_) => break,
+                    }
+                },
+                None => match self.connection.receiver.recv() {
+                    Ok(message) => message,
+                    Err(_) => break,
+                },
+            };
+            match message {
+                Message::Request(request) => {
+                    if self.connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    // Requests are answered for the current text of the documents
+                    self.analyze_pending()?;
+                    let response = self.handle_request(request);
+                    self.connection.sender.send(Message::Response(response))?;
+                },
+                Message::Notification(notification) => self.handle_notification(notification)?,
+                Message::Response(_) => {},
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_request(&self, request: Request) -> Response {
+        match request.method.as_str() {
+            HoverRequest::METHOD => respond(request, |params: HoverParams| {
+                let position = params.text_document_position_params;
+                self.documents
+                    .get(&position.text_document.uri)
+                    .and_then(|document| features::hover(document, position.position))
+            }),
+            Completion::METHOD => respond(request, |params: CompletionParams| {
+                let position = params.text_document_position;
+                self.documents
+                    .get(&position.text_document.uri)
+                    .map(|document| CompletionResponse::Array(features::completion(document, position.position)))
+            }),
+            GotoDefinition::METHOD => respond(request, |params: GotoDefinitionParams| {
+                let position = params.text_document_position_params;
+                let uri = position.text_document.uri;
+                self.documents
+                    .get(&uri)
+                    .and_then(|document| features::definition(document, &uri, position.position))
+                    .map(GotoDefinitionResponse::Scalar)
+            }),
+            method => Response::new_err(
+                request.id.clone(),
+                ErrorCode::MethodNotFound as i32,
+                format!("Unsupported request {method}"),
+            ),
+        }
+    }
+
+    /// Handles a notification, notifications with invalid parameters are logged and ignored.
+    fn handle_notification(&mut self, notification: Notification) -> Result<()> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let Some(params) = self.params::<DidOpenTextDocumentParams>(notification)? else {
+                    return Ok(());
+                };
+                let document = params.text_document;
+                self.pending.remove(&document.uri);
+                self.update(document.uri, document.version, document.text)
+            },
+            DidChangeTextDocument::METHOD => {
+                let Some(params) = self.params::<DidChangeTextDocumentParams>(notification)? else {
+                    return Ok(());
+                };
+                // With full synchronization, the last change contains the whole text
+                if let Some(change) = params.content_changes.into_iter().last() {
+                    let document = params.text_document;
+                    self.pending.insert(document.uri, (document.version, change.text));
+                    self.deadline = Some(Instant::now() + DEBOUNCE);
+                }
+                Ok(())
+            },
+            DidCloseTextDocument::METHOD => {
+                let Some(params) = self.params::<DidCloseTextDocumentParams>(notification)? else {
+                    return Ok(());
+                };
+                self.pending.remove(&params.text_document.uri);
+                self.documents.remove(&params.text_document.uri);
+                self.publish_diagnostics(params.text_document.uri, vec![], None)
+            },
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the parameters of a notification, or `None` after logging why they are invalid.
+    fn params<P: DeserializeOwned>(&self, notification: Notification) -> Result<Option<P>> {
+        match serde_json::from_value(notification.params) {
+            Ok(params) => Ok(Some(params)),
+            Err(err) => {
+                self.log_message(format!("Invalid parameters of {}: {err}", notification.method))?;
+                Ok(None)
+            },
+        }
+    }
+
+    /// Analyzes the changed documents.
+    fn analyze_pending(&mut self) -> Result<()> {
+        self.deadline = None;
+        for (uri, (version, text)) in std::mem::take(&mut self.pending) {
+            self.update(uri, version, text)?;
+        }
+        Ok(())
+    }
+
+    /// Analyzes the new text of a document and publishes its diagnostics.
+    fn update(&mut self, uri: Uri, version: i32, text: String) -> Result<()> {
+        let previous = self.documents.remove(&uri);
+        let document = Document::new(text, self.preset, previous);
+        let diagnostics = document.diagnostics.clone();
+        self.documents.insert(uri.clone(), document);
+        self.publish_diagnostics(uri, diagnostics, Some(version))
+    }
+
+    fn publish_diagnostics(
+        &self,
+        uri: Uri,
+        diagnostics: Vec<lsp_types::Diagnostic>,
+        version: Option<i32>,
+    ) -> Result<()> {
+        let params = PublishDiagnosticsParams {
+            uri,
+            diagnostics,
+            version,
+        };
+        let notification = Notification::new(PublishDiagnostics::METHOD.to_string(), params);
+        self.connection.sender.send(Message::Notification(notification))?;
+        Ok(())
+    }
+
+    /// Shows an error in the log of the client.
+    fn log_message(&self, message: String) -> Result<()> {
+        let params = LogMessageParams {
+            typ: MessageType::ERROR,
+            message,
+        };
+        let notification = Notification::new(LogMessage::METHOD.to_string(), params);
+        self.connection.sender.send(Message::Notification(notification))?;
+        Ok(())
+    }
+}
+
+/// Responds to a request with the result of `handle` for its parameters.
+fn respond<P, R>(request: Request, handle: impl FnOnce(P) -> R) -> Response
+where
+    P: DeserializeOwned,
+    R: serde::Serialize,
+{
+    match serde_json::from_value(request.params) {
+        Ok(params) => Response::new_ok(request.id, handle(params)),
+        Err(err) => Response::new_err(request.id, ErrorCode::InvalidParams as i32, err.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn notification(method: &str, params: serde_json::Value) -> Message {
+        Message::Notification(Notification::new(method.to_string(), params))
+    }
+
+    fn did_change(version: i32, text: &str) -> Message {
+        notification(
+            DidChangeTextDocument::METHOD,
+            json!({
+                "textDocument": { "uri": "file:///advisory.json", "version": version },
+                "contentChanges": [{ "text": text }]
+            }),
+        )
+    }
+
+    #[test]
+    fn test_notifications() {
+        let (connection, client) = Connection::memory();
+        let server = std::thread::spawn(move || Server::new(connection, ValidationPreset::Basic).run());
+
+        // Invalid parameters are logged instead of stopping the server
+        client
+            .sender
+            .send(notification(DidOpenTextDocument::METHOD, json!({ "textDocument": 1 })))
+            .unwrap();
+        let Message::Notification(log) = client.receiver.recv().unwrap() else {
+            panic!("expected a notification");
+        };
+        assert_eq!(log.method, LogMessage::METHOD);
+
+        // Quick changes are analyzed once, for the last version
+        client.sender.send(did_change(1, "{")).unwrap();
+        client.sender.send(did_change(2, "{}")).unwrap();
+        let Message::Notification(published) = client.receiver.recv_timeout(Duration::from_secs(10)).unwrap() else {
+            panic!("expected a notification");
+        };
+        let params: PublishDiagnosticsParams = serde_json::from_value(published.params).unwrap();
+        assert_eq!(params.version, Some(2));
+        assert!(client.receiver.recv_timeout(DEBOUNCE * 2).is_err());
+
+        // Deeply nested text is a syntax error and does not overflow the stack
+        client.sender.send(did_change(3, &"[".repeat(200_000))).unwrap();
+        let Message::Notification(published) = client.receiver.recv_timeout(Duration::from_secs(10)).unwrap() else {
+            panic!("expected a notification");
+        };
+        let params: PublishDiagnosticsParams = serde_json::from_value(published.params).unwrap();
+        assert_eq!(params.version, Some(3));
+        assert_eq!(params.diagnostics.len(), 1);
+
+        drop(client);
+        server.join().unwrap().unwrap();
+    }
+}
@@ -1,5 +1,7 @@
+use proc_macro2::{TokenStream, TokenTree};
 use quote::quote;
 use serde_json::Value;
+use std::collections::BTreeSet;
 use std::path::Path;
 use std::string::ToString;
 use std::{fs, io};
@@ -16,6 +18,8 @@ pub enum BuildError {
     SyntaxError(#[from] syn::Error),
     #[error("JSON parsing error")]
     JsonError(#[from] serde_json::Error),
+    #[error("validators not referenced by the test registry of their CSAF version: {0:?}")]
+    UnreachableValidators(Vec<String>),
     #[error("other error")]
     Other,
 }
@@ -49,6 +53,8 @@ fn main() -> Result<(), BuildError> {
 
     generate_language_subtags()?;
 
+    check_test_registry()?;
+
     Ok(())
 }
 
@@ -104,47 +110,84 @@ fn build(input: &str, output: &str, schema_patch: &Option<&dyn Fn(&mut Value)>)
 /// Compile-time-embedded language-subtag-registry.txt
 const LANGUAGE_REGISTRY: &str = include_str!("assets/language-subtag-registry.txt");
 
-/// Generates the language subtags array from the build-embedded text file.
+/// Generates the subtag arrays from the build-embedded text file.
+///
+/// Subtags are lower cased and sorted for binary search. Ranges of private use subtags (like
+/// `qaa..qtz`) are not expanded, but listed with their type in `PRIVATE_USE_RANGES`.
 fn generate_language_subtags() -> Result<(), BuildError> {
-    let mut subtags = Vec::new();
-    let mut current_entry_type = None;
-
-    for line in LANGUAGE_REGISTRY.lines() {
-        let line = line.trim();
+    let mut subtags: std::collections::BTreeMap<&str, Vec<String>> = std::collections::BTreeMap::new();
+    let mut private_use_ranges = Vec::new();
 
-        if line.is_empty() || line.starts_with("%%") {
-            current_entry_type = None;
-            continue;
+    for record in LANGUAGE_REGISTRY.split("%%") {
+        let mut entry_type = None;
+        let mut subtag = None;
+        let mut private_use = false;
+        for line in record.lines() {
+            if let Some(value) = line.strip_prefix("Type: ") {
+                entry_type = Some(value.trim());
+            } else if let Some(value) = line.strip_prefix("Subtag: ").or_else(|| line.strip_prefix("Tag: ")) {
+                subtag = Some(value.trim().to_lowercase());
+            } else if line.trim() == "Description: Private use" {
+                private_use = true;
+            }
         }
-
-        if let Some(type_value) = line.strip_prefix("Type: ") {
-            current_entry_type = Some(type_value.to_string());
+        let (Some(entry_type), Some(subtag)) = (entry_type, subtag) else {
             continue;
+        };
+        if private_use {
+            let (first, last) = subtag.split_once("..").unwrap_or((&subtag, &subtag));
+            private_use_ranges.push((entry_type.to_string(), first.to_string(), last.to_string()));
         }
-
-        if let Some(ref entry_type) = current_entry_type
-            && entry_type == "language"
-            && let Some(subtag) = line.strip_prefix("Subtag: ")
-        {
-            subtags.push(subtag.to_lowercase().to_string());
+        if !subtag.contains("..") {
+            subtags.entry(entry_type).or_default().push(subtag);
         }
     }
 
-    subtags.sort_unstable();
-
-    let subtags_iter = subtags.iter().map(|s| s.as_str());
+    let mut arrays = Vec::new();
+    for (entry_type, name) in [
+        ("language", "LANGUAGE_SUBTAGS_ARRAY"),
+        ("extlang", "EXTLANG_SUBTAGS_ARRAY"),
+        ("script", "SCRIPT_SUBTAGS_ARRAY"),
+        ("region", "REGION_SUBTAGS_ARRAY"),
+        ("variant", "VARIANT_SUBTAGS_ARRAY"),
+        ("grandfathered", "GRANDFATHERED_TAGS_ARRAY"),
+    ] {
+        let mut values = subtags.remove(entry_type).unwrap_or_default();
+        values.sort_unstable();
+        let ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+        let doc = format!(" Registered {entry_type} subtags, lower cased and sorted");
+        arrays.push(quote! {
+            #[doc = #doc]
+            pub static #ident: &[&str] = &[#(#values),*];
+        });
+    }
+    let range_types = private_use_ranges.iter().map(|(entry_type, _, _)| entry_type);
+    let range_firsts = private_use_ranges.iter().map(|(_, first, _)| first);
+    let range_lasts = private_use_ranges.iter().map(|(_, _, last)| last);
 
     let tokens = quote! {
         #![doc = #GENERATED_CODE_HEADER]
-        pub static LANGUAGE_SUBTAGS_ARRAY: &[&str] = &[
-            #(#subtags_iter),*
+        #(#arrays)*
+
+        /// Subtags reserved for private use as (type, first, last), lower cased
+        pub static PRIVATE_USE_RANGES: &[(&str, &str, &str)] = &[
+            #((#range_types, #range_firsts, #range_lasts)),*
         ];
 
         /// Checks if a given subtag is a valid language subtag.
         /// Lower cases the input before checking.
         pub fn is_valid_language_subtag(subtag: &str) -> bool {
             let sub = &subtag.to_lowercase();
-            LANGUAGE_SUBTAGS_ARRAY.binary_search(&sub.as_str()).is_ok()
+            LANGUAGE_SUBTAGS_ARRAY.binary_search(&sub.as_str()).is_ok() || is_private_use_subtag("language", sub)
+        }
+
+        /// Checks if a subtag of the given type (like `language` or `region`) is reserved for private use.
+        /// Lower cases the input before checking.
+        pub fn is_private_use_subtag(subtag_type: &str, subtag: &str) -> bool {
+            let sub = subtag.to_lowercase();
+            PRIVATE_USE_RANGES.iter().any(|(range_type, first, last)| {
+                *range_type == subtag_type && sub.len() == first.len() && *first <= sub.as_str() && sub.as_str() <= *last
+            })
         }
     };
 
@@ -158,6 +201,74 @@ fn generate_language_subtags() -> Result<(), BuildError> {
     let out_path = Path::new("src").join("generated").join("language_subtags.rs");
     fs::write(&out_path, code)?;
 
-    println!("cargo:rerun-if-changed=../assets/language-subtag-registry.txt");
+    println!("cargo:rerun-if-changed=assets/language-subtag-registry.txt");
     Ok(())
 }
+
+/// CSAF versions with a test registry, as used in module names
+const REGISTRY_VERSIONS: [&str; 2] = ["csaf2_0", "csaf2_1"];
+
+/// Checks that every `TestValidator` implementation in `src/validations` is referenced by the test
+/// registry in `src/<VERSION>/validation.rs`, so implemented tests cannot be left unwired.
+fn check_test_registry() -> Result<(), BuildError> {
+    println!("cargo:rerun-if-changed=src/validations");
+    for version in REGISTRY_VERSIONS {
+        println!("cargo:rerun-if-changed=src/{version}/validation.rs");
+    }
+
+    let mut implemented = BTreeSet::new();
+    for entry in fs::read_dir("src/validations")? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|extension| extension == "rs") {
+            let tokens = tokenize(&path)?;
+            // Implementations are written as `impl TestValidator<..> for crate::<VERSION>::testcases::<VALIDATOR>`
+            for window in tokens.windows(7) {
+                if let [version, _, _, testcases, _, _, validator] = window
+                    && REGISTRY_VERSIONS.contains(&version.as_str())
+                    && testcases == "testcases"
+                    && validator.starts_with("ValidatorForTest")
+                {
+                    implemented.insert((version.clone(), validator.clone()));
+                }
+            }
+        }
+    }
+
+    let mut unreachable = Vec::new();
+    for version in REGISTRY_VERSIONS {
+        let registered: BTreeSet<String> = tokenize(&Path::new("src").join(version).join("validation.rs"))?
+            .into_iter()
+            .filter(|token| token.starts_with("ValidatorForTest"))
+            .collect();
+        for (_, validator) in implemented.iter().filter(|(v, _)| v == version) {
+            if !registered.contains(validator) {
+                unreachable.push(format!("{version}::{validator}"));
+            }
+        }
+    }
+
+    if unreachable.is_empty() {
+        Ok(())
+    } else {
+        Err(BuildError::UnreachableValidators(unreachable))
+    }
+}
+
+/// Returns the identifiers and punctuation of a Rust source file, without comments.
+fn tokenize(path: &Path) -> Result<Vec<String>, BuildError> {
+    fn flatten(stream: TokenStream, tokens: &mut Vec<String>) {
+        for tree in stream {
+            match tree {
+                TokenTree::Group(group) => flatten(group.stream(), tokens),
+                TokenTree::Ident(ident) => tokens.push(ident.to_string()),
+                TokenTree::Punct(punct) => tokens.push(punct.as_char().to_string()),
+                TokenTree::Literal(_) => {},
+            }
+        }
+    }
+
+    let stream: TokenStream = fs::read_to_string(path)?.parse().map_err(syn::Error::from)?;
+    let mut tokens = Vec::new();
+    flatten(stream, &mut tokens);
+    Ok(tokens)
+}
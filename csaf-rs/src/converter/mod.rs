@@ -1,3 +1,7 @@
+mod v2_0_to_v2_1;
+
+pub use v2_0_to_v2_1::*;
+
 use crate::csaf_traits::{CsafTrait, CsafVersion, DocumentTrait};
 
 /// Checks if a CSAF document is version 2.0
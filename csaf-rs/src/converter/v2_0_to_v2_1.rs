@@ -0,0 +1,536 @@
+use crate::csaf::raw::{HasParsed, RawDocument};
+use crate::csaf::types::csaf_datetime::CsafDateTime;
+use crate::csaf_traits::{CsafTrait, DocumentTrait, TrackingTrait};
+use crate::helpers::{CWE_ENTRIES, get_latest_cwe_version};
+use crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+use crate::schema::csaf2_1::schema::{CommonSecurityAdvisoryFramework as Csaf21, LabelOfTlp};
+use chrono::NaiveDate;
+use serde::Serialize;
+use serde_json::{Map, Value, json};
+use std::fmt::{Display, Formatter};
+
+/// The value of `/$schema` in converted documents.
+pub const CSAF_2_1_SCHEMA_URL: &str = "https://docs.oasis-open.org/csaf/csaf/v2.1/schema/csaf.json";
+
+/// Options influencing the conversion from CSAF 2.0 to CSAF 2.1.
+#[derive(Debug, Clone, Default)]
+pub struct ConverterOptions {
+    /// TLP label that is set if the CSAF 2.0 document does not carry one.
+    ///
+    /// CSAF 2.1 requires `/document/distribution/tlp/label`. As the label cannot be derived from
+    /// the document itself, the conversion fails if this is `None` and the label is missing.
+    pub default_tlp_label: Option<LabelOfTlp>,
+}
+
+/// Kind of a conversion step that could not be performed as a one-to-one mapping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ConversionNoteKind {
+    /// Information of the CSAF 2.0 document was dropped.
+    Lossy,
+    /// A value was derived or guessed, as CSAF 2.0 does not contain the required information.
+    Heuristic,
+}
+
+impl Display for ConversionNoteKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConversionNoteKind::Lossy => write!(f, "lossy"),
+            ConversionNoteKind::Heuristic => write!(f, "heuristic"),
+        }
+    }
+}
+
+/// A lossy or heuristic step that was taken during the conversion.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConversionNote {
+    pub kind: ConversionNoteKind,
+    pub message: String,
+    /// JSON pointer into the original CSAF 2.0 document
+    pub instance_path: String,
+}
+
+impl Display for ConversionNote {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} [{}]: {}", self.kind, self.instance_path, self.message)
+    }
+}
+
+/// Errors that prevent a CSAF 2.0 document from being converted.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ConversionError {
+    #[error("the input is not a valid CSAF 2.0 document: {0}")]
+    InvalidInput(String),
+    #[error("the document has no TLP label and no default TLP label was configured")]
+    MissingTlpLabel,
+    #[error("the converted document is not a valid CSAF 2.1 document: {0}")]
+    InvalidOutput(String),
+}
+
+/// Result of a successful conversion.
+pub struct Conversion {
+    /// The converted CSAF 2.1 document. Key order of the original document is preserved.
+    pub document: RawDocument<Csaf21>,
+    /// All lossy or heuristic steps taken during the conversion
+    pub notes: Vec<ConversionNote>,
+}
+
+/// Converts a CSAF 2.0 document into a CSAF 2.1 document.
+///
+/// The conversion follows the rules for CSAF 2.1 converters:
+/// - `/$schema` is set and `/document/csaf_version` is changed to `2.1`
+/// - TLP label `WHITE` becomes `CLEAR`, a missing label is replaced with the configured default
+/// - `product_identification_helper/purl` becomes `purls`
+/// - the removed branch category `legacy` becomes `product_family`
+/// - `/vulnerabilities[]/cwe` becomes `cwes`, with the CWE version derived from the current release date
+/// - `/vulnerabilities[]/release_date` becomes `disclosure_date`
+/// - `/vulnerabilities[]/scores` becomes `metrics`
+pub fn convert_2_0_to_2_1(
+    doc: &RawDocument<Csaf20>,
+    options: &ConverterOptions,
+) -> Result<Conversion, ConversionError> {
+    let parsed = doc
+        .get_parsed()
+        .as_ref()
+        .map_err(|e| ConversionError::InvalidInput(e.clone()))?;
+    let cwe_date = match parsed.get_document().get_tracking().get_current_release_date() {
+        CsafDateTime::Valid(date) => Some(date.get_as_utc().date_naive()),
+        CsafDateTime::Invalid(_) => None,
+    };
+
+    let mut converter = Converter {
+        options,
+        cwe_date,
+        notes: Vec::new(),
+    };
+    let mut json = doc.get_json().clone();
+    converter.convert_root(&mut json)?;
+
+    let document = RawDocument::<Csaf21>::new(json);
+    if let Err(e) = document.get_parsed() {
+        return Err(ConversionError::InvalidOutput(e.clone()));
+    }
+
+    Ok(Conversion {
+        document,
+        notes: converter.notes,
+    })
+}
+
+struct Converter<'a> {
+    options: &'a ConverterOptions,
+    cwe_date: Option<NaiveDate>,
+    notes: Vec<ConversionNote>,
+}
+
+impl Converter<'_> {
+    fn note(&mut self, kind: ConversionNoteKind, instance_path: String, message: String) {
+        self.notes.push(ConversionNote {
+            kind,
+            message,
+            instance_path,
+        });
+    }
+
+    fn convert_root(&mut self, json: &mut Value) -> Result<(), ConversionError> {
+        let root = json
+            .as_object_mut()
+            .ok_or_else(|| ConversionError::InvalidInput("document is not a JSON object".to_string()))?;
+
+        edit_object(root, |root| {
+            root.insert("$schema".to_string(), Value::String(CSAF_2_1_SCHEMA_URL.to_string()));
+        });
+
+        if let Some(document) = root.get_mut("document").and_then(Value::as_object_mut) {
+            document.insert("csaf_version".to_string(), Value::String("2.1".to_string()));
+            self.convert_distribution(document)?;
+        }
+
+        if let Some(product_tree) = root.get_mut("product_tree").and_then(Value::as_object_mut) {
+            self.convert_product_tree(product_tree);
+        }
+
+        if let Some(vulnerabilities) = root.get_mut("vulnerabilities").and_then(Value::as_array_mut) {
+            for (i_v, vulnerability) in vulnerabilities.iter_mut().enumerate() {
+                if let Some(vulnerability) = vulnerability.as_object_mut() {
+                    self.convert_vulnerability(vulnerability, &format!("/vulnerabilities/{i_v}"));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn convert_distribution(&mut self, document: &mut Map<String, Value>) -> Result<(), ConversionError> {
+        let label = document
+            .get("distribution")
+            .and_then(|d| d.get("tlp"))
+            .and_then(|t| t.get("label"))
+            .and_then(Value::as_str)
+            .map(str::to_string);
+
+        let new_label = match label.as_deref() {
+            Some("WHITE") => LabelOfTlp::Clear.to_string(),
+            Some(label) => label.to_string(),
+            None => {
+                let default_label = self.options.default_tlp_label.ok_or(ConversionError::MissingTlpLabel)?;
+                self.note(
+                    ConversionNoteKind::Heuristic,
+                    "/document/distribution/tlp/label".to_string(),
+                    format!("No TLP label given, the configured default TLP:{default_label} was used"),
+                );
+                default_label.to_string()
+            },
+        };
+
+        edit_object(document, |document| {
+            let distribution = document
+                .entry("distribution")
+                .or_insert_with(|| Value::Object(Map::new()));
+            if let Some(distribution) = distribution.as_object_mut() {
+                edit_object(distribution, |distribution| {
+                    let tlp = distribution.entry("tlp").or_insert_with(|| Value::Object(Map::new()));
+                    if let Some(tlp) = tlp.as_object_mut() {
+                        edit_object(tlp, |tlp| {
+                            tlp.insert("label".to_string(), Value::String(new_label));
+                        });
+                    }
+                });
+            }
+        });
+
+        Ok(())
+    }
+
+    fn convert_product_tree(&mut self, product_tree: &mut Map<String, Value>) {
+        if let Some(branches) = product_tree.get_mut("branches").and_then(Value::as_array_mut) {
+            self.convert_branches(branches, "/product_tree/branches");
+        }
+        if let Some(full_product_names) = product_tree.get_mut("full_product_names").and_then(Value::as_array_mut) {
+            for fpn in full_product_names.iter_mut().filter_map(Value::as_object_mut) {
+                convert_full_product_name(fpn);
+            }
+        }
+        if let Some(relationships) = product_tree.get_mut("relationships").and_then(Value::as_array_mut) {
+            for relationship in relationships.iter_mut().filter_map(Value::as_object_mut) {
+                if let Some(fpn) = relationship.get_mut("full_product_name").and_then(Value::as_object_mut) {
+                    convert_full_product_name(fpn);
+                }
+            }
+        }
+    }
+
+    fn convert_branches(&mut self, branches: &mut [Value], path: &str) {
+        for (i_b, branch) in branches.iter_mut().enumerate() {
+            let Some(branch) = branch.as_object_mut() else {
+                continue;
+            };
+            let branch_path = format!("{path}/{i_b}");
+            if branch.get("category").and_then(Value::as_str) == Some("legacy") {
+                branch.insert("category".to_string(), Value::String("product_family".to_string()));
+                self.note(
+                    ConversionNoteKind::Heuristic,
+                    format!("{branch_path}/category"),
+                    "The branch category 'legacy' was removed in CSAF 2.1 and was replaced with 'product_family'"
+                        .to_string(),
+                );
+            }
+            if let Some(product) = branch.get_mut("product").and_then(Value::as_object_mut) {
+                convert_full_product_name(product);
+            }
+            if let Some(children) = branch.get_mut("branches").and_then(Value::as_array_mut) {
+                self.convert_branches(children, &format!("{branch_path}/branches"));
+            }
+        }
+    }
+
+    fn convert_vulnerability(&mut self, vulnerability: &mut Map<String, Value>, path: &str) {
+        let cwes = vulnerability
+            .get("cwe")
+            .and_then(|cwe| self.convert_cwe(cwe, &format!("{path}/cwe")));
+        let metrics = vulnerability.get("scores").and_then(Value::as_array).map(|scores| {
+            scores
+                .iter()
+                .filter_map(Value::as_object)
+                .map(convert_score)
+                .collect::<Vec<Value>>()
+        });
+
+        edit_object(vulnerability, |vulnerability| {
+            if let Some(release_date) = vulnerability.shift_remove("release_date") {
+                vulnerability.insert("disclosure_date".to_string(), release_date);
+            }
+            if vulnerability.shift_remove("cwe").is_some()
+                && let Some(cwes) = cwes
+            {
+                vulnerability.insert("cwes".to_string(), Value::Array(vec![cwes]));
+            }
+            if vulnerability.shift_remove("scores").is_some()
+                && let Some(metrics) = metrics
+            {
+                vulnerability.insert("metrics".to_string(), Value::Array(metrics));
+            }
+        });
+    }
+
+    /// Converts a CSAF 2.0 CWE object into a CSAF 2.1 CWE object, which additionally requires a version.
+    ///
+    /// The version is the latest CWE version released before the current release date of the document
+    /// (the same version test 6.1.11 assumes for CSAF 2.0). If the name does not match in that version,
+    /// the latest version with a matching name is used instead.
+    fn convert_cwe(&mut self, cwe: &Value, path: &str) -> Option<Value> {
+        let id = cwe.get("id").and_then(Value::as_str)?;
+        let name = cwe.get("name").and_then(Value::as_str)?;
+
+        let assumed_version = get_latest_cwe_version(self.cwe_date).or_else(|| get_latest_cwe_version(None))?;
+        if CWE_ENTRIES[assumed_version].1.get(id).map(String::as_str) == Some(name) {
+            return Some(json!({ "id": id, "name": name, "version": assumed_version }));
+        }
+
+        // Look for the latest version in which the weakness had the given name
+        let matching_version = CWE_ENTRIES
+            .iter()
+            .filter(|(_, (_, entries))| entries.get(id).map(String::as_str) == Some(name))
+            .max_by_key(|(_, (release_date, _))| *release_date)
+            .map(|(version, _)| version);
+        if let Some(version) = matching_version {
+            self.note(
+                ConversionNoteKind::Heuristic,
+                format!("{path}/name"),
+                format!(
+                    "Weakness '{id}' does not have this name in CWE version {assumed_version}, used CWE version {version} instead"
+                ),
+            );
+            return Some(json!({ "id": id, "name": name, "version": version }));
+        }
+
+        // Fall back to the official name of the weakness in the assumed version
+        if let Some(official_name) = CWE_ENTRIES[assumed_version].1.get(id) {
+            self.note(
+                ConversionNoteKind::Heuristic,
+                format!("{path}/name"),
+                format!("Unknown name for weakness '{id}', replaced with its name in CWE version {assumed_version}"),
+            );
+            return Some(json!({ "id": id, "name": official_name, "version": assumed_version }));
+        }
+
+        self.note(
+            ConversionNoteKind::Lossy,
+            path.to_string(),
+            format!("Weakness '{id}' does not exist in any known CWE version and was removed"),
+        );
+        None
+    }
+}
+
+/// Converts the single `purl` of a product identification helper into the `purls` array.
+fn convert_full_product_name(fpn: &mut Map<String, Value>) {
+    if let Some(helper) = fpn
+        .get_mut("product_identification_helper")
+        .and_then(Value::as_object_mut)
+    {
+        edit_object(helper, |helper| {
+            if let Some(purl) = helper.shift_remove("purl") {
+                helper.insert("purls".to_string(), Value::Array(vec![purl]));
+            }
+        });
+    }
+}
+
+/// Converts a CSAF 2.0 `scores[]` item into a CSAF 2.1 `metrics[]` item.
+fn convert_score(score: &Map<String, Value>) -> Value {
+    let mut content = Map::new();
+    let mut metric = Map::new();
+    for (key, value) in score {
+        match key.as_str() {
+            "cvss_v2" | "cvss_v3" => {
+                content.insert(key.clone(), value.clone());
+            },
+            _ => {
+                metric.insert(key.clone(), value.clone());
+            },
+        }
+    }
+    metric.insert("content".to_string(), Value::Object(content));
+    sort_keys(&mut metric);
+    Value::Object(metric)
+}
+
+/// Applies `edit` to `object` and keeps the keys sorted, if they were sorted before.
+///
+/// Renaming or adding keys would otherwise break documents that satisfy test 6.2.13.
+fn edit_object(object: &mut Map<String, Value>, edit: impl FnOnce(&mut Map<String, Value>)) {
+    let was_sorted = object.keys().zip(object.keys().skip(1)).all(|(a, b)| a <= b);
+    edit(object);
+    if was_sorted {
+        sort_keys(object);
+    }
+}
+
+fn sort_keys(object: &mut Map<String, Value>) {
+    let mut entries: Vec<(String, Value)> = std::mem::take(object).into_iter().collect();
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+    object.extend(entries);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_0::loader::load_document_from_str;
+
+    fn minimal_document(distribution: Value, vulnerabilities: Value) -> RawDocument<Csaf20> {
+        let doc = json!({
+            "document": {
+                "category": "csaf_base",
+                "csaf_version": "2.0",
+                "distribution": distribution,
+                "publisher": {
+                    "category": "vendor",
+                    "name": "Example Company",
+                    "namespace": "https://example.com"
+                },
+                "title": "Conversion test",
+                "tracking": {
+                    "current_release_date": "2024-01-24T10:00:00.000Z",
+                    "id": "CONVERSION-TEST",
+                    "initial_release_date": "2024-01-24T10:00:00.000Z",
+                    "revision_history": [
+                        { "date": "2024-01-24T10:00:00.000Z", "number": "1", "summary": "Initial version." }
+                    ],
+                    "status": "final",
+                    "version": "1"
+                }
+            },
+            "product_tree": {
+                "branches": [{
+                    "category": "legacy",
+                    "name": "Legacy Products",
+                    "branches": [{
+                        "category": "product_version",
+                        "name": "1.0",
+                        "product": {
+                            "name": "Product A 1.0",
+                            "product_id": "CSAFPID-0001",
+                            "product_identification_helper": { "purl": "pkg:generic/product-a@1.0" }
+                        }
+                    }]
+                }]
+            },
+            "vulnerabilities": vulnerabilities
+        });
+        load_document_from_str(&doc.to_string()).unwrap()
+    }
+
+    #[test]
+    fn test_converts_document_and_product_tree() {
+        let doc = minimal_document(json!({ "tlp": { "label": "WHITE" } }), json!([]));
+        let conversion = convert_2_0_to_2_1(&doc, &ConverterOptions::default()).unwrap();
+        let json = conversion.document.get_json();
+
+        assert_eq!(json["$schema"], CSAF_2_1_SCHEMA_URL);
+        assert_eq!(json["document"]["csaf_version"], "2.1");
+        assert_eq!(json["document"]["distribution"]["tlp"]["label"], "CLEAR");
+        assert_eq!(json["product_tree"]["branches"][0]["category"], "product_family");
+        assert_eq!(
+            json["product_tree"]["branches"][0]["branches"][0]["product"]["product_identification_helper"],
+            json!({ "purls": ["pkg:generic/product-a@1.0"] })
+        );
+        assert_eq!(
+            conversion.notes,
+            vec![ConversionNote {
+                kind: ConversionNoteKind::Heuristic,
+                message: "The branch category 'legacy' was removed in CSAF 2.1 and was replaced with 'product_family'"
+                    .to_string(),
+                instance_path: "/product_tree/branches/0/category".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_missing_tlp_label() {
+        let doc = minimal_document(json!({ "text": "Distribute freely." }), json!([]));
+        assert_eq!(
+            convert_2_0_to_2_1(&doc, &ConverterOptions::default()).err(),
+            Some(ConversionError::MissingTlpLabel)
+        );
+
+        let options = ConverterOptions {
+            default_tlp_label: Some(LabelOfTlp::Green),
+        };
+        let conversion = convert_2_0_to_2_1(&doc, &options).unwrap();
+        let json = conversion.document.get_json();
+        assert_eq!(json["document"]["distribution"]["tlp"]["label"], "GREEN");
+        assert_eq!(json["document"]["distribution"]["text"], "Distribute freely.");
+        assert!(
+            conversion
+                .notes
+                .iter()
+                .any(|note| note.instance_path == "/document/distribution/tlp/label")
+        );
+    }
+
+    #[test]
+    fn test_converts_vulnerabilities() {
+        let doc = minimal_document(
+            json!({ "tlp": { "label": "AMBER" } }),
+            json!([{
+                "cve": "CVE-2024-0001",
+                "cwe": { "id": "CWE-79", "name": "Improper Neutralization of Input During Web Page Generation ('Cross-site Scripting')" },
+                "product_status": { "known_affected": ["CSAFPID-0001"] },
+                "release_date": "2024-01-20T10:00:00.000Z",
+                "scores": [{
+                    "cvss_v3": {
+                        "version": "3.1",
+                        "vectorString": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+                        "baseScore": 9.8,
+                        "baseSeverity": "CRITICAL"
+                    },
+                    "products": ["CSAFPID-0001"]
+                }]
+            }]),
+        );
+        let conversion = convert_2_0_to_2_1(&doc, &ConverterOptions::default()).unwrap();
+        let vulnerability = &conversion.document.get_json()["vulnerabilities"][0];
+
+        assert_eq!(vulnerability["disclosure_date"], "2024-01-20T10:00:00.000Z");
+        assert!(vulnerability.get("release_date").is_none());
+        assert!(vulnerability.get("scores").is_none());
+        assert_eq!(vulnerability["metrics"][0]["products"], json!(["CSAFPID-0001"]));
+        assert_eq!(vulnerability["metrics"][0]["content"]["cvss_v3"]["baseScore"], 9.8);
+        assert_eq!(vulnerability["cwes"][0]["id"], "CWE-79");
+        assert_eq!(
+            vulnerability["cwes"][0]["version"].as_str(),
+            get_latest_cwe_version(NaiveDate::from_ymd_opt(2024, 1, 24)).map(String::as_str)
+        );
+
+        // keys of sorted objects stay sorted
+        let keys: Vec<&String> = vulnerability.as_object().unwrap().keys().collect();
+        assert_eq!(
+            keys,
+            vec!["cve", "cwes", "disclosure_date", "metrics", "product_status"]
+        );
+    }
+
+    #[test]
+    fn test_unknown_cwe_is_removed() {
+        let doc = minimal_document(
+            json!({ "tlp": { "label": "RED" } }),
+            json!([{ "cwe": { "id": "CWE-999999", "name": "Does not exist" } }]),
+        );
+        let conversion = convert_2_0_to_2_1(&doc, &ConverterOptions::default()).unwrap();
+
+        assert!(
+            conversion.document.get_json()["vulnerabilities"][0]
+                .get("cwes")
+                .is_none()
+        );
+        let lossy_paths: Vec<&str> = conversion
+            .notes
+            .iter()
+            .filter(|note| note.kind == ConversionNoteKind::Lossy)
+            .map(|note| note.instance_path.as_str())
+            .collect();
+        assert_eq!(lossy_paths, vec!["/vulnerabilities/0/cwe"]);
+    }
+}
@@ -0,0 +1,435 @@
+//! A CSAF document of any supported version.
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::csaf::loader::{DEFAULT_MAX_SIZE, LoadError, check_size, csaf_version, read_file, read_to_string};
+use crate::csaf::raw::RawDocument;
+use crate::csaf::span::SpanIndex;
+use crate::csaf::types::csaf_datetime::CsafDateTime;
+use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
+use crate::csaf_traits::{
+    CsafTrait, CsafVersion, DocumentTrait, ProductTrait, ProductTreeTrait, TrackingTrait, VulnerabilityTrait,
+};
+use crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+use crate::schema::csaf2_1::schema::{CommonSecurityAdvisoryFramework as Csaf21, DocumentStatus};
+use crate::spell_check::SpellChecker;
+use crate::url_resolver::UrlResolver;
+use crate::validation::{
+    TestResult, Validatable, ValidationPreset, ValidationResult, validate_by_preset, validate_by_tests,
+};
+
+/// Source a [CsafDocument] is loaded from.
+pub enum DocumentSource<'a> {
+    /// Path of a JSON file
+    Path(&'a Path),
+    /// A JSON string
+    Str(&'a str),
+    /// A reader providing JSON
+    Reader(&'a mut dyn Read),
+}
+
+impl<'a> From<&'a Path> for DocumentSource<'a> {
+    fn from(path: &'a Path) -> Self {
+        DocumentSource::Path(path)
+    }
+}
+
+impl<'a> From<&'a PathBuf> for DocumentSource<'a> {
+    fn from(path: &'a PathBuf) -> Self {
+        DocumentSource::Path(path)
+    }
+}
+
+impl<'a, R: Read> From<&'a mut R> for DocumentSource<'a> {
+    fn from(reader: &'a mut R) -> Self {
+        DocumentSource::Reader(reader)
+    }
+}
+
+/// A CSAF document, in the version given by its `/document/csaf_version`.
+///
+/// The document keeps a [SpanIndex] of its JSON source, so validation results contain the source
+/// locations of all findings.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CsafDocument {
+    V2_0(RawDocument<Csaf20>),
+    V2_1(RawDocument<Csaf21>),
+}
+
+/// Operation on the parsed document, independent of its version.
+///
+/// Used with [CsafDocument::visit] to write code once for all CSAF versions.
+pub trait CsafVisitor {
+    type Output;
+
+    fn visit(self, doc: &impl CsafTrait) -> Self::Output;
+}
+
+/// Evaluates `$body` with `$doc` bound to the parsed document, whatever its version is.
+///
+/// Returns the parse error if the document does not match its version.
+macro_rules! with_parsed {
+    ($self:expr, $doc:ident => $body:expr) => {
+        match $self {
+            $crate::CsafDocument::V2_0(raw) => $crate::csaf::raw::HasParsed::get_parsed(raw)
+                .as_ref()
+                .map(|$doc| $body)
+                .map_err(String::as_str),
+            $crate::CsafDocument::V2_1(raw) => $crate::csaf::raw::HasParsed::get_parsed(raw)
+                .as_ref()
+                .map(|$doc| $body)
+                .map_err(String::as_str),
+        }
+    };
+}
+pub(crate) use with_parsed;
+
+/// Loads [CsafDocument]s with custom options.
+///
+/// ```
+/// # use csaf::csaf::document::{DocumentSource, Loader};
+/// let json = r#"{"document": {"csaf_version": "2.0"}}"#;
+/// let document = Loader::new()
+///     .version("2.1")
+///     .max_size(1024 * 1024)
+///     .load(DocumentSource::Str(json))?;
+/// assert_eq!(document.version(), "2.1");
+/// # Ok::<(), csaf::csaf::loader::LoadError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Loader {
+    version: Option<String>,
+    max_size: u64,
+}
+
+impl Default for Loader {
+    fn default() -> Self {
+        Loader {
+            version: None,
+            max_size: DEFAULT_MAX_SIZE,
+        }
+    }
+}
+
+impl Loader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads documents as the given CSAF version, regardless of their `/document/csaf_version`.
+    pub fn version(mut self, version: &str) -> Self {
+        self.version = Some(version.to_string());
+        self
+    }
+
+    /// Sets the maximum size of documents in bytes, larger documents are rejected without being
+    /// parsed. Defaults to [DEFAULT_MAX_SIZE].
+    pub fn max_size(mut self, max_size: u64) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Loads a document.
+    ///
+    /// Fails if the source cannot be read, is too large, is not valid JSON or has an unsupported
+    /// version.
+    pub fn load<'a>(&self, source: impl Into<DocumentSource<'a>>) -> Result<CsafDocument, LoadError> {
+        let json_str = match source.into() {
+            DocumentSource::Path(path) => read_file(path, self.max_size)?,
+            DocumentSource::Str(json_str) => {
+                check_size(json_str, self.max_size)?;
+                json_str.to_string()
+            },
+            DocumentSource::Reader(reader) => read_to_string(reader, self.max_size)?,
+        };
+        let json: Value = serde_json::from_str(&json_str)?;
+        let version = match &self.version {
+            Some(version) => version.clone(),
+            None => csaf_version(&json)?.to_string(),
+        };
+
+        let span_index = SpanIndex::new(&json_str);
+        match version.as_str() {
+            "2.0" => Ok(CsafDocument::V2_0(RawDocument::new(json).with_span_index(span_index))),
+            "2.1" => Ok(CsafDocument::V2_1(RawDocument::new(json).with_span_index(span_index))),
+            _ => Err(LoadError::UnsupportedVersion(version)),
+        }
+    }
+}
+
+impl CsafDocument {
+    /// Loads a document, detecting its version from `/document/csaf_version`.
+    ///
+    /// Fails if the source cannot be read, is too large, is not valid JSON or has an unsupported
+    /// version.
+    pub fn load<'a>(source: impl Into<DocumentSource<'a>>) -> Result<Self, LoadError> {
+        Loader::new().load(source)
+    }
+
+    /// Loads a document as the given CSAF version, regardless of its `/document/csaf_version`.
+    pub fn load_as<'a>(source: impl Into<DocumentSource<'a>>, version: &str) -> Result<Self, LoadError> {
+        Loader::new().version(version).load(source)
+    }
+
+    /// Returns the CSAF version of the document, as used by [validate_by_preset].
+    pub fn version(&self) -> &'static str {
+        match self {
+            CsafDocument::V2_0(_) => "2.0",
+            CsafDocument::V2_1(_) => "2.1",
+        }
+    }
+
+    /// Returns the CSAF version of the document.
+    pub fn csaf_version(&self) -> CsafVersion {
+        match self {
+            CsafDocument::V2_0(_) => CsafVersion::X20,
+            CsafDocument::V2_1(_) => CsafVersion::X21,
+        }
+    }
+
+    /// Returns the JSON of the document.
+    pub fn get_json(&self) -> &Value {
+        match self {
+            CsafDocument::V2_0(raw) => raw.get_json(),
+            CsafDocument::V2_1(raw) => raw.get_json(),
+        }
+    }
+
+    /// Attaches the spell checker used by test 6.3.8 when validating the document, see
+    /// [crate::spell_check].
+    pub fn with_spell_checker(self, spell_checker: Arc<SpellChecker>) -> Self {
+        match self {
+            CsafDocument::V2_0(raw) => CsafDocument::V2_0(raw.with_spell_checker(spell_checker)),
+            CsafDocument::V2_1(raw) => CsafDocument::V2_1(raw.with_spell_checker(spell_checker)),
+        }
+    }
+
+    /// Attaches the URL resolver used by tests 6.3.6 and 6.3.7 when validating the document, see
+    /// [crate::url_resolver].
+    pub fn with_url_resolver(self, url_resolver: Arc<dyn UrlResolver>) -> Self {
+        match self {
+            CsafDocument::V2_0(raw) => CsafDocument::V2_0(raw.with_url_resolver(url_resolver)),
+            CsafDocument::V2_1(raw) => CsafDocument::V2_1(raw.with_url_resolver(url_resolver)),
+        }
+    }
+
+    /// Validates the document with a preset.
+    pub fn validate(&self, preset: ValidationPreset) -> ValidationResult {
+        validate_by_preset(self, self.version(), preset)
+    }
+
+    /// Validates the document with specific tests.
+    pub fn validate_tests(&self, preset: ValidationPreset, test_ids: &[&str]) -> ValidationResult {
+        validate_by_tests(self, self.version(), preset, test_ids)
+    }
+
+    /// Runs a [CsafVisitor] on the parsed document.
+    ///
+    /// Returns the parse error if the document does not match the types of its CSAF version.
+    pub fn visit<V: CsafVisitor>(&self, visitor: V) -> Result<V::Output, &str> {
+        with_parsed!(self, doc => visitor.visit(doc))
+    }
+
+    /// Returns the category of the document.
+    pub fn category(&self) -> Result<CsafDocumentCategory, &str> {
+        with_parsed!(self, doc => doc.get_document().get_category())
+    }
+
+    /// Returns the tracking ID of the document.
+    pub fn tracking_id(&self) -> Result<&str, &str> {
+        with_parsed!(self, doc => doc.get_document().get_tracking().get_id().as_str())
+    }
+
+    /// Returns the tracking status of the document.
+    pub fn tracking_status(&self) -> Result<DocumentStatus, &str> {
+        with_parsed!(self, doc => doc.get_document().get_tracking().get_status())
+    }
+
+    /// Returns the current release date of the document.
+    pub fn current_release_date(&self) -> Result<CsafDateTime, &str> {
+        with_parsed!(self, doc => doc.get_document().get_tracking().get_current_release_date())
+    }
+
+    /// Returns the IDs of all products defined in the product tree.
+    pub fn product_ids(&self) -> Result<Vec<String>, &str> {
+        with_parsed!(self, doc => {
+            let mut ids = Vec::new();
+            if let Some(product_tree) = doc.get_product_tree() {
+                product_tree.visit_all_products(&mut |product, _| ids.push(product.get_product_id().to_owned()));
+            }
+            ids
+        })
+    }
+
+    /// Returns all product ID references with their JSON pointers.
+    pub fn product_references(&self) -> Result<Vec<(String, String)>, &str> {
+        with_parsed!(self, doc => doc.get_all_product_references())
+    }
+
+    /// Returns the CVE IDs of all vulnerabilities.
+    pub fn cves(&self) -> Result<Vec<&str>, &str> {
+        with_parsed!(self, doc => doc
+            .get_vulnerabilities()
+            .iter()
+            .filter_map(|vulnerability| vulnerability.get_cve().map(String::as_str))
+            .collect())
+    }
+
+    /// Returns a document of the same version with different JSON.
+    pub(crate) fn with_json(&self, json: Value) -> Self {
+        match self {
+            CsafDocument::V2_0(raw) => CsafDocument::V2_0(raw.with_json(json)),
+            CsafDocument::V2_1(raw) => CsafDocument::V2_1(raw.with_json(json)),
+        }
+    }
+}
+
+impl FromStr for CsafDocument {
+    type Err = LoadError;
+
+    fn from_str(json_str: &str) -> Result<Self, Self::Err> {
+        CsafDocument::load(DocumentSource::Str(json_str))
+    }
+}
+
+impl Validatable for CsafDocument {
+    /// Returns the test IDs belonging to a preset in any CSAF version.
+    ///
+    /// The tests of a loaded document depend on its version, see [Validatable::preset_tests].
+    fn tests_in_preset(preset: &ValidationPreset) -> Vec<&str> {
+        let mut test_ids = RawDocument::<Csaf20>::tests_in_preset(preset);
+        for test_id in RawDocument::<Csaf21>::tests_in_preset(preset) {
+            if !test_ids.contains(&test_id) {
+                test_ids.push(test_id);
+            }
+        }
+        test_ids
+    }
+
+    fn preset_tests<'a>(&'a self, preset: &'a ValidationPreset) -> Vec<&'a str> {
+        match self {
+            CsafDocument::V2_0(_) => RawDocument::<Csaf20>::tests_in_preset(preset),
+            CsafDocument::V2_1(_) => RawDocument::<Csaf21>::tests_in_preset(preset),
+        }
+    }
+
+    fn run_test(&self, test_id: &str) -> TestResult {
+        match self {
+            CsafDocument::V2_0(raw) => raw.run_test(test_id),
+            CsafDocument::V2_1(raw) => raw.run_test(test_id),
+        }
+    }
+
+    fn span_index(&self) -> Option<&SpanIndex> {
+        match self {
+            CsafDocument::V2_0(raw) => raw.span_index(),
+            CsafDocument::V2_1(raw) => raw.span_index(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::TestResultStatus;
+
+    const DOCUMENT: &str = r#"{
+  "document": {
+    "category": "csaf_vex",
+    "csaf_version": "2.0",
+    "distribution": { "tlp": { "label": "WHITE" } },
+    "publisher": { "category": "vendor", "name": "Example Company", "namespace": "https://example.com" },
+    "title": "Unified loader test",
+    "tracking": {
+      "current_release_date": "2024-01-24T10:00:00.000Z",
+      "id": "LOADER-TEST",
+      "initial_release_date": "2024-01-24T10:00:00.000Z",
+      "revision_history": [{ "date": "2024-01-24T10:00:00.000Z", "number": "1", "summary": "Initial version." }],
+      "status": "final",
+      "version": "1"
+    }
+  },
+  "product_tree": { "full_product_names": [{ "name": "Product A", "product_id": "CSAFPID-0001" }] },
+  "vulnerabilities": [
+    { "cve": "CVE-2024-0001", "product_status": { "known_affected": ["CSAFPID-0001", "CSAFPID-0002"] } }
+  ]
+}"#;
+
+    struct CountVulnerabilities;
+
+    impl CsafVisitor for CountVulnerabilities {
+        type Output = usize;
+
+        fn visit(self, doc: &impl CsafTrait) -> usize {
+            doc.get_vulnerabilities().len()
+        }
+    }
+
+    #[test]
+    fn test_load() {
+        let from_str: CsafDocument = DOCUMENT.parse().unwrap();
+        let from_reader = CsafDocument::load(&mut DOCUMENT.as_bytes()).unwrap();
+        assert_eq!(from_str, from_reader);
+        assert_eq!(from_str.version(), "2.0");
+        assert_eq!(from_str.csaf_version(), CsafVersion::X20);
+
+        let as_2_1 = CsafDocument::load_as(DocumentSource::Str(DOCUMENT), "2.1").unwrap();
+        assert_eq!(as_2_1.version(), "2.1");
+
+        let unsupported = DOCUMENT.replace("\"2.0\"", "\"1.2\"");
+        assert!(matches!(
+            unsupported.parse::<CsafDocument>(),
+            Err(LoadError::UnsupportedVersion(version)) if version == "1.2"
+        ));
+        assert!(matches!(
+            "{\"document\": {}}".parse::<CsafDocument>(),
+            Err(LoadError::MissingVersion)
+        ));
+        assert!(matches!(
+            "{\n  \"document\"".parse::<CsafDocument>(),
+            Err(LoadError::Syntax { line: 2, .. })
+        ));
+        assert!(matches!(
+            Loader::new().max_size(100).load(DocumentSource::Str(DOCUMENT)),
+            Err(LoadError::SizeLimitExceeded { max_size: 100 })
+        ));
+    }
+
+    #[test]
+    fn test_accessors() {
+        let doc: CsafDocument = DOCUMENT.parse().unwrap();
+        assert_eq!(doc.category(), Ok(CsafDocumentCategory::CsafVex));
+        assert_eq!(doc.tracking_id(), Ok("LOADER-TEST"));
+        assert_eq!(doc.tracking_status(), Ok(DocumentStatus::Final));
+        assert_eq!(doc.product_ids(), Ok(vec!["CSAFPID-0001".to_string()]));
+        assert_eq!(doc.cves(), Ok(vec!["CVE-2024-0001"]));
+        assert_eq!(doc.visit(CountVulnerabilities), Ok(1));
+        assert_eq!(doc.product_references().unwrap().len(), 2);
+
+        let invalid: CsafDocument = DOCUMENT.replace("\"final\"", "\"unknown\"").parse().unwrap();
+        assert!(invalid.tracking_id().is_err());
+    }
+
+    #[test]
+    fn test_validate() {
+        let doc: CsafDocument = DOCUMENT.parse().unwrap();
+        let result = doc.validate_tests(ValidationPreset::Basic, &["6.1.1"]);
+        assert_eq!(result.version, "2.0");
+        let TestResultStatus::Failure { errors, .. } = &result.test_results[0].status else {
+            panic!("6.1.1 did not fail");
+        };
+        assert_eq!(result.location_of(&errors[0]).unwrap().line, 19);
+
+        let result = doc.validate(ValidationPreset::Basic);
+        assert_eq!(result.test_results[0].test_id, "schema");
+        assert_eq!(
+            result.test_results.len(),
+            RawDocument::<Csaf20>::tests_in_preset(&ValidationPreset::Basic).len()
+        );
+    }
+}
@@ -0,0 +1,319 @@
+use std::sync::LazyLock;
+
+use jsonschema::{Resource, Validator};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::csaf::raw::{HasParsed, RawDocument};
+use crate::validation::ValidationError;
+
+/// Parses an embedded schema.
+///
+/// Custom meta schemas (as used by CSAF 2.1 and CVSS v4) are not available offline, they are replaced
+/// with the JSON schema draft 2020-12 they are based on.
+fn parse_schema(schema_str: &str) -> Value {
+    let mut schema: Value = serde_json::from_str(schema_str).unwrap();
+    if let Some(meta_schema) = schema.get("$schema").and_then(Value::as_str)
+        && !meta_schema.contains("://json-schema.org/")
+    {
+        schema["$schema"] = Value::String("https://json-schema.org/draft/2020-12/schema".to_string());
+    }
+    schema
+}
+
+pub(crate) const CSAF_2_0_SCHEMA_URL: &str = "https://docs.oasis-open.org/csaf/csaf/v2.0/csaf_json_schema.json";
+pub(crate) static CSAF_2_0_SCHEMA: LazyLock<Value> =
+    LazyLock::new(|| parse_schema(include_str!("../../assets/csaf_2.0_json_schema.json")));
+
+pub(crate) const CSAF_2_1_SCHEMA_URL: &str = "https://docs.oasis-open.org/csaf/csaf/v2.1/schema/csaf.json";
+pub(crate) static CSAF_2_1_SCHEMA: LazyLock<Value> =
+    LazyLock::new(|| parse_schema(include_str!("../../assets/csaf_2.1_json_schema.json")));
+
+pub(crate) const CVSS_V2_SCHEMA_URL: &str = "https://www.first.org/cvss/cvss-v2.0.json";
+pub(crate) static CVSS_V2_SCHEMA: LazyLock<Value> =
+    LazyLock::new(|| parse_schema(include_str!("../../assets/cvss-v2.0.json")));
+
+pub(crate) const CVSS_V3_0_SCHEMA_URL: &str = "https://www.first.org/cvss/cvss-v3.0.json";
+pub(crate) static CVSS_V3_0_SCHEMA: LazyLock<Value> =
+    LazyLock::new(|| parse_schema(include_str!("../../assets/cvss-v3.0.json")));
+
+pub(crate) const CVSS_V3_1_SCHEMA_URL: &str = "https://www.first.org/cvss/cvss-v3.1.json";
+pub(crate) static CVSS_V3_1_SCHEMA: LazyLock<Value> =
+    LazyLock::new(|| parse_schema(include_str!("../../assets/cvss-v3.1.json")));
+
+pub(crate) const CVSS_V4_0_1_SCHEMA_URL: &str = "https://www.first.org/cvss/cvss-v4.0.1.json";
+pub(crate) static CVSS_V4_0_1_SCHEMA: LazyLock<Value> =
+    LazyLock::new(|| parse_schema(include_str!("../../assets/cvss-v4.0.rev.json")));
+
+pub(crate) const SSVC_2_SCHEMA_URL: &str =
+    "https://certcc.github.io/SSVC/data/schema/v2/SelectionList_2_0_0.schema.json";
+pub(crate) static SSVC_2_SCHEMA: LazyLock<Value> = LazyLock::new(|| {
+    parse_schema(include_str!(
+        "../../assets/decision_point_selection_list_json_schema.json"
+    ))
+});
+
+/// Builds a validator for the schema registered as `schema_url`, with all schemas referenced by the
+/// CSAF JSON schemas registered as resources.
+///
+/// `transform` is applied to every schema (identified by its URL) before it is used, e.g. to make the
+/// schemas stricter.
+pub(crate) fn build_validator(schema_url: &str, transform: impl Fn(&str, &Value) -> Value) -> Validator {
+    let schemas: [(&str, &Value); 7] = [
+        (CSAF_2_0_SCHEMA_URL, &CSAF_2_0_SCHEMA),
+        (CSAF_2_1_SCHEMA_URL, &CSAF_2_1_SCHEMA),
+        (CVSS_V2_SCHEMA_URL, &CVSS_V2_SCHEMA),
+        (CVSS_V3_0_SCHEMA_URL, &CVSS_V3_0_SCHEMA),
+        (CVSS_V3_1_SCHEMA_URL, &CVSS_V3_1_SCHEMA),
+        (CVSS_V4_0_1_SCHEMA_URL, &CVSS_V4_0_1_SCHEMA),
+        (SSVC_2_SCHEMA_URL, &SSVC_2_SCHEMA),
+    ];
+
+    let mut options = jsonschema::options().should_validate_formats(true);
+    let mut root = None;
+    for (url, schema) in schemas {
+        let schema = transform(url, schema);
+        if url == schema_url {
+            root = Some(schema.clone());
+        }
+        options = options.with_resource(url, Resource::from_contents(schema));
+    }
+    options.build(&root.expect("schema URL is not registered")).unwrap()
+}
+
+static CSAF_2_0_VALIDATOR: LazyLock<Validator> =
+    LazyLock::new(|| build_validator(CSAF_2_0_SCHEMA_URL, |_, schema| schema.clone()));
+static CSAF_2_1_VALIDATOR: LazyLock<Validator> =
+    LazyLock::new(|| build_validator(CSAF_2_1_SCHEMA_URL, |_, schema| schema.clone()));
+
+/// Validates a JSON value against a JSON schema validator.
+///
+/// Returns one error per schema violation, with the JSON pointer of the violating value as instance path.
+pub fn validate_json_schema(json: &Value, validator: &Validator) -> Result<(), Vec<ValidationError>> {
+    let errors: Vec<ValidationError> = validator
+        .iter_errors(json)
+        .map(|error| ValidationError {
+            message: error.to_string(),
+            instance_path: error.instance_path().as_str().to_string(),
+        })
+        .collect();
+
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+/// Validates a raw document against the JSON schema of its CSAF version.
+///
+/// If the document satisfies the schema but still cannot be parsed, the parse error is returned.
+fn validate_raw_document<T: DeserializeOwned>(
+    doc: &RawDocument<T>,
+    validator: &Validator,
+) -> Result<(), Vec<ValidationError>> {
+    validate_json_schema(doc.get_json(), validator)?;
+    match doc.get_parsed() {
+        Ok(_) => Ok(()),
+        Err(err) => Err(vec![ValidationError {
+            message: err.clone(),
+            instance_path: "".to_string(),
+        }]),
+    }
+}
+
+/// Validates a CSAF 2.0 document against the CSAF 2.0 JSON schema.
+pub fn validate_csaf_2_0_schema(
+    doc: &RawDocument<crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework>,
+) -> Result<(), Vec<ValidationError>> {
+    validate_raw_document(doc, &CSAF_2_0_VALIDATOR)
+}
+
+/// Validates a CSAF 2.1 document against the CSAF 2.1 JSON schema.
+pub fn validate_csaf_2_1_schema(
+    doc: &RawDocument<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>,
+) -> Result<(), Vec<ValidationError>> {
+    validate_raw_document(doc, &CSAF_2_1_VALIDATOR)
+}
+
+/// Documentation of a value of a CSAF document, taken from the JSON schema.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchemaDocumentation {
+    /// Title of the value
+    pub title: Option<String>,
+    /// Description of the value
+    pub description: Option<String>,
+    /// Allowed values, if the value is an enumeration of strings
+    pub enum_values: Vec<String>,
+}
+
+/// Returns the embedded schema registered as `url`.
+fn schema_by_url(url: &str) -> Option<&'static Value> {
+    match url {
+        CSAF_2_0_SCHEMA_URL => Some(&CSAF_2_0_SCHEMA),
+        CSAF_2_1_SCHEMA_URL => Some(&CSAF_2_1_SCHEMA),
+        CVSS_V2_SCHEMA_URL => Some(&CVSS_V2_SCHEMA),
+        CVSS_V3_0_SCHEMA_URL => Some(&CVSS_V3_0_SCHEMA),
+        CVSS_V3_1_SCHEMA_URL => Some(&CVSS_V3_1_SCHEMA),
+        CVSS_V4_0_1_SCHEMA_URL => Some(&CVSS_V4_0_1_SCHEMA),
+        SSVC_2_SCHEMA_URL => Some(&SSVC_2_SCHEMA),
+        _ => None,
+    }
+}
+
+/// A subschema together with the schema it belongs to, which is needed to resolve local references.
+#[derive(Clone, Copy)]
+struct SchemaNode {
+    root: &'static Value,
+    schema: &'static Value,
+}
+
+impl SchemaNode {
+    /// Follows `$ref`s until a schema without reference is reached.
+    fn resolve(self) -> Option<SchemaNode> {
+        let mut node = self;
+        // Bounded to stay safe from reference cycles
+        for _ in 0..32 {
+            let Some(reference) = node.schema.get("$ref").and_then(Value::as_str) else {
+                return Some(node);
+            };
+            let (url, fragment) = reference.split_once('#').unwrap_or((reference, ""));
+            let root = if url.is_empty() { node.root } else { schema_by_url(url)? };
+            node = SchemaNode {
+                root,
+                schema: root.pointer(fragment)?,
+            };
+        }
+        None
+    }
+
+    /// Returns the subschema of the property or array item `segment`.
+    fn child(self, segment: &str) -> Option<SchemaNode> {
+        let node = self.resolve()?;
+        let child = |schema| SchemaNode {
+            root: node.root,
+            schema,
+        };
+
+        if let Some(property) = node.schema.get("properties").and_then(|p| p.get(segment)) {
+            return Some(child(property));
+        }
+        if segment.parse::<usize>().is_ok()
+            && let Some(items) = node.schema.get("items")
+        {
+            return Some(child(items));
+        }
+        ["allOf", "anyOf", "oneOf"]
+            .iter()
+            .filter_map(|keyword| node.schema.get(keyword).and_then(Value::as_array))
+            .flatten()
+            .find_map(|subschema| child(subschema).child(segment))
+    }
+}
+
+/// Returns the documentation of the value at a JSON pointer of a CSAF document, according to the
+/// JSON schema of the given CSAF version.
+///
+/// Returns `None` if the version is unknown or the schema does not describe the value.
+pub fn schema_documentation(csaf_version: &str, pointer: &str) -> Option<SchemaDocumentation> {
+    let root: &'static Value = match csaf_version {
+        "2.0" => &CSAF_2_0_SCHEMA,
+        "2.1" => &CSAF_2_1_SCHEMA,
+        _ => return None,
+    };
+    let mut node = SchemaNode { root, schema: root };
+    for segment in pointer.split('/').skip(1) {
+        node = node.child(&segment.replace("~1", "/").replace("~0", "~"))?;
+    }
+
+    // Title and description may be given next to a reference, these take precedence
+    let text = |key: &str| {
+        node.schema
+            .get(key)
+            .or_else(|| node.resolve().and_then(|resolved| resolved.schema.get(key)))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    };
+    let enum_values = node
+        .resolve()
+        .and_then(|resolved| resolved.schema.get("enum"))
+        .and_then(Value::as_array)
+        .map(|values| values.iter().filter_map(Value::as_str).map(str::to_string).collect())
+        .unwrap_or_default();
+
+    Some(SchemaDocumentation {
+        title: text("title"),
+        description: text("description"),
+        enum_values,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_schema_documentation() {
+        let category = schema_documentation("2.1", "/vulnerabilities/0/remediations/1/category").unwrap();
+        assert_eq!(category.title.as_deref(), Some("Category of the remediation"));
+        assert!(category.enum_values.contains(&"vendor_fix".to_string()));
+
+        let product_id = schema_documentation("2.0", "/product_tree/full_product_names/0/product_id").unwrap();
+        assert_eq!(
+            product_id.title.as_deref(),
+            Some("Reference token for product instance")
+        );
+
+        let attack_vector = schema_documentation("2.0", "/vulnerabilities/0/scores/0/cvss_v3/attackVector").unwrap();
+        assert!(attack_vector.enum_values.contains(&"NETWORK".to_string()));
+
+        assert_eq!(schema_documentation("2.0", "/document/unknown"), None);
+        assert_eq!(schema_documentation("1.0", "/document"), None);
+    }
+
+    fn minimal_document() -> Value {
+        json!({
+            "document": {
+                "category": "csaf_base",
+                "csaf_version": "2.0",
+                "distribution": { "tlp": { "label": "WHITE" } },
+                "publisher": {
+                    "category": "vendor",
+                    "name": "Example Company",
+                    "namespace": "https://example.com"
+                },
+                "title": "Schema test",
+                "tracking": {
+                    "current_release_date": "2024-01-24T10:00:00.000Z",
+                    "id": "SCHEMA-TEST",
+                    "initial_release_date": "2024-01-24T10:00:00.000Z",
+                    "revision_history": [
+                        { "date": "2024-01-24T10:00:00.000Z", "number": "1", "summary": "Initial version." }
+                    ],
+                    "status": "final",
+                    "version": "1"
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_valid_document() {
+        assert_eq!(validate_csaf_2_0_schema(&RawDocument::new(minimal_document())), Ok(()));
+    }
+
+    #[test]
+    fn test_one_error_per_violation() {
+        let mut doc = minimal_document();
+        doc["document"]["publisher"]["category"] = json!("unknown");
+        doc["document"]["tracking"]["revision_history"][0]
+            .as_object_mut()
+            .unwrap()
+            .remove("summary");
+
+        let errors = validate_csaf_2_0_schema(&RawDocument::new(doc)).unwrap_err();
+        let paths: Vec<&str> = errors.iter().map(|e| e.instance_path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec!["/document/publisher/category", "/document/tracking/revision_history/0"]
+        );
+        assert!(errors[1].message.contains("summary"));
+    }
+}
@@ -1,19 +1,105 @@
-use std::{fs::File, io::BufReader};
+use std::io::Read;
+use std::path::Path;
 
-#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, Eq, PartialEq)]
-struct CommonSecurityAdvisoryFramework {
-    pub document: DocumentLevelMetaData,
+use serde_json::Value;
+
+/// Default maximum size of a document in bytes, see [Loader::max_size]
+pub const DEFAULT_MAX_SIZE: u64 = 64 * 1024 * 1024;
+
+/// Reasons why a CSAF document cannot be loaded.
+#[derive(Debug, thiserror::Error)]
+pub enum LoadError {
+    #[error("failed to read the document: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid JSON: {message}")]
+    Syntax {
+        /// Line of the syntax error, starting at 1
+        line: usize,
+        /// Column of the syntax error in bytes, starting at 1
+        column: usize,
+        message: String,
+    },
+    #[error("the document has no document/csaf_version")]
+    MissingVersion,
+    #[error("unsupported CSAF version {0}, supported versions are 2.0 and 2.1")]
+    UnsupportedVersion(String),
+    #[error("the document has more than {max_size} bytes")]
+    SizeLimitExceeded { max_size: u64 },
+}
+
+impl From<serde_json::Error> for LoadError {
+    fn from(err: serde_json::Error) -> Self {
+        if err.is_io() {
+            return LoadError::Io(err.into());
+        }
+        LoadError::Syntax {
+            line: err.line(),
+            column: err.column(),
+            message: err.to_string(),
+        }
+    }
 }
 
-#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, Eq, PartialEq)]
-struct DocumentLevelMetaData {
-    ///Gives the version of the CSAF specification which the document was generated for.
-    pub csaf_version: String,
+/// Reads a document, failing if it has more than `max_size` bytes.
+pub(crate) fn read_to_string(reader: impl Read, max_size: u64) -> Result<String, LoadError> {
+    let mut json_str = String::new();
+    // One more byte than allowed is read to detect documents exceeding the limit
+    reader.take(max_size.saturating_add(1)).read_to_string(&mut json_str)?;
+    check_size(&json_str, max_size)?;
+    Ok(json_str)
 }
 
-pub fn detect_version(path: &str) -> std::io::Result<String> {
-    let f = File::open(path)?;
-    let reader = BufReader::new(f);
-    let doc: CommonSecurityAdvisoryFramework = serde_json::from_reader(reader)?;
-    Ok(doc.document.csaf_version)
+/// Reads the document at `path`, failing if it has more than `max_size` bytes.
+pub(crate) fn read_file(path: &Path, max_size: u64) -> Result<String, LoadError> {
+    log::debug!("Trying to load document {}", path.display());
+
+    let file = std::fs::File::open(path)?;
+    if file.metadata()?.len() > max_size {
+        return Err(LoadError::SizeLimitExceeded { max_size });
+    }
+    read_to_string(file, max_size)
+}
+
+pub(crate) fn check_size(json_str: &str, max_size: u64) -> Result<(), LoadError> {
+    if json_str.len() as u64 > max_size {
+        return Err(LoadError::SizeLimitExceeded { max_size });
+    }
+    Ok(())
+}
+
+/// Returns the CSAF version given in `/document/csaf_version`.
+pub(crate) fn csaf_version(json: &Value) -> Result<&str, LoadError> {
+    json.pointer("/document/csaf_version")
+        .and_then(Value::as_str)
+        .ok_or(LoadError::MissingVersion)
+}
+
+/// Detects the CSAF version of the document at `path`.
+pub fn detect_version(path: &str) -> Result<String, LoadError> {
+    let json: Value = serde_json::from_str(&read_file(Path::new(path), DEFAULT_MAX_SIZE)?)?;
+    csaf_version(&json).map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_errors() {
+        let LoadError::Syntax { line, column, .. } = serde_json::from_str::<Value>("{\n  \"a\": }").unwrap_err().into()
+        else {
+            panic!("not a syntax error");
+        };
+        assert_eq!((line, column), (2, 8));
+
+        assert!(matches!(
+            csaf_version(&serde_json::json!({"document": {}})),
+            Err(LoadError::MissingVersion)
+        ));
+        assert!(matches!(
+            read_to_string("{\"document\": {}}".as_bytes(), 10),
+            Err(LoadError::SizeLimitExceeded { max_size: 10 })
+        ));
+        assert!(matches!(detect_version("does/not/exist.json"), Err(LoadError::Io(_))));
+    }
 }
@@ -1,3 +1,6 @@
+pub mod document;
+pub mod json_schema;
 pub mod loader;
 pub mod raw;
+pub mod span;
 pub mod types;
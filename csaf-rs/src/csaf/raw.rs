@@ -1,22 +1,90 @@
 use std::cell::OnceCell;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
 
 use serde::de::DeserializeOwned;
 
 use crate::{
-    schema::csaf2_0::schema::CommonSecurityAdvisoryFramework,
+    csaf::span::SpanIndex,
+    spell_check::SpellChecker,
+    url_resolver::UrlResolver,
     validation::{TestResult, TestResultStatus, Validatable, ValidationError, ValidationPreset},
 };
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone)]
 pub struct RawDocument<T> {
     raw: serde_json::Value,
     parsed: OnceCell<Result<T, String>>,
+    span_index: Option<SpanIndex>,
+    spell_checker: Option<Arc<SpellChecker>>,
+    url_resolver: Option<Arc<dyn UrlResolver>>,
 }
 
+/// Formats the document without the URL resolver, which does not need to implement [Debug].
+impl<T: Debug> Debug for RawDocument<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RawDocument")
+            .field("raw", &self.raw)
+            .field("parsed", &self.parsed)
+            .field("span_index", &self.span_index)
+            .field("spell_checker", &self.spell_checker)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Documents are equal if their JSON is, the spell checker and URL resolver are not compared.
+impl<T: PartialEq> PartialEq for RawDocument<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw && self.parsed == other.parsed && self.span_index == other.span_index
+    }
+}
+
+impl<T: Eq> Eq for RawDocument<T> {}
+
 impl<T> RawDocument<T> {
     pub fn get_json(&self) -> &serde_json::Value {
         &self.raw
     }
+
+    /// Attaches the span index of the JSON source the document was loaded from.
+    pub fn with_span_index(mut self, span_index: SpanIndex) -> Self {
+        self.span_index = Some(span_index);
+        self
+    }
+
+    /// Attaches the spell checker used by test 6.3.8, which is skipped without one.
+    pub fn with_spell_checker(mut self, spell_checker: Arc<SpellChecker>) -> Self {
+        self.spell_checker = Some(spell_checker);
+        self
+    }
+
+    /// Returns the spell checker attached with [RawDocument::with_spell_checker], if any.
+    pub fn get_spell_checker(&self) -> Option<&SpellChecker> {
+        self.spell_checker.as_deref()
+    }
+
+    /// Attaches the URL resolver used by tests 6.3.6 and 6.3.7, which are skipped without one.
+    pub fn with_url_resolver(mut self, url_resolver: Arc<dyn UrlResolver>) -> Self {
+        self.url_resolver = Some(url_resolver);
+        self
+    }
+
+    /// Returns the URL resolver attached with [RawDocument::with_url_resolver], if any.
+    pub fn get_url_resolver(&self) -> Option<&dyn UrlResolver> {
+        self.url_resolver.as_deref()
+    }
+
+    /// Returns a document with different JSON, keeping the spell checker and URL resolver but not
+    /// the span index.
+    pub fn with_json(&self, raw: serde_json::Value) -> Self {
+        Self {
+            raw,
+            parsed: OnceCell::new(),
+            span_index: None,
+            spell_checker: self.spell_checker.clone(),
+            url_resolver: self.url_resolver.clone(),
+        }
+    }
 }
 
 impl<T> RawDocument<T>
@@ -27,6 +95,9 @@ where
         Self {
             raw,
             parsed: OnceCell::new(),
+            span_index: None,
+            spell_checker: None,
+            url_resolver: None,
         }
     }
 }
@@ -41,14 +112,26 @@ where
         self.parsed
             .get_or_init(|| serde_json::from_value::<T>(self.raw.clone()).map_err(|e| e.to_string()))
     }
+
+    fn get_span_index(&self) -> Option<&SpanIndex> {
+        self.span_index.as_ref()
+    }
 }
 
 pub trait HasParsed {
     type Parsed;
     fn get_parsed(&self) -> &Result<Self::Parsed, String>;
+
+    /// Returns the span index of the JSON source, if it was kept when loading the document.
+    fn get_span_index(&self) -> Option<&SpanIndex> {
+        None
+    }
 }
 
 pub trait RawValidatable {
+    /// Validates the raw document against the JSON schema of its CSAF version.
+    fn validate_schema(&self) -> Result<(), Vec<ValidationError>>;
+
     fn run_raw_test(&self, test_id: &str) -> TestResult {
         TestResult {
             test_id: test_id.to_string(),
@@ -64,24 +147,22 @@ where
 {
     /// Returns the test IDs belonging to a preset
     fn tests_in_preset(preset: &ValidationPreset) -> Vec<&str> {
-        [vec!["schema"], CommonSecurityAdvisoryFramework::tests_in_preset(preset)].concat()
+        [vec!["schema"], T::Parsed::tests_in_preset(preset)].concat()
+    }
+
+    fn span_index(&self) -> Option<&SpanIndex> {
+        self.get_span_index()
     }
 
     /// Runs a test by test ID
     fn run_test(&self, test_id: &str) -> TestResult {
         if test_id == "schema" {
-            return match self.get_parsed() {
-                Ok(_) => TestResult {
-                    test_id: test_id.to_string(),
-                    status: crate::validation::TestResultStatus::Success,
-                },
-                Err(err) => TestResult {
-                    test_id: test_id.to_string(),
-                    status: crate::validation::TestResultStatus::Failure {
-                        errors: vec![ValidationError {
-                            message: err.clone(),
-                            instance_path: "".to_string(),
-                        }],
+            return TestResult {
+                test_id: test_id.to_string(),
+                status: match self.validate_schema() {
+                    Ok(()) => TestResultStatus::Success,
+                    Err(errors) => TestResultStatus::Failure {
+                        errors,
                         warnings: vec![],
                         infos: vec![],
                     },
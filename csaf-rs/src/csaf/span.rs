@@ -0,0 +1,441 @@
+//! Mapping of JSON pointers to their position in the JSON source of a document.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+/// Position of a JSON value in the source of a document.
+///
+/// Offsets are byte offsets, lines and columns start at 1. Columns are counted in characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct Span {
+    /// Byte offset of the first character of the value
+    pub start: usize,
+    /// Byte offset after the last character of the value
+    pub end: usize,
+    /// Line of the first character of the value
+    pub line: usize,
+    /// Column of the first character of the value
+    pub column: usize,
+}
+
+/// Maximum nesting depth of arrays and objects, deeper values are a syntax error as in serde_json
+const MAX_DEPTH: usize = 128;
+
+/// Index of the spans of all values of a JSON document, identified by their JSON pointer.
+///
+/// The property names of objects are indexed as well, by the JSON pointer of their value.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpanIndex {
+    spans: HashMap<String, Span>,
+    keys: HashMap<String, Span>,
+}
+
+impl SpanIndex {
+    /// Indexes the values of a JSON source.
+    ///
+    /// The source does not need to be valid JSON: everything up to the first syntax error is indexed.
+    /// Arrays and objects nested deeper than serde_json accepts are a syntax error as well.
+    pub fn new(source: &str) -> Self {
+        let mut scanner = Scanner {
+            source,
+            bytes: source.as_bytes(),
+            pos: 0,
+            line: 1,
+            column_offset: 0,
+            column: 1,
+            depth: 0,
+            spans: HashMap::new(),
+            keys: HashMap::new(),
+        };
+        let mut pointer = String::new();
+        // Syntax errors only end the indexing, the spans found so far are kept
+        let _ = scanner.value(&mut pointer);
+        SpanIndex {
+            spans: scanner.spans,
+            keys: scanner.keys,
+        }
+    }
+
+    /// Returns the span of the value at the JSON pointer, if it exists.
+    pub fn get(&self, pointer: &str) -> Option<&Span> {
+        self.spans.get(pointer)
+    }
+
+    /// Returns the span of the value at the JSON pointer or, if it does not exist, the span of its
+    /// closest existing ancestor.
+    ///
+    /// This is useful for findings about missing properties, whose instance path may point to the
+    /// missing value.
+    pub fn locate(&self, pointer: &str) -> Option<&Span> {
+        let mut pointer = pointer;
+        loop {
+            if let Some(span) = self.spans.get(pointer) {
+                return Some(span);
+            }
+            match pointer.rfind('/') {
+                Some(index) => pointer = &pointer[..index],
+                None => return None,
+            }
+        }
+    }
+
+    /// Returns the span of the property name of the value at the JSON pointer, if the value is a property.
+    pub fn get_key(&self, pointer: &str) -> Option<&Span> {
+        self.keys.get(pointer)
+    }
+
+    /// Returns the JSON pointer and span of the innermost value containing the byte offset.
+    pub fn value_at(&self, offset: usize) -> Option<(&str, &Span)> {
+        Self::innermost(&self.spans, offset)
+    }
+
+    /// Returns the JSON pointer of the value and the span of the property name containing the byte offset.
+    pub fn key_at(&self, offset: usize) -> Option<(&str, &Span)> {
+        Self::innermost(&self.keys, offset)
+    }
+
+    fn innermost(spans: &HashMap<String, Span>, offset: usize) -> Option<(&str, &Span)> {
+        spans
+            .iter()
+            .filter(|(_, span)| span.start <= offset && offset < span.end)
+            .min_by_key(|(_, span)| span.end - span.start)
+            .map(|(pointer, span)| (pointer.as_str(), span))
+    }
+
+    /// Returns the number of indexed values.
+    pub fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    /// Returns whether no values are indexed.
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+}
+
+/// Escapes a property name for use as a JSON pointer segment.
+fn escape_pointer_segment(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+/// A minimal JSON scanner that records the span of every value.
+struct Scanner<'a> {
+    source: &'a str,
+    bytes: &'a [u8],
+    pos: usize,
+    line: usize,
+    /// Byte offset up to which the characters of the current line are counted in `column`
+    column_offset: usize,
+    /// Column of the character at `column_offset`
+    column: usize,
+    /// Number of arrays and objects the current value is nested in
+    depth: usize,
+    spans: HashMap<String, Span>,
+    keys: HashMap<String, Span>,
+}
+
+/// Marker for syntax errors, which stop the scanner.
+struct SyntaxError;
+
+impl Scanner<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn advance(&mut self) {
+        if self.bytes[self.pos] == b'\n' {
+            self.line += 1;
+            self.column_offset = self.pos + 1;
+            self.column = 1;
+        }
+        self.pos += 1;
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.advance();
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), SyntaxError> {
+        if self.peek() == Some(byte) {
+            self.advance();
+            Ok(())
+        } else {
+            Err(SyntaxError)
+        }
+    }
+
+    /// Returns the span starting at the current position, with the end still to be set.
+    fn start_span(&mut self) -> Span {
+        // Columns are counted in characters, counting continues where the previous span started
+        // to stay linear for long lines (e.g. minified documents)
+        self.column += self.source[self.column_offset..self.pos].chars().count();
+        self.column_offset = self.pos;
+        Span {
+            start: self.pos,
+            end: self.pos,
+            line: self.line,
+            column: self.column,
+        }
+    }
+
+    fn value(&mut self, pointer: &mut String) -> Result<(), SyntaxError> {
+        self.skip_whitespace();
+        let mut span = self.start_span();
+
+        match self.peek() {
+            Some(open @ (b'{' | b'[')) => {
+                // Limits the recursion, serde_json rejects the 128th nested array or object
+                if self.depth + 1 >= MAX_DEPTH {
+                    return Err(SyntaxError);
+                }
+                self.depth += 1;
+                let result = if open == b'{' {
+                    self.object(pointer)
+                } else {
+                    self.array(pointer)
+                };
+                self.depth -= 1;
+                result?;
+            },
+            Some(b'"') => {
+                self.string()?;
+            },
+            Some(_) => self.literal()?,
+            None => return Err(SyntaxError),
+        }
+
+        span.end = self.pos;
+        self.spans.insert(pointer.clone(), span);
+        Ok(())
+    }
+
+    fn object(&mut self, pointer: &mut String) -> Result<(), SyntaxError> {
+        self.expect(b'{')?;
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.advance();
+            return Ok(());
+        }
+        loop {
+            self.skip_whitespace();
+            let mut key_span = self.start_span();
+            self.string()?;
+            key_span.end = self.pos;
+            let key: String =
+                serde_json::from_str(&self.source[key_span.start..key_span.end]).map_err(|_| SyntaxError)?;
+
+            let parent_len = pointer.len();
+            pointer.push('/');
+            pointer.push_str(&escape_pointer_segment(&key));
+            self.keys.insert(pointer.clone(), key_span);
+
+            self.skip_whitespace();
+            if let Err(err) = self.expect(b':') {
+                pointer.truncate(parent_len);
+                return Err(err);
+            }
+            let result = self.value(pointer);
+            pointer.truncate(parent_len);
+            result?;
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.advance(),
+                Some(b'}') => {
+                    self.advance();
+                    return Ok(());
+                },
+                _ => return Err(SyntaxError),
+            }
+        }
+    }
+
+    fn array(&mut self, pointer: &mut String) -> Result<(), SyntaxError> {
+        self.expect(b'[')?;
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.advance();
+            return Ok(());
+        }
+        let mut index = 0;
+        loop {
+            let parent_len = pointer.len();
+            let _ = write!(pointer, "/{index}");
+            let result = self.value(pointer);
+            pointer.truncate(parent_len);
+            result?;
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.advance(),
+                Some(b']') => {
+                    self.advance();
+                    return Ok(());
+                },
+                _ => return Err(SyntaxError),
+            }
+            index += 1;
+        }
+    }
+
+    fn string(&mut self) -> Result<(), SyntaxError> {
+        self.expect(b'"')?;
+        loop {
+            match self.peek() {
+                Some(b'"') => {
+                    self.advance();
+                    return Ok(());
+                },
+                Some(b'\\') => {
+                    self.advance();
+                    if self.peek().is_none() {
+                        return Err(SyntaxError);
+                    }
+                    self.advance();
+                },
+                Some(_) => self.advance(),
+                None => return Err(SyntaxError),
+            }
+        }
+    }
+
+    /// Numbers, `true`, `false` and `null`
+    fn literal(&mut self) -> Result<(), SyntaxError> {
+        let start = self.pos;
+        while matches!(
+            self.peek(),
+            Some(b'0'..=b'9' | b'a'..=b'z' | b'A'..=b'Z' | b'+' | b'-' | b'.')
+        ) {
+            self.advance();
+        }
+        if self.pos == start { Err(SyntaxError) } else { Ok(()) }
+    }
+}
+
+/// Renders the lines around a span, marking its first character.
+///
+/// ```text
+///    4 |     "category": "csaf_base",
+/// >  5 |     "csaf_version": "2.0",
+///      |                     ^
+///    6 |     "distribution": {
+/// ```
+pub fn code_frame(source: &str, span: &Span, context_lines: usize) -> String {
+    let lines: Vec<&str> = source.lines().collect();
+    let first = span.line.saturating_sub(context_lines).max(1);
+    let last = (span.line + context_lines).min(lines.len());
+    let width = last.to_string().len();
+
+    let mut frame = String::new();
+    for line_number in first..=last {
+        let line = lines[line_number - 1];
+        let marker = if line_number == span.line { '>' } else { ' ' };
+        let _ = writeln!(frame, "{marker} {line_number:>width$} | {line}");
+        if line_number == span.line {
+            let _ = writeln!(frame, "  {:width$} | {:>column$}", "", "^", column = span.column);
+        }
+    }
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"{
+  "document": {
+    "title": "Ünïcödé title",
+    "notes": [
+      { "text": "a" },
+      { "te/x~t": 42 }
+    ]
+  }
+}"#;
+
+    #[test]
+    fn test_spans() {
+        let index = SpanIndex::new(SOURCE);
+
+        let root = index.get("").unwrap();
+        assert_eq!((root.start, root.end, root.line, root.column), (0, SOURCE.len(), 1, 1));
+
+        let title = index.get("/document/title").unwrap();
+        assert_eq!((title.line, title.column), (3, 14));
+        assert_eq!(&SOURCE[title.start..title.end], "\"Ünïcödé title\"");
+
+        let note = index.get("/document/notes/1").unwrap();
+        assert_eq!((note.line, note.column), (6, 7));
+
+        let escaped = index.get("/document/notes/1/te~1x~0t").unwrap();
+        assert_eq!(&SOURCE[escaped.start..escaped.end], "42");
+
+        let key = index.get_key("/document/title").unwrap();
+        assert_eq!((key.line, key.column), (3, 5));
+        assert_eq!(&SOURCE[key.start..key.end], "\"title\"");
+    }
+
+    #[test]
+    fn test_lookup_by_offset() {
+        let index = SpanIndex::new(SOURCE);
+        let offset = SOURCE.find("\"a\"").unwrap() + 1;
+        assert_eq!(
+            index.value_at(offset).map(|(pointer, _)| pointer),
+            Some("/document/notes/0/text")
+        );
+        assert_eq!(index.key_at(offset), None);
+
+        let offset = SOURCE.find("notes").unwrap();
+        assert_eq!(
+            index.key_at(offset).map(|(pointer, _)| pointer),
+            Some("/document/notes")
+        );
+        assert_eq!(index.value_at(offset).map(|(pointer, _)| pointer), Some("/document"));
+    }
+
+    #[test]
+    fn test_locate_falls_back_to_ancestor() {
+        let index = SpanIndex::new(SOURCE);
+        assert_eq!(
+            index.locate("/document/notes/0/summary"),
+            index.get("/document/notes/0")
+        );
+        assert_eq!(index.get("/document/notes/0/summary"), None);
+    }
+
+    #[test]
+    fn test_invalid_json_is_indexed_partially() {
+        let index = SpanIndex::new(r#"{"document": {"title": "a", "category": }"#);
+        assert!(index.get("/document/title").is_some());
+        assert!(index.get("/document/category").is_none());
+    }
+
+    #[test]
+    fn test_nesting_limit() {
+        let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(serde_json::from_str::<serde_json::Value>(&nested(MAX_DEPTH - 1)).is_ok());
+        assert!(SpanIndex::new(&nested(MAX_DEPTH - 1)).get("").is_some());
+        assert!(serde_json::from_str::<serde_json::Value>(&nested(MAX_DEPTH)).is_err());
+        assert!(SpanIndex::new(&nested(MAX_DEPTH)).get("").is_none());
+
+        assert!(SpanIndex::new(&"[".repeat(100_000)).is_empty());
+    }
+
+    #[test]
+    fn test_code_frame() {
+        let index = SpanIndex::new(SOURCE);
+        let frame = code_frame(SOURCE, index.get("/document/title").unwrap(), 1);
+        assert_eq!(
+            frame,
+            "  2 |   \"document\": {\n\
+             > 3 |     \"title\": \"Ünïcödé title\",\n\
+             \x20   |              ^\n\
+             \x20 4 |     \"notes\": [\n"
+        );
+    }
+}
@@ -17,6 +17,14 @@ impl CsafDateTime {
     pub fn is_valid(&self) -> bool {
         matches!(self, CsafDateTime::Valid(_))
     }
+
+    /// Returns the date as given in the document.
+    pub fn get_raw_string(&self) -> &str {
+        match self {
+            CsafDateTime::Valid(valid) => valid.get_raw_string(),
+            CsafDateTime::Invalid(invalid) => invalid.get_raw_string(),
+        }
+    }
 }
 
 impl From<&str> for CsafDateTime {
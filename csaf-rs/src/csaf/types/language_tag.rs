@@ -0,0 +1,407 @@
+use crate::generated::language_subtags::{
+    EXTLANG_SUBTAGS_ARRAY, GRANDFATHERED_TAGS_ARRAY, LANGUAGE_SUBTAGS_ARRAY, REGION_SUBTAGS_ARRAY,
+    SCRIPT_SUBTAGS_ARRAY, VARIANT_SUBTAGS_ARRAY, is_private_use_subtag,
+};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// The default language tag, which does not denote a language
+const DEFAULT_LANGUAGE_TAG: &str = "i-default";
+
+/// Type of a subtag in the IANA Language Subtag Registry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubtagType {
+    Language,
+    Extlang,
+    Script,
+    Region,
+    Variant,
+}
+
+impl SubtagType {
+    /// Returns the name of the type as used in the registry.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SubtagType::Language => "language",
+            SubtagType::Extlang => "extlang",
+            SubtagType::Script => "script",
+            SubtagType::Region => "region",
+            SubtagType::Variant => "variant",
+        }
+    }
+
+    fn registered_subtags(&self) -> &'static [&'static str] {
+        match self {
+            SubtagType::Language => LANGUAGE_SUBTAGS_ARRAY,
+            SubtagType::Extlang => EXTLANG_SUBTAGS_ARRAY,
+            SubtagType::Script => SCRIPT_SUBTAGS_ARRAY,
+            SubtagType::Region => REGION_SUBTAGS_ARRAY,
+            SubtagType::Variant => VARIANT_SUBTAGS_ARRAY,
+        }
+    }
+
+    /// Returns whether the subtag is registered with this type, including private use subtags.
+    pub fn is_registered(&self, subtag: &str) -> bool {
+        let subtag = subtag.to_lowercase();
+        self.registered_subtags().binary_search(&subtag.as_str()).is_ok() || self.is_private_use(&subtag)
+    }
+
+    /// Returns whether the subtag is reserved for private use, like the language `qaa` or the region `XA`.
+    pub fn is_private_use(&self, subtag: &str) -> bool {
+        is_private_use_subtag(self.as_str(), subtag)
+    }
+}
+
+impl Display for SubtagType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error of a language tag that is not well-formed or not valid according to RFC 5646
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum LanguageTagError {
+    #[error("the language tag is empty")]
+    Empty,
+    #[error("subtag '{0}' is not allowed at this position")]
+    MalformedSubtag(String),
+    #[error("{}", describe_unknown_subtag(*subtag_type, subtag))]
+    UnknownSubtag { subtag_type: SubtagType, subtag: String },
+    #[error("variant subtag '{0}' is used more than once")]
+    DuplicateVariant(String),
+    #[error("extension '{0}' is used more than once")]
+    DuplicateExtension(char),
+}
+
+fn describe_unknown_subtag(subtag_type: SubtagType, subtag: &str) -> String {
+    match subtag_type {
+        SubtagType::Language => format!("primary language subtag '{subtag}' is not a valid language subtag"),
+        _ => format!("{subtag_type} subtag '{subtag}' is not a valid {subtag_type} subtag"),
+    }
+}
+
+/// An extension of a language tag, like `u-co-phonebk`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Extension {
+    /// The singleton introducing the extension, like `u`
+    pub singleton: char,
+    /// The subtags of the extension, like `co` and `phonebk`
+    pub subtags: Vec<String>,
+}
+
+/// A language tag as defined by BCP 47 (RFC 5646), like `de-CH-1996` or `zh-Hant-TW`.
+///
+/// Parsing checks that the tag is well-formed, [LanguageTag::validate] that its subtags are
+/// registered in the IANA Language Subtag Registry. Subtags keep the case they were written in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LanguageTag {
+    /// A tag composed of subtags
+    Subtags(LanguageSubtags),
+    /// A tag consisting of private use subtags only, like `x-klingon`
+    PrivateUse(Vec<String>),
+    /// A tag registered as a whole before RFC 4646, like `i-default`
+    Grandfathered(String),
+}
+
+/// The subtags of a [LanguageTag::Subtags]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LanguageSubtags {
+    pub language: String,
+    pub extlangs: Vec<String>,
+    pub script: Option<String>,
+    pub region: Option<String>,
+    pub variants: Vec<String>,
+    pub extensions: Vec<Extension>,
+    /// The subtags after the `x` singleton
+    pub private_use: Vec<String>,
+}
+
+impl LanguageTag {
+    /// Checks that all subtags are registered and that no variant or extension is repeated.
+    pub fn validate(&self) -> Result<(), LanguageTagError> {
+        let LanguageTag::Subtags(subtags) = self else {
+            return Ok(());
+        };
+        let typed = std::iter::once((SubtagType::Language, &subtags.language))
+            .chain(subtags.extlangs.iter().map(|extlang| (SubtagType::Extlang, extlang)))
+            .chain(subtags.script.iter().map(|script| (SubtagType::Script, script)))
+            .chain(subtags.region.iter().map(|region| (SubtagType::Region, region)))
+            .chain(subtags.variants.iter().map(|variant| (SubtagType::Variant, variant)));
+        for (subtag_type, subtag) in typed {
+            if !subtag_type.is_registered(subtag) {
+                return Err(LanguageTagError::UnknownSubtag {
+                    subtag_type,
+                    subtag: subtag.clone(),
+                });
+            }
+        }
+        for (i, variant) in subtags.variants.iter().enumerate() {
+            if subtags.variants[..i]
+                .iter()
+                .any(|other| other.eq_ignore_ascii_case(variant))
+            {
+                return Err(LanguageTagError::DuplicateVariant(variant.clone()));
+            }
+        }
+        for (i, extension) in subtags.extensions.iter().enumerate() {
+            if subtags.extensions[..i]
+                .iter()
+                .any(|other| other.singleton == extension.singleton)
+            {
+                return Err(LanguageTagError::DuplicateExtension(extension.singleton));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the subtags reserved for private use: private use languages, scripts and regions as
+    /// well as all subtags after the `x` singleton.
+    pub fn private_use_subtags(&self) -> Vec<&str> {
+        match self {
+            LanguageTag::Subtags(subtags) => {
+                let typed = [
+                    (SubtagType::Language, Some(&subtags.language)),
+                    (SubtagType::Script, subtags.script.as_ref()),
+                    (SubtagType::Region, subtags.region.as_ref()),
+                ];
+                typed
+                    .into_iter()
+                    .filter_map(|(subtag_type, subtag)| subtag.filter(|subtag| subtag_type.is_private_use(subtag)))
+                    .chain(&subtags.private_use)
+                    .map(String::as_str)
+                    .collect()
+            },
+            LanguageTag::PrivateUse(subtags) => subtags.iter().map(String::as_str).collect(),
+            LanguageTag::Grandfathered(_) => Vec::new(),
+        }
+    }
+
+    /// Returns whether this is the default language tag `i-default`.
+    pub fn is_default(&self) -> bool {
+        matches!(self, LanguageTag::Grandfathered(tag) if tag.eq_ignore_ascii_case(DEFAULT_LANGUAGE_TAG))
+    }
+}
+
+fn is_alpha(s: &str, lengths: std::ops::RangeInclusive<usize>) -> bool {
+    lengths.contains(&s.len()) && s.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn is_alphanumeric(s: &str, lengths: std::ops::RangeInclusive<usize>) -> bool {
+    lengths.contains(&s.len()) && s.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+fn is_script(s: &str) -> bool {
+    is_alpha(s, 4..=4)
+}
+
+fn is_region(s: &str) -> bool {
+    is_alpha(s, 2..=2) || (s.len() == 3 && s.bytes().all(|b| b.is_ascii_digit()))
+}
+
+fn is_variant(s: &str) -> bool {
+    is_alphanumeric(s, 5..=8) || (is_alphanumeric(s, 4..=4) && s.as_bytes()[0].is_ascii_digit())
+}
+
+fn singleton(s: &str) -> Option<char> {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_ascii_alphanumeric() => Some(c.to_ascii_lowercase()),
+        _ => None,
+    }
+}
+
+/// Parses the subtags following the `x` singleton.
+fn parse_private_use<'a>(subtags: impl Iterator<Item = &'a str>) -> Result<Vec<String>, LanguageTagError> {
+    let subtags: Vec<String> = subtags
+        .map(|subtag| {
+            is_alphanumeric(subtag, 1..=8)
+                .then(|| subtag.to_string())
+                .ok_or_else(|| LanguageTagError::MalformedSubtag(subtag.to_string()))
+        })
+        .collect::<Result<_, _>>()?;
+    if subtags.is_empty() {
+        return Err(LanguageTagError::MalformedSubtag("x".to_string()));
+    }
+    Ok(subtags)
+}
+
+impl FromStr for LanguageTag {
+    type Err = LanguageTagError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(LanguageTagError::Empty);
+        }
+        if GRANDFATHERED_TAGS_ARRAY.contains(&s.to_lowercase().as_str()) {
+            return Ok(LanguageTag::Grandfathered(s.to_string()));
+        }
+
+        let mut parts = s.split('-').peekable();
+        let language = parts.next().unwrap_or_default();
+        if language.eq_ignore_ascii_case("x") {
+            return parse_private_use(parts).map(LanguageTag::PrivateUse);
+        }
+        if !is_alpha(language, 2..=8) {
+            return Err(LanguageTagError::MalformedSubtag(language.to_string()));
+        }
+        let mut subtags = LanguageSubtags {
+            language: language.to_string(),
+            ..Default::default()
+        };
+
+        // Up to three extlangs follow a language of two or three letters
+        if language.len() <= 3 {
+            while subtags.extlangs.len() < 3
+                && let Some(extlang) = parts.next_if(|part| is_alpha(part, 3..=3))
+            {
+                subtags.extlangs.push(extlang.to_string());
+            }
+        }
+        subtags.script = parts.next_if(|part| is_script(part)).map(str::to_string);
+        subtags.region = parts.next_if(|part| is_region(part)).map(str::to_string);
+        while let Some(variant) = parts.next_if(|part| is_variant(part)) {
+            subtags.variants.push(variant.to_string());
+        }
+
+        while let Some(part) = parts.next() {
+            match singleton(part) {
+                Some('x') => {
+                    subtags.private_use = parse_private_use(parts.by_ref())?;
+                },
+                Some(singleton) => {
+                    let mut extension = Extension {
+                        singleton,
+                        subtags: Vec::new(),
+                    };
+                    while let Some(subtag) = parts.next_if(|part| is_alphanumeric(part, 2..=8)) {
+                        extension.subtags.push(subtag.to_string());
+                    }
+                    if extension.subtags.is_empty() {
+                        return Err(LanguageTagError::MalformedSubtag(part.to_string()));
+                    }
+                    subtags.extensions.push(extension);
+                },
+                None => return Err(LanguageTagError::MalformedSubtag(part.to_string())),
+            }
+        }
+
+        Ok(LanguageTag::Subtags(subtags))
+    }
+}
+
+impl Display for LanguageTag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            LanguageTag::Subtags(subtags) => {
+                let mut parts: Vec<&str> = vec![&subtags.language];
+                parts.extend(subtags.extlangs.iter().map(String::as_str));
+                parts.extend(subtags.script.as_deref());
+                parts.extend(subtags.region.as_deref());
+                parts.extend(subtags.variants.iter().map(String::as_str));
+                let singletons: Vec<String> = subtags.extensions.iter().map(|e| e.singleton.to_string()).collect();
+                for (extension, singleton) in subtags.extensions.iter().zip(&singletons) {
+                    parts.push(singleton);
+                    parts.extend(extension.subtags.iter().map(String::as_str));
+                }
+                if !subtags.private_use.is_empty() {
+                    parts.push("x");
+                    parts.extend(subtags.private_use.iter().map(String::as_str));
+                }
+                f.write_str(&parts.join("-"))
+            },
+            LanguageTag::PrivateUse(subtags) => write!(f, "x-{}", subtags.join("-")),
+            LanguageTag::Grandfathered(tag) => f.write_str(tag),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(tag: &str) -> LanguageTag {
+        tag.parse()
+            .unwrap_or_else(|e| panic!("{tag} should be well-formed: {e}"))
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("zh-yue-Hant-HK-u-co-pinyin-x-private"),
+            LanguageTag::Subtags(LanguageSubtags {
+                language: "zh".to_string(),
+                extlangs: vec!["yue".to_string()],
+                script: Some("Hant".to_string()),
+                region: Some("HK".to_string()),
+                variants: vec![],
+                extensions: vec![Extension {
+                    singleton: 'u',
+                    subtags: vec!["co".to_string(), "pinyin".to_string()],
+                }],
+                private_use: vec!["private".to_string()],
+            })
+        );
+        for tag in [
+            "de-CH-1996",
+            "sl-rozaj-biske",
+            "es-419",
+            "x-whatever",
+            "i-default",
+            "en-GB-oed",
+        ] {
+            assert_eq!(parse(tag).to_string(), tag);
+            assert_eq!(parse(tag).validate(), Ok(()), "{tag}");
+        }
+
+        assert_eq!("".parse::<LanguageTag>(), Err(LanguageTagError::Empty));
+        for (tag, subtag) in [
+            ("de-419-DE", "DE"),
+            ("a-DE", "a"),
+            ("en-x", "x"),
+            ("en-u", "u"),
+            ("abcdefghi", ""),
+        ] {
+            let expected = if subtag.is_empty() { tag } else { subtag };
+            assert_eq!(
+                tag.parse::<LanguageTag>(),
+                Err(LanguageTagError::MalformedSubtag(expected.to_string())),
+                "{tag}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(
+            parse("EZ").validate(),
+            Err(LanguageTagError::UnknownSubtag {
+                subtag_type: SubtagType::Language,
+                subtag: "EZ".to_string(),
+            })
+        );
+        assert_eq!(
+            parse("en-Abcd").validate().unwrap_err().to_string(),
+            "script subtag 'Abcd' is not a valid script subtag"
+        );
+        assert_eq!(
+            parse("de-DE-1901-1901").validate(),
+            Err(LanguageTagError::DuplicateVariant("1901".to_string()))
+        );
+        assert_eq!(
+            parse("en-a-bbb-a-ccc").validate(),
+            Err(LanguageTagError::DuplicateExtension('a'))
+        );
+    }
+
+    #[test]
+    fn test_private_use_subtags() {
+        assert_eq!(
+            parse("qtx-Qaaa-QM-x-foo").private_use_subtags(),
+            vec!["qtx", "Qaaa", "QM", "foo"]
+        );
+        assert_eq!(parse("x-klingon").private_use_subtags(), vec!["klingon"]);
+        assert!(parse("en-US").private_use_subtags().is_empty());
+        assert!(parse("I-Default").is_default());
+        assert!(!parse("en").is_default());
+    }
+}
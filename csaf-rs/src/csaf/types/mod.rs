@@ -3,3 +3,4 @@ pub mod csaf_document_category;
 pub mod csaf_hash_algo;
 pub mod csaf_version_number;
 pub mod csaf_vuln_metric;
+pub mod language_tag;
@@ -63,6 +63,14 @@ impl RemediationTrait for Remediation {
             CategoryOfTheRemediation::NoneAvailable => Remediation21::NoneAvailable,
         }
     }
+
+    fn get_details(&self) -> &String {
+        self.details.deref()
+    }
+
+    fn get_url(&self) -> Option<&String> {
+        self.url.as_ref()
+    }
 }
 
 impl WithOptionalDate for Remediation {
@@ -188,6 +196,10 @@ impl ThreatTrait for Threat {
             CategoryOfTheThreat::TargetSet => CategoryOfTheThreat21::TargetSet,
         }
     }
+
+    fn get_details(&self) -> &String {
+        self.details.deref()
+    }
 }
 
 impl WithOptionalDate for Threat {
@@ -249,6 +261,10 @@ impl VulnerabilityTrait for Vulnerability {
         self.cve.as_deref()
     }
 
+    fn get_title(&self) -> Option<&String> {
+        self.title.as_deref()
+    }
+
     fn get_cwe(&self) -> Option<Vec<Cwe>> {
         self.cwe.as_ref().map(|cwe| vec![Cwe::from(cwe)])
     }
@@ -308,7 +324,11 @@ impl WithOptionalDate for Flag {
     }
 }
 
-impl FirstKnownExploitationDatesTrait for () {}
+impl FirstKnownExploitationDatesTrait for () {
+    fn get_exploitation_date(&self) -> CsafDateTime {
+        panic!("First known exploitation dates are not implemented in CSAF 2.0");
+    }
+}
 
 impl WithOptionalProductIds for () {
     fn get_product_ids(&self) -> Option<impl Iterator<Item = &String> + '_> {
@@ -407,6 +427,15 @@ impl DocumentTrait for DocumentLevelMetaData {
         self.notes.as_deref()
     }
 
+    fn get_title(&self) -> &String {
+        &self.title
+    }
+
+    /// Not present in CSAF 2.0
+    fn get_license_expression(&self) -> Option<&String> {
+        None
+    }
+
     fn get_lang(&self) -> Option<&String> {
         self.lang.as_deref()
     }
@@ -462,6 +491,14 @@ impl PublisherTrait for Publisher {
             CategoryOfPublisher::User => CategoryOfPublisher21::User,
         }
     }
+
+    fn get_name(&self) -> &String {
+        self.name.deref()
+    }
+
+    fn get_namespace(&self) -> &String {
+        &self.namespace
+    }
 }
 
 impl DistributionTrait for RulesForSharingDocument {
@@ -513,6 +550,10 @@ impl NoteTrait for Note {
             NoteCategory::LegalDisclaimer => NoteCategory21::LegalDisclaimer,
         }
     }
+
+    fn get_title(&self) -> Option<&String> {
+        self.title.as_deref()
+    }
 }
 
 impl SharingGroupTrait for () {
@@ -598,6 +639,10 @@ impl RevisionTrait for Revision {
     fn get_summary(&self) -> &String {
         &self.summary
     }
+
+    fn get_legacy_version(&self) -> Option<&String> {
+        self.legacy_version.as_deref()
+    }
 }
 
 impl WithDate for Revision {
@@ -721,6 +766,14 @@ impl ProductIdentificationHelperTrait for HelperToIdentifyTheProduct {
     fn get_hashes(&self) -> &Vec<Self::HashType> {
         self.hashes.as_ref()
     }
+
+    fn get_cpe(&self) -> Option<&String> {
+        self.cpe.as_deref()
+    }
+
+    fn get_sbom_urls(&self) -> &Vec<String> {
+        &self.sbom_urls
+    }
 }
 
 impl HashTrait for CryptographicHashes {
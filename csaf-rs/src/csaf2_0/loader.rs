@@ -1,19 +1,36 @@
-use crate::{csaf::raw::RawDocument, schema::csaf2_0::schema::CommonSecurityAdvisoryFramework};
-use std::{fs::File, io::BufReader};
+use crate::{
+    csaf::{
+        loader::{DEFAULT_MAX_SIZE, LoadError, check_size, read_file},
+        raw::RawDocument,
+        span::SpanIndex,
+    },
+    schema::csaf2_0::schema::CommonSecurityAdvisoryFramework,
+};
+use std::path::Path;
 
-pub fn load_document(path: &str) -> std::io::Result<RawDocument<CommonSecurityAdvisoryFramework>> {
-    println!("Trying to load document {path}");
-
-    let f = File::open(path)?;
-    let reader = BufReader::new(f);
-    let doc: RawDocument<CommonSecurityAdvisoryFramework> = RawDocument::new(serde_json::from_reader(reader)?);
-    Ok(doc)
+pub fn load_document(path: &str) -> Result<RawDocument<CommonSecurityAdvisoryFramework>, LoadError> {
+    load_document_from_str(&read_file(Path::new(path), DEFAULT_MAX_SIZE)?)
 }
 
 /// Load a CSAF document from a JSON string
-pub fn load_document_from_str(json_str: &str) -> std::io::Result<RawDocument<CommonSecurityAdvisoryFramework>> {
-    let doc: RawDocument<CommonSecurityAdvisoryFramework> = RawDocument::new(serde_json::from_str(json_str)?);
-    Ok(doc)
+pub fn load_document_from_str(json_str: &str) -> Result<RawDocument<CommonSecurityAdvisoryFramework>, LoadError> {
+    check_size(json_str, DEFAULT_MAX_SIZE)?;
+    Ok(RawDocument::new(serde_json::from_str(json_str)?))
+}
+
+/// Load a CSAF document from a file, keeping a [SpanIndex] that maps JSON pointers to their
+/// position in the file
+pub fn load_document_with_spans(path: &str) -> Result<RawDocument<CommonSecurityAdvisoryFramework>, LoadError> {
+    load_document_from_str_with_spans(&read_file(Path::new(path), DEFAULT_MAX_SIZE)?)
+}
+
+/// Load a CSAF document from a JSON string, keeping a [SpanIndex] that maps JSON pointers to their
+/// position in the string
+pub fn load_document_from_str_with_spans(
+    json_str: &str,
+) -> Result<RawDocument<CommonSecurityAdvisoryFramework>, LoadError> {
+    let doc = load_document_from_str(json_str)?;
+    Ok(doc.with_span_index(SpanIndex::new(json_str)))
 }
 
 #[cfg(test)]
@@ -62,4 +79,30 @@ mod tests {
     fn it_works() {
         mock_document();
     }
+
+    #[test]
+    fn test_source_locations() {
+        let mut json = serde_json::to_value(mock_document()).unwrap();
+        json["product_tree"] = serde_json::json!({
+            "full_product_names": [
+                { "name": "Product A", "product_id": "CSAFPID-0001" },
+                { "name": "Product B", "product_id": "CSAFPID-0001" }
+            ]
+        });
+        let json_str = serde_json::to_string_pretty(&json).unwrap();
+
+        let doc = super::load_document_from_str_with_spans(&json_str).unwrap();
+        let result =
+            crate::validation::validate_by_tests(&doc, "2.0", crate::validation::ValidationPreset::Basic, &["6.1.2"]);
+
+        let span = result
+            .source_locations
+            .get("/product_tree/full_product_names/1/product_id")
+            .unwrap();
+        assert_eq!(&json_str[span.start..span.end], "\"CSAFPID-0001\"");
+        assert_eq!(
+            json_str.lines().nth(span.line - 1).unwrap().trim(),
+            "\"product_id\": \"CSAFPID-0001\""
+        );
+    }
 }
@@ -1,188 +1,124 @@
 use crate::csaf::raw::{RawDocument, RawValidatable};
+use crate::csaf_traits::CsafVersion;
 use crate::csaf2_0::testcases::*;
 use crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework;
+use crate::test_registry::TestGroup::{Informative, Mandatory, Recommended};
+use crate::test_registry::{TestDefinition, TestRegistry};
 use crate::test_validation::TestValidator;
-use crate::validation::{TestResult, TestResultStatus, Validatable, ValidationPreset};
+use crate::validation::{TestResult, Validatable, ValidationPreset};
+use crate::validations::test_6_3_6::has_url_resolver;
+use crate::validations::test_6_3_8::has_dictionary;
+use crate::validations::{test_6_1_14, test_6_1_16, test_6_1_21, test_6_2_01, test_6_2_04, test_6_2_13};
 
-enum Severity {
-    Error,
-    Warning,
-    Info,
-}
-
-fn to_test_result(
-    test_id: &str,
-    severity: Severity,
-    result: Option<Result<(), Vec<crate::validation::ValidationError>>>,
-) -> TestResult {
-    TestResult {
-        test_id: test_id.to_string(),
-        status: match result {
-            None => TestResultStatus::NotFound,
-            Some(Ok(())) => TestResultStatus::Success,
-            Some(Err(data)) => match severity {
-                Severity::Error => TestResultStatus::Failure {
-                    errors: data,
-                    warnings: vec![],
-                    infos: vec![],
-                },
-                Severity::Warning => TestResultStatus::Failure {
-                    errors: vec![],
-                    warnings: data,
-                    infos: vec![],
-                },
-                Severity::Info => TestResultStatus::Failure {
-                    errors: vec![],
-                    warnings: vec![],
-                    infos: data,
-                },
-            },
-        },
-    }
-}
+/// All tests of CSAF 2.0, see [crate::test_registry]
+pub static TEST_REGISTRY: TestRegistry<CommonSecurityAdvisoryFramework> = TestRegistry {
+    version: CsafVersion::X20,
+    tests: &[
+        // mandatory tests
+        TestDefinition::document("6.1.1", Mandatory, |doc| ValidatorForTest6_1_1.validate(doc)),
+        TestDefinition::document("6.1.2", Mandatory, |doc| ValidatorForTest6_1_2.validate(doc)),
+        TestDefinition::document("6.1.3", Mandatory, |doc| ValidatorForTest6_1_3.validate(doc)),
+        TestDefinition::document("6.1.4", Mandatory, |doc| ValidatorForTest6_1_4.validate(doc)),
+        TestDefinition::document("6.1.5", Mandatory, |doc| ValidatorForTest6_1_5.validate(doc)),
+        TestDefinition::document("6.1.6", Mandatory, |doc| ValidatorForTest6_1_6.validate(doc)),
+        TestDefinition::document("6.1.7", Mandatory, |doc| ValidatorForTest6_1_7.validate(doc)),
+        TestDefinition::document("6.1.8", Mandatory, |doc| ValidatorForTest6_1_8.validate(doc)),
+        TestDefinition::document("6.1.9", Mandatory, |doc| ValidatorForTest6_1_9.validate(doc)),
+        TestDefinition::document("6.1.10", Mandatory, |doc| ValidatorForTest6_1_10.validate(doc)),
+        TestDefinition::document("6.1.11", Mandatory, |doc| ValidatorForTest6_1_11.validate(doc)),
+        TestDefinition::document("6.1.12", Mandatory, |doc| ValidatorForTest6_1_12.validate(doc)),
+        TestDefinition::document("6.1.13", Mandatory, |doc| ValidatorForTest6_1_13.validate(doc)),
+        TestDefinition::document("6.1.14", Mandatory, |doc| ValidatorForTest6_1_14.validate(doc))
+            .with_fix(test_6_1_14::fix_6_1_14_sorted_revision_history),
+        TestDefinition::document("6.1.15", Mandatory, |doc| ValidatorForTest6_1_15.validate(doc)),
+        TestDefinition::document("6.1.16", Mandatory, |doc| ValidatorForTest6_1_16.validate(doc))
+            .with_fix(test_6_1_16::fix_6_1_16_latest_document_version),
+        TestDefinition::document("6.1.17", Mandatory, |doc| ValidatorForTest6_1_17.validate(doc)),
+        TestDefinition::document("6.1.18", Mandatory, |doc| ValidatorForTest6_1_18.validate(doc)),
+        TestDefinition::document("6.1.19", Mandatory, |doc| ValidatorForTest6_1_19.validate(doc)),
+        TestDefinition::document("6.1.20", Mandatory, |doc| ValidatorForTest6_1_20.validate(doc)),
+        TestDefinition::document("6.1.21", Mandatory, |doc| ValidatorForTest6_1_21.validate(doc))
+            .with_fix(test_6_1_21::fix_6_1_21_missing_item_in_revision_history),
+        TestDefinition::document("6.1.22", Mandatory, |doc| ValidatorForTest6_1_22.validate(doc)),
+        TestDefinition::document("6.1.23", Mandatory, |doc| ValidatorForTest6_1_23.validate(doc)),
+        TestDefinition::document("6.1.24", Mandatory, |doc| ValidatorForTest6_1_24.validate(doc)),
+        TestDefinition::document("6.1.25", Mandatory, |doc| ValidatorForTest6_1_25.validate(doc)),
+        TestDefinition::document("6.1.26", Mandatory, |doc| ValidatorForTest6_1_26.validate(doc)),
+        TestDefinition::document("6.1.27.1", Mandatory, |doc| ValidatorForTest6_1_27_1.validate(doc)),
+        TestDefinition::document("6.1.27.2", Mandatory, |doc| ValidatorForTest6_1_27_2.validate(doc)),
+        TestDefinition::document("6.1.27.3", Mandatory, |doc| ValidatorForTest6_1_27_3.validate(doc)),
+        TestDefinition::document("6.1.27.4", Mandatory, |doc| ValidatorForTest6_1_27_4.validate(doc)),
+        TestDefinition::document("6.1.27.5", Mandatory, |doc| ValidatorForTest6_1_27_5.validate(doc)),
+        TestDefinition::document("6.1.27.6", Mandatory, |doc| ValidatorForTest6_1_27_6.validate(doc)),
+        TestDefinition::document("6.1.27.7", Mandatory, |doc| ValidatorForTest6_1_27_7.validate(doc)),
+        TestDefinition::document("6.1.27.8", Mandatory, |doc| ValidatorForTest6_1_27_8.validate(doc)),
+        TestDefinition::document("6.1.27.9", Mandatory, |doc| ValidatorForTest6_1_27_9.validate(doc)),
+        TestDefinition::document("6.1.27.10", Mandatory, |doc| ValidatorForTest6_1_27_10.validate(doc)),
+        TestDefinition::document("6.1.27.11", Mandatory, |doc| ValidatorForTest6_1_27_11.validate(doc)),
+        TestDefinition::document("6.1.28", Mandatory, |doc| ValidatorForTest6_1_28.validate(doc)),
+        TestDefinition::document("6.1.29", Mandatory, |doc| ValidatorForTest6_1_29.validate(doc)),
+        TestDefinition::document("6.1.30", Mandatory, |doc| ValidatorForTest6_1_30.validate(doc)),
+        TestDefinition::document("6.1.31", Mandatory, |doc| ValidatorForTest6_1_31.validate(doc)),
+        TestDefinition::document("6.1.32", Mandatory, |doc| ValidatorForTest6_1_32.validate(doc)),
+        TestDefinition::document("6.1.33", Mandatory, |doc| ValidatorForTest6_1_33.validate(doc)),
+        // recommended tests
+        TestDefinition::document("6.2.1", Recommended, |doc| ValidatorForTest6_2_1.validate(doc))
+            .with_fix(test_6_2_01::fix_6_2_01_unused_definition_of_product_id),
+        TestDefinition::document("6.2.2", Recommended, |doc| ValidatorForTest6_2_2.validate(doc)),
+        TestDefinition::document("6.2.3", Recommended, |doc| ValidatorForTest6_2_3.validate(doc)),
+        TestDefinition::document("6.2.4", Recommended, |doc| ValidatorForTest6_2_4.validate(doc))
+            .with_fix(test_6_2_04::fix_6_2_04_build_metadata_in_rev_history),
+        TestDefinition::document("6.2.5", Recommended, |doc| ValidatorForTest6_2_5.validate(doc)),
+        TestDefinition::document("6.2.6", Recommended, |doc| ValidatorForTest6_2_6.validate(doc)),
+        TestDefinition::document("6.2.7", Recommended, |doc| ValidatorForTest6_2_7.validate(doc)),
+        TestDefinition::document("6.2.8", Recommended, |doc| ValidatorForTest6_2_8.validate(doc)),
+        TestDefinition::document("6.2.9", Recommended, |doc| ValidatorForTest6_2_9.validate(doc)),
+        TestDefinition::document("6.2.10", Recommended, |doc| ValidatorForTest6_2_10.validate(doc)),
+        TestDefinition::document("6.2.11", Recommended, |doc| ValidatorForTest6_2_11.validate(doc)),
+        TestDefinition::document("6.2.12", Recommended, |doc| ValidatorForTest6_2_12.validate(doc)),
+        TestDefinition::raw("6.2.13", Recommended, |doc| ValidatorForTest6_2_13.validate(doc))
+            .with_fix(|_, json| test_6_2_13::fix_6_2_13_sorting(json)),
+        TestDefinition::document("6.2.14", Recommended, |doc| ValidatorForTest6_2_14.validate(doc)),
+        TestDefinition::document("6.2.15", Recommended, |doc| ValidatorForTest6_2_15.validate(doc)),
+        TestDefinition::document("6.2.16", Recommended, |doc| ValidatorForTest6_2_16.validate(doc)),
+        TestDefinition::document("6.2.17", Recommended, |doc| ValidatorForTest6_2_17.validate(doc)),
+        TestDefinition::document("6.2.18", Recommended, |doc| ValidatorForTest6_2_18.validate(doc)),
+        TestDefinition::not_implemented("6.2.19", Recommended),
+        TestDefinition::raw("6.2.20", Recommended, |doc| ValidatorForTest6_2_20.validate(doc)),
+        // informative tests
+        TestDefinition::document("6.3.1", Informative, |doc| ValidatorForTest6_3_1.validate(doc)),
+        TestDefinition::document("6.3.2", Informative, |doc| ValidatorForTest6_3_2.validate(doc)),
+        TestDefinition::document("6.3.3", Informative, |doc| ValidatorForTest6_3_3.validate(doc)),
+        TestDefinition::document("6.3.4", Informative, |doc| ValidatorForTest6_3_4.validate(doc)),
+        TestDefinition::document("6.3.5", Informative, |doc| ValidatorForTest6_3_5.validate(doc)),
+        TestDefinition::raw("6.3.6", Informative, |doc| ValidatorForTest6_3_6.validate(doc))
+            .with_precondition(has_url_resolver),
+        TestDefinition::raw("6.3.7", Informative, |doc| ValidatorForTest6_3_7.validate(doc))
+            .with_precondition(has_url_resolver),
+        TestDefinition::raw("6.3.8", Informative, |doc| ValidatorForTest6_3_8.validate(doc))
+            .with_precondition(has_dictionary),
+        TestDefinition::document("6.3.9", Informative, |doc| ValidatorForTest6_3_9.validate(doc)),
+        TestDefinition::document("6.3.10", Informative, |doc| ValidatorForTest6_3_10.validate(doc)),
+        TestDefinition::document("6.3.11", Informative, |doc| ValidatorForTest6_3_11.validate(doc)),
+    ],
+};
 
 impl Validatable for CommonSecurityAdvisoryFramework {
     fn tests_in_preset(preset: &ValidationPreset) -> Vec<&str> {
-        match preset {
-            ValidationPreset::Basic => mandatory_tests(),
-            ValidationPreset::Extended => [mandatory_tests(), recommended_tests()].concat(),
-            ValidationPreset::Full => [mandatory_tests(), recommended_tests(), informative_tests()].concat(),
-        }
+        TEST_REGISTRY.tests_in_preset(preset)
     }
 
     fn run_test(&self, test_id: &str) -> TestResult {
-        let mandatory_result = to_test_result(
-            test_id,
-            Severity::Error,
-            match test_id {
-                // mandatory tests
-                "6.1.1" => Some(ValidatorForTest6_1_1.validate(self)),
-                "6.1.2" => Some(ValidatorForTest6_1_2.validate(self)),
-                "6.1.3" => Some(ValidatorForTest6_1_3.validate(self)),
-                "6.1.4" => Some(ValidatorForTest6_1_4.validate(self)),
-                "6.1.5" => Some(ValidatorForTest6_1_5.validate(self)),
-                "6.1.6" => Some(ValidatorForTest6_1_6.validate(self)),
-                "6.1.7" => Some(ValidatorForTest6_1_7.validate(self)),
-                "6.1.8" => Some(ValidatorForTest6_1_8.validate(self)),
-                "6.1.9" => None,  // Some(ValidatorForTest6_1_9.validate(self)),
-                "6.1.10" => None, // Some(ValidatorForTest6_1_10.validate(self)),
-                "6.1.11" => Some(ValidatorForTest6_1_11.validate(self)),
-                "6.1.12" => Some(ValidatorForTest6_1_12.validate(self)),
-                "6.1.13" => Some(ValidatorForTest6_1_13.validate(self)),
-                "6.1.14" => Some(ValidatorForTest6_1_14.validate(self)),
-                "6.1.15" => Some(ValidatorForTest6_1_15.validate(self)),
-                "6.1.16" => Some(ValidatorForTest6_1_16.validate(self)),
-                "6.1.17" => Some(ValidatorForTest6_1_17.validate(self)),
-                "6.1.18" => Some(ValidatorForTest6_1_18.validate(self)),
-                "6.1.19" => Some(ValidatorForTest6_1_19.validate(self)),
-                "6.1.20" => Some(ValidatorForTest6_1_20.validate(self)),
-                "6.1.21" => Some(ValidatorForTest6_1_21.validate(self)),
-                "6.1.22" => Some(ValidatorForTest6_1_22.validate(self)),
-                "6.1.23" => Some(ValidatorForTest6_1_23.validate(self)),
-                "6.1.24" => Some(ValidatorForTest6_1_24.validate(self)),
-                "6.1.25" => Some(ValidatorForTest6_1_25.validate(self)),
-                "6.1.26" => None, // Some(ValidatorForTest6_1_26.validate(self)),
-                "6.1.27.1" => Some(ValidatorForTest6_1_27_1.validate(self)),
-                "6.1.27.2" => Some(ValidatorForTest6_1_27_2.validate(self)),
-                "6.1.27.3" => Some(ValidatorForTest6_1_27_3.validate(self)),
-                "6.1.27.4" => Some(ValidatorForTest6_1_27_4.validate(self)),
-                "6.1.27.5" => Some(ValidatorForTest6_1_27_5.validate(self)),
-                "6.1.27.6" => Some(ValidatorForTest6_1_27_6.validate(self)),
-                "6.1.27.7" => Some(ValidatorForTest6_1_27_7.validate(self)),
-                "6.1.27.8" => Some(ValidatorForTest6_1_27_8.validate(self)),
-                "6.1.27.9" => Some(ValidatorForTest6_1_27_9.validate(self)),
-                "6.1.27.10" => Some(ValidatorForTest6_1_27_10.validate(self)),
-                "6.1.27.11" => Some(ValidatorForTest6_1_27_11.validate(self)),
-                "6.1.28" => Some(ValidatorForTest6_1_28.validate(self)),
-                "6.1.29" => Some(ValidatorForTest6_1_29.validate(self)),
-                "6.1.30" => Some(ValidatorForTest6_1_30.validate(self)),
-                "6.1.31" => Some(ValidatorForTest6_1_31.validate(self)),
-                "6.1.32" => Some(ValidatorForTest6_1_32.validate(self)),
-                "6.1.33" => Some(ValidatorForTest6_1_33.validate(self)),
-                _ => None,
-            },
-        );
-        if TestResultStatus::NotFound != mandatory_result.status {
-            return mandatory_result;
-        }
-
-        let recommended_result = to_test_result(
-            test_id,
-            Severity::Warning,
-            match test_id {
-                // recommended tests
-                "6.2.1" => Some(ValidatorForTest6_2_1.validate(self)),
-                "6.2.2" => Some(ValidatorForTest6_2_2.validate(self)),
-                "6.2.3" => Some(ValidatorForTest6_2_3.validate(self)),
-                "6.2.4" => Some(ValidatorForTest6_2_4.validate(self)),
-                "6.2.5" => Some(ValidatorForTest6_2_5.validate(self)),
-                "6.2.6" => Some(ValidatorForTest6_2_6.validate(self)),
-                "6.2.7" => Some(ValidatorForTest6_2_7.validate(self)),
-                "6.2.8" => None, // Some(ValidatorForTest6_2_8.validate(self)),
-                "6.2.9" => None, // Some(ValidatorForTest6_2_9.validate(self)),
-                "6.2.10" => Some(ValidatorForTest6_2_10.validate(self)),
-                "6.2.11" => Some(ValidatorForTest6_2_11.validate(self)),
-                "6.2.12" => Some(ValidatorForTest6_2_12.validate(self)),
-                "6.2.13" => {
-                    // see below in RawValidatable
-                    return TestResult {
-                        test_id: test_id.to_string(),
-                        status: TestResultStatus::Skipped,
-                    };
-                },
-                "6.2.14" => None, // Some(ValidatorForTest6_2_14.validate(self)),
-                "6.2.15" => Some(ValidatorForTest6_2_15.validate(self)),
-                "6.2.16" => Some(ValidatorForTest6_2_16.validate(self)),
-                "6.2.17" => Some(ValidatorForTest6_2_17.validate(self)),
-                "6.2.18" => Some(ValidatorForTest6_2_18.validate(self)),
-                "6.2.19" => None, // Some(ValidatorForTest6_2_19.validate(self)),
-                "6.2.20" => {
-                    // see below in RawValidatable
-                    return TestResult {
-                        test_id: test_id.to_string(),
-                        status: TestResultStatus::Skipped,
-                    };
-                },
-                _ => None,
-            },
-        );
-        if TestResultStatus::NotFound != recommended_result.status {
-            return recommended_result;
-        }
-
-        to_test_result(
-            test_id,
-            Severity::Info,
-            match test_id {
-                // informative tests
-                "6.3.1" => Some(ValidatorForTest6_3_1.validate(self)),
-                "6.3.2" => None, // Some(ValidatorForTest6_3_2.validate(self)),
-                "6.3.3" => Some(ValidatorForTest6_3_3.validate(self)),
-                "6.3.4" => Some(ValidatorForTest6_3_4.validate(self)),
-                "6.3.5" => Some(ValidatorForTest6_3_5.validate(self)),
-                "6.3.6" => None, // Some(ValidatorForTest6_3_6.validate(self)),
-                "6.3.7" => None, // Some(ValidatorForTest6_3_7.validate(self)),
-                "6.3.8" => None, // Some(ValidatorForTest6_3_8.validate(self)),
-                "6.3.9" => None, // Some(ValidatorForTest6_3_9.validate(self)),
-                "6.3.10" => Some(ValidatorForTest6_3_10.validate(self)),
-                "6.3.11" => Some(ValidatorForTest6_3_11.validate(self)),
-                // invalid tests
-                _ => None,
-            },
-        )
+        TEST_REGISTRY.run_test(self, test_id)
     }
 }
 
 impl RawValidatable for RawDocument<CommonSecurityAdvisoryFramework> {
+    fn validate_schema(&self) -> Result<(), Vec<crate::validation::ValidationError>> {
+        crate::csaf::json_schema::validate_csaf_2_0_schema(self)
+    }
+
     fn run_raw_test(&self, test_id: &str) -> TestResult {
-        to_test_result(
-            test_id,
-            Severity::Warning,
-            match test_id {
-                "6.2.13" => Some(ValidatorForTest6_2_13.validate(self)),
-                "6.2.20" => Some(ValidatorForTest6_2_20.validate(self)),
-                _ => None,
-            },
-        )
+        TEST_REGISTRY.run_raw_test(self, test_id)
     }
 }
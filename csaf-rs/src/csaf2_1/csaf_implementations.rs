@@ -41,6 +41,14 @@ impl RemediationTrait for Remediation {
     fn get_category(&self) -> CategoryOfTheRemediation {
         self.category
     }
+
+    fn get_details(&self) -> &String {
+        self.details.deref()
+    }
+
+    fn get_url(&self) -> Option<&String> {
+        self.url.as_ref()
+    }
 }
 
 impl WithOptionalDate for Remediation {
@@ -163,6 +171,10 @@ impl ThreatTrait for Threat {
     fn get_category(&self) -> CategoryOfTheThreat {
         self.category
     }
+
+    fn get_details(&self) -> &String {
+        self.details.deref()
+    }
 }
 
 impl WithOptionalDate for Threat {
@@ -222,6 +234,10 @@ impl VulnerabilityTrait for Vulnerability {
         self.cve.as_deref()
     }
 
+    fn get_title(&self) -> Option<&String> {
+        self.title.as_deref()
+    }
+
     fn get_cwe(&self) -> Option<Vec<Cwe>> {
         self.cwes.as_ref().map(|cwes| cwes.iter().map(Cwe::from).collect())
     }
@@ -273,7 +289,11 @@ impl WithOptionalDate for Flag {
     }
 }
 
-impl FirstKnownExploitationDatesTrait for FirstKnownExploitationDate {}
+impl FirstKnownExploitationDatesTrait for FirstKnownExploitationDate {
+    fn get_exploitation_date(&self) -> CsafDateTime {
+        CsafDateTime::from(&self.exploitation_date)
+    }
+}
 
 impl WithOptionalProductIds for FirstKnownExploitationDate {
     fn get_product_ids(&self) -> Option<impl Iterator<Item = &String> + '_> {
@@ -360,6 +380,14 @@ impl DocumentTrait for DocumentLevelMetaData {
         self.notes.as_deref()
     }
 
+    fn get_title(&self) -> &String {
+        &self.title
+    }
+
+    fn get_license_expression(&self) -> Option<&String> {
+        self.license_expression.as_deref()
+    }
+
     fn get_lang(&self) -> Option<&String> {
         self.lang.as_deref()
     }
@@ -405,6 +433,14 @@ impl PublisherTrait for Publisher {
     fn get_category(&self) -> CategoryOfPublisher {
         self.category
     }
+
+    fn get_name(&self) -> &String {
+        self.name.deref()
+    }
+
+    fn get_namespace(&self) -> &String {
+        &self.namespace
+    }
 }
 
 impl DistributionTrait for RulesForDocumentSharing {
@@ -442,6 +478,10 @@ impl NoteTrait for Note {
     fn get_category(&self) -> NoteCategory {
         self.category
     }
+
+    fn get_title(&self) -> Option<&String> {
+        self.title.as_deref()
+    }
 }
 
 impl SharingGroupTrait for SharingGroup {
@@ -509,6 +549,10 @@ impl RevisionTrait for Revision {
     fn get_summary(&self) -> &String {
         &self.summary
     }
+
+    fn get_legacy_version(&self) -> Option<&String> {
+        self.legacy_version.as_deref()
+    }
 }
 
 impl WithDate for Revision {
@@ -633,6 +677,14 @@ impl ProductIdentificationHelperTrait for HelperToIdentifyTheProduct {
     fn get_hashes(&self) -> &Vec<Self::HashType> {
         self.hashes.as_ref()
     }
+
+    fn get_cpe(&self) -> Option<&String> {
+        self.cpe.as_deref()
+    }
+
+    fn get_sbom_urls(&self) -> &Vec<String> {
+        &self.sbom_urls
+    }
 }
 
 impl HashTrait for CryptographicHashes {
@@ -1,253 +1,208 @@
 use crate::csaf::raw::{RawDocument, RawValidatable};
+use crate::csaf_traits::CsafVersion;
 use crate::csaf2_1::testcases::*;
 use crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework;
+use crate::test_registry::TestGroup::{Informative, Mandatory, Recommended};
+use crate::test_registry::{TestDefinition, TestRegistry};
 use crate::test_validation::TestValidator;
-use crate::validation::{TestResult, TestResultStatus, Validatable, ValidationPreset};
-enum Severity {
-    Error,
-    Warning,
-    Info,
-}
+use crate::validation::{TestResult, Validatable, ValidationPreset};
+use crate::validations::test_6_3_6::has_url_resolver;
+use crate::validations::test_6_3_8::has_dictionary;
+use crate::validations::{test_6_1_14, test_6_1_16, test_6_1_21, test_6_2_01, test_6_2_04, test_6_2_13};
 
-fn to_test_result(
-    test_id: &str,
-    severity: Severity,
-    result: Option<Result<(), Vec<crate::validation::ValidationError>>>,
-) -> TestResult {
-    TestResult {
-        test_id: test_id.to_string(),
-        status: match result {
-            None => TestResultStatus::NotFound,
-            Some(Ok(())) => TestResultStatus::Success,
-            Some(Err(data)) => match severity {
-                Severity::Error => TestResultStatus::Failure {
-                    errors: data,
-                    warnings: vec![],
-                    infos: vec![],
-                },
-                Severity::Warning => TestResultStatus::Failure {
-                    errors: vec![],
-                    warnings: data,
-                    infos: vec![],
-                },
-                Severity::Info => TestResultStatus::Failure {
-                    errors: vec![],
-                    warnings: vec![],
-                    infos: data,
-                },
-            },
-        },
-    }
-}
+/// All tests of CSAF 2.1, see [crate::test_registry]
+pub static TEST_REGISTRY: TestRegistry<CommonSecurityAdvisoryFramework> = TestRegistry {
+    version: CsafVersion::X21,
+    tests: &[
+        // mandatory tests
+        TestDefinition::document("6.1.1", Mandatory, |doc| ValidatorForTest6_1_1.validate(doc)),
+        TestDefinition::document("6.1.2", Mandatory, |doc| ValidatorForTest6_1_2.validate(doc)),
+        TestDefinition::document("6.1.3", Mandatory, |doc| ValidatorForTest6_1_3.validate(doc)),
+        TestDefinition::document("6.1.4", Mandatory, |doc| ValidatorForTest6_1_4.validate(doc)),
+        TestDefinition::document("6.1.5", Mandatory, |doc| ValidatorForTest6_1_5.validate(doc)),
+        TestDefinition::document("6.1.6", Mandatory, |doc| ValidatorForTest6_1_6.validate(doc)),
+        TestDefinition::document("6.1.7", Mandatory, |doc| ValidatorForTest6_1_7.validate(doc)),
+        TestDefinition::document("6.1.8", Mandatory, |doc| ValidatorForTest6_1_8.validate(doc)),
+        TestDefinition::document("6.1.9", Mandatory, |doc| ValidatorForTest6_1_9.validate(doc)),
+        TestDefinition::document("6.1.10", Mandatory, |doc| ValidatorForTest6_1_10.validate(doc)),
+        TestDefinition::document("6.1.11", Mandatory, |doc| ValidatorForTest6_1_11.validate(doc)),
+        TestDefinition::document("6.1.12", Mandatory, |doc| ValidatorForTest6_1_12.validate(doc)),
+        TestDefinition::document("6.1.13", Mandatory, |doc| ValidatorForTest6_1_13.validate(doc)),
+        TestDefinition::document("6.1.14", Mandatory, |doc| ValidatorForTest6_1_14.validate(doc))
+            .with_fix(test_6_1_14::fix_6_1_14_sorted_revision_history),
+        TestDefinition::document("6.1.15", Mandatory, |doc| ValidatorForTest6_1_15.validate(doc)),
+        TestDefinition::document("6.1.16", Mandatory, |doc| ValidatorForTest6_1_16.validate(doc))
+            .with_fix(test_6_1_16::fix_6_1_16_latest_document_version),
+        TestDefinition::document("6.1.17", Mandatory, |doc| ValidatorForTest6_1_17.validate(doc)),
+        TestDefinition::document("6.1.18", Mandatory, |doc| ValidatorForTest6_1_18.validate(doc)),
+        TestDefinition::document("6.1.19", Mandatory, |doc| ValidatorForTest6_1_19.validate(doc)),
+        TestDefinition::document("6.1.20", Mandatory, |doc| ValidatorForTest6_1_20.validate(doc)),
+        TestDefinition::document("6.1.21", Mandatory, |doc| ValidatorForTest6_1_21.validate(doc))
+            .with_fix(test_6_1_21::fix_6_1_21_missing_item_in_revision_history),
+        TestDefinition::document("6.1.22", Mandatory, |doc| ValidatorForTest6_1_22.validate(doc)),
+        TestDefinition::document("6.1.23", Mandatory, |doc| ValidatorForTest6_1_23.validate(doc)),
+        TestDefinition::document("6.1.24", Mandatory, |doc| ValidatorForTest6_1_24.validate(doc)),
+        TestDefinition::document("6.1.25", Mandatory, |doc| ValidatorForTest6_1_25.validate(doc)),
+        TestDefinition::document("6.1.26", Mandatory, |doc| ValidatorForTest6_1_26.validate(doc)),
+        TestDefinition::document("6.1.27.1", Mandatory, |doc| ValidatorForTest6_1_27_1.validate(doc)),
+        TestDefinition::document("6.1.27.2", Mandatory, |doc| ValidatorForTest6_1_27_2.validate(doc)),
+        TestDefinition::document("6.1.27.3", Mandatory, |doc| ValidatorForTest6_1_27_3.validate(doc)),
+        TestDefinition::document("6.1.27.4", Mandatory, |doc| ValidatorForTest6_1_27_4.validate(doc)),
+        TestDefinition::document("6.1.27.5", Mandatory, |doc| ValidatorForTest6_1_27_5.validate(doc)),
+        TestDefinition::document("6.1.27.6", Mandatory, |doc| ValidatorForTest6_1_27_6.validate(doc)),
+        TestDefinition::document("6.1.27.7", Mandatory, |doc| ValidatorForTest6_1_27_7.validate(doc)),
+        TestDefinition::document("6.1.27.8", Mandatory, |doc| ValidatorForTest6_1_27_8.validate(doc)),
+        TestDefinition::document("6.1.27.9", Mandatory, |doc| ValidatorForTest6_1_27_9.validate(doc)),
+        TestDefinition::document("6.1.27.10", Mandatory, |doc| ValidatorForTest6_1_27_10.validate(doc)),
+        TestDefinition::document("6.1.27.11", Mandatory, |doc| ValidatorForTest6_1_27_11.validate(doc)),
+        TestDefinition::document("6.1.27.12", Mandatory, |doc| ValidatorForTest6_1_27_12.validate(doc)),
+        TestDefinition::document("6.1.27.13", Mandatory, |doc| ValidatorForTest6_1_27_13.validate(doc)),
+        TestDefinition::document("6.1.27.14", Mandatory, |doc| ValidatorForTest6_1_27_14.validate(doc)),
+        TestDefinition::document("6.1.27.15", Mandatory, |doc| ValidatorForTest6_1_27_15.validate(doc)),
+        TestDefinition::document("6.1.27.16", Mandatory, |doc| ValidatorForTest6_1_27_16.validate(doc)),
+        TestDefinition::document("6.1.27.17", Mandatory, |doc| ValidatorForTest6_1_27_17.validate(doc)),
+        TestDefinition::document("6.1.27.18", Mandatory, |doc| ValidatorForTest6_1_27_18.validate(doc)),
+        TestDefinition::document("6.1.27.19", Mandatory, |doc| ValidatorForTest6_1_27_19.validate(doc)),
+        TestDefinition::document("6.1.28", Mandatory, |doc| ValidatorForTest6_1_28.validate(doc)),
+        TestDefinition::document("6.1.29", Mandatory, |doc| ValidatorForTest6_1_29.validate(doc)),
+        TestDefinition::document("6.1.30", Mandatory, |doc| ValidatorForTest6_1_30.validate(doc)),
+        TestDefinition::document("6.1.31", Mandatory, |doc| ValidatorForTest6_1_31.validate(doc)),
+        TestDefinition::document("6.1.32", Mandatory, |doc| ValidatorForTest6_1_32.validate(doc)),
+        TestDefinition::document("6.1.33", Mandatory, |doc| ValidatorForTest6_1_33.validate(doc)),
+        TestDefinition::document("6.1.34", Mandatory, |doc| ValidatorForTest6_1_34.validate(doc)),
+        TestDefinition::document("6.1.35", Mandatory, |doc| ValidatorForTest6_1_35.validate(doc)),
+        TestDefinition::document("6.1.36", Mandatory, |doc| ValidatorForTest6_1_36.validate(doc)),
+        TestDefinition::document("6.1.37", Mandatory, |doc| ValidatorForTest6_1_37.validate(doc)),
+        TestDefinition::document("6.1.38", Mandatory, |doc| ValidatorForTest6_1_38.validate(doc)),
+        TestDefinition::document("6.1.39", Mandatory, |doc| ValidatorForTest6_1_39.validate(doc)),
+        TestDefinition::document("6.1.40", Mandatory, |doc| ValidatorForTest6_1_40.validate(doc)),
+        TestDefinition::document("6.1.41", Mandatory, |doc| ValidatorForTest6_1_41.validate(doc)),
+        TestDefinition::document("6.1.42", Mandatory, |doc| ValidatorForTest6_1_42.validate(doc)),
+        TestDefinition::document("6.1.43", Mandatory, |doc| ValidatorForTest6_1_43.validate(doc)),
+        TestDefinition::document("6.1.44", Mandatory, |doc| ValidatorForTest6_1_44.validate(doc)),
+        TestDefinition::document("6.1.45", Mandatory, |doc| ValidatorForTest6_1_45.validate(doc)),
+        TestDefinition::document("6.1.46", Mandatory, |doc| ValidatorForTest6_1_46.validate(doc)),
+        TestDefinition::document("6.1.47", Mandatory, |doc| ValidatorForTest6_1_47.validate(doc)),
+        TestDefinition::document("6.1.48", Mandatory, |doc| ValidatorForTest6_1_48.validate(doc)),
+        TestDefinition::document("6.1.49", Mandatory, |doc| ValidatorForTest6_1_49.validate(doc)),
+        TestDefinition::document("6.1.50", Mandatory, |doc| ValidatorForTest6_1_50.validate(doc)),
+        TestDefinition::document("6.1.51", Mandatory, |doc| ValidatorForTest6_1_51.validate(doc)),
+        TestDefinition::document("6.1.52", Mandatory, |doc| ValidatorForTest6_1_52.validate(doc)),
+        TestDefinition::document("6.1.53", Mandatory, |doc| ValidatorForTest6_1_53.validate(doc)),
+        TestDefinition::document("6.1.54", Mandatory, |doc| ValidatorForTest6_1_54.validate(doc)),
+        TestDefinition::document("6.1.55", Mandatory, |doc| ValidatorForTest6_1_55.validate(doc)),
+        TestDefinition::document("6.1.56", Mandatory, |doc| ValidatorForTest6_1_56.validate(doc)),
+        TestDefinition::not_implemented("6.1.57", Mandatory),
+        TestDefinition::not_implemented("6.1.58", Mandatory),
+        TestDefinition::not_implemented("6.1.59", Mandatory),
+        // recommended tests
+        TestDefinition::document("6.2.1", Recommended, |doc| ValidatorForTest6_2_1.validate(doc))
+            .with_fix(test_6_2_01::fix_6_2_01_unused_definition_of_product_id),
+        TestDefinition::document("6.2.2", Recommended, |doc| ValidatorForTest6_2_2.validate(doc)),
+        TestDefinition::document("6.2.3", Recommended, |doc| ValidatorForTest6_2_3.validate(doc)),
+        TestDefinition::document("6.2.4", Recommended, |doc| ValidatorForTest6_2_4.validate(doc))
+            .with_fix(test_6_2_04::fix_6_2_04_build_metadata_in_rev_history),
+        TestDefinition::document("6.2.5", Recommended, |doc| ValidatorForTest6_2_5.validate(doc)),
+        TestDefinition::document("6.2.6", Recommended, |doc| ValidatorForTest6_2_6.validate(doc)),
+        TestDefinition::document("6.2.7", Recommended, |doc| ValidatorForTest6_2_7.validate(doc)),
+        TestDefinition::document("6.2.8", Recommended, |doc| ValidatorForTest6_2_8.validate(doc)),
+        TestDefinition::document("6.2.9", Recommended, |doc| ValidatorForTest6_2_9.validate(doc)),
+        TestDefinition::document("6.2.11", Recommended, |doc| ValidatorForTest6_2_11.validate(doc)),
+        TestDefinition::document("6.2.12", Recommended, |doc| ValidatorForTest6_2_12.validate(doc)),
+        TestDefinition::raw("6.2.13", Recommended, |doc| ValidatorForTest6_2_13.validate(doc))
+            .with_fix(|_, json| test_6_2_13::fix_6_2_13_sorting(json)),
+        TestDefinition::document("6.2.14", Recommended, |doc| ValidatorForTest6_2_14.validate(doc)),
+        TestDefinition::document("6.2.15", Recommended, |doc| ValidatorForTest6_2_15.validate(doc)),
+        TestDefinition::document("6.2.16", Recommended, |doc| ValidatorForTest6_2_16.validate(doc)),
+        TestDefinition::document("6.2.17", Recommended, |doc| ValidatorForTest6_2_17.validate(doc)),
+        TestDefinition::document("6.2.18", Recommended, |doc| ValidatorForTest6_2_18.validate(doc)),
+        TestDefinition::not_implemented("6.2.19", Recommended),
+        TestDefinition::raw("6.2.20", Recommended, |doc| ValidatorForTest6_2_20.validate(doc)),
+        TestDefinition::document("6.2.21", Recommended, |doc| ValidatorForTest6_2_21.validate(doc)),
+        TestDefinition::document("6.2.22", Recommended, |doc| ValidatorForTest6_2_22.validate(doc)),
+        TestDefinition::document("6.2.23", Recommended, |doc| ValidatorForTest6_2_23.validate(doc)),
+        TestDefinition::document("6.2.24", Recommended, |doc| ValidatorForTest6_2_24.validate(doc)),
+        TestDefinition::document("6.2.25", Recommended, |doc| ValidatorForTest6_2_25.validate(doc)),
+        TestDefinition::document("6.2.26", Recommended, |doc| ValidatorForTest6_2_26.validate(doc)),
+        TestDefinition::document("6.2.27", Recommended, |doc| ValidatorForTest6_2_27.validate(doc)),
+        TestDefinition::document("6.2.28", Recommended, |doc| ValidatorForTest6_2_28.validate(doc)),
+        TestDefinition::document("6.2.29", Recommended, |doc| ValidatorForTest6_2_29.validate(doc)),
+        TestDefinition::document("6.2.30", Recommended, |doc| ValidatorForTest6_2_30.validate(doc)),
+        TestDefinition::document("6.2.31", Recommended, |doc| ValidatorForTest6_2_31.validate(doc)),
+        TestDefinition::document("6.2.32", Recommended, |doc| ValidatorForTest6_2_32.validate(doc)),
+        TestDefinition::document("6.2.33", Recommended, |doc| ValidatorForTest6_2_33.validate(doc)),
+        TestDefinition::document("6.2.34", Recommended, |doc| ValidatorForTest6_2_34.validate(doc)),
+        TestDefinition::document("6.2.35", Recommended, |doc| ValidatorForTest6_2_35.validate(doc)),
+        TestDefinition::document("6.2.36", Recommended, |doc| ValidatorForTest6_2_36.validate(doc)),
+        TestDefinition::document("6.2.37", Recommended, |doc| ValidatorForTest6_2_37.validate(doc)),
+        TestDefinition::document("6.2.38", Recommended, |doc| ValidatorForTest6_2_38.validate(doc)),
+        TestDefinition::document("6.2.39.1", Recommended, |doc| ValidatorForTest6_2_39_1.validate(doc)),
+        TestDefinition::document("6.2.39.2", Recommended, |doc| ValidatorForTest6_2_39_2.validate(doc)),
+        TestDefinition::document("6.2.39.3", Recommended, |doc| ValidatorForTest6_2_39_3.validate(doc)),
+        TestDefinition::document("6.2.39.4", Recommended, |doc| ValidatorForTest6_2_39_4.validate(doc)),
+        TestDefinition::document("6.2.40", Recommended, |doc| ValidatorForTest6_2_40.validate(doc)),
+        TestDefinition::document("6.2.41", Recommended, |doc| ValidatorForTest6_2_41.validate(doc)),
+        TestDefinition::document("6.2.42", Recommended, |doc| ValidatorForTest6_2_42.validate(doc)),
+        TestDefinition::document("6.2.43", Recommended, |doc| ValidatorForTest6_2_43.validate(doc)),
+        TestDefinition::document("6.2.44", Recommended, |doc| ValidatorForTest6_2_44.validate(doc)),
+        TestDefinition::document("6.2.45", Recommended, |doc| ValidatorForTest6_2_45.validate(doc)),
+        TestDefinition::document("6.2.46", Recommended, |doc| ValidatorForTest6_2_46.validate(doc)),
+        TestDefinition::document("6.2.47", Recommended, |doc| ValidatorForTest6_2_47.validate(doc)),
+        TestDefinition::not_implemented("6.2.48", Recommended),
+        TestDefinition::not_implemented("6.2.49", Recommended),
+        TestDefinition::not_implemented("6.2.50.1", Recommended),
+        TestDefinition::not_implemented("6.2.50.2", Recommended),
+        TestDefinition::not_implemented("6.2.50.3", Recommended),
+        TestDefinition::not_implemented("6.2.51", Recommended),
+        TestDefinition::not_implemented("6.2.52", Recommended),
+        TestDefinition::not_implemented("6.2.53", Recommended),
+        // informative tests
+        TestDefinition::document("6.3.1", Informative, |doc| ValidatorForTest6_3_1.validate(doc)),
+        TestDefinition::document("6.3.2", Informative, |doc| ValidatorForTest6_3_2.validate(doc)),
+        TestDefinition::document("6.3.3", Informative, |doc| ValidatorForTest6_3_3.validate(doc)),
+        TestDefinition::document("6.3.4", Informative, |doc| ValidatorForTest6_3_4.validate(doc)),
+        TestDefinition::document("6.3.5", Informative, |doc| ValidatorForTest6_3_5.validate(doc)),
+        TestDefinition::raw("6.3.6", Informative, |doc| ValidatorForTest6_3_6.validate(doc))
+            .with_precondition(has_url_resolver),
+        TestDefinition::raw("6.3.7", Informative, |doc| ValidatorForTest6_3_7.validate(doc))
+            .with_precondition(has_url_resolver),
+        TestDefinition::raw("6.3.8", Informative, |doc| ValidatorForTest6_3_8.validate(doc))
+            .with_precondition(has_dictionary),
+        TestDefinition::document("6.3.9", Informative, |doc| ValidatorForTest6_3_9.validate(doc)),
+        TestDefinition::document("6.3.10", Informative, |doc| ValidatorForTest6_3_10.validate(doc)),
+        TestDefinition::document("6.3.11", Informative, |doc| ValidatorForTest6_3_11.validate(doc)),
+        TestDefinition::document("6.3.12", Informative, |doc| ValidatorForTest6_3_12.validate(doc)),
+        TestDefinition::document("6.3.13", Informative, |doc| ValidatorForTest6_3_13.validate(doc)),
+        TestDefinition::document("6.3.14", Informative, |doc| ValidatorForTest6_3_14.validate(doc)),
+        TestDefinition::document("6.3.15", Informative, |doc| ValidatorForTest6_3_15.validate(doc)),
+        TestDefinition::document("6.3.16", Informative, |doc| ValidatorForTest6_3_16.validate(doc)),
+        TestDefinition::document("6.3.17", Informative, |doc| ValidatorForTest6_3_17.validate(doc)),
+        TestDefinition::document("6.3.18", Informative, |doc| ValidatorForTest6_3_18.validate(doc)),
+        TestDefinition::not_implemented("6.3.19.1", Informative),
+        TestDefinition::not_implemented("6.3.19.2", Informative),
+        TestDefinition::not_implemented("6.3.19.3", Informative),
+        TestDefinition::not_implemented("6.3.19.4", Informative),
+        TestDefinition::not_implemented("6.3.19.5", Informative),
+        TestDefinition::not_implemented("6.3.20", Informative),
+    ],
+};
 
 impl Validatable for CommonSecurityAdvisoryFramework {
     fn tests_in_preset(preset: &ValidationPreset) -> Vec<&str> {
-        match preset {
-            ValidationPreset::Basic => mandatory_tests(),
-            ValidationPreset::Extended => [mandatory_tests(), recommended_tests()].concat(),
-            ValidationPreset::Full => [mandatory_tests(), recommended_tests(), informative_tests()].concat(),
-        }
+        TEST_REGISTRY.tests_in_preset(preset)
     }
 
     fn run_test(&self, test_id: &str) -> TestResult {
-        let mandatory_result = to_test_result(
-            test_id,
-            Severity::Error,
-            match test_id {
-                // mandatory tests
-                "6.1.1" => Some(ValidatorForTest6_1_1.validate(self)),
-                "6.1.2" => Some(ValidatorForTest6_1_2.validate(self)),
-                "6.1.3" => Some(ValidatorForTest6_1_3.validate(self)),
-                "6.1.4" => Some(ValidatorForTest6_1_4.validate(self)),
-                "6.1.5" => Some(ValidatorForTest6_1_5.validate(self)),
-                "6.1.6" => Some(ValidatorForTest6_1_6.validate(self)),
-                "6.1.7" => Some(ValidatorForTest6_1_7.validate(self)),
-                "6.1.8" => Some(ValidatorForTest6_1_8.validate(self)),
-                "6.1.9" => None,  // Some(ValidatorForTest6_1_9.validate(self)),
-                "6.1.10" => None, // Some(ValidatorForTest6_1_10.validate(self)),
-                "6.1.11" => Some(ValidatorForTest6_1_11.validate(self)),
-                "6.1.12" => Some(ValidatorForTest6_1_12.validate(self)),
-                "6.1.13" => Some(ValidatorForTest6_1_13.validate(self)),
-                "6.1.14" => Some(ValidatorForTest6_1_14.validate(self)),
-                "6.1.15" => Some(ValidatorForTest6_1_15.validate(self)),
-                "6.1.16" => Some(ValidatorForTest6_1_16.validate(self)),
-                "6.1.17" => Some(ValidatorForTest6_1_17.validate(self)),
-                "6.1.18" => Some(ValidatorForTest6_1_18.validate(self)),
-                "6.1.19" => Some(ValidatorForTest6_1_19.validate(self)),
-                "6.1.20" => Some(ValidatorForTest6_1_20.validate(self)),
-                "6.1.21" => Some(ValidatorForTest6_1_21.validate(self)),
-                "6.1.22" => Some(ValidatorForTest6_1_22.validate(self)),
-                "6.1.23" => Some(ValidatorForTest6_1_23.validate(self)),
-                "6.1.24" => Some(ValidatorForTest6_1_24.validate(self)),
-                "6.1.25" => Some(ValidatorForTest6_1_25.validate(self)),
-                "6.1.26" => None, // Some(ValidatorForTest6_1_26.validate(self)),
-                "6.1.27.1" => Some(ValidatorForTest6_1_27_1.validate(self)),
-                "6.1.27.2" => Some(ValidatorForTest6_1_27_2.validate(self)),
-                "6.1.27.3" => Some(ValidatorForTest6_1_27_3.validate(self)),
-                "6.1.27.4" => Some(ValidatorForTest6_1_27_4.validate(self)),
-                "6.1.27.5" => Some(ValidatorForTest6_1_27_5.validate(self)),
-                "6.1.27.6" => Some(ValidatorForTest6_1_27_6.validate(self)),
-                "6.1.27.7" => Some(ValidatorForTest6_1_27_7.validate(self)),
-                "6.1.27.8" => Some(ValidatorForTest6_1_27_8.validate(self)),
-                "6.1.27.9" => Some(ValidatorForTest6_1_27_9.validate(self)),
-                "6.1.27.10" => Some(ValidatorForTest6_1_27_10.validate(self)),
-                "6.1.27.11" => Some(ValidatorForTest6_1_27_11.validate(self)),
-                "6.1.27.12" => None, // Some(ValidatorForTest6_1_27_12.validate(self)),
-                "6.1.27.13" => None, // Some(ValidatorForTest6_1_27_13.validate(self)),
-                "6.1.27.14" => None, // Some(ValidatorForTest6_1_27_14.validate(self)),
-                "6.1.27.15" => None, // Some(ValidatorForTest6_1_27_15.validate(self)),
-                "6.1.27.16" => None, // Some(ValidatorForTest6_1_27_16.validate(self)),
-                "6.1.27.17" => None, // Some(ValidatorForTest6_1_27_17.validate(self)),
-                "6.1.27.18" => None, // Some(ValidatorForTest6_1_27_18.validate(self)),
-                "6.1.27.19" => None, // Some(ValidatorForTest6_1_27_19.validate(self)),
-                "6.1.28" => Some(ValidatorForTest6_1_28.validate(self)),
-                "6.1.29" => Some(ValidatorForTest6_1_29.validate(self)),
-                "6.1.30" => Some(ValidatorForTest6_1_30.validate(self)),
-                "6.1.31" => Some(ValidatorForTest6_1_31.validate(self)),
-                "6.1.32" => Some(ValidatorForTest6_1_32.validate(self)),
-                "6.1.33" => Some(ValidatorForTest6_1_33.validate(self)),
-                "6.1.34" => Some(ValidatorForTest6_1_34.validate(self)),
-                "6.1.35" => Some(ValidatorForTest6_1_35.validate(self)),
-                "6.1.36" => Some(ValidatorForTest6_1_36.validate(self)),
-                "6.1.37" => Some(ValidatorForTest6_1_37.validate(self)),
-                "6.1.38" => Some(ValidatorForTest6_1_38.validate(self)),
-                "6.1.39" => Some(ValidatorForTest6_1_39.validate(self)),
-                "6.1.40" => Some(ValidatorForTest6_1_40.validate(self)),
-                "6.1.41" => Some(ValidatorForTest6_1_41.validate(self)),
-                "6.1.42" => Some(ValidatorForTest6_1_42.validate(self)),
-                "6.1.43" => Some(ValidatorForTest6_1_43.validate(self)),
-                "6.1.44" => Some(ValidatorForTest6_1_44.validate(self)),
-                "6.1.45" => Some(ValidatorForTest6_1_45.validate(self)),
-                "6.1.46" => Some(ValidatorForTest6_1_46.validate(self)),
-                "6.1.47" => Some(ValidatorForTest6_1_47.validate(self)),
-                "6.1.48" => Some(ValidatorForTest6_1_48.validate(self)),
-                "6.1.49" => Some(ValidatorForTest6_1_49.validate(self)),
-                "6.1.50" => None, // Some(ValidatorForTest6_1_50.validate(self)),
-                "6.1.51" => None, // Some(ValidatorForTest6_1_51.validate(self)),
-                "6.1.52" => None, // Some(ValidatorForTest6_1_52.validate(self)),
-                "6.1.53" => None, // Some(ValidatorForTest6_1_53.validate(self)),
-                "6.1.54" => None, // Some(ValidatorForTest6_1_54.validate(self)),
-                "6.1.55" => None, // Some(ValidatorForTest6_1_55.validate(self)),
-                "6.1.56" => None, // Some(ValidatorForTest6_1_56.validate(self)),
-                _ => None,
-            },
-        );
-        if TestResultStatus::NotFound != mandatory_result.status {
-            return mandatory_result;
-        }
-
-        let recommended_result = to_test_result(
-            test_id,
-            Severity::Warning,
-            match test_id {
-                // recommended tests
-                "6.2.1" => Some(ValidatorForTest6_2_1.validate(self)),
-                "6.2.2" => Some(ValidatorForTest6_2_2.validate(self)),
-                "6.2.3" => Some(ValidatorForTest6_2_3.validate(self)),
-                "6.2.4" => Some(ValidatorForTest6_2_4.validate(self)),
-                "6.2.5" => Some(ValidatorForTest6_2_5.validate(self)),
-                "6.2.6" => Some(ValidatorForTest6_2_6.validate(self)),
-                "6.2.7" => Some(ValidatorForTest6_2_7.validate(self)),
-                "6.2.8" => None, // Some(ValidatorForTest6_2_8.validate(self)),
-                "6.2.9" => None, // Some(ValidatorForTest6_2_9.validate(self)),
-                "6.2.11" => Some(ValidatorForTest6_2_11.validate(self)),
-                "6.2.12" => Some(ValidatorForTest6_2_12.validate(self)),
-                "6.2.13" => {
-                    // see below in RawValidatable
-                    return TestResult {
-                        test_id: test_id.to_string(),
-                        status: TestResultStatus::Skipped,
-                    };
-                },
-                "6.2.14" => None, // Some(ValidatorForTest6_2_14.validate(self)),
-                "6.2.15" => Some(ValidatorForTest6_2_15.validate(self)),
-                "6.2.16" => Some(ValidatorForTest6_2_16.validate(self)),
-                "6.2.17" => Some(ValidatorForTest6_2_17.validate(self)),
-                "6.2.18" => Some(ValidatorForTest6_2_18.validate(self)),
-                "6.2.19" => None, // Some(ValidatorForTest6_2_19.validate(self)),
-                "6.2.20" => {
-                    // see below in RawValidatable
-                    return TestResult {
-                        test_id: test_id.to_string(),
-                        status: TestResultStatus::Skipped,
-                    };
-                },
-                "6.2.21" => None,   // Some(ValidatorForTest6_2_21.validate(self)),
-                "6.2.22" => None,   // Some(ValidatorForTest6_2_22.validate(self)),
-                "6.2.23" => None,   // Some(ValidatorForTest6_2_23.validate(self)),
-                "6.2.24" => None,   // Some(ValidatorForTest6_2_24.validate(self)),
-                "6.2.25" => None,   // Some(ValidatorForTest6_2_25.validate(self)),
-                "6.2.26" => None,   // Some(ValidatorForTest6_2_26.validate(self)),
-                "6.2.27" => None,   // Some(ValidatorForTest6_2_27.validate(self)),
-                "6.2.28" => None,   // Some(ValidatorForTest6_2_28.validate(self)),
-                "6.2.29" => None,   // Some(ValidatorForTest6_2_29.validate(self)),
-                "6.2.30" => None,   // Some(ValidatorForTest6_2_30.validate(self)),
-                "6.2.31" => None,   // Some(ValidatorForTest6_2_31.validate(self)),
-                "6.2.32" => None,   // Some(ValidatorForTest6_2_32.validate(self)),
-                "6.2.33" => None,   // Some(ValidatorForTest6_2_33.validate(self)),
-                "6.2.34" => None,   // Some(ValidatorForTest6_2_34.validate(self)),
-                "6.2.35" => None,   // Some(ValidatorForTest6_2_35.validate(self)),
-                "6.2.36" => None,   // Some(ValidatorForTest6_2_36.validate(self)),
-                "6.2.37" => None,   // Some(ValidatorForTest6_2_37.validate(self)),
-                "6.2.38" => None,   // Some(ValidatorForTest6_2_38.validate(self)),
-                "6.2.39.1" => None, // Some(ValidatorForTest6_2_39_1.validate(self)),
-                "6.2.39.2" => None, // Some(ValidatorForTest6_2_39_2.validate(self)),
-                "6.2.39.3" => None, // Some(ValidatorForTest6_2_39_3.validate(self)),
-                "6.2.39.4" => None, // Some(ValidatorForTest6_2_39_4.validate(self)),
-                "6.2.40" => None,   // Some(ValidatorForTest6_2_40.validate(self)),
-                "6.2.41" => None,   // Some(ValidatorForTest6_2_41.validate(self)),
-                "6.2.42" => None,   // Some(ValidatorForTest6_2_42.validate(self)),
-                "6.2.43" => None,   // Some(ValidatorForTest6_2_43.validate(self)),
-                "6.2.44" => None,   // Some(ValidatorForTest6_2_44.validate(self)),
-                "6.2.45" => None,   // Some(ValidatorForTest6_2_45.validate(self)),
-                "6.2.46" => None,   // Some(ValidatorForTest6_2_46.validate(self)),
-                "6.2.47" => None,   // Some(ValidatorForTest6_2_47.validate(self)),
-                _ => None,
-            },
-        );
-        if TestResultStatus::NotFound != recommended_result.status {
-            return recommended_result;
-        }
-
-        to_test_result(
-            test_id,
-            Severity::Info,
-            match test_id {
-                // informative tests
-                "6.3.1" => None,  // Some(ValidatorForTests6_3_1.validate(self)),
-                "6.3.2" => None,  // Some(ValidatorForTests6_3_2.validate(self)),
-                "6.3.3" => None,  // Some(ValidatorForTests6_3_3.validate(self)),
-                "6.3.4" => None,  // Some(ValidatorForTests6_3_4.validate(self)),
-                "6.3.5" => None,  // Some(ValidatorForTests6_3_5.validate(self)),
-                "6.3.6" => None,  // Some(ValidatorForTests6_3_6.validate(self)),
-                "6.3.7" => None,  // Some(ValidatorForTests6_3_7.validate(self)),
-                "6.3.8" => None,  // Some(ValidatorForTests6_3_8.validate(self)),
-                "6.3.9" => None,  // Some(ValidatorForTests6_3_9.validate(self)),
-                "6.3.10" => None, // Some(ValidatorForTests6_3_10.validate(self)),
-                "6.3.11" => None, // Some(ValidatorForTests6_3_11.validate(self)),
-                "6.3.12" => None, // Some(ValidatorForTests6_3_12.validate(self)),
-                "6.3.13" => None, // Some(ValidatorForTests6_3_13.validate(self)),
-                "6.3.14" => None, // Some(ValidatorForTests6_3_14.validate(self)),
-                "6.3.15" => None, // Some(ValidatorForTests6_3_15.validate(self)),
-                "6.3.16" => None, // Some(ValidatorForTests6_3_16.validate(self)),
-                "6.3.17" => None, // Some(ValidatorForTests6_3_17.validate(self)),
-                "6.3.18" => None, // Some(ValidatorForTests6_3_18.validate(self)),
-                _ => None,
-            },
-        )
+        TEST_REGISTRY.run_test(self, test_id)
     }
 }
 
 impl RawValidatable for RawDocument<CommonSecurityAdvisoryFramework> {
+    fn validate_schema(&self) -> Result<(), Vec<crate::validation::ValidationError>> {
+        crate::csaf::json_schema::validate_csaf_2_1_schema(self)
+    }
+
     fn run_raw_test(&self, test_id: &str) -> TestResult {
-        to_test_result(
-            test_id,
-            Severity::Warning,
-            match test_id {
-                "6.2.13" => Some(ValidatorForTest6_2_13.validate(self)),
-                "6.2.20" => Some(ValidatorForTest6_2_20.validate(self)),
-                _ => None,
-            },
-        )
+        TEST_REGISTRY.run_raw_test(self, test_id)
     }
 }
@@ -1,5 +1,5 @@
-use crate::csaf::types::csaf_datetime::CsafDateTime;
 use crate::csaf::types::csaf_datetime::CsafDateTime::{Invalid, Valid};
+use crate::csaf::types::csaf_datetime::{CsafDateTime, ValidCsafDateTime};
 use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
 use crate::csaf::types::csaf_hash_algo::CsafHashAlgorithm;
 use crate::csaf::types::csaf_version_number::{CsafVersionNumber, ValidVersionNumber};
@@ -13,6 +13,7 @@ use crate::schema::csaf2_1::schema::{
 };
 use crate::validation::ValidationError;
 use chrono::{DateTime, Utc};
+use serde::Serialize;
 use std::collections::{BTreeSet, HashMap, HashSet};
 use std::fmt::{Display, Formatter};
 use uuid::Uuid;
@@ -165,6 +166,12 @@ pub trait DocumentTrait {
         self.get_notes().extract_product_references("/document/notes")
     }
 
+    /// Returns the title of this document
+    fn get_title(&self) -> &String;
+
+    /// Returns the SPDX license expression of this document (CSAF 2.1 only)
+    fn get_license_expression(&self) -> Option<&String>;
+
     /// Returns the language associated with this document.
     fn get_lang(&self) -> Option<&String>;
 
@@ -206,6 +213,12 @@ pub trait DocumentReferenceTrait {
 
 pub trait PublisherTrait {
     fn get_category(&self) -> CategoryOfPublisher;
+
+    /// Returns the name of the publisher.
+    fn get_name(&self) -> &String;
+
+    /// Returns the namespace of the publisher, a URL.
+    fn get_namespace(&self) -> &String;
 }
 
 /// Trait representing distribution information for a document
@@ -228,6 +241,9 @@ pub trait DistributionTrait {
 
 pub trait NoteTrait: WithOptionalGroupIds + WithOptionalProductIds {
     fn get_category(&self) -> NoteCategory;
+
+    /// Returns the optional title of the note
+    fn get_title(&self) -> Option<&String>;
 }
 
 /// Trait representing sharing group information
@@ -302,6 +318,19 @@ pub trait TrackingTrait {
     /// Returns the revision history for this document
     fn get_revision_history(&self) -> &Vec<Self::RevisionType>;
 
+    /// Returns the newest date of the revision history.
+    ///
+    /// Invalid dates are skipped, they are reported by test 6.1.37.
+    fn get_newest_revision_date(&self) -> Option<ValidCsafDateTime> {
+        self.get_revision_history()
+            .iter()
+            .filter_map(|revision| match revision.get_date() {
+                Valid(date) => Some(date),
+                Invalid(_) => None,
+            })
+            .max()
+    }
+
     /// Utility function to get revision history as structs containing revision history path index, date and number
     fn get_revision_history_tuples(&self) -> RevisionHistory {
         let mut revision_history: RevisionHistory = Vec::new();
@@ -347,6 +376,9 @@ pub trait RevisionTrait: WithDate {
 
     /// Returns the summary of changes in this revision
     fn get_summary(&self) -> &String;
+
+    /// Returns the optional version string used in an existing document with the same content
+    fn get_legacy_version(&self) -> Option<&String>;
 }
 
 pub struct Cwe {
@@ -499,6 +531,9 @@ pub trait VulnerabilityTrait {
     /// Returns the CVE associated with the vulnerability.
     fn get_cve(&self) -> Option<&String>;
 
+    /// Returns the title of the vulnerability, if any.
+    fn get_title(&self) -> Option<&String>;
+
     /// Returns the CWE associated with the vulnerability.
     fn get_cwe(&self) -> Option<Vec<Cwe>>;
 
@@ -545,7 +580,13 @@ pub trait FlagTrait: WithOptionalGroupIds + WithOptionalProductIds + WithOptiona
     fn get_label(&self) -> LabelOfTheFlag;
 }
 
-pub trait FirstKnownExploitationDatesTrait: WithDate + WithOptionalProductIds + WithOptionalGroupIds {}
+/// Trait for accessing a first known exploitation date of a vulnerability.
+///
+/// The date of [WithDate] is the date when the information was last updated.
+pub trait FirstKnownExploitationDatesTrait: WithDate + WithOptionalProductIds + WithOptionalGroupIds {
+    /// Returns the date when the exploitation happened
+    fn get_exploitation_date(&self) -> CsafDateTime;
+}
 
 /// Trait for accessing vulnerability involvement information
 pub trait InvolvementTrait: WithOptionalGroupIds + WithOptionalDate + WithOptionalProductIds {
@@ -563,6 +604,12 @@ pub trait RemediationTrait: WithOptionalGroupIds + WithOptionalProductIds + With
     /// Categories are defined by the CSAF schema.
     fn get_category(&self) -> CategoryOfTheRemediation;
 
+    /// Returns the details of the remediation.
+    fn get_details(&self) -> &String;
+
+    /// Returns the URL of the remediation, if any.
+    fn get_url(&self) -> Option<&String>;
+
     /// Computes a set of all product IDs affected by this remediation, either
     /// directly or through product groups.
     ///
@@ -592,7 +639,8 @@ pub trait RemediationTrait: WithOptionalGroupIds + WithOptionalProductIds + With
 }
 
 /// Enum representing product status groups
-#[derive(PartialEq, Eq, Hash, Clone, Ord, PartialOrd)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Ord, PartialOrd, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ProductStatusGroup {
     // first_affected, known_affected, last_affected
     Affected,
@@ -834,6 +882,9 @@ pub trait ContentTrait {
 pub trait ThreatTrait: WithOptionalGroupIds + WithOptionalProductIds + WithOptionalDate {
     /// Returns the category of the threat
     fn get_category(&self) -> CategoryOfTheThreat;
+
+    /// Returns the details of the threat.
+    fn get_details(&self) -> &String;
 }
 
 /// Trait representing an abstract product tree in a CSAF document.
@@ -1120,6 +1171,12 @@ pub trait ProductIdentificationHelperTrait {
     fn get_serial_numbers(&self) -> Option<impl Iterator<Item = &String> + '_>;
 
     fn get_hashes(&self) -> &Vec<Self::HashType>;
+
+    /// Returns the CPE identifying the associated product.
+    fn get_cpe(&self) -> Option<&String>;
+
+    /// Returns the URLs of SBOMs describing the associated product.
+    fn get_sbom_urls(&self) -> &Vec<String>;
 }
 
 /// Trait representing a collection of file_hashes for a file as part of a product identification helper
@@ -0,0 +1,276 @@
+//! Parsing of CVSS vector strings and computation of CVSS scores.
+//!
+//! Supported are CVSS v2.0 ([`CvssV2`]), CVSS v3.0 and v3.1 ([`CvssV3`]) and CVSS v4.0 ([`CvssV4`]).
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+pub mod v2;
+pub mod v3;
+pub mod v4;
+
+pub use v2::CvssV2;
+pub use v3::{CvssV3, CvssV3Version};
+pub use v4::CvssV4;
+
+/// Errors that occur while parsing a CVSS vector string.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum CvssError {
+    #[error("the vector string does not start with {0}")]
+    InvalidPrefix(String),
+    #[error("'{0}' is not a valid metric of the form <metric>:<value>")]
+    InvalidComponent(String),
+    #[error("unknown metric '{0}'")]
+    UnknownMetric(String),
+    #[error("metric '{0}' is defined more than once")]
+    DuplicateMetric(String),
+    #[error("metric '{0}' is missing")]
+    MissingMetric(String),
+    #[error("'{value}' is not a valid value for metric '{metric}'")]
+    InvalidValue { metric: String, value: String },
+}
+
+/// Qualitative severity rating of a CVSS score.
+///
+/// CVSS v2.0 does not define a severity rating, the NVD ratings (low, medium, high) are used instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CvssSeverity {
+    None,
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl CvssSeverity {
+    /// Returns the CVSS v3.x / v4.0 severity rating of a score.
+    pub fn from_score(score: f64) -> Self {
+        match score {
+            s if s <= 0.0 => CvssSeverity::None,
+            s if s < 4.0 => CvssSeverity::Low,
+            s if s < 7.0 => CvssSeverity::Medium,
+            s if s < 9.0 => CvssSeverity::High,
+            _ => CvssSeverity::Critical,
+        }
+    }
+
+    /// Returns the value of the severity as used in the CVSS JSON schemas.
+    pub fn json_value(&self) -> &'static str {
+        match self {
+            CvssSeverity::None => "NONE",
+            CvssSeverity::Low => "LOW",
+            CvssSeverity::Medium => "MEDIUM",
+            CvssSeverity::High => "HIGH",
+            CvssSeverity::Critical => "CRITICAL",
+        }
+    }
+}
+
+impl Display for CvssSeverity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.json_value())
+    }
+}
+
+impl FromStr for CvssSeverity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NONE" => Ok(CvssSeverity::None),
+            "LOW" => Ok(CvssSeverity::Low),
+            "MEDIUM" => Ok(CvssSeverity::Medium),
+            "HIGH" => Ok(CvssSeverity::High),
+            "CRITICAL" => Ok(CvssSeverity::Critical),
+            _ => Err(format!("Unknown CVSS severity: {s}")),
+        }
+    }
+}
+
+/// A single metric of a CVSS vector, e.g. the attack vector.
+pub trait Metric: Sized + Copy {
+    /// Abbreviation of the metric in vector strings, e.g. `AV`
+    const ABBREVIATION: &'static str;
+    /// Name of the metric property in the CVSS JSON representation, e.g. `attackVector`
+    const JSON_PROPERTY: &'static str;
+
+    /// Parses the metric value from its abbreviation in vector strings.
+    fn from_abbreviation(value: &str) -> Option<Self>;
+
+    /// Returns the abbreviation of the metric value in vector strings.
+    fn abbreviation(&self) -> &'static str;
+
+    /// Returns the value of the metric in the CVSS JSON representation.
+    fn json_value(&self) -> &'static str;
+}
+
+/// Defines an enum for a CVSS metric, including its vector string and JSON representations.
+macro_rules! metric {
+    (
+        $(#[$doc:meta])*
+        $name:ident, $abbreviation:literal, $json_property:literal {
+            $($variant:ident = $value:literal / $json_value:literal),+ $(,)?
+        }
+    ) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant),+
+        }
+
+        impl $crate::cvss::Metric for $name {
+            const ABBREVIATION: &'static str = $abbreviation;
+            const JSON_PROPERTY: &'static str = $json_property;
+
+            fn from_abbreviation(value: &str) -> Option<Self> {
+                match value {
+                    $($value => Some(Self::$variant),)+
+                    _ => None,
+                }
+            }
+
+            fn abbreviation(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $value),+
+                }
+            }
+
+            fn json_value(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $json_value),+
+                }
+            }
+        }
+    };
+}
+pub(crate) use metric;
+
+/// The metrics of a vector string, indexed by their abbreviation.
+pub(crate) struct VectorComponents<'a> {
+    components: HashMap<&'a str, &'a str>,
+    /// Value that marks optional metrics as "not defined"
+    not_defined: &'static str,
+}
+
+impl<'a> VectorComponents<'a> {
+    /// Splits a vector string (without prefix) into its metrics.
+    pub(crate) fn parse(vector: &'a str, not_defined: &'static str) -> Result<Self, CvssError> {
+        let mut components = HashMap::new();
+        for component in vector.split('/') {
+            let (metric, value) = component
+                .split_once(':')
+                .ok_or_else(|| CvssError::InvalidComponent(component.to_string()))?;
+            if components.insert(metric, value).is_some() {
+                return Err(CvssError::DuplicateMetric(metric.to_string()));
+            }
+        }
+        Ok(Self {
+            components,
+            not_defined,
+        })
+    }
+
+    /// Removes and parses a mandatory metric.
+    pub(crate) fn required<M: Metric>(&mut self) -> Result<M, CvssError> {
+        let value = self
+            .components
+            .remove(M::ABBREVIATION)
+            .ok_or_else(|| CvssError::MissingMetric(M::ABBREVIATION.to_string()))?;
+        parse_value(value)
+    }
+
+    /// Removes and parses an optional metric, `None` if it is missing or not defined.
+    pub(crate) fn optional<M: Metric>(&mut self) -> Result<Option<M>, CvssError> {
+        match self.components.remove(M::ABBREVIATION) {
+            None => Ok(None),
+            Some(value) if value == self.not_defined => Ok(None),
+            Some(value) => parse_value(value).map(Some),
+        }
+    }
+
+    /// Fails if any metric was not consumed by [`Self::required`] or [`Self::optional`].
+    pub(crate) fn finish(self) -> Result<(), CvssError> {
+        let mut remaining: Vec<&&str> = self.components.keys().collect();
+        remaining.sort();
+        match remaining.first() {
+            Some(metric) => Err(CvssError::UnknownMetric(metric.to_string())),
+            None => Ok(()),
+        }
+    }
+}
+
+fn parse_value<M: Metric>(value: &str) -> Result<M, CvssError> {
+    M::from_abbreviation(value).ok_or_else(|| CvssError::InvalidValue {
+        metric: M::ABBREVIATION.to_string(),
+        value: value.to_string(),
+    })
+}
+
+/// Builds a vector string from a prefix and the (optional) metrics.
+pub(crate) struct VectorBuilder {
+    vector: String,
+}
+
+impl VectorBuilder {
+    pub(crate) fn new(prefix: &str) -> Self {
+        Self {
+            vector: prefix.to_string(),
+        }
+    }
+
+    pub(crate) fn add<M: Metric>(mut self, metric: M) -> Self {
+        if !self.vector.is_empty() {
+            self.vector.push('/');
+        }
+        self.vector.push_str(M::ABBREVIATION);
+        self.vector.push(':');
+        self.vector.push_str(metric.abbreviation());
+        self
+    }
+
+    pub(crate) fn add_optional<M: Metric>(self, metric: Option<M>) -> Self {
+        match metric {
+            Some(metric) => self.add(metric),
+            None => self,
+        }
+    }
+
+    pub(crate) fn build(self) -> String {
+        self.vector
+    }
+}
+
+/// Returns the JSON property name and value of a metric.
+pub(crate) fn json_property<M: Metric>(metric: M) -> (&'static str, &'static str) {
+    (M::JSON_PROPERTY, metric.json_value())
+}
+
+/// Returns the JSON property name and value of an optional metric, `NOT_DEFINED` if it is `None`.
+pub(crate) fn optional_json_property<M: Metric>(metric: Option<M>) -> (&'static str, &'static str) {
+    (M::JSON_PROPERTY, metric.map_or("NOT_DEFINED", |m| m.json_value()))
+}
+
+/// Rounds a score to one decimal, as done by CVSS v2.0 and v4.0.
+pub(crate) fn round_to_one_decimal(value: f64) -> f64 {
+    // The small offset compensates floating point errors like 4.45 being stored as 4.4499999...
+    ((value * 10.0) + 1e-9).round() / 10.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_severity_from_score() {
+        assert_eq!(CvssSeverity::from_score(0.0), CvssSeverity::None);
+        assert_eq!(CvssSeverity::from_score(0.1), CvssSeverity::Low);
+        assert_eq!(CvssSeverity::from_score(3.9), CvssSeverity::Low);
+        assert_eq!(CvssSeverity::from_score(4.0), CvssSeverity::Medium);
+        assert_eq!(CvssSeverity::from_score(6.9), CvssSeverity::Medium);
+        assert_eq!(CvssSeverity::from_score(7.0), CvssSeverity::High);
+        assert_eq!(CvssSeverity::from_score(8.9), CvssSeverity::High);
+        assert_eq!(CvssSeverity::from_score(9.0), CvssSeverity::Critical);
+        assert_eq!(CvssSeverity::from_score(10.0), CvssSeverity::Critical);
+    }
+}
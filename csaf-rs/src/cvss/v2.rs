@@ -0,0 +1,398 @@
+//! CVSS v2.0, see <https://www.first.org/cvss/v2/guide>
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::cvss::{
+    CvssError, CvssSeverity, Metric, VectorBuilder, VectorComponents, json_property, metric, optional_json_property,
+    round_to_one_decimal,
+};
+
+metric! {
+    /// Access Vector (AV)
+    AccessVector, "AV", "accessVector" {
+        Local = "L" / "LOCAL",
+        AdjacentNetwork = "A" / "ADJACENT_NETWORK",
+        Network = "N" / "NETWORK",
+    }
+}
+
+metric! {
+    /// Access Complexity (AC)
+    AccessComplexity, "AC", "accessComplexity" {
+        High = "H" / "HIGH",
+        Medium = "M" / "MEDIUM",
+        Low = "L" / "LOW",
+    }
+}
+
+metric! {
+    /// Authentication (Au)
+    Authentication, "Au", "authentication" {
+        Multiple = "M" / "MULTIPLE",
+        Single = "S" / "SINGLE",
+        None = "N" / "NONE",
+    }
+}
+
+metric! {
+    /// Confidentiality Impact (C)
+    ConfidentialityImpact, "C", "confidentialityImpact" {
+        None = "N" / "NONE",
+        Partial = "P" / "PARTIAL",
+        Complete = "C" / "COMPLETE",
+    }
+}
+
+metric! {
+    /// Integrity Impact (I)
+    IntegrityImpact, "I", "integrityImpact" {
+        None = "N" / "NONE",
+        Partial = "P" / "PARTIAL",
+        Complete = "C" / "COMPLETE",
+    }
+}
+
+metric! {
+    /// Availability Impact (A)
+    AvailabilityImpact, "A", "availabilityImpact" {
+        None = "N" / "NONE",
+        Partial = "P" / "PARTIAL",
+        Complete = "C" / "COMPLETE",
+    }
+}
+
+metric! {
+    /// Exploitability (E)
+    Exploitability, "E", "exploitability" {
+        Unproven = "U" / "UNPROVEN",
+        ProofOfConcept = "POC" / "PROOF_OF_CONCEPT",
+        Functional = "F" / "FUNCTIONAL",
+        High = "H" / "HIGH",
+    }
+}
+
+metric! {
+    /// Remediation Level (RL)
+    RemediationLevel, "RL", "remediationLevel" {
+        OfficialFix = "OF" / "OFFICIAL_FIX",
+        TemporaryFix = "TF" / "TEMPORARY_FIX",
+        Workaround = "W" / "WORKAROUND",
+        Unavailable = "U" / "UNAVAILABLE",
+    }
+}
+
+metric! {
+    /// Report Confidence (RC)
+    ReportConfidence, "RC", "reportConfidence" {
+        Unconfirmed = "UC" / "UNCONFIRMED",
+        Uncorroborated = "UR" / "UNCORROBORATED",
+        Confirmed = "C" / "CONFIRMED",
+    }
+}
+
+metric! {
+    /// Collateral Damage Potential (CDP)
+    CollateralDamagePotential, "CDP", "collateralDamagePotential" {
+        None = "N" / "NONE",
+        Low = "L" / "LOW",
+        LowMedium = "LM" / "LOW_MEDIUM",
+        MediumHigh = "MH" / "MEDIUM_HIGH",
+        High = "H" / "HIGH",
+    }
+}
+
+metric! {
+    /// Target Distribution (TD)
+    TargetDistribution, "TD", "targetDistribution" {
+        None = "N" / "NONE",
+        Low = "L" / "LOW",
+        Medium = "M" / "MEDIUM",
+        High = "H" / "HIGH",
+    }
+}
+
+metric! {
+    /// Confidentiality Requirement (CR)
+    ConfidentialityRequirement, "CR", "confidentialityRequirement" {
+        Low = "L" / "LOW",
+        Medium = "M" / "MEDIUM",
+        High = "H" / "HIGH",
+    }
+}
+
+metric! {
+    /// Integrity Requirement (IR)
+    IntegrityRequirement, "IR", "integrityRequirement" {
+        Low = "L" / "LOW",
+        Medium = "M" / "MEDIUM",
+        High = "H" / "HIGH",
+    }
+}
+
+metric! {
+    /// Availability Requirement (AR)
+    AvailabilityRequirement, "AR", "availabilityRequirement" {
+        Low = "L" / "LOW",
+        Medium = "M" / "MEDIUM",
+        High = "H" / "HIGH",
+    }
+}
+
+/// A CVSS v2.0 vector. Temporal and environmental metrics are `None` if they are not defined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CvssV2 {
+    pub access_vector: AccessVector,
+    pub access_complexity: AccessComplexity,
+    pub authentication: Authentication,
+    pub confidentiality_impact: ConfidentialityImpact,
+    pub integrity_impact: IntegrityImpact,
+    pub availability_impact: AvailabilityImpact,
+    pub exploitability: Option<Exploitability>,
+    pub remediation_level: Option<RemediationLevel>,
+    pub report_confidence: Option<ReportConfidence>,
+    pub collateral_damage_potential: Option<CollateralDamagePotential>,
+    pub target_distribution: Option<TargetDistribution>,
+    pub confidentiality_requirement: Option<ConfidentialityRequirement>,
+    pub integrity_requirement: Option<IntegrityRequirement>,
+    pub availability_requirement: Option<AvailabilityRequirement>,
+}
+
+impl FromStr for CvssV2 {
+    type Err = CvssError;
+
+    fn from_str(vector: &str) -> Result<Self, Self::Err> {
+        let mut components = VectorComponents::parse(vector, "ND")?;
+        let cvss = CvssV2 {
+            access_vector: components.required()?,
+            access_complexity: components.required()?,
+            authentication: components.required()?,
+            confidentiality_impact: components.required()?,
+            integrity_impact: components.required()?,
+            availability_impact: components.required()?,
+            exploitability: components.optional()?,
+            remediation_level: components.optional()?,
+            report_confidence: components.optional()?,
+            collateral_damage_potential: components.optional()?,
+            target_distribution: components.optional()?,
+            confidentiality_requirement: components.optional()?,
+            integrity_requirement: components.optional()?,
+            availability_requirement: components.optional()?,
+        };
+        components.finish()?;
+        Ok(cvss)
+    }
+}
+
+impl Display for CvssV2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let vector = VectorBuilder::new("")
+            .add(self.access_vector)
+            .add(self.access_complexity)
+            .add(self.authentication)
+            .add(self.confidentiality_impact)
+            .add(self.integrity_impact)
+            .add(self.availability_impact)
+            .add_optional(self.exploitability)
+            .add_optional(self.remediation_level)
+            .add_optional(self.report_confidence)
+            .add_optional(self.collateral_damage_potential)
+            .add_optional(self.target_distribution)
+            .add_optional(self.confidentiality_requirement)
+            .add_optional(self.integrity_requirement)
+            .add_optional(self.availability_requirement)
+            .build();
+        write!(f, "{vector}")
+    }
+}
+
+fn impact_weight(impact: &str) -> f64 {
+    match impact {
+        "P" => 0.275,
+        "C" => 0.660,
+        _ => 0.0,
+    }
+}
+
+fn requirement_weight(requirement: Option<&str>) -> f64 {
+    match requirement {
+        Some("L") => 0.5,
+        Some("H") => 1.51,
+        _ => 1.0,
+    }
+}
+
+impl CvssV2 {
+    fn exploitability_subscore(&self) -> f64 {
+        let av = match self.access_vector {
+            AccessVector::Local => 0.395,
+            AccessVector::AdjacentNetwork => 0.646,
+            AccessVector::Network => 1.0,
+        };
+        let ac = match self.access_complexity {
+            AccessComplexity::High => 0.35,
+            AccessComplexity::Medium => 0.61,
+            AccessComplexity::Low => 0.71,
+        };
+        let au = match self.authentication {
+            Authentication::Multiple => 0.45,
+            Authentication::Single => 0.56,
+            Authentication::None => 0.704,
+        };
+        20.0 * av * ac * au
+    }
+
+    fn base_equation(&self, impact: f64) -> f64 {
+        let f_impact = if impact == 0.0 { 0.0 } else { 1.176 };
+        round_to_one_decimal(((0.6 * impact) + (0.4 * self.exploitability_subscore()) - 1.5) * f_impact)
+    }
+
+    fn temporal_multiplier(&self) -> f64 {
+        let e = match self.exploitability {
+            Some(Exploitability::Unproven) => 0.85,
+            Some(Exploitability::ProofOfConcept) => 0.9,
+            Some(Exploitability::Functional) => 0.95,
+            Some(Exploitability::High) | None => 1.0,
+        };
+        let rl = match self.remediation_level {
+            Some(RemediationLevel::OfficialFix) => 0.87,
+            Some(RemediationLevel::TemporaryFix) => 0.9,
+            Some(RemediationLevel::Workaround) => 0.95,
+            Some(RemediationLevel::Unavailable) | None => 1.0,
+        };
+        let rc = match self.report_confidence {
+            Some(ReportConfidence::Unconfirmed) => 0.9,
+            Some(ReportConfidence::Uncorroborated) => 0.95,
+            Some(ReportConfidence::Confirmed) | None => 1.0,
+        };
+        e * rl * rc
+    }
+
+    /// Computes the base score.
+    pub fn base_score(&self) -> f64 {
+        let c = impact_weight(self.confidentiality_impact.abbreviation());
+        let i = impact_weight(self.integrity_impact.abbreviation());
+        let a = impact_weight(self.availability_impact.abbreviation());
+        self.base_equation(10.41 * (1.0 - (1.0 - c) * (1.0 - i) * (1.0 - a)))
+    }
+
+    /// Computes the temporal score. Undefined temporal metrics do not change the base score.
+    pub fn temporal_score(&self) -> f64 {
+        round_to_one_decimal(self.base_score() * self.temporal_multiplier())
+    }
+
+    /// Computes the environmental score. Undefined environmental metrics do not change the temporal score.
+    pub fn environmental_score(&self) -> f64 {
+        let c = impact_weight(self.confidentiality_impact.abbreviation())
+            * requirement_weight(self.confidentiality_requirement.map(|r| r.abbreviation()));
+        let i = impact_weight(self.integrity_impact.abbreviation())
+            * requirement_weight(self.integrity_requirement.map(|r| r.abbreviation()));
+        let a = impact_weight(self.availability_impact.abbreviation())
+            * requirement_weight(self.availability_requirement.map(|r| r.abbreviation()));
+        let adjusted_impact = f64::min(10.0, 10.41 * (1.0 - (1.0 - c) * (1.0 - i) * (1.0 - a)));
+        let adjusted_temporal = round_to_one_decimal(self.base_equation(adjusted_impact) * self.temporal_multiplier());
+
+        let cdp = match self.collateral_damage_potential {
+            Some(CollateralDamagePotential::Low) => 0.1,
+            Some(CollateralDamagePotential::LowMedium) => 0.3,
+            Some(CollateralDamagePotential::MediumHigh) => 0.4,
+            Some(CollateralDamagePotential::High) => 0.5,
+            Some(CollateralDamagePotential::None) | None => 0.0,
+        };
+        let td = match self.target_distribution {
+            Some(TargetDistribution::None) => 0.0,
+            Some(TargetDistribution::Low) => 0.25,
+            Some(TargetDistribution::Medium) => 0.75,
+            Some(TargetDistribution::High) | None => 1.0,
+        };
+        round_to_one_decimal((adjusted_temporal + (10.0 - adjusted_temporal) * cdp) * td)
+    }
+
+    /// Returns the severity of a CVSS v2.0 score, as defined by the NVD.
+    pub fn severity(score: f64) -> CvssSeverity {
+        match score {
+            s if s < 4.0 => CvssSeverity::Low,
+            s if s < 7.0 => CvssSeverity::Medium,
+            _ => CvssSeverity::High,
+        }
+    }
+
+    /// Returns the JSON property names and expected values of all metrics of this vector.
+    pub fn json_properties(&self) -> Vec<(&'static str, &'static str)> {
+        vec![
+            json_property(self.access_vector),
+            json_property(self.access_complexity),
+            json_property(self.authentication),
+            json_property(self.confidentiality_impact),
+            json_property(self.integrity_impact),
+            json_property(self.availability_impact),
+            optional_json_property(self.exploitability),
+            optional_json_property(self.remediation_level),
+            optional_json_property(self.report_confidence),
+            optional_json_property(self.collateral_damage_potential),
+            optional_json_property(self.target_distribution),
+            optional_json_property(self.confidentiality_requirement),
+            optional_json_property(self.integrity_requirement),
+            optional_json_property(self.availability_requirement),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(vector: &str) -> CvssV2 {
+        CvssV2::from_str(vector).unwrap()
+    }
+
+    #[test]
+    fn test_base_score() {
+        assert_eq!(parse("AV:N/AC:L/Au:N/C:P/I:P/A:P").base_score(), 7.5);
+        assert_eq!(parse("AV:N/AC:L/Au:N/C:C/I:C/A:C").base_score(), 10.0);
+        assert_eq!(parse("AV:N/AC:M/Au:N/C:N/I:P/A:N").base_score(), 4.3);
+        assert_eq!(parse("AV:L/AC:H/Au:M/C:N/I:N/A:N").base_score(), 0.0);
+    }
+
+    #[test]
+    fn test_temporal_and_environmental_score() {
+        // Example from the CVSS v2 guide (CVE-2002-0392)
+        let cvss = parse("AV:N/AC:L/Au:N/C:N/I:N/A:C/E:F/RL:OF/RC:C/CDP:H/TD:H/CR:M/IR:M/AR:H");
+        assert_eq!(cvss.base_score(), 7.8);
+        assert_eq!(cvss.temporal_score(), 6.4);
+        assert_eq!(cvss.environmental_score(), 9.2);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let vector = "AV:N/AC:L/Au:N/C:N/I:N/A:C/E:F/RL:OF/RC:C";
+        assert_eq!(parse(vector).to_string(), vector);
+        assert_eq!(
+            parse("AV:N/AC:L/Au:N/C:N/I:N/A:C/E:ND").to_string(),
+            "AV:N/AC:L/Au:N/C:N/I:N/A:C"
+        );
+    }
+
+    #[test]
+    fn test_invalid_vectors() {
+        assert_eq!(
+            CvssV2::from_str("AV:N/AC:L/Au:N/C:N/I:N"),
+            Err(CvssError::MissingMetric("A".to_string()))
+        );
+        assert_eq!(
+            CvssV2::from_str("AV:X/AC:L/Au:N/C:N/I:N/A:C"),
+            Err(CvssError::InvalidValue {
+                metric: "AV".to_string(),
+                value: "X".to_string()
+            })
+        );
+        assert_eq!(
+            CvssV2::from_str("AV:N/AV:N/AC:L/Au:N/C:N/I:N/A:C"),
+            Err(CvssError::DuplicateMetric("AV".to_string()))
+        );
+        assert_eq!(
+            CvssV2::from_str("AV:N/AC:L/Au:N/C:N/I:N/A:C/XY:Z"),
+            Err(CvssError::UnknownMetric("XY".to_string()))
+        );
+    }
+}
@@ -0,0 +1,570 @@
+//! CVSS v3.0 and v3.1, see <https://www.first.org/cvss/v3.1/specification-document>
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::cvss::{
+    CvssError, CvssSeverity, Metric, VectorBuilder, VectorComponents, json_property, metric, optional_json_property,
+};
+
+metric! {
+    /// Attack Vector (AV)
+    AttackVector, "AV", "attackVector" {
+        Network = "N" / "NETWORK",
+        Adjacent = "A" / "ADJACENT_NETWORK",
+        Local = "L" / "LOCAL",
+        Physical = "P" / "PHYSICAL",
+    }
+}
+
+metric! {
+    /// Attack Complexity (AC)
+    AttackComplexity, "AC", "attackComplexity" {
+        Low = "L" / "LOW",
+        High = "H" / "HIGH",
+    }
+}
+
+metric! {
+    /// Privileges Required (PR)
+    PrivilegesRequired, "PR", "privilegesRequired" {
+        None = "N" / "NONE",
+        Low = "L" / "LOW",
+        High = "H" / "HIGH",
+    }
+}
+
+metric! {
+    /// User Interaction (UI)
+    UserInteraction, "UI", "userInteraction" {
+        None = "N" / "NONE",
+        Required = "R" / "REQUIRED",
+    }
+}
+
+metric! {
+    /// Scope (S)
+    Scope, "S", "scope" {
+        Unchanged = "U" / "UNCHANGED",
+        Changed = "C" / "CHANGED",
+    }
+}
+
+metric! {
+    /// Confidentiality (C)
+    Confidentiality, "C", "confidentialityImpact" {
+        High = "H" / "HIGH",
+        Low = "L" / "LOW",
+        None = "N" / "NONE",
+    }
+}
+
+metric! {
+    /// Integrity (I)
+    Integrity, "I", "integrityImpact" {
+        High = "H" / "HIGH",
+        Low = "L" / "LOW",
+        None = "N" / "NONE",
+    }
+}
+
+metric! {
+    /// Availability (A)
+    Availability, "A", "availabilityImpact" {
+        High = "H" / "HIGH",
+        Low = "L" / "LOW",
+        None = "N" / "NONE",
+    }
+}
+
+metric! {
+    /// Exploit Code Maturity (E)
+    ExploitCodeMaturity, "E", "exploitCodeMaturity" {
+        High = "H" / "HIGH",
+        Functional = "F" / "FUNCTIONAL",
+        ProofOfConcept = "P" / "PROOF_OF_CONCEPT",
+        Unproven = "U" / "UNPROVEN",
+    }
+}
+
+metric! {
+    /// Remediation Level (RL)
+    RemediationLevel, "RL", "remediationLevel" {
+        Unavailable = "U" / "UNAVAILABLE",
+        Workaround = "W" / "WORKAROUND",
+        TemporaryFix = "T" / "TEMPORARY_FIX",
+        OfficialFix = "O" / "OFFICIAL_FIX",
+    }
+}
+
+metric! {
+    /// Report Confidence (RC)
+    ReportConfidence, "RC", "reportConfidence" {
+        Confirmed = "C" / "CONFIRMED",
+        Reasonable = "R" / "REASONABLE",
+        Unknown = "U" / "UNKNOWN",
+    }
+}
+
+metric! {
+    /// Confidentiality Requirement (CR)
+    ConfidentialityRequirement, "CR", "confidentialityRequirement" {
+        High = "H" / "HIGH",
+        Medium = "M" / "MEDIUM",
+        Low = "L" / "LOW",
+    }
+}
+
+metric! {
+    /// Integrity Requirement (IR)
+    IntegrityRequirement, "IR", "integrityRequirement" {
+        High = "H" / "HIGH",
+        Medium = "M" / "MEDIUM",
+        Low = "L" / "LOW",
+    }
+}
+
+metric! {
+    /// Availability Requirement (AR)
+    AvailabilityRequirement, "AR", "availabilityRequirement" {
+        High = "H" / "HIGH",
+        Medium = "M" / "MEDIUM",
+        Low = "L" / "LOW",
+    }
+}
+
+metric! {
+    /// Modified Attack Vector (MAV)
+    ModifiedAttackVector, "MAV", "modifiedAttackVector" {
+        Network = "N" / "NETWORK",
+        Adjacent = "A" / "ADJACENT_NETWORK",
+        Local = "L" / "LOCAL",
+        Physical = "P" / "PHYSICAL",
+    }
+}
+
+metric! {
+    /// Modified Attack Complexity (MAC)
+    ModifiedAttackComplexity, "MAC", "modifiedAttackComplexity" {
+        Low = "L" / "LOW",
+        High = "H" / "HIGH",
+    }
+}
+
+metric! {
+    /// Modified Privileges Required (MPR)
+    ModifiedPrivilegesRequired, "MPR", "modifiedPrivilegesRequired" {
+        None = "N" / "NONE",
+        Low = "L" / "LOW",
+        High = "H" / "HIGH",
+    }
+}
+
+metric! {
+    /// Modified User Interaction (MUI)
+    ModifiedUserInteraction, "MUI", "modifiedUserInteraction" {
+        None = "N" / "NONE",
+        Required = "R" / "REQUIRED",
+    }
+}
+
+metric! {
+    /// Modified Scope (MS)
+    ModifiedScope, "MS", "modifiedScope" {
+        Unchanged = "U" / "UNCHANGED",
+        Changed = "C" / "CHANGED",
+    }
+}
+
+metric! {
+    /// Modified Confidentiality (MC)
+    ModifiedConfidentiality, "MC", "modifiedConfidentialityImpact" {
+        High = "H" / "HIGH",
+        Low = "L" / "LOW",
+        None = "N" / "NONE",
+    }
+}
+
+metric! {
+    /// Modified Integrity (MI)
+    ModifiedIntegrity, "MI", "modifiedIntegrityImpact" {
+        High = "H" / "HIGH",
+        Low = "L" / "LOW",
+        None = "N" / "NONE",
+    }
+}
+
+metric! {
+    /// Modified Availability (MA)
+    ModifiedAvailability, "MA", "modifiedAvailabilityImpact" {
+        High = "H" / "HIGH",
+        Low = "L" / "LOW",
+        None = "N" / "NONE",
+    }
+}
+
+/// Minor version of a CVSS v3 vector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CvssV3Version {
+    V3_0,
+    V3_1,
+}
+
+impl CvssV3Version {
+    /// Returns the version as used in the `version` property of the CVSS JSON representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CvssV3Version::V3_0 => "3.0",
+            CvssV3Version::V3_1 => "3.1",
+        }
+    }
+}
+
+/// A CVSS v3.0 or v3.1 vector. Temporal and environmental metrics are `None` if they are not defined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CvssV3 {
+    pub version: CvssV3Version,
+    pub attack_vector: AttackVector,
+    pub attack_complexity: AttackComplexity,
+    pub privileges_required: PrivilegesRequired,
+    pub user_interaction: UserInteraction,
+    pub scope: Scope,
+    pub confidentiality: Confidentiality,
+    pub integrity: Integrity,
+    pub availability: Availability,
+    pub exploit_code_maturity: Option<ExploitCodeMaturity>,
+    pub remediation_level: Option<RemediationLevel>,
+    pub report_confidence: Option<ReportConfidence>,
+    pub confidentiality_requirement: Option<ConfidentialityRequirement>,
+    pub integrity_requirement: Option<IntegrityRequirement>,
+    pub availability_requirement: Option<AvailabilityRequirement>,
+    pub modified_attack_vector: Option<ModifiedAttackVector>,
+    pub modified_attack_complexity: Option<ModifiedAttackComplexity>,
+    pub modified_privileges_required: Option<ModifiedPrivilegesRequired>,
+    pub modified_user_interaction: Option<ModifiedUserInteraction>,
+    pub modified_scope: Option<ModifiedScope>,
+    pub modified_confidentiality: Option<ModifiedConfidentiality>,
+    pub modified_integrity: Option<ModifiedIntegrity>,
+    pub modified_availability: Option<ModifiedAvailability>,
+}
+
+impl FromStr for CvssV3 {
+    type Err = CvssError;
+
+    fn from_str(vector: &str) -> Result<Self, Self::Err> {
+        let (version, metrics) = if let Some(metrics) = vector.strip_prefix("CVSS:3.0/") {
+            (CvssV3Version::V3_0, metrics)
+        } else if let Some(metrics) = vector.strip_prefix("CVSS:3.1/") {
+            (CvssV3Version::V3_1, metrics)
+        } else {
+            return Err(CvssError::InvalidPrefix("'CVSS:3.0/' or 'CVSS:3.1/'".to_string()));
+        };
+
+        let mut components = VectorComponents::parse(metrics, "X")?;
+        let cvss = CvssV3 {
+            version,
+            attack_vector: components.required()?,
+            attack_complexity: components.required()?,
+            privileges_required: components.required()?,
+            user_interaction: components.required()?,
+            scope: components.required()?,
+            confidentiality: components.required()?,
+            integrity: components.required()?,
+            availability: components.required()?,
+            exploit_code_maturity: components.optional()?,
+            remediation_level: components.optional()?,
+            report_confidence: components.optional()?,
+            confidentiality_requirement: components.optional()?,
+            integrity_requirement: components.optional()?,
+            availability_requirement: components.optional()?,
+            modified_attack_vector: components.optional()?,
+            modified_attack_complexity: components.optional()?,
+            modified_privileges_required: components.optional()?,
+            modified_user_interaction: components.optional()?,
+            modified_scope: components.optional()?,
+            modified_confidentiality: components.optional()?,
+            modified_integrity: components.optional()?,
+            modified_availability: components.optional()?,
+        };
+        components.finish()?;
+        Ok(cvss)
+    }
+}
+
+impl Display for CvssV3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let vector = VectorBuilder::new(&format!("CVSS:{}", self.version.as_str()))
+            .add(self.attack_vector)
+            .add(self.attack_complexity)
+            .add(self.privileges_required)
+            .add(self.user_interaction)
+            .add(self.scope)
+            .add(self.confidentiality)
+            .add(self.integrity)
+            .add(self.availability)
+            .add_optional(self.exploit_code_maturity)
+            .add_optional(self.remediation_level)
+            .add_optional(self.report_confidence)
+            .add_optional(self.confidentiality_requirement)
+            .add_optional(self.integrity_requirement)
+            .add_optional(self.availability_requirement)
+            .add_optional(self.modified_attack_vector)
+            .add_optional(self.modified_attack_complexity)
+            .add_optional(self.modified_privileges_required)
+            .add_optional(self.modified_user_interaction)
+            .add_optional(self.modified_scope)
+            .add_optional(self.modified_confidentiality)
+            .add_optional(self.modified_integrity)
+            .add_optional(self.modified_availability)
+            .build();
+        write!(f, "{vector}")
+    }
+}
+
+/// Rounds up to one decimal, avoiding floating point errors as described in appendix A of CVSS v3.1.
+fn round_up(value: f64) -> f64 {
+    let int_input = (value * 100_000.0).round() as i64;
+    if int_input % 10_000 == 0 {
+        int_input as f64 / 100_000.0
+    } else {
+        ((int_input / 10_000) as f64 + 1.0) / 10.0
+    }
+}
+
+/// Weights of the metrics, looked up by their vector string abbreviations.
+fn attack_vector_weight(value: &str) -> f64 {
+    match value {
+        "N" => 0.85,
+        "A" => 0.62,
+        "L" => 0.55,
+        _ => 0.2,
+    }
+}
+
+fn attack_complexity_weight(value: &str) -> f64 {
+    if value == "L" { 0.77 } else { 0.44 }
+}
+
+fn privileges_required_weight(value: &str, scope_changed: bool) -> f64 {
+    match (value, scope_changed) {
+        ("N", _) => 0.85,
+        ("L", false) => 0.62,
+        ("L", true) => 0.68,
+        (_, false) => 0.27,
+        (_, true) => 0.5,
+    }
+}
+
+fn user_interaction_weight(value: &str) -> f64 {
+    if value == "N" { 0.85 } else { 0.62 }
+}
+
+fn impact_weight(value: &str) -> f64 {
+    match value {
+        "H" => 0.56,
+        "L" => 0.22,
+        _ => 0.0,
+    }
+}
+
+fn requirement_weight(value: Option<&str>) -> f64 {
+    match value {
+        Some("H") => 1.5,
+        Some("L") => 0.5,
+        _ => 1.0,
+    }
+}
+
+/// Returns the abbreviation of the modified metric, or of the base metric if it is not defined.
+fn effective<B: Metric, M: Metric>(base: B, modified: Option<M>) -> &'static str {
+    modified.map_or(base.abbreviation(), |m| m.abbreviation())
+}
+
+impl CvssV3 {
+    fn temporal_multiplier(&self) -> f64 {
+        let e = match self.exploit_code_maturity {
+            Some(ExploitCodeMaturity::Unproven) => 0.91,
+            Some(ExploitCodeMaturity::ProofOfConcept) => 0.94,
+            Some(ExploitCodeMaturity::Functional) => 0.97,
+            Some(ExploitCodeMaturity::High) | None => 1.0,
+        };
+        let rl = match self.remediation_level {
+            Some(RemediationLevel::OfficialFix) => 0.95,
+            Some(RemediationLevel::TemporaryFix) => 0.96,
+            Some(RemediationLevel::Workaround) => 0.97,
+            Some(RemediationLevel::Unavailable) | None => 1.0,
+        };
+        let rc = match self.report_confidence {
+            Some(ReportConfidence::Unknown) => 0.92,
+            Some(ReportConfidence::Reasonable) => 0.96,
+            Some(ReportConfidence::Confirmed) | None => 1.0,
+        };
+        e * rl * rc
+    }
+
+    /// Computes the base score.
+    pub fn base_score(&self) -> f64 {
+        let scope_changed = self.scope == Scope::Changed;
+        let iss = 1.0
+            - (1.0 - impact_weight(self.confidentiality.abbreviation()))
+                * (1.0 - impact_weight(self.integrity.abbreviation()))
+                * (1.0 - impact_weight(self.availability.abbreviation()));
+        let impact = if scope_changed {
+            7.52 * (iss - 0.029) - 3.25 * (iss - 0.02).powi(15)
+        } else {
+            6.42 * iss
+        };
+        let exploitability = 8.22
+            * attack_vector_weight(self.attack_vector.abbreviation())
+            * attack_complexity_weight(self.attack_complexity.abbreviation())
+            * privileges_required_weight(self.privileges_required.abbreviation(), scope_changed)
+            * user_interaction_weight(self.user_interaction.abbreviation());
+
+        if impact <= 0.0 {
+            0.0
+        } else if scope_changed {
+            round_up(f64::min(1.08 * (impact + exploitability), 10.0))
+        } else {
+            round_up(f64::min(impact + exploitability, 10.0))
+        }
+    }
+
+    /// Computes the temporal score. Undefined temporal metrics do not change the base score.
+    pub fn temporal_score(&self) -> f64 {
+        round_up(self.base_score() * self.temporal_multiplier())
+    }
+
+    /// Computes the environmental score. Undefined environmental metrics do not change the temporal score.
+    pub fn environmental_score(&self) -> f64 {
+        let scope_changed = effective(self.scope, self.modified_scope) == "C";
+        let miss = f64::min(
+            1.0 - (1.0
+                - requirement_weight(self.confidentiality_requirement.map(|r| r.abbreviation()))
+                    * impact_weight(effective(self.confidentiality, self.modified_confidentiality)))
+                * (1.0
+                    - requirement_weight(self.integrity_requirement.map(|r| r.abbreviation()))
+                        * impact_weight(effective(self.integrity, self.modified_integrity)))
+                * (1.0
+                    - requirement_weight(self.availability_requirement.map(|r| r.abbreviation()))
+                        * impact_weight(effective(self.availability, self.modified_availability))),
+            0.915,
+        );
+        let modified_impact = match (scope_changed, self.version) {
+            (false, _) => 6.42 * miss,
+            (true, CvssV3Version::V3_0) => 7.52 * (miss - 0.029) - 3.25 * (miss - 0.02).powi(15),
+            (true, CvssV3Version::V3_1) => 7.52 * (miss - 0.029) - 3.25 * (miss * 0.9731 - 0.02).powi(13),
+        };
+        let modified_exploitability = 8.22
+            * attack_vector_weight(effective(self.attack_vector, self.modified_attack_vector))
+            * attack_complexity_weight(effective(self.attack_complexity, self.modified_attack_complexity))
+            * privileges_required_weight(
+                effective(self.privileges_required, self.modified_privileges_required),
+                scope_changed,
+            )
+            * user_interaction_weight(effective(self.user_interaction, self.modified_user_interaction));
+
+        if modified_impact <= 0.0 {
+            0.0
+        } else if scope_changed {
+            round_up(
+                round_up(f64::min(1.08 * (modified_impact + modified_exploitability), 10.0))
+                    * self.temporal_multiplier(),
+            )
+        } else {
+            round_up(round_up(f64::min(modified_impact + modified_exploitability, 10.0)) * self.temporal_multiplier())
+        }
+    }
+
+    /// Returns the JSON property names and expected values of all metrics of this vector.
+    pub fn json_properties(&self) -> Vec<(&'static str, &'static str)> {
+        vec![
+            json_property(self.attack_vector),
+            json_property(self.attack_complexity),
+            json_property(self.privileges_required),
+            json_property(self.user_interaction),
+            json_property(self.scope),
+            json_property(self.confidentiality),
+            json_property(self.integrity),
+            json_property(self.availability),
+            optional_json_property(self.exploit_code_maturity),
+            optional_json_property(self.remediation_level),
+            optional_json_property(self.report_confidence),
+            optional_json_property(self.confidentiality_requirement),
+            optional_json_property(self.integrity_requirement),
+            optional_json_property(self.availability_requirement),
+            optional_json_property(self.modified_attack_vector),
+            optional_json_property(self.modified_attack_complexity),
+            optional_json_property(self.modified_privileges_required),
+            optional_json_property(self.modified_user_interaction),
+            optional_json_property(self.modified_scope),
+            optional_json_property(self.modified_confidentiality),
+            optional_json_property(self.modified_integrity),
+            optional_json_property(self.modified_availability),
+        ]
+    }
+
+    /// Returns the severity of a CVSS v3 score.
+    pub fn severity(score: f64) -> CvssSeverity {
+        CvssSeverity::from_score(score)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(vector: &str) -> CvssV3 {
+        CvssV3::from_str(vector).unwrap()
+    }
+
+    #[test]
+    fn test_base_score() {
+        assert_eq!(parse("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").base_score(), 9.8);
+        assert_eq!(parse("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:H/A:H").base_score(), 10.0);
+        assert_eq!(parse("CVSS:3.1/AV:L/AC:L/PR:H/UI:R/S:U/C:H/I:H/A:H").base_score(), 6.5);
+        assert_eq!(parse("CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N").base_score(), 6.1);
+        assert_eq!(parse("CVSS:3.0/AV:N/AC:L/PR:L/UI:N/S:U/C:H/I:N/A:N").base_score(), 6.5);
+        assert_eq!(parse("CVSS:3.1/AV:P/AC:H/PR:H/UI:R/S:U/C:N/I:N/A:N").base_score(), 0.0);
+    }
+
+    #[test]
+    fn test_temporal_and_environmental_score() {
+        let cvss = parse("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P/RL:O/RC:C");
+        assert_eq!(cvss.temporal_score(), 8.8);
+        assert_eq!(cvss.environmental_score(), 8.8);
+
+        let cvss = parse("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/MAV:L/MC:L/CR:L");
+        assert_eq!(cvss.temporal_score(), 9.8);
+        assert_eq!(cvss.environmental_score(), 7.9);
+    }
+
+    #[test]
+    fn test_severity() {
+        assert_eq!(
+            CvssV3::severity(parse("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").base_score()),
+            CvssSeverity::Critical
+        );
+        assert_eq!(
+            CvssV3::severity(parse("CVSS:3.1/AV:L/AC:L/PR:H/UI:R/S:U/C:H/I:H/A:H").base_score()),
+            CvssSeverity::Medium
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let vector = "CVSS:3.0/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P/MAV:L";
+        assert_eq!(parse(vector).to_string(), vector);
+    }
+
+    #[test]
+    fn test_invalid_vectors() {
+        assert!(matches!(
+            CvssV3::from_str("CVSS:2.0/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"),
+            Err(CvssError::InvalidPrefix(_))
+        ));
+        assert_eq!(
+            CvssV3::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/C:H/I:H/A:H"),
+            Err(CvssError::MissingMetric("S".to_string()))
+        );
+    }
+}
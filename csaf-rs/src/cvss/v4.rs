@@ -0,0 +1,1120 @@
+//! CVSS v4.0, see <https://www.first.org/cvss/v4.0/specification-document>
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+use crate::cvss::{
+    CvssError, CvssSeverity, Metric, VectorBuilder, VectorComponents, json_property, metric, optional_json_property,
+    round_to_one_decimal,
+};
+
+metric! {
+    /// Attack Vector (AV)
+    AttackVector, "AV", "attackVector" {
+        Network = "N" / "NETWORK",
+        Adjacent = "A" / "ADJACENT",
+        Local = "L" / "LOCAL",
+        Physical = "P" / "PHYSICAL",
+    }
+}
+
+metric! {
+    /// Attack Complexity (AC)
+    AttackComplexity, "AC", "attackComplexity" {
+        Low = "L" / "LOW",
+        High = "H" / "HIGH",
+    }
+}
+
+metric! {
+    /// Attack Requirements (AT)
+    AttackRequirements, "AT", "attackRequirements" {
+        None = "N" / "NONE",
+        Present = "P" / "PRESENT",
+    }
+}
+
+metric! {
+    /// Privileges Required (PR)
+    PrivilegesRequired, "PR", "privilegesRequired" {
+        None = "N" / "NONE",
+        Low = "L" / "LOW",
+        High = "H" / "HIGH",
+    }
+}
+
+metric! {
+    /// User Interaction (UI)
+    UserInteraction, "UI", "userInteraction" {
+        None = "N" / "NONE",
+        Passive = "P" / "PASSIVE",
+        Active = "A" / "ACTIVE",
+    }
+}
+
+metric! {
+    /// Vulnerable System Confidentiality Impact (VC)
+    VulnConfidentialityImpact, "VC", "vulnConfidentialityImpact" {
+        High = "H" / "HIGH",
+        Low = "L" / "LOW",
+        None = "N" / "NONE",
+    }
+}
+
+metric! {
+    /// Vulnerable System Integrity Impact (VI)
+    VulnIntegrityImpact, "VI", "vulnIntegrityImpact" {
+        High = "H" / "HIGH",
+        Low = "L" / "LOW",
+        None = "N" / "NONE",
+    }
+}
+
+metric! {
+    /// Vulnerable System Availability Impact (VA)
+    VulnAvailabilityImpact, "VA", "vulnAvailabilityImpact" {
+        High = "H" / "HIGH",
+        Low = "L" / "LOW",
+        None = "N" / "NONE",
+    }
+}
+
+metric! {
+    /// Subsequent System Confidentiality Impact (SC)
+    SubConfidentialityImpact, "SC", "subConfidentialityImpact" {
+        High = "H" / "HIGH",
+        Low = "L" / "LOW",
+        None = "N" / "NONE",
+    }
+}
+
+metric! {
+    /// Subsequent System Integrity Impact (SI)
+    SubIntegrityImpact, "SI", "subIntegrityImpact" {
+        High = "H" / "HIGH",
+        Low = "L" / "LOW",
+        None = "N" / "NONE",
+    }
+}
+
+metric! {
+    /// Subsequent System Availability Impact (SA)
+    SubAvailabilityImpact, "SA", "subAvailabilityImpact" {
+        High = "H" / "HIGH",
+        Low = "L" / "LOW",
+        None = "N" / "NONE",
+    }
+}
+
+metric! {
+    /// Exploit Maturity (E)
+    ExploitMaturity, "E", "exploitMaturity" {
+        Attacked = "A" / "ATTACKED",
+        ProofOfConcept = "P" / "PROOF_OF_CONCEPT",
+        Unreported = "U" / "UNREPORTED",
+    }
+}
+
+metric! {
+    /// Confidentiality Requirement (CR)
+    ConfidentialityRequirement, "CR", "confidentialityRequirement" {
+        High = "H" / "HIGH",
+        Medium = "M" / "MEDIUM",
+        Low = "L" / "LOW",
+    }
+}
+
+metric! {
+    /// Integrity Requirement (IR)
+    IntegrityRequirement, "IR", "integrityRequirement" {
+        High = "H" / "HIGH",
+        Medium = "M" / "MEDIUM",
+        Low = "L" / "LOW",
+    }
+}
+
+metric! {
+    /// Availability Requirement (AR)
+    AvailabilityRequirement, "AR", "availabilityRequirement" {
+        High = "H" / "HIGH",
+        Medium = "M" / "MEDIUM",
+        Low = "L" / "LOW",
+    }
+}
+
+metric! {
+    /// Modified Attack Vector (MAV)
+    ModifiedAttackVector, "MAV", "modifiedAttackVector" {
+        Network = "N" / "NETWORK",
+        Adjacent = "A" / "ADJACENT",
+        Local = "L" / "LOCAL",
+        Physical = "P" / "PHYSICAL",
+    }
+}
+
+metric! {
+    /// Modified Attack Complexity (MAC)
+    ModifiedAttackComplexity, "MAC", "modifiedAttackComplexity" {
+        Low = "L" / "LOW",
+        High = "H" / "HIGH",
+    }
+}
+
+metric! {
+    /// Modified Attack Requirements (MAT)
+    ModifiedAttackRequirements, "MAT", "modifiedAttackRequirements" {
+        None = "N" / "NONE",
+        Present = "P" / "PRESENT",
+    }
+}
+
+metric! {
+    /// Modified Privileges Required (MPR)
+    ModifiedPrivilegesRequired, "MPR", "modifiedPrivilegesRequired" {
+        None = "N" / "NONE",
+        Low = "L" / "LOW",
+        High = "H" / "HIGH",
+    }
+}
+
+metric! {
+    /// Modified User Interaction (MUI)
+    ModifiedUserInteraction, "MUI", "modifiedUserInteraction" {
+        None = "N" / "NONE",
+        Passive = "P" / "PASSIVE",
+        Active = "A" / "ACTIVE",
+    }
+}
+
+metric! {
+    /// Modified Vulnerable System Confidentiality Impact (MVC)
+    ModifiedVulnConfidentialityImpact, "MVC", "modifiedVulnConfidentialityImpact" {
+        High = "H" / "HIGH",
+        Low = "L" / "LOW",
+        None = "N" / "NONE",
+    }
+}
+
+metric! {
+    /// Modified Vulnerable System Integrity Impact (MVI)
+    ModifiedVulnIntegrityImpact, "MVI", "modifiedVulnIntegrityImpact" {
+        High = "H" / "HIGH",
+        Low = "L" / "LOW",
+        None = "N" / "NONE",
+    }
+}
+
+metric! {
+    /// Modified Vulnerable System Availability Impact (MVA)
+    ModifiedVulnAvailabilityImpact, "MVA", "modifiedVulnAvailabilityImpact" {
+        High = "H" / "HIGH",
+        Low = "L" / "LOW",
+        None = "N" / "NONE",
+    }
+}
+
+metric! {
+    /// Modified Subsequent System Confidentiality Impact (MSC)
+    ModifiedSubConfidentialityImpact, "MSC", "modifiedSubConfidentialityImpact" {
+        High = "H" / "HIGH",
+        Low = "L" / "LOW",
+        Negligible = "N" / "NEGLIGIBLE",
+    }
+}
+
+metric! {
+    /// Modified Subsequent System Integrity Impact (MSI)
+    ModifiedSubIntegrityImpact, "MSI", "modifiedSubIntegrityImpact" {
+        Safety = "S" / "SAFETY",
+        High = "H" / "HIGH",
+        Low = "L" / "LOW",
+        Negligible = "N" / "NEGLIGIBLE",
+    }
+}
+
+metric! {
+    /// Modified Subsequent System Availability Impact (MSA)
+    ModifiedSubAvailabilityImpact, "MSA", "modifiedSubAvailabilityImpact" {
+        Safety = "S" / "SAFETY",
+        High = "H" / "HIGH",
+        Low = "L" / "LOW",
+        Negligible = "N" / "NEGLIGIBLE",
+    }
+}
+
+metric! {
+    /// Safety (S)
+    Safety, "S", "Safety" {
+        Negligible = "N" / "NEGLIGIBLE",
+        Present = "P" / "PRESENT",
+    }
+}
+
+metric! {
+    /// Automatable (AU)
+    Automatable, "AU", "Automatable" {
+        No = "N" / "NO",
+        Yes = "Y" / "YES",
+    }
+}
+
+metric! {
+    /// Recovery (R)
+    Recovery, "R", "Recovery" {
+        Automatic = "A" / "AUTOMATIC",
+        User = "U" / "USER",
+        Irrecoverable = "I" / "IRRECOVERABLE",
+    }
+}
+
+metric! {
+    /// Value Density (V)
+    ValueDensity, "V", "valueDensity" {
+        Diffuse = "D" / "DIFFUSE",
+        Concentrated = "C" / "CONCENTRATED",
+    }
+}
+
+metric! {
+    /// Vulnerability Response Effort (RE)
+    VulnerabilityResponseEffort, "RE", "vulnerabilityResponseEffort" {
+        Low = "L" / "LOW",
+        Moderate = "M" / "MODERATE",
+        High = "H" / "HIGH",
+    }
+}
+
+metric! {
+    /// Provider Urgency (U)
+    ProviderUrgency, "U", "providerUrgency" {
+        Clear = "Clear" / "CLEAR",
+        Green = "Green" / "GREEN",
+        Amber = "Amber" / "AMBER",
+        Red = "Red" / "RED",
+    }
+}
+
+/// A CVSS v4.0 vector. Threat, environmental and supplemental metrics are `None` if they are not defined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CvssV4 {
+    pub attack_vector: AttackVector,
+    pub attack_complexity: AttackComplexity,
+    pub attack_requirements: AttackRequirements,
+    pub privileges_required: PrivilegesRequired,
+    pub user_interaction: UserInteraction,
+    pub vuln_confidentiality_impact: VulnConfidentialityImpact,
+    pub vuln_integrity_impact: VulnIntegrityImpact,
+    pub vuln_availability_impact: VulnAvailabilityImpact,
+    pub sub_confidentiality_impact: SubConfidentialityImpact,
+    pub sub_integrity_impact: SubIntegrityImpact,
+    pub sub_availability_impact: SubAvailabilityImpact,
+    pub exploit_maturity: Option<ExploitMaturity>,
+    pub confidentiality_requirement: Option<ConfidentialityRequirement>,
+    pub integrity_requirement: Option<IntegrityRequirement>,
+    pub availability_requirement: Option<AvailabilityRequirement>,
+    pub modified_attack_vector: Option<ModifiedAttackVector>,
+    pub modified_attack_complexity: Option<ModifiedAttackComplexity>,
+    pub modified_attack_requirements: Option<ModifiedAttackRequirements>,
+    pub modified_privileges_required: Option<ModifiedPrivilegesRequired>,
+    pub modified_user_interaction: Option<ModifiedUserInteraction>,
+    pub modified_vuln_confidentiality_impact: Option<ModifiedVulnConfidentialityImpact>,
+    pub modified_vuln_integrity_impact: Option<ModifiedVulnIntegrityImpact>,
+    pub modified_vuln_availability_impact: Option<ModifiedVulnAvailabilityImpact>,
+    pub modified_sub_confidentiality_impact: Option<ModifiedSubConfidentialityImpact>,
+    pub modified_sub_integrity_impact: Option<ModifiedSubIntegrityImpact>,
+    pub modified_sub_availability_impact: Option<ModifiedSubAvailabilityImpact>,
+    pub safety: Option<Safety>,
+    pub automatable: Option<Automatable>,
+    pub recovery: Option<Recovery>,
+    pub value_density: Option<ValueDensity>,
+    pub vulnerability_response_effort: Option<VulnerabilityResponseEffort>,
+    pub provider_urgency: Option<ProviderUrgency>,
+}
+
+impl FromStr for CvssV4 {
+    type Err = CvssError;
+
+    fn from_str(vector: &str) -> Result<Self, Self::Err> {
+        let metrics = vector
+            .strip_prefix("CVSS:4.0/")
+            .ok_or_else(|| CvssError::InvalidPrefix("'CVSS:4.0/'".to_string()))?;
+
+        let mut components = VectorComponents::parse(metrics, "X")?;
+        let cvss = CvssV4 {
+            attack_vector: components.required()?,
+            attack_complexity: components.required()?,
+            attack_requirements: components.required()?,
+            privileges_required: components.required()?,
+            user_interaction: components.required()?,
+            vuln_confidentiality_impact: components.required()?,
+            vuln_integrity_impact: components.required()?,
+            vuln_availability_impact: components.required()?,
+            sub_confidentiality_impact: components.required()?,
+            sub_integrity_impact: components.required()?,
+            sub_availability_impact: components.required()?,
+            exploit_maturity: components.optional()?,
+            confidentiality_requirement: components.optional()?,
+            integrity_requirement: components.optional()?,
+            availability_requirement: components.optional()?,
+            modified_attack_vector: components.optional()?,
+            modified_attack_complexity: components.optional()?,
+            modified_attack_requirements: components.optional()?,
+            modified_privileges_required: components.optional()?,
+            modified_user_interaction: components.optional()?,
+            modified_vuln_confidentiality_impact: components.optional()?,
+            modified_vuln_integrity_impact: components.optional()?,
+            modified_vuln_availability_impact: components.optional()?,
+            modified_sub_confidentiality_impact: components.optional()?,
+            modified_sub_integrity_impact: components.optional()?,
+            modified_sub_availability_impact: components.optional()?,
+            safety: components.optional()?,
+            automatable: components.optional()?,
+            recovery: components.optional()?,
+            value_density: components.optional()?,
+            vulnerability_response_effort: components.optional()?,
+            provider_urgency: components.optional()?,
+        };
+        components.finish()?;
+        Ok(cvss)
+    }
+}
+
+impl Display for CvssV4 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let vector = VectorBuilder::new("CVSS:4.0")
+            .add(self.attack_vector)
+            .add(self.attack_complexity)
+            .add(self.attack_requirements)
+            .add(self.privileges_required)
+            .add(self.user_interaction)
+            .add(self.vuln_confidentiality_impact)
+            .add(self.vuln_integrity_impact)
+            .add(self.vuln_availability_impact)
+            .add(self.sub_confidentiality_impact)
+            .add(self.sub_integrity_impact)
+            .add(self.sub_availability_impact)
+            .add_optional(self.exploit_maturity)
+            .add_optional(self.confidentiality_requirement)
+            .add_optional(self.integrity_requirement)
+            .add_optional(self.availability_requirement)
+            .add_optional(self.modified_attack_vector)
+            .add_optional(self.modified_attack_complexity)
+            .add_optional(self.modified_attack_requirements)
+            .add_optional(self.modified_privileges_required)
+            .add_optional(self.modified_user_interaction)
+            .add_optional(self.modified_vuln_confidentiality_impact)
+            .add_optional(self.modified_vuln_integrity_impact)
+            .add_optional(self.modified_vuln_availability_impact)
+            .add_optional(self.modified_sub_confidentiality_impact)
+            .add_optional(self.modified_sub_integrity_impact)
+            .add_optional(self.modified_sub_availability_impact)
+            .add_optional(self.safety)
+            .add_optional(self.automatable)
+            .add_optional(self.recovery)
+            .add_optional(self.value_density)
+            .add_optional(self.vulnerability_response_effort)
+            .add_optional(self.provider_urgency)
+            .build();
+        write!(f, "{vector}")
+    }
+}
+
+/// Nomenclature of a CVSS v4.0 score, depending on the metric groups used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CvssV4Nomenclature {
+    /// Base metrics only
+    CvssB,
+    /// Base and threat metrics
+    CvssBT,
+    /// Base and environmental metrics
+    CvssBE,
+    /// Base, threat and environmental metrics
+    CvssBTE,
+}
+
+impl Display for CvssV4Nomenclature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CvssV4Nomenclature::CvssB => write!(f, "CVSS-B"),
+            CvssV4Nomenclature::CvssBT => write!(f, "CVSS-BT"),
+            CvssV4Nomenclature::CvssBE => write!(f, "CVSS-BE"),
+            CvssV4Nomenclature::CvssBTE => write!(f, "CVSS-BTE"),
+        }
+    }
+}
+
+/// The metric values the score is computed from, after applying modified metrics and defaults.
+struct EffectiveMetrics {
+    av: &'static str,
+    ac: &'static str,
+    at: &'static str,
+    pr: &'static str,
+    ui: &'static str,
+    vc: &'static str,
+    vi: &'static str,
+    va: &'static str,
+    sc: &'static str,
+    si: &'static str,
+    sa: &'static str,
+    e: &'static str,
+    cr: &'static str,
+    ir: &'static str,
+    ar: &'static str,
+}
+
+/// Returns the abbreviation of the modified metric, or of the base metric if it is not defined.
+fn effective<B: Metric, M: Metric>(base: B, modified: Option<M>) -> &'static str {
+    modified.map_or(base.abbreviation(), |m| m.abbreviation())
+}
+
+/// Returns the abbreviation of an optional metric, or `default` if it is not defined.
+fn or_default<M: Metric>(metric: Option<M>, default: &'static str) -> &'static str {
+    metric.map_or(default, |m| m.abbreviation())
+}
+
+/// Severity levels of the metric values, used to compute the distance to the highest severity
+/// vector of a macro vector.
+fn level(metric: &str, value: &str) -> f64 {
+    match (metric, value) {
+        ("AV", "N") | ("PR", "N") | ("UI", "N") | ("AC", "L") | ("AT", "N") => 0.0,
+        ("AV", "A") | ("PR", "L") | ("UI", "P") | ("AC", "H") | ("AT", "P") => 0.1,
+        ("AV", "L") | ("PR", "H") | ("UI", "A") => 0.2,
+        ("AV", "P") => 0.3,
+        ("VC" | "VI" | "VA", "H") => 0.0,
+        ("VC" | "VI" | "VA", "L") => 0.1,
+        ("VC" | "VI" | "VA", "N") => 0.2,
+        ("SI" | "SA", "S") => 0.0,
+        ("SC" | "SI" | "SA", "H") => 0.1,
+        ("SC" | "SI" | "SA", "L") => 0.2,
+        ("SC" | "SI" | "SA", "N") => 0.3,
+        ("CR" | "IR" | "AR", "H") => 0.0,
+        ("CR" | "IR" | "AR", "M") => 0.1,
+        ("CR" | "IR" | "AR", "L") => 0.2,
+        _ => 0.0,
+    }
+}
+
+/// Highest severity vectors of each equivalence class, as published with the CVSS v4.0 calculator.
+fn max_vectors(eq: &str, class: (u8, u8)) -> &'static [&'static str] {
+    match (eq, class) {
+        ("eq1", (0, _)) => &["AV:N/PR:N/UI:N"],
+        ("eq1", (1, _)) => &["AV:A/PR:N/UI:N", "AV:N/PR:L/UI:N", "AV:N/PR:N/UI:P"],
+        ("eq1", (2, _)) => &["AV:P/PR:N/UI:N", "AV:A/PR:L/UI:P"],
+        ("eq2", (0, _)) => &["AC:L/AT:N"],
+        ("eq2", (1, _)) => &["AC:H/AT:N", "AC:L/AT:P"],
+        ("eq3eq6", (0, 0)) => &["VC:H/VI:H/VA:H/CR:H/IR:H/AR:H"],
+        ("eq3eq6", (0, 1)) => &["VC:H/VI:H/VA:L/CR:M/IR:M/AR:H", "VC:H/VI:H/VA:H/CR:M/IR:M/AR:M"],
+        ("eq3eq6", (1, 0)) => &["VC:L/VI:H/VA:H/CR:H/IR:H/AR:H", "VC:H/VI:L/VA:H/CR:H/IR:H/AR:H"],
+        ("eq3eq6", (1, 1)) => &[
+            "VC:L/VI:H/VA:H/CR:H/IR:M/AR:M",
+            "VC:H/VI:L/VA:H/CR:M/IR:H/AR:M",
+            "VC:L/VI:L/VA:H/CR:H/IR:H/AR:M",
+            "VC:H/VI:L/VA:L/CR:M/IR:H/AR:H",
+            "VC:L/VI:H/VA:L/CR:H/IR:M/AR:H",
+        ],
+        ("eq3eq6", (2, 1)) => &["VC:L/VI:L/VA:L/CR:H/IR:H/AR:H"],
+        ("eq4", (0, _)) => &["SC:H/SI:S/SA:S"],
+        ("eq4", (1, _)) => &["SC:H/SI:H/SA:H"],
+        ("eq4", (2, _)) => &["SC:L/SI:L/SA:L"],
+        _ => &[],
+    }
+}
+
+/// Maximal severity distance within each equivalence class (in steps of 0.1).
+fn max_severity(eq: &str, class: (u8, u8)) -> f64 {
+    let steps = match (eq, class) {
+        ("eq1", (0, _)) => 1,
+        ("eq1", (1, _)) => 4,
+        ("eq1", (2, _)) => 5,
+        ("eq2", (0, _)) => 1,
+        ("eq2", (1, _)) => 2,
+        ("eq3eq6", (0, 0)) => 7,
+        ("eq3eq6", (0, 1)) => 6,
+        ("eq3eq6", (1, _)) => 8,
+        ("eq3eq6", (2, _)) => 10,
+        ("eq4", (0, _)) => 6,
+        ("eq4", (1, _)) => 5,
+        ("eq4", (2, _)) => 4,
+        _ => 1,
+    };
+    steps as f64 * 0.1
+}
+
+impl CvssV4 {
+    fn effective_metrics(&self) -> EffectiveMetrics {
+        EffectiveMetrics {
+            av: effective(self.attack_vector, self.modified_attack_vector),
+            ac: effective(self.attack_complexity, self.modified_attack_complexity),
+            at: effective(self.attack_requirements, self.modified_attack_requirements),
+            pr: effective(self.privileges_required, self.modified_privileges_required),
+            ui: effective(self.user_interaction, self.modified_user_interaction),
+            vc: effective(
+                self.vuln_confidentiality_impact,
+                self.modified_vuln_confidentiality_impact,
+            ),
+            vi: effective(self.vuln_integrity_impact, self.modified_vuln_integrity_impact),
+            va: effective(self.vuln_availability_impact, self.modified_vuln_availability_impact),
+            sc: effective(
+                self.sub_confidentiality_impact,
+                self.modified_sub_confidentiality_impact,
+            ),
+            si: effective(self.sub_integrity_impact, self.modified_sub_integrity_impact),
+            sa: effective(self.sub_availability_impact, self.modified_sub_availability_impact),
+            // Undefined threat and requirement metrics default to the worst case
+            e: or_default(self.exploit_maturity, "A"),
+            cr: or_default(self.confidentiality_requirement, "H"),
+            ir: or_default(self.integrity_requirement, "H"),
+            ar: or_default(self.availability_requirement, "H"),
+        }
+    }
+
+    /// Returns the nomenclature of the score, depending on the defined metric groups.
+    pub fn nomenclature(&self) -> CvssV4Nomenclature {
+        let threat = self.exploit_maturity.is_some();
+        let environmental = self.confidentiality_requirement.is_some()
+            || self.integrity_requirement.is_some()
+            || self.availability_requirement.is_some()
+            || self.modified_attack_vector.is_some()
+            || self.modified_attack_complexity.is_some()
+            || self.modified_attack_requirements.is_some()
+            || self.modified_privileges_required.is_some()
+            || self.modified_user_interaction.is_some()
+            || self.modified_vuln_confidentiality_impact.is_some()
+            || self.modified_vuln_integrity_impact.is_some()
+            || self.modified_vuln_availability_impact.is_some()
+            || self.modified_sub_confidentiality_impact.is_some()
+            || self.modified_sub_integrity_impact.is_some()
+            || self.modified_sub_availability_impact.is_some();
+        match (threat, environmental) {
+            (false, false) => CvssV4Nomenclature::CvssB,
+            (true, false) => CvssV4Nomenclature::CvssBT,
+            (false, true) => CvssV4Nomenclature::CvssBE,
+            (true, true) => CvssV4Nomenclature::CvssBTE,
+        }
+    }
+
+    /// Returns the macro vector (EQ1 to EQ6) of this vector.
+    pub fn macro_vector(&self) -> [u8; 6] {
+        let m = self.effective_metrics();
+
+        let eq1 = if m.av == "N" && m.pr == "N" && m.ui == "N" {
+            0
+        } else if (m.av == "N" || m.pr == "N" || m.ui == "N") && m.av != "P" {
+            1
+        } else {
+            2
+        };
+        let eq2 = if m.ac == "L" && m.at == "N" { 0 } else { 1 };
+        let eq3 = if m.vc == "H" && m.vi == "H" {
+            0
+        } else if m.vc == "H" || m.vi == "H" || m.va == "H" {
+            1
+        } else {
+            2
+        };
+        let eq4 = if m.si == "S" || m.sa == "S" {
+            0
+        } else if m.sc == "H" || m.si == "H" || m.sa == "H" {
+            1
+        } else {
+            2
+        };
+        let eq5 = match m.e {
+            "A" => 0,
+            "P" => 1,
+            _ => 2,
+        };
+        let eq6 = if (m.cr == "H" && m.vc == "H") || (m.ir == "H" && m.vi == "H") || (m.ar == "H" && m.va == "H") {
+            0
+        } else {
+            1
+        };
+        [eq1, eq2, eq3, eq4, eq5, eq6]
+    }
+
+    /// Computes the CVSS v4.0 score of the vector, taking all defined metric groups into account.
+    ///
+    /// Depending on the defined metrics, this is the CVSS-B, CVSS-BT, CVSS-BE or CVSS-BTE score,
+    /// see [`CvssV4::nomenclature`].
+    pub fn score(&self) -> f64 {
+        let m = self.effective_metrics();
+        if [m.vc, m.vi, m.va, m.sc, m.si, m.sa].iter().all(|v| *v == "N") {
+            return 0.0;
+        }
+
+        let macro_vector = self.macro_vector();
+        let [eq1, eq2, eq3, eq4, eq5, eq6] = macro_vector;
+        let Some(value) = lookup(macro_vector) else {
+            return 0.0;
+        };
+
+        // Scores of the next lower macro vectors of each equivalence class
+        let eq1_next_lower = lookup([eq1 + 1, eq2, eq3, eq4, eq5, eq6]);
+        let eq2_next_lower = lookup([eq1, eq2 + 1, eq3, eq4, eq5, eq6]);
+        let eq3eq6_next_lower = match (eq3, eq6) {
+            (0, 0) => {
+                let left = lookup([eq1, eq2, eq3, eq4, eq5, eq6 + 1]);
+                let right = lookup([eq1, eq2, eq3 + 1, eq4, eq5, eq6]);
+                match (left, right) {
+                    (Some(left), Some(right)) => Some(f64::max(left, right)),
+                    (left, right) => left.or(right),
+                }
+            },
+            (1, 0) => lookup([eq1, eq2, eq3, eq4, eq5, eq6 + 1]),
+            _ => lookup([eq1, eq2, eq3 + 1, eq4, eq5, eq6]),
+        };
+        let eq4_next_lower = lookup([eq1, eq2, eq3, eq4 + 1, eq5, eq6]);
+        let eq5_next_lower = lookup([eq1, eq2, eq3, eq4, eq5 + 1, eq6]);
+
+        let vector_metrics = [
+            ("AV", m.av),
+            ("PR", m.pr),
+            ("UI", m.ui),
+            ("AC", m.ac),
+            ("AT", m.at),
+            ("VC", m.vc),
+            ("VI", m.vi),
+            ("VA", m.va),
+            ("CR", m.cr),
+            ("IR", m.ir),
+            ("AR", m.ar),
+            ("SC", m.sc),
+            ("SI", m.si),
+            ("SA", m.sa),
+        ];
+        // Distance of the vector to the first highest severity vector of the class that is at least as severe
+        let severity_distance = |eq: &str, class: (u8, u8)| -> f64 {
+            max_vectors(eq, class)
+                .iter()
+                .find_map(|max_vector| {
+                    let distances: Vec<f64> = max_vector
+                        .split('/')
+                        .filter_map(|component| component.split_once(':'))
+                        .map(|(metric, max_value)| {
+                            let value = vector_metrics
+                                .iter()
+                                .find(|(name, _)| *name == metric)
+                                .map_or("", |(_, value)| *value);
+                            level(metric, value) - level(metric, max_value)
+                        })
+                        .collect();
+                    if distances.iter().all(|d| *d >= -1e-9) {
+                        Some(distances.iter().sum())
+                    } else {
+                        None
+                    }
+                })
+                .unwrap_or(0.0)
+        };
+
+        let classes = [
+            ("eq1", (eq1, 0), eq1_next_lower),
+            ("eq2", (eq2, 0), eq2_next_lower),
+            ("eq3eq6", (eq3, eq6), eq3eq6_next_lower),
+            ("eq4", (eq4, 0), eq4_next_lower),
+            // The distance within EQ5 is always 0
+            ("eq5", (eq5, 0), eq5_next_lower),
+        ];
+        let mut n_existing_lower = 0;
+        let mut normalized_severity_sum = 0.0;
+        for (eq, class, next_lower) in classes {
+            if let Some(next_lower) = next_lower {
+                n_existing_lower += 1;
+                let available_distance = value - next_lower;
+                let distance = if eq == "eq5" { 0.0 } else { severity_distance(eq, class) };
+                normalized_severity_sum += available_distance * (distance / max_severity(eq, class));
+            }
+        }
+        let mean_distance = if n_existing_lower == 0 {
+            0.0
+        } else {
+            normalized_severity_sum / n_existing_lower as f64
+        };
+
+        round_to_one_decimal((value - mean_distance).clamp(0.0, 10.0))
+    }
+
+    /// Returns the severity of a CVSS v4.0 score.
+    pub fn severity(score: f64) -> CvssSeverity {
+        CvssSeverity::from_score(score)
+    }
+
+    /// Returns the JSON property names and expected values of all metrics of this vector.
+    pub fn json_properties(&self) -> Vec<(&'static str, &'static str)> {
+        vec![
+            json_property(self.attack_vector),
+            json_property(self.attack_complexity),
+            json_property(self.attack_requirements),
+            json_property(self.privileges_required),
+            json_property(self.user_interaction),
+            json_property(self.vuln_confidentiality_impact),
+            json_property(self.vuln_integrity_impact),
+            json_property(self.vuln_availability_impact),
+            json_property(self.sub_confidentiality_impact),
+            json_property(self.sub_integrity_impact),
+            json_property(self.sub_availability_impact),
+            optional_json_property(self.exploit_maturity),
+            optional_json_property(self.confidentiality_requirement),
+            optional_json_property(self.integrity_requirement),
+            optional_json_property(self.availability_requirement),
+            optional_json_property(self.modified_attack_vector),
+            optional_json_property(self.modified_attack_complexity),
+            optional_json_property(self.modified_attack_requirements),
+            optional_json_property(self.modified_privileges_required),
+            optional_json_property(self.modified_user_interaction),
+            optional_json_property(self.modified_vuln_confidentiality_impact),
+            optional_json_property(self.modified_vuln_integrity_impact),
+            optional_json_property(self.modified_vuln_availability_impact),
+            optional_json_property(self.modified_sub_confidentiality_impact),
+            optional_json_property(self.modified_sub_integrity_impact),
+            optional_json_property(self.modified_sub_availability_impact),
+            optional_json_property(self.safety),
+            optional_json_property(self.automatable),
+            optional_json_property(self.recovery),
+            optional_json_property(self.value_density),
+            optional_json_property(self.vulnerability_response_effort),
+            optional_json_property(self.provider_urgency),
+        ]
+    }
+}
+
+fn lookup(macro_vector: [u8; 6]) -> Option<f64> {
+    let key: String = macro_vector.iter().map(|eq| char::from(b'0' + eq)).collect();
+    LOOKUP_TABLE.get(key.as_str()).copied()
+}
+
+/// Scores of all macro vectors (EQ1 to EQ6), as published with the CVSS v4.0 calculator.
+static LOOKUP_TABLE: LazyLock<HashMap<&'static str, f64>> = LazyLock::new(|| {
+    HashMap::from([
+        ("000000", 10.0),
+        ("000001", 9.9),
+        ("000010", 9.8),
+        ("000011", 9.5),
+        ("000020", 9.5),
+        ("000021", 9.2),
+        ("000100", 10.0),
+        ("000101", 9.6),
+        ("000110", 9.3),
+        ("000111", 8.7),
+        ("000120", 9.1),
+        ("000121", 8.1),
+        ("000200", 9.3),
+        ("000201", 9.0),
+        ("000210", 8.9),
+        ("000211", 8.0),
+        ("000220", 8.1),
+        ("000221", 6.8),
+        ("001000", 9.8),
+        ("001001", 9.5),
+        ("001010", 9.5),
+        ("001011", 9.2),
+        ("001020", 9.0),
+        ("001021", 8.4),
+        ("001100", 9.3),
+        ("001101", 9.2),
+        ("001110", 8.9),
+        ("001111", 8.1),
+        ("001120", 8.1),
+        ("001121", 6.5),
+        ("001200", 8.8),
+        ("001201", 8.0),
+        ("001210", 7.8),
+        ("001211", 7.0),
+        ("001220", 6.9),
+        ("001221", 4.8),
+        ("002001", 9.2),
+        ("002011", 8.2),
+        ("002021", 7.2),
+        ("002101", 7.9),
+        ("002111", 6.9),
+        ("002121", 5.0),
+        ("002201", 6.9),
+        ("002211", 5.5),
+        ("002221", 2.7),
+        ("010000", 9.9),
+        ("010001", 9.7),
+        ("010010", 9.5),
+        ("010011", 9.2),
+        ("010020", 9.2),
+        ("010021", 8.5),
+        ("010100", 9.5),
+        ("010101", 9.1),
+        ("010110", 9.0),
+        ("010111", 8.3),
+        ("010120", 8.4),
+        ("010121", 7.1),
+        ("010200", 9.2),
+        ("010201", 8.1),
+        ("010210", 8.2),
+        ("010211", 7.1),
+        ("010220", 7.2),
+        ("010221", 5.3),
+        ("011000", 9.5),
+        ("011001", 9.3),
+        ("011010", 9.2),
+        ("011011", 8.5),
+        ("011020", 8.5),
+        ("011021", 7.3),
+        ("011100", 9.2),
+        ("011101", 8.2),
+        ("011110", 8.0),
+        ("011111", 7.2),
+        ("011120", 7.0),
+        ("011121", 5.9),
+        ("011200", 8.4),
+        ("011201", 7.0),
+        ("011210", 7.1),
+        ("011211", 5.2),
+        ("011220", 5.0),
+        ("011221", 3.0),
+        ("012001", 8.6),
+        ("012011", 7.5),
+        ("012021", 5.2),
+        ("012101", 7.1),
+        ("012111", 5.2),
+        ("012121", 2.9),
+        ("012201", 6.3),
+        ("012211", 2.9),
+        ("012221", 1.7),
+        ("100000", 9.8),
+        ("100001", 9.5),
+        ("100010", 9.4),
+        ("100011", 8.7),
+        ("100020", 9.1),
+        ("100021", 8.1),
+        ("100100", 9.4),
+        ("100101", 8.9),
+        ("100110", 8.6),
+        ("100111", 7.4),
+        ("100120", 7.7),
+        ("100121", 6.4),
+        ("100200", 8.7),
+        ("100201", 7.5),
+        ("100210", 7.4),
+        ("100211", 6.3),
+        ("100220", 6.3),
+        ("100221", 4.9),
+        ("101000", 9.4),
+        ("101001", 8.9),
+        ("101010", 8.8),
+        ("101011", 7.7),
+        ("101020", 7.6),
+        ("101021", 6.7),
+        ("101100", 8.6),
+        ("101101", 7.6),
+        ("101110", 7.4),
+        ("101111", 5.8),
+        ("101120", 5.9),
+        ("101121", 5.0),
+        ("101200", 7.2),
+        ("101201", 5.7),
+        ("101210", 5.7),
+        ("101211", 5.2),
+        ("101220", 5.2),
+        ("101221", 2.5),
+        ("102001", 8.3),
+        ("102011", 7.0),
+        ("102021", 5.4),
+        ("102101", 6.5),
+        ("102111", 5.8),
+        ("102121", 2.6),
+        ("102201", 5.3),
+        ("102211", 2.1),
+        ("102221", 1.3),
+        ("110000", 9.5),
+        ("110001", 9.0),
+        ("110010", 8.8),
+        ("110011", 7.6),
+        ("110020", 7.6),
+        ("110021", 7.0),
+        ("110100", 9.0),
+        ("110101", 7.7),
+        ("110110", 7.5),
+        ("110111", 6.2),
+        ("110120", 6.1),
+        ("110121", 5.3),
+        ("110200", 7.7),
+        ("110201", 6.6),
+        ("110210", 6.8),
+        ("110211", 5.9),
+        ("110220", 5.2),
+        ("110221", 3.0),
+        ("111000", 8.9),
+        ("111001", 7.8),
+        ("111010", 7.6),
+        ("111011", 6.7),
+        ("111020", 6.2),
+        ("111021", 5.8),
+        ("111100", 7.4),
+        ("111101", 5.9),
+        ("111110", 5.7),
+        ("111111", 5.7),
+        ("111120", 4.7),
+        ("111121", 2.3),
+        ("111200", 6.1),
+        ("111201", 5.2),
+        ("111210", 5.7),
+        ("111211", 2.9),
+        ("111220", 2.4),
+        ("111221", 1.6),
+        ("112001", 7.1),
+        ("112011", 5.9),
+        ("112021", 3.0),
+        ("112101", 5.8),
+        ("112111", 2.6),
+        ("112121", 1.5),
+        ("112201", 2.3),
+        ("112211", 1.3),
+        ("112221", 0.6),
+        ("200000", 9.3),
+        ("200001", 8.7),
+        ("200010", 8.6),
+        ("200011", 7.2),
+        ("200020", 7.5),
+        ("200021", 5.8),
+        ("200100", 8.6),
+        ("200101", 7.4),
+        ("200110", 7.4),
+        ("200111", 6.1),
+        ("200120", 5.6),
+        ("200121", 3.4),
+        ("200200", 7.0),
+        ("200201", 5.4),
+        ("200210", 5.2),
+        ("200211", 4.0),
+        ("200220", 4.0),
+        ("200221", 2.2),
+        ("201000", 8.5),
+        ("201001", 7.5),
+        ("201010", 7.4),
+        ("201011", 5.5),
+        ("201020", 6.2),
+        ("201021", 5.1),
+        ("201100", 7.2),
+        ("201101", 5.7),
+        ("201110", 5.5),
+        ("201111", 4.1),
+        ("201120", 4.6),
+        ("201121", 1.9),
+        ("201200", 5.3),
+        ("201201", 3.6),
+        ("201210", 3.4),
+        ("201211", 1.9),
+        ("201220", 1.9),
+        ("201221", 0.8),
+        ("202001", 6.4),
+        ("202011", 5.1),
+        ("202021", 2.0),
+        ("202101", 4.7),
+        ("202111", 2.1),
+        ("202121", 1.1),
+        ("202201", 2.4),
+        ("202211", 0.9),
+        ("202221", 0.4),
+        ("210000", 8.8),
+        ("210001", 7.5),
+        ("210010", 7.3),
+        ("210011", 5.3),
+        ("210020", 6.0),
+        ("210021", 5.0),
+        ("210100", 7.3),
+        ("210101", 5.5),
+        ("210110", 5.9),
+        ("210111", 4.0),
+        ("210120", 4.1),
+        ("210121", 2.0),
+        ("210200", 5.4),
+        ("210201", 4.3),
+        ("210210", 4.5),
+        ("210211", 2.2),
+        ("210220", 2.0),
+        ("210221", 1.1),
+        ("211000", 7.5),
+        ("211001", 5.5),
+        ("211010", 5.8),
+        ("211011", 4.5),
+        ("211020", 4.0),
+        ("211021", 2.1),
+        ("211100", 6.1),
+        ("211101", 5.1),
+        ("211110", 4.8),
+        ("211111", 1.8),
+        ("211120", 2.0),
+        ("211121", 0.9),
+        ("211200", 4.6),
+        ("211201", 1.8),
+        ("211210", 1.7),
+        ("211211", 0.7),
+        ("211220", 0.8),
+        ("211221", 0.2),
+        ("212001", 5.3),
+        ("212011", 2.4),
+        ("212021", 1.4),
+        ("212101", 2.4),
+        ("212111", 1.2),
+        ("212121", 0.5),
+        ("212201", 1.0),
+        ("212211", 0.3),
+        ("212221", 0.1),
+    ])
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(vector: &str) -> f64 {
+        CvssV4::from_str(vector).unwrap().score()
+    }
+
+    #[test]
+    fn test_lookup_table_is_complete() {
+        assert_eq!(LOOKUP_TABLE.len(), 270);
+    }
+
+    #[test]
+    fn test_score() {
+        assert_eq!(
+            score("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:H/SI:H/SA:H"),
+            10.0
+        );
+        assert_eq!(
+            score("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N"),
+            9.3
+        );
+        assert_eq!(
+            score("CVSS:4.0/AV:L/AC:L/AT:N/PR:L/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N"),
+            8.5
+        );
+        assert_eq!(
+            score("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:L/VI:L/VA:N/SC:N/SI:N/SA:N"),
+            6.9
+        );
+        assert_eq!(
+            score("CVSS:4.0/AV:N/AC:L/AT:N/PR:H/UI:N/VC:L/VI:L/VA:N/SC:N/SI:N/SA:N"),
+            5.1
+        );
+        assert_eq!(
+            score("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:N/VI:N/VA:N/SC:N/SI:N/SA:N"),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_nomenclature() {
+        let cvss = CvssV4::from_str("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N").unwrap();
+        assert_eq!(cvss.nomenclature(), CvssV4Nomenclature::CvssB);
+        let cvss =
+            CvssV4::from_str("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/E:U/CR:L").unwrap();
+        assert_eq!(cvss.nomenclature(), CvssV4Nomenclature::CvssBTE);
+        assert_eq!(cvss.nomenclature().to_string(), "CVSS-BTE");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let vector = "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/E:P/MSI:S/U:Amber";
+        assert_eq!(CvssV4::from_str(vector).unwrap().to_string(), vector);
+    }
+}
@@ -0,0 +1,535 @@
+//! Semantic differences between two revisions of a CSAF document.
+//!
+//! Reviewers of a new revision of an advisory are interested in what changed for the
+//! vulnerabilities and products, not in the changed lines of the JSON. [diff] compares two
+//! documents with the same `/document/tracking/id` and reports the added revisions, the added and
+//! removed vulnerabilities and, for vulnerabilities in both documents, the products whose
+//! [ProductStatusGroup]s changed, the changed metrics and the new remediations.
+//!
+//! Vulnerabilities are matched by their CVE, by their first ID if they do not have a CVE and by
+//! their position otherwise.
+//!
+//! ```no_run
+//! # use csaf::CsafDocument;
+//! let old = CsafDocument::load(std::path::Path::new("advisory-3.json"))?;
+//! let new = CsafDocument::load(std::path::Path::new("advisory-4.json"))?;
+//! println!("{}", csaf::diff::diff(&old, &new)?);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+
+use serde::Serialize;
+
+use crate::CsafDocument;
+use crate::csaf::document::with_parsed;
+use crate::csaf::types::csaf_version_number::CsafVersionNumber;
+use crate::csaf_traits::{
+    ContentTrait, CsafTrait, DocumentTrait, MetricTrait, ProductStatusGroup, ProductStatusTrait, RemediationTrait,
+    RevisionTrait, TrackingTrait, VulnerabilityIdTrait, VulnerabilityTrait, WithDate,
+};
+
+/// Reasons why two documents cannot be compared.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DiffError {
+    #[error("the documents have different tracking IDs '{old}' and '{new}'")]
+    TrackingIdMismatch { old: String, new: String },
+    #[error("invalid document: {0}")]
+    InvalidDocument(String),
+}
+
+/// Differences between two revisions of a document, see the [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentDiff {
+    pub tracking_id: String,
+    pub old_version: String,
+    pub new_version: String,
+    /// Revision history items of the new document that are not in the old one
+    pub added_revisions: Vec<Revision>,
+    pub added_vulnerabilities: Vec<String>,
+    pub removed_vulnerabilities: Vec<String>,
+    /// Changes of the vulnerabilities in both documents, only listing changed vulnerabilities
+    pub changed_vulnerabilities: Vec<VulnerabilityDiff>,
+}
+
+/// An item of the revision history
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Revision {
+    pub number: String,
+    pub date: String,
+    pub summary: String,
+}
+
+/// Changes of a vulnerability, identified by its CVE or ID
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VulnerabilityDiff {
+    pub vulnerability: String,
+    pub product_status_changes: Vec<ProductStatusChange>,
+    pub metric_changes: Vec<MetricChange>,
+    pub added_remediations: Vec<Remediation>,
+}
+
+/// A product whose status groups changed, empty if the product is not listed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductStatusChange {
+    pub product_id: String,
+    pub old: Vec<ProductStatusGroup>,
+    pub new: Vec<ProductStatusGroup>,
+}
+
+/// A changed metric of a product, like its CVSS v3 vector
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricChange {
+    pub product_id: String,
+    /// The kind of metric, like `CVSS v3` or `EPSS`
+    pub metric: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+/// A remediation, with the product IDs of its product groups resolved
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Remediation {
+    pub category: String,
+    pub details: String,
+    pub url: Option<String>,
+    pub product_ids: BTreeSet<String>,
+}
+
+impl DocumentDiff {
+    /// Returns whether the documents have the same version and no semantic differences.
+    pub fn is_empty(&self) -> bool {
+        self.old_version == self.new_version
+            && self.added_revisions.is_empty()
+            && self.added_vulnerabilities.is_empty()
+            && self.removed_vulnerabilities.is_empty()
+            && self.changed_vulnerabilities.is_empty()
+    }
+}
+
+/// The parts of a vulnerability that are compared
+struct VulnerabilitySnapshot {
+    product_status: BTreeMap<String, BTreeSet<ProductStatusGroup>>,
+    /// Values of the metrics by product ID and kind of metric
+    metrics: BTreeMap<(String, &'static str), BTreeSet<String>>,
+    remediations: Vec<Remediation>,
+}
+
+/// Compares two revisions of a document.
+///
+/// Fails if the documents have different tracking IDs or do not match the types of their CSAF
+/// version.
+pub fn diff(old: &CsafDocument, new: &CsafDocument) -> Result<DocumentDiff, DiffError> {
+    let invalid = |err: &str| DiffError::InvalidDocument(err.to_string());
+    with_parsed!(old, old => with_parsed!(new, new => diff_parsed(old, new)))
+        .map_err(invalid)?
+        .map_err(invalid)?
+}
+
+/// Compares two revisions of a parsed document.
+///
+/// Fails if the documents have different tracking IDs.
+pub fn diff_parsed(old: &impl CsafTrait, new: &impl CsafTrait) -> Result<DocumentDiff, DiffError> {
+    let old_tracking = old.get_document().get_tracking();
+    let new_tracking = new.get_document().get_tracking();
+    if old_tracking.get_id() != new_tracking.get_id() {
+        return Err(DiffError::TrackingIdMismatch {
+            old: old_tracking.get_id().clone(),
+            new: new_tracking.get_id().clone(),
+        });
+    }
+
+    let old_revisions = revisions(old);
+    let added_revisions = revisions(new)
+        .into_iter()
+        .filter(|revision| {
+            !old_revisions
+                .iter()
+                .any(|old| old.number == revision.number && old.date == revision.date)
+        })
+        .collect();
+
+    let old_vulnerabilities = vulnerabilities(old);
+    let new_vulnerabilities = vulnerabilities(new);
+    let mut changed_vulnerabilities = Vec::new();
+    for (key, new_vulnerability) in &new_vulnerabilities {
+        if let Some(old_vulnerability) = old_vulnerabilities.get(key) {
+            let vulnerability_diff = diff_vulnerability(key, old_vulnerability, new_vulnerability);
+            if !vulnerability_diff.product_status_changes.is_empty()
+                || !vulnerability_diff.metric_changes.is_empty()
+                || !vulnerability_diff.added_remediations.is_empty()
+            {
+                changed_vulnerabilities.push(vulnerability_diff);
+            }
+        }
+    }
+
+    Ok(DocumentDiff {
+        tracking_id: new_tracking.get_id().clone(),
+        old_version: version_string(old_tracking.get_version()),
+        new_version: version_string(new_tracking.get_version()),
+        added_revisions,
+        added_vulnerabilities: new_vulnerabilities
+            .keys()
+            .filter(|key| !old_vulnerabilities.contains_key(*key))
+            .cloned()
+            .collect(),
+        removed_vulnerabilities: old_vulnerabilities
+            .keys()
+            .filter(|key| !new_vulnerabilities.contains_key(*key))
+            .cloned()
+            .collect(),
+        changed_vulnerabilities,
+    })
+}
+
+fn diff_vulnerability(key: &str, old: &VulnerabilitySnapshot, new: &VulnerabilitySnapshot) -> VulnerabilityDiff {
+    let product_ids: BTreeSet<&String> = old.product_status.keys().chain(new.product_status.keys()).collect();
+    let product_status_changes = product_ids
+        .into_iter()
+        .filter_map(|product_id| {
+            let old_groups = old.product_status.get(product_id);
+            let new_groups = new.product_status.get(product_id);
+            (old_groups != new_groups).then(|| ProductStatusChange {
+                product_id: product_id.clone(),
+                old: old_groups.into_iter().flatten().cloned().collect(),
+                new: new_groups.into_iter().flatten().cloned().collect(),
+            })
+        })
+        .collect();
+
+    let metric_keys: BTreeSet<&(String, &str)> = old.metrics.keys().chain(new.metrics.keys()).collect();
+    let join = |values: Option<&BTreeSet<String>>| values.map(|values| Vec::from_iter(values.clone()).join(", "));
+    let metric_changes = metric_keys
+        .into_iter()
+        .filter_map(|metric_key| {
+            let (old_value, new_value) = (join(old.metrics.get(metric_key)), join(new.metrics.get(metric_key)));
+            (old_value != new_value).then(|| MetricChange {
+                product_id: metric_key.0.clone(),
+                metric: metric_key.1.to_string(),
+                old: old_value,
+                new: new_value,
+            })
+        })
+        .collect();
+
+    VulnerabilityDiff {
+        vulnerability: key.to_string(),
+        product_status_changes,
+        metric_changes,
+        added_remediations: new
+            .remediations
+            .iter()
+            .filter(|remediation| !old.remediations.contains(remediation))
+            .cloned()
+            .collect(),
+    }
+}
+
+fn revisions(doc: &impl CsafTrait) -> Vec<Revision> {
+    doc.get_document()
+        .get_tracking()
+        .get_revision_history()
+        .iter()
+        .map(|revision| Revision {
+            number: version_string(revision.get_number()),
+            date: revision.get_date().get_raw_string().to_string(),
+            summary: revision.get_summary().clone(),
+        })
+        .collect()
+}
+
+/// Returns the version as written in the document, even if it is invalid.
+fn version_string(version: CsafVersionNumber) -> String {
+    match version {
+        CsafVersionNumber::Valid(version) => version.to_string(),
+        CsafVersionNumber::Invalid(err) => err.raw_string,
+    }
+}
+
+/// Returns the snapshots of the vulnerabilities by their key.
+fn vulnerabilities(doc: &impl CsafTrait) -> BTreeMap<String, VulnerabilitySnapshot> {
+    let mut snapshots = BTreeMap::new();
+    for (i, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        let key = vulnerability
+            .get_cve()
+            .cloned()
+            .or_else(|| {
+                vulnerability.get_ids().as_ref().and_then(|ids| {
+                    ids.first()
+                        .map(|id| format!("{} {}", id.get_system_name(), id.get_text()))
+                })
+            })
+            .unwrap_or_else(|| format!("#{i}"));
+        // Duplicate keys are invalid (6.1.23), the first vulnerability wins
+        snapshots.entry(key).or_insert_with(|| snapshot(doc, vulnerability));
+    }
+    snapshots
+}
+
+fn snapshot(doc: &impl CsafTrait, vulnerability: &impl VulnerabilityTrait) -> VulnerabilitySnapshot {
+    let mut product_status: BTreeMap<String, BTreeSet<ProductStatusGroup>> = BTreeMap::new();
+    if let Some(status) = vulnerability.get_product_status() {
+        for (group, product_ids) in status.get_all_by_product_status() {
+            for product_id in product_ids {
+                product_status
+                    .entry(product_id.clone())
+                    .or_default()
+                    .insert(group.clone());
+            }
+        }
+    }
+
+    let mut metrics: BTreeMap<(String, &'static str), BTreeSet<String>> = BTreeMap::new();
+    for metric in vulnerability.get_metrics().into_iter().flatten() {
+        let content = metric.get_content();
+        let vector = |cvss: Option<&serde_json::Map<String, serde_json::Value>>| {
+            cvss.and_then(|cvss| cvss.get("vectorString")?.as_str().map(str::to_string))
+        };
+        let values = [
+            ("CVSS v2", vector(content.get_cvss_v2())),
+            ("CVSS v3", vector(content.get_cvss_v3())),
+            ("CVSS v4", vector(content.get_cvss_v4())),
+            (
+                "EPSS",
+                content
+                    .get_epss()
+                    .as_ref()
+                    .map(|epss| format!("probability {}, percentile {}", *epss.probability, *epss.percentile)),
+            ),
+            (
+                "SSVC",
+                content
+                    .has_ssvc()
+                    .then(|| content.get_ssvc().ok())
+                    .flatten()
+                    .and_then(|ssvc| serde_json::to_string(&ssvc).ok()),
+            ),
+        ];
+        for product_id in metric.get_products() {
+            for (kind, value) in &values {
+                if let Some(value) = value {
+                    metrics
+                        .entry((product_id.clone(), *kind))
+                        .or_default()
+                        .insert(value.clone());
+                }
+            }
+        }
+    }
+
+    let remediations = vulnerability
+        .get_remediations()
+        .iter()
+        .map(|remediation| Remediation {
+            category: remediation.get_category().to_string(),
+            details: remediation.get_details().clone(),
+            url: remediation.get_url().cloned(),
+            product_ids: remediation.get_all_product_ids(doc).unwrap_or_default(),
+        })
+        .collect();
+
+    VulnerabilitySnapshot {
+        product_status,
+        metrics,
+        remediations,
+    }
+}
+
+impl Display for DocumentDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Changes of {} from version {} to {}",
+            self.tracking_id, self.old_version, self.new_version
+        )?;
+        if self.is_empty() {
+            return writeln!(f, "No changes");
+        }
+        for revision in &self.added_revisions {
+            writeln!(
+                f,
+                "+ revision {} ({}): {}",
+                revision.number, revision.date, revision.summary
+            )?;
+        }
+        for vulnerability in &self.added_vulnerabilities {
+            writeln!(f, "+ vulnerability {vulnerability}")?;
+        }
+        for vulnerability in &self.removed_vulnerabilities {
+            writeln!(f, "- vulnerability {vulnerability}")?;
+        }
+        let groups = |groups: &[ProductStatusGroup]| match groups {
+            [] => "not listed".to_string(),
+            groups => groups.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "),
+        };
+        for vulnerability in &self.changed_vulnerabilities {
+            writeln!(f, "~ vulnerability {}", vulnerability.vulnerability)?;
+            for change in &vulnerability.product_status_changes {
+                writeln!(
+                    f,
+                    "    {}: {} -> {}",
+                    change.product_id,
+                    groups(&change.old),
+                    groups(&change.new)
+                )?;
+            }
+            for change in &vulnerability.metric_changes {
+                writeln!(
+                    f,
+                    "    {} of {}: {} -> {}",
+                    change.metric,
+                    change.product_id,
+                    change.old.as_deref().unwrap_or("none"),
+                    change.new.as_deref().unwrap_or("none")
+                )?;
+            }
+            for remediation in &vulnerability.added_remediations {
+                let product_ids = Vec::from_iter(remediation.product_ids.iter().map(String::as_str)).join(", ");
+                writeln!(
+                    f,
+                    "    + remediation {} for {product_ids}: {}",
+                    remediation.category, remediation.details
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf::document::DocumentSource;
+    use crate::test_documents::{csaf_2_1, document, json_2_0};
+    use serde_json::{Value, json};
+
+    fn advisory(
+        version: &str,
+        vulnerabilities: Value,
+    ) -> crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework {
+        let revision = |number: &str, date: &str| json!({ "date": date, "number": number, "summary": format!("Version {number}.") });
+        let revision_history: Vec<Value> = (1..=version.parse::<u32>().unwrap())
+            .map(|number| {
+                revision(
+                    &number.to_string(),
+                    &format!("2024-01-{:02}T10:00:00.000Z", 20 + number),
+                )
+            })
+            .collect();
+        csaf_2_1(json!({
+            "document": { "tracking": { "revision_history": revision_history, "version": version } },
+            "product_tree": {
+                "full_product_names": [
+                    { "name": "Product A 1.0", "product_id": "CSAFPID-0001" },
+                    { "name": "Product A 1.1", "product_id": "CSAFPID-0002" }
+                ]
+            },
+            "vulnerabilities": vulnerabilities
+        }))
+    }
+
+    fn cvss_v3(vector: &str, score: f64) -> Value {
+        json!({ "cvss_v3": { "version": "3.1", "vectorString": vector, "baseScore": score, "baseSeverity": "HIGH" } })
+    }
+
+    #[test]
+    fn test_diff() {
+        let old = advisory(
+            "3",
+            json!([
+                {
+                    "cve": "CVE-2024-0001",
+                    "product_status": { "known_affected": ["CSAFPID-0001"], "under_investigation": ["CSAFPID-0002"] },
+                    "metrics": [{ "content": cvss_v3("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H", 9.8), "products": ["CSAFPID-0001"] }]
+                },
+                { "cve": "CVE-2024-0002", "product_status": { "known_affected": ["CSAFPID-0001"] } }
+            ]),
+        );
+        let new = advisory(
+            "4",
+            json!([
+                {
+                    "cve": "CVE-2024-0001",
+                    "product_status": { "known_affected": ["CSAFPID-0001"], "fixed": ["CSAFPID-0002"] },
+                    "metrics": [{ "content": cvss_v3("CVSS:3.1/AV:N/AC:H/PR:N/UI:N/S:U/C:H/I:H/A:H", 8.1), "products": ["CSAFPID-0001"] }],
+                    "remediations": [{ "category": "vendor_fix", "details": "Update to 1.1.", "product_ids": ["CSAFPID-0001"] }]
+                },
+                { "ids": [{ "system_name": "Example Tracker", "text": "EX-42" }], "product_status": { "known_affected": ["CSAFPID-0001"] } }
+            ]),
+        );
+
+        let document_diff = diff_parsed(&old, &new).unwrap();
+        assert_eq!(document_diff.old_version, "3");
+        assert_eq!(document_diff.new_version, "4");
+        assert_eq!(
+            document_diff.added_revisions,
+            vec![Revision {
+                number: "4".to_string(),
+                date: "2024-01-24T10:00:00.000Z".to_string(),
+                summary: "Version 4.".to_string(),
+            }]
+        );
+        assert_eq!(document_diff.added_vulnerabilities, vec!["Example Tracker EX-42"]);
+        assert_eq!(document_diff.removed_vulnerabilities, vec!["CVE-2024-0002"]);
+
+        let [vulnerability_diff] = document_diff.changed_vulnerabilities.as_slice() else {
+            panic!("expected one changed vulnerability");
+        };
+        assert_eq!(
+            vulnerability_diff.product_status_changes,
+            vec![ProductStatusChange {
+                product_id: "CSAFPID-0002".to_string(),
+                old: vec![ProductStatusGroup::UnderInvestigation],
+                new: vec![ProductStatusGroup::Fixed],
+            }]
+        );
+        assert_eq!(
+            vulnerability_diff.metric_changes,
+            vec![MetricChange {
+                product_id: "CSAFPID-0001".to_string(),
+                metric: "CVSS v3".to_string(),
+                old: Some("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H".to_string()),
+                new: Some("CVSS:3.1/AV:N/AC:H/PR:N/UI:N/S:U/C:H/I:H/A:H".to_string()),
+            }]
+        );
+        assert_eq!(vulnerability_diff.added_remediations[0].category, "vendor_fix");
+
+        let text = document_diff.to_string();
+        assert!(text.contains("    CSAFPID-0002: under investigation -> fixed\n"));
+        assert!(text.contains("- vulnerability CVE-2024-0002\n"));
+
+        assert!(diff_parsed(&new, &new).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_tracking_id_mismatch() {
+        let old = advisory("1", json!([{ "cve": "CVE-2024-0001" }]));
+        let mut new = old.clone();
+        new.document.tracking.id = "OTHER".parse().unwrap();
+        assert!(matches!(
+            diff_parsed(&old, &new),
+            Err(DiffError::TrackingIdMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_diff_documents() {
+        let json = json_2_0(json!({ "vulnerabilities": [{ "cve": "CVE-2024-0001" }] }));
+        let doc = document(&json);
+        assert!(diff(&doc, &doc).unwrap().is_empty());
+        // The TLP label WHITE does not exist in CSAF 2.1
+        let as_2_1 = CsafDocument::load_as(DocumentSource::Str(&json.to_string()), "2.1").unwrap();
+        assert!(matches!(diff(&doc, &as_2_1), Err(DiffError::InvalidDocument(_))));
+
+        let other = document(&json_2_0(json!({ "document": { "tracking": { "id": "OTHER" } } })));
+        assert!(matches!(diff(&doc, &other), Err(DiffError::TrackingIdMismatch { .. })));
+    }
+}
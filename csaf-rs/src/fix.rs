@@ -0,0 +1,570 @@
+//! Fixes for findings with one obvious repair, like an unsorted revision history.
+//!
+//! A [Fix] is a list of JSON Patch (RFC 6902) operations on the JSON of a document
+//! ([crate::csaf::raw::RawDocument::get_json]). Tests provide fixes through the fix function of their
+//! [crate::test_registry::TestDefinition], which is called with the same document as the test and
+//! returns a fix for each finding it can repair. Besides fixes for findings, the document is
+//! normalized by fixes without a test, like writing hash algorithm names in lower case.
+//!
+//! [fix] applies all fixes of a set of tests to a [CsafDocument] and returns the fixed document.
+//!
+//! ```
+//! # use csaf::fix::{PatchOperation, apply_patch};
+//! # use serde_json::json;
+//! let mut json = json!({"document": {"tracking": {"version": "1"}}});
+//! let patch = [PatchOperation::Replace {
+//!     path: "/document/tracking/version".to_string(),
+//!     value: json!("2"),
+//! }];
+//! apply_patch(&mut json, &patch)?;
+//! assert_eq!(json, json!({"document": {"tracking": {"version": "2"}}}));
+//! # Ok::<(), csaf::fix::PatchError>(())
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::CsafDocument;
+use crate::csaf::document::with_parsed;
+use crate::csaf_traits::{
+    CsafTrait, FileHashTrait, HashTrait, ProductIdentificationHelperTrait, ProductTrait, ProductTreeTrait,
+};
+use crate::test_registry;
+
+/// Function returning the fixes for the findings of a test in a document, given as parsed document
+/// and as JSON
+pub type FixFn<D> = fn(&D, &Value) -> Vec<Fix>;
+
+/// Maximum number of times [fix] fixes the findings of all tests, as fixing a finding of one test can
+/// lead to a finding of another test
+const MAX_FIX_PASSES: usize = 5;
+
+/// A JSON Patch operation, see RFC 6902
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum PatchOperation {
+    Add { path: String, value: Value },
+    Remove { path: String },
+    Replace { path: String, value: Value },
+    Move { from: String, path: String },
+    Copy { from: String, path: String },
+    Test { path: String, value: Value },
+}
+
+/// Reasons why a JSON Patch cannot be applied.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PatchError {
+    #[error("invalid JSON pointer {0}")]
+    InvalidPointer(String),
+    #[error("no value at {0}")]
+    NotFound(String),
+    #[error("value at {0} differs from the tested value")]
+    TestFailed(String),
+}
+
+/// A repair of a finding, see the [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Fix {
+    /// The test whose finding is fixed, `None` for fixes normalizing the document
+    pub test_id: Option<String>,
+    /// Description of the change
+    pub message: String,
+    /// JSON path of the value that is changed
+    pub instance_path: String,
+    /// The operations applied to the JSON of the document
+    pub patch: Vec<PatchOperation>,
+}
+
+impl Fix {
+    pub fn new(message: impl Into<String>, instance_path: impl Into<String>, patch: Vec<PatchOperation>) -> Self {
+        Self {
+            test_id: None,
+            message: message.into(),
+            instance_path: instance_path.into(),
+            patch,
+        }
+    }
+
+    /// Creates a fix replacing the value at `path`.
+    pub fn replace(message: impl Into<String>, path: &str, value: Value) -> Self {
+        Self::new(
+            message,
+            path,
+            vec![PatchOperation::Replace {
+                path: path.to_string(),
+                value,
+            }],
+        )
+    }
+}
+
+/// Returns the fixes for the findings of the given tests in a document.
+///
+/// The fixes normalizing the document come first. Each test's fixes apply to the given document, so
+/// fixes of different tests may conflict; use [fix] to apply them.
+pub fn fixes(document: &CsafDocument, test_ids: &[&str]) -> Vec<Fix> {
+    let mut fixes = fixes_of(document, None);
+    for test_id in test_ids {
+        fixes.extend(fixes_of(document, Some(test_id)));
+    }
+    fixes
+}
+
+/// Applies the fixes for the findings of the given tests and returns the fixed document with the
+/// applied fixes.
+///
+/// The fixes of each test are applied to the document fixed by the previous tests. This is repeated
+/// as long as there are fixes, at most [MAX_FIX_PASSES] times.
+pub fn fix(document: &CsafDocument, test_ids: &[&str]) -> Result<(CsafDocument, Vec<Fix>), PatchError> {
+    let mut doc = document.clone();
+    let mut applied = Vec::new();
+    for _ in 0..MAX_FIX_PASSES {
+        let applied_before = applied.len();
+        for test_id in std::iter::once(None).chain(test_ids.iter().copied().map(Some)) {
+            let fixes = fixes_of(&doc, test_id);
+            if fixes.is_empty() {
+                continue;
+            }
+            let mut json = doc.get_json().clone();
+            for fix in &fixes {
+                apply_patch(&mut json, &fix.patch)?;
+            }
+            doc = doc.with_json(json);
+            applied.extend(fixes);
+        }
+        if applied.len() == applied_before {
+            break;
+        }
+    }
+    Ok((doc, applied))
+}
+
+/// Returns the fixes for the findings of a test, or the fixes normalizing the document for `None`.
+fn fixes_of(document: &CsafDocument, test_id: Option<&str>) -> Vec<Fix> {
+    match (document, test_id) {
+        (_, None) => with_parsed!(document, doc => fix_hash_algorithm_case(doc)).unwrap_or_default(),
+        (CsafDocument::V2_0(raw), Some(test_id)) => test_registry::csaf_2_0().fix(raw, test_id),
+        (CsafDocument::V2_1(raw), Some(test_id)) => test_registry::csaf_2_1().fix(raw, test_id),
+    }
+}
+
+/// Applies the operations of a JSON Patch in order.
+///
+/// The patch is applied atomically, if an operation fails the JSON is left unchanged.
+pub fn apply_patch(json: &mut Value, patch: &[PatchOperation]) -> Result<(), PatchError> {
+    let mut patched = json.clone();
+    for operation in patch {
+        match operation {
+            PatchOperation::Add { path, value } => add(&mut patched, path, value.clone())?,
+            PatchOperation::Remove { path } => {
+                remove(&mut patched, path)?;
+            },
+            PatchOperation::Replace { path, value } => {
+                *pointer_mut(&mut patched, path)? = value.clone();
+            },
+            PatchOperation::Move { from, path } => {
+                let value = remove(&mut patched, from)?;
+                add(&mut patched, path, value)?;
+            },
+            PatchOperation::Copy { from, path } => {
+                let value = patched.pointer(from).cloned().ok_or_else(|| not_found(from))?;
+                add(&mut patched, path, value)?;
+            },
+            PatchOperation::Test { path, value } => {
+                if patched.pointer(path) != Some(value) {
+                    return Err(PatchError::TestFailed(path.clone()));
+                }
+            },
+        }
+    }
+    *json = patched;
+    Ok(())
+}
+
+/// Escapes a property name for use in a JSON pointer.
+pub fn escape_pointer_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+fn not_found(path: &str) -> PatchError {
+    PatchError::NotFound(path.to_string())
+}
+
+fn pointer_mut<'a>(json: &'a mut Value, path: &str) -> Result<&'a mut Value, PatchError> {
+    json.pointer_mut(path).ok_or_else(|| not_found(path))
+}
+
+/// Splits a JSON pointer into the pointer of the parent and the unescaped last token.
+fn split_pointer(path: &str) -> Result<(&str, String), PatchError> {
+    let (parent, token) = path
+        .rsplit_once('/')
+        .ok_or_else(|| PatchError::InvalidPointer(path.to_string()))?;
+    Ok((parent, token.replace("~1", "/").replace("~0", "~")))
+}
+
+/// Returns the array index a token refers to, for tokens consisting of digits only.
+fn array_index(token: &str, path: &str) -> Result<usize, PatchError> {
+    if token.is_empty() || !token.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(PatchError::InvalidPointer(path.to_string()));
+    }
+    token.parse().map_err(|_| PatchError::InvalidPointer(path.to_string()))
+}
+
+fn add(json: &mut Value, path: &str, value: Value) -> Result<(), PatchError> {
+    if path.is_empty() {
+        *json = value;
+        return Ok(());
+    }
+    let (parent, token) = split_pointer(path)?;
+    match pointer_mut(json, parent)? {
+        Value::Object(object) => {
+            object.insert(token, value);
+        },
+        Value::Array(array) if token == "-" => array.push(value),
+        Value::Array(array) => {
+            let index = array_index(&token, path)?;
+            if index > array.len() {
+                return Err(not_found(path));
+            }
+            array.insert(index, value);
+        },
+        _ => return Err(not_found(path)),
+    }
+    Ok(())
+}
+
+fn remove(json: &mut Value, path: &str) -> Result<Value, PatchError> {
+    let (parent, token) = split_pointer(path)?;
+    match pointer_mut(json, parent)? {
+        // Removing with `shift_remove` keeps the order of the other properties
+        Value::Object(object) => object.shift_remove(&token).ok_or_else(|| not_found(path)),
+        Value::Array(array) => {
+            let index = array_index(&token, path)?;
+            if index >= array.len() {
+                return Err(not_found(path));
+            }
+            Ok(array.remove(index))
+        },
+        _ => Err(not_found(path)),
+    }
+}
+
+/// Writes hash algorithm names in lower case, as output by OpenSSL.
+pub fn fix_hash_algorithm_case(doc: &impl CsafTrait) -> Vec<Fix> {
+    let mut fixes = Vec::new();
+    if let Some(product_tree) = doc.get_product_tree() {
+        product_tree.visit_all_products(&mut |product, path| {
+            let Some(helper) = product.get_product_identification_helper() else {
+                return;
+            };
+            for (hash_i, hash) in helper.get_hashes().iter().enumerate() {
+                for (file_hash_i, file_hash) in hash.get_file_hashes().iter().enumerate() {
+                    let algorithm = file_hash.get_algorithm();
+                    if !algorithm.is_lowercase() {
+                        let lowercase = algorithm.convert_to_lowercase().to_string();
+                        fixes.push(Fix::replace(
+                            format!("Write hash algorithm '{algorithm}' in lower case"),
+                            &format!(
+                                "{path}/product_identification_helper/hashes/{hash_i}/file_hashes/{file_hash_i}/algorithm"
+                            ),
+                            Value::String(lowercase),
+                        ));
+                    }
+                }
+            }
+        });
+    }
+    fixes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework;
+    use crate::test_documents::{csaf_2_0, csaf_2_1, document, json_2_0, json_2_1};
+    use crate::validation::{TestResultStatus, ValidationPreset};
+    use crate::validations::test_6_1_21::fix_6_1_21_missing_item_in_revision_history;
+    use crate::validations::test_6_2_01::fix_6_2_01_unused_definition_of_product_id;
+    use serde_json::json;
+
+    fn patch(patch: Value) -> Vec<PatchOperation> {
+        serde_json::from_value(patch).unwrap()
+    }
+
+    #[test]
+    fn test_apply_patch() {
+        // Operations as in the examples of RFC 6902, appendix A
+        let mut json = json!({"foo": ["bar", "baz"], "biscuits": [{"name": "Digestive"}, {"name": "Choco Leibniz"}]});
+        apply_patch(
+            &mut json,
+            &patch(json!([
+                {"op": "add", "path": "/foo/1", "value": "qux"},
+                {"op": "remove", "path": "/foo/0"},
+                {"op": "replace", "path": "/biscuits/0/name", "value": "Ginger Nut"},
+                {"op": "move", "from": "/biscuits/1", "path": "/biscuits/-"},
+                {"op": "copy", "from": "/biscuits/0", "path": "/best_biscuit"},
+                {"op": "add", "path": "/a~1b", "value": 1},
+                {"op": "test", "path": "/foo", "value": ["qux", "baz"]}
+            ])),
+        )
+        .unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "foo": ["qux", "baz"],
+                "biscuits": [{"name": "Ginger Nut"}, {"name": "Choco Leibniz"}],
+                "best_biscuit": {"name": "Ginger Nut"},
+                "a/b": 1
+            })
+        );
+
+        let original = json.clone();
+        let failing = patch(json!([
+            {"op": "remove", "path": "/foo"},
+            {"op": "test", "path": "/foo", "value": ["qux", "baz"]}
+        ]));
+        assert_eq!(
+            apply_patch(&mut json, &failing),
+            Err(PatchError::TestFailed("/foo".to_string()))
+        );
+        assert_eq!(json, original);
+        assert_eq!(
+            apply_patch(&mut json, &patch(json!([{"op": "remove", "path": "/foo/5"}]))),
+            Err(PatchError::NotFound("/foo/5".to_string()))
+        );
+        assert_eq!(
+            apply_patch(&mut json, &patch(json!([{"op": "remove", "path": "foo"}]))),
+            Err(PatchError::InvalidPointer("foo".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_remove_keeps_order() {
+        let mut json = json!({"a": 1, "b": 2, "c": 3});
+        apply_patch(&mut json, &patch(json!([{"op": "remove", "path": "/a"}]))).unwrap();
+        assert_eq!(serde_json::to_string(&json).unwrap(), r#"{"b":2,"c":3}"#);
+    }
+
+    #[test]
+    fn test_fix_hash_algorithm_case() {
+        let doc = csaf_2_0(json!({
+            "product_tree": {
+                "full_product_names": [{
+                    "name": "Product",
+                    "product_id": "CSAFPID-0001",
+                    "product_identification_helper": {
+                        "hashes": [{
+                            "filename": "product.zip",
+                            "file_hashes": [
+                                { "algorithm": "sha256", "value": "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08" },
+                                { "algorithm": "SHA512", "value": "ee26b0dd4af7e749aa1a8ee3c10ae9923f618980772e473f8819a5d4940e0db27ac185f8a0e1d5f84f88bc887fd67b143732c304cc5fa9ad8e6f57f50028a8ff" }
+                            ]
+                        }]
+                    }
+                }]
+            }
+        }));
+        assert_eq!(
+            fix_hash_algorithm_case(&doc),
+            vec![Fix::replace(
+                "Write hash algorithm 'SHA512' in lower case",
+                "/product_tree/full_product_names/0/product_identification_helper/hashes/0/file_hashes/1/algorithm",
+                json!("sha512")
+            )]
+        );
+    }
+
+    #[test]
+    fn test_fix_6_1_21() {
+        let revision = |date: &str, number: &str| json!({ "date": date, "number": number, "summary": "Revision." });
+        let doc = csaf_2_1(json!({
+            "document": { "tracking": { "revision_history": [
+                revision("2024-01-26T10:00:00.000Z", "5"),
+                revision("2024-01-24T10:00:00.000Z", "1"),
+                revision("2024-01-25T10:00:00.000Z", "3")
+            ] } }
+        }));
+        let fixes = fix_6_1_21_missing_item_in_revision_history(&doc, &Value::Null);
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(
+            fixes[0].patch,
+            vec![
+                PatchOperation::Replace {
+                    path: "/document/tracking/revision_history/2/number".to_string(),
+                    value: json!("2"),
+                },
+                PatchOperation::Replace {
+                    path: "/document/tracking/revision_history/0/number".to_string(),
+                    value: json!("3"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fix_6_2_01() {
+        let product = |id: &str| json!({ "name": id, "product_id": id });
+        let json = json_2_1(json!({
+            "product_tree": {
+                "full_product_names": [product("CSAFPID-0001"), product("CSAFPID-0002"), product("CSAFPID-0003")],
+                "relationships": [{
+                    "category": "installed_on",
+                    "full_product_name": product("CSAFPID-0004"),
+                    "product_reference": "CSAFPID-0001",
+                    "relates_to_product_reference": "CSAFPID-0003"
+                }]
+            },
+            "vulnerabilities": [{ "product_status": { "known_affected": ["CSAFPID-0003"] } }]
+        }));
+        let doc: CommonSecurityAdvisoryFramework = serde_json::from_value(json.clone()).unwrap();
+        let fixes = fix_6_2_01_unused_definition_of_product_id(&doc, &json);
+        let patches: Vec<&Vec<PatchOperation>> = fixes.iter().map(|fix| &fix.patch).collect();
+        assert_eq!(
+            patches,
+            vec![
+                &vec![PatchOperation::Remove {
+                    path: "/product_tree/full_product_names/1".to_string()
+                }],
+                &vec![PatchOperation::Remove {
+                    path: "/product_tree/relationships".to_string()
+                }],
+            ]
+        );
+
+        // Without any used product, the product tree is removed
+        let json = json_2_1(json!({ "product_tree": { "full_product_names": [product("CSAFPID-0001")] } }));
+        let doc: CommonSecurityAdvisoryFramework = serde_json::from_value(json.clone()).unwrap();
+        let fixes = fix_6_2_01_unused_definition_of_product_id(&doc, &json);
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(fixes[0].instance_path, "/product_tree");
+    }
+
+    #[test]
+    fn test_fix() {
+        let doc = document(&json_2_0(json!({
+            "document": { "tracking": { "revision_history": [
+                { "date": "2024-01-25T10:00:00.000Z", "number": "1", "summary": "Update." },
+                { "date": "2024-01-24T10:00:00.000Z", "number": "2", "summary": "Initial version." }
+            ] } }
+        })));
+        let test_ids = ["6.1.14", "6.1.16"];
+        assert!(!doc.validate_tests(ValidationPreset::Basic, &test_ids).success);
+        // The version matches the latest revision until 6.1.14 renumbers the revision history
+        assert_eq!(fixes(&doc, &test_ids).len(), 1);
+
+        let (fixed, applied) = fix(&doc, &test_ids).unwrap();
+        let fixed_tests: Vec<Option<&str>> = applied.iter().map(|fix| fix.test_id.as_deref()).collect();
+        assert_eq!(fixed_tests, vec![Some("6.1.14"), Some("6.1.16")]);
+        assert_eq!(fixed.version(), "2.0");
+        assert_eq!(fixed.get_json()["document"]["tracking"]["version"], "2");
+        assert_eq!(
+            fixed.get_json()["document"]["tracking"]["revision_history"][1]["summary"],
+            "Update."
+        );
+        assert!(fixed.validate_tests(ValidationPreset::Basic, &test_ids).success);
+        assert!(fixes(&fixed, &test_ids).is_empty());
+    }
+
+    /// Applies the fixes of a test and asserts that the document is schema valid afterwards and that
+    /// the test and 6.1.1 (missing definition of product IDs) pass.
+    fn assert_fixed(json: Value, test_id: &str) -> CsafDocument {
+        let doc = document(&json);
+        let failed = |doc: &CsafDocument| {
+            let result = doc.validate_tests(ValidationPreset::Full, &["schema", "6.1.1", test_id]);
+            result
+                .test_results
+                .into_iter()
+                .filter(|test_result| test_result.status != TestResultStatus::Success)
+                .collect::<Vec<_>>()
+        };
+        assert!(!failed(&doc).is_empty(), "{test_id} does not fail before fixing");
+
+        let (fixed, applied) = fix(&doc, &[test_id]).unwrap();
+        assert!(!applied.is_empty());
+        let failed = failed(&fixed);
+        assert!(failed.is_empty(), "{test_id} fails after fixing: {failed:?}");
+        fixed
+    }
+
+    #[test]
+    fn test_fixes_revalidate() {
+        let revision = |date: &str, number: &str| json!({ "date": date, "number": number, "summary": "Revision." });
+        let fixed = assert_fixed(
+            json_2_1(json!({ "document": { "tracking": { "revision_history": [
+                revision("2024-01-24T10:00:00.000Z", "1"),
+                revision("2024-01-25T10:00:00.000Z", "2")
+            ] } } })),
+            "6.1.16",
+        );
+        assert_eq!(fixed.get_json()["document"]["tracking"]["version"], "2");
+
+        assert_fixed(
+            json_2_1(json!({ "document": { "tracking": { "revision_history": [
+                revision("2024-01-26T10:00:00.000Z", "5"),
+                revision("2024-01-24T10:00:00.000Z", "1"),
+                revision("2024-01-25T10:00:00.000Z", "3")
+            ], "version": "5" } } })),
+            "6.1.21",
+        );
+
+        let fixed = assert_fixed(
+            json_2_1(json!({ "document": { "tracking": {
+                "revision_history": [revision("2024-01-24T10:00:00.000Z", "1.0.0+build.1")],
+                "version": "1.0.0"
+            } } })),
+            "6.2.4",
+        );
+        assert_eq!(
+            fixed.get_json()["document"]["tracking"]["revision_history"][0]["number"],
+            "1.0.0"
+        );
+    }
+
+    #[test]
+    fn test_fix_6_2_01_revalidates() {
+        let product = |id: &str| json!({ "name": id, "product_id": id });
+        let relationship = |id: &str, reference: &str, relates_to: &str| {
+            json!({
+                "category": "installed_on",
+                "full_product_name": product(id),
+                "product_reference": reference,
+                "relates_to_product_reference": relates_to
+            })
+        };
+        // CSAFPID-0002 and the relationship CSAFPID-0004 are unused, CSAFPID-0001 is only used by the
+        // relationship CSAFPID-0004 and becomes unused when it is removed
+        let fixed = assert_fixed(
+            json_2_1(json!({
+                "product_tree": {
+                    "full_product_names": [product("CSAFPID-0001"), product("CSAFPID-0002"), product("CSAFPID-0003")],
+                    "relationships": [
+                        relationship("CSAFPID-0004", "CSAFPID-0001", "CSAFPID-0003"),
+                        relationship("CSAFPID-0005", "CSAFPID-0003", "CSAFPID-0003")
+                    ]
+                },
+                "vulnerabilities": [{ "product_status": { "known_affected": ["CSAFPID-0003", "CSAFPID-0005"] } }]
+            })),
+            "6.2.1",
+        );
+        assert_eq!(
+            fixed.get_json()["product_tree"],
+            json!({
+                "full_product_names": [product("CSAFPID-0003")],
+                "relationships": [relationship("CSAFPID-0005", "CSAFPID-0003", "CSAFPID-0003")]
+            })
+        );
+
+        // Without any used product, the product tree is removed
+        let fixed = assert_fixed(
+            json_2_1(json!({
+                "product_tree": {
+                    "full_product_names": [product("CSAFPID-0001"), product("CSAFPID-0002")],
+                    "relationships": [relationship("CSAFPID-0003", "CSAFPID-0001", "CSAFPID-0002")]
+                }
+            })),
+            "6.2.1",
+        );
+        assert_eq!(fixed.get_json().get("product_tree"), None);
+    }
+}
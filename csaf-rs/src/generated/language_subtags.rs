@@ -4,6 +4,7 @@
  * This file is automatically generated by build.rs.
  * Do not edit manually!
  */
+/// Registered language subtags, lower cased and sorted
 pub static LANGUAGE_SUBTAGS_ARRAY: &[&str] = &[
     "aa",
     "aaa",
@@ -5720,7 +5721,6 @@ pub static LANGUAGE_SUBTAGS_ARRAY: &[&str] = &[
     "pze",
     "pzh",
     "pzn",
-    "qaa..qtz",
     "qu",
     "qua",
     "qub",
@@ -8274,9 +8274,988 @@ pub static LANGUAGE_SUBTAGS_ARRAY: &[&str] = &[
     "zza",
     "zzj",
 ];
+/// Registered extlang subtags, lower cased and sorted
+pub static EXTLANG_SUBTAGS_ARRAY: &[&str] = &[
+    "aao",
+    "abh",
+    "abv",
+    "acm",
+    "acq",
+    "acw",
+    "acx",
+    "acy",
+    "adf",
+    "ads",
+    "aeb",
+    "aec",
+    "aed",
+    "aen",
+    "afb",
+    "afg",
+    "ajp",
+    "ajs",
+    "apc",
+    "apd",
+    "arb",
+    "arq",
+    "ars",
+    "ary",
+    "arz",
+    "ase",
+    "asf",
+    "asp",
+    "asq",
+    "asw",
+    "auz",
+    "avl",
+    "ayh",
+    "ayl",
+    "ayn",
+    "ayp",
+    "bbz",
+    "bfi",
+    "bfk",
+    "bjn",
+    "bog",
+    "bqn",
+    "bqy",
+    "btj",
+    "bve",
+    "bvl",
+    "bvu",
+    "bzs",
+    "cdo",
+    "cds",
+    "cjy",
+    "cmn",
+    "cnp",
+    "coa",
+    "cpx",
+    "csc",
+    "csd",
+    "cse",
+    "csf",
+    "csg",
+    "csl",
+    "csn",
+    "csp",
+    "csq",
+    "csr",
+    "csx",
+    "czh",
+    "czo",
+    "doq",
+    "dse",
+    "dsl",
+    "dsz",
+    "dup",
+    "ecs",
+    "ehs",
+    "esl",
+    "esn",
+    "eso",
+    "eth",
+    "fcs",
+    "fse",
+    "fsl",
+    "fss",
+    "gan",
+    "gds",
+    "gom",
+    "gse",
+    "gsg",
+    "gsm",
+    "gss",
+    "gus",
+    "hab",
+    "haf",
+    "hak",
+    "hds",
+    "hji",
+    "hks",
+    "hnm",
+    "hos",
+    "hps",
+    "hsh",
+    "hsl",
+    "hsn",
+    "icl",
+    "iks",
+    "ils",
+    "inl",
+    "ins",
+    "ise",
+    "isg",
+    "isr",
+    "jak",
+    "jax",
+    "jcs",
+    "jhs",
+    "jks",
+    "jls",
+    "jos",
+    "jsl",
+    "jus",
+    "kgi",
+    "knn",
+    "kvb",
+    "kvk",
+    "kvr",
+    "kxd",
+    "lbs",
+    "lce",
+    "lcf",
+    "lgs",
+    "liw",
+    "lls",
+    "lsb",
+    "lsc",
+    "lsg",
+    "lsl",
+    "lsn",
+    "lso",
+    "lsp",
+    "lst",
+    "lsv",
+    "lsw",
+    "lsy",
+    "ltg",
+    "luh",
+    "lvs",
+    "lws",
+    "lzh",
+    "max",
+    "mdl",
+    "meo",
+    "mfa",
+    "mfb",
+    "mfs",
+    "min",
+    "mnp",
+    "mqg",
+    "mre",
+    "msd",
+    "msi",
+    "msr",
+    "mui",
+    "mzc",
+    "mzg",
+    "mzy",
+    "nan",
+    "nbs",
+    "ncs",
+    "nsi",
+    "nsl",
+    "nsp",
+    "nsr",
+    "nzs",
+    "okl",
+    "orn",
+    "ors",
+    "pel",
+    "pga",
+    "pgz",
+    "pks",
+    "prl",
+    "prz",
+    "psc",
+    "psd",
+    "pse",
+    "psg",
+    "psl",
+    "pso",
+    "psp",
+    "psr",
+    "pys",
+    "rib",
+    "rms",
+    "rnb",
+    "rsi",
+    "rsl",
+    "rsm",
+    "rsn",
+    "sdl",
+    "sfb",
+    "sfs",
+    "sgg",
+    "sgx",
+    "shu",
+    "sjc",
+    "slf",
+    "sls",
+    "sqk",
+    "sqs",
+    "sqx",
+    "ssh",
+    "ssp",
+    "ssr",
+    "svk",
+    "swc",
+    "swh",
+    "swl",
+    "syy",
+    "szs",
+    "tmw",
+    "tse",
+    "tsm",
+    "tsq",
+    "tss",
+    "tsy",
+    "tza",
+    "ugn",
+    "ugy",
+    "ukl",
+    "uks",
+    "urk",
+    "uzn",
+    "uzs",
+    "vgt",
+    "vkk",
+    "vkt",
+    "vsi",
+    "vsl",
+    "vsv",
+    "wbs",
+    "wuu",
+    "xki",
+    "xml",
+    "xmm",
+    "xms",
+    "yds",
+    "ygs",
+    "yhs",
+    "ysl",
+    "ysm",
+    "yue",
+    "zib",
+    "zlm",
+    "zmi",
+    "zsl",
+    "zsm",
+];
+/// Registered script subtags, lower cased and sorted
+pub static SCRIPT_SUBTAGS_ARRAY: &[&str] = &[
+    "adlm",
+    "afak",
+    "aghb",
+    "ahom",
+    "arab",
+    "aran",
+    "armi",
+    "armn",
+    "avst",
+    "bali",
+    "bamu",
+    "bass",
+    "batk",
+    "beng",
+    "berf",
+    "bhks",
+    "blis",
+    "bopo",
+    "brah",
+    "brai",
+    "bugi",
+    "buhd",
+    "cakm",
+    "cans",
+    "cari",
+    "cham",
+    "cher",
+    "chis",
+    "chrs",
+    "cirt",
+    "copt",
+    "cpmn",
+    "cprt",
+    "cyrl",
+    "cyrs",
+    "deva",
+    "diak",
+    "dogr",
+    "dsrt",
+    "dupl",
+    "egyd",
+    "egyh",
+    "egyp",
+    "elba",
+    "elym",
+    "ethi",
+    "gara",
+    "geok",
+    "geor",
+    "glag",
+    "gong",
+    "gonm",
+    "goth",
+    "gran",
+    "grek",
+    "gujr",
+    "gukh",
+    "guru",
+    "hanb",
+    "hang",
+    "hani",
+    "hano",
+    "hans",
+    "hant",
+    "hatr",
+    "hebr",
+    "hira",
+    "hluw",
+    "hmng",
+    "hmnp",
+    "hntl",
+    "hrkt",
+    "hung",
+    "inds",
+    "ital",
+    "jamo",
+    "java",
+    "jpan",
+    "jurc",
+    "kali",
+    "kana",
+    "kawi",
+    "khar",
+    "khmr",
+    "khoj",
+    "kitl",
+    "kits",
+    "knda",
+    "kore",
+    "kpel",
+    "krai",
+    "kthi",
+    "lana",
+    "laoo",
+    "latf",
+    "latg",
+    "latn",
+    "leke",
+    "lepc",
+    "limb",
+    "lina",
+    "linb",
+    "lisu",
+    "loma",
+    "lyci",
+    "lydi",
+    "mahj",
+    "maka",
+    "mand",
+    "mani",
+    "marc",
+    "maya",
+    "medf",
+    "mend",
+    "merc",
+    "mero",
+    "mlym",
+    "modi",
+    "mong",
+    "moon",
+    "mroo",
+    "mtei",
+    "mult",
+    "mymr",
+    "nagm",
+    "nand",
+    "narb",
+    "nbat",
+    "newa",
+    "nkdb",
+    "nkgb",
+    "nkoo",
+    "nshu",
+    "ogam",
+    "olck",
+    "onao",
+    "orkh",
+    "orya",
+    "osge",
+    "osma",
+    "ougr",
+    "palm",
+    "pauc",
+    "pcun",
+    "pelm",
+    "perm",
+    "phag",
+    "phli",
+    "phlp",
+    "phlv",
+    "phnx",
+    "piqd",
+    "plrd",
+    "prti",
+    "psin",
+    "ranj",
+    "rjng",
+    "rohg",
+    "roro",
+    "runr",
+    "samr",
+    "sara",
+    "sarb",
+    "saur",
+    "seal",
+    "sgnw",
+    "shaw",
+    "shrd",
+    "shui",
+    "sidd",
+    "sidt",
+    "sind",
+    "sinh",
+    "sogd",
+    "sogo",
+    "sora",
+    "soyo",
+    "sund",
+    "sunu",
+    "sylo",
+    "syrc",
+    "syre",
+    "syrj",
+    "syrn",
+    "tagb",
+    "takr",
+    "tale",
+    "talu",
+    "taml",
+    "tang",
+    "tavt",
+    "tayo",
+    "telu",
+    "teng",
+    "tfng",
+    "tglg",
+    "thaa",
+    "thai",
+    "tibt",
+    "tirh",
+    "tnsa",
+    "todr",
+    "tols",
+    "toto",
+    "tutg",
+    "ugar",
+    "vaii",
+    "visp",
+    "vith",
+    "wara",
+    "wcho",
+    "wole",
+    "xpeo",
+    "xsux",
+    "yezi",
+    "yiii",
+    "zanb",
+    "zinh",
+    "zmth",
+    "zsye",
+    "zsym",
+    "zxxx",
+    "zyyy",
+    "zzzz",
+];
+/// Registered region subtags, lower cased and sorted
+pub static REGION_SUBTAGS_ARRAY: &[&str] = &[
+    "001",
+    "002",
+    "003",
+    "005",
+    "009",
+    "011",
+    "013",
+    "014",
+    "015",
+    "017",
+    "018",
+    "019",
+    "021",
+    "029",
+    "030",
+    "034",
+    "035",
+    "039",
+    "053",
+    "054",
+    "057",
+    "061",
+    "142",
+    "143",
+    "145",
+    "150",
+    "151",
+    "154",
+    "155",
+    "202",
+    "419",
+    "aa",
+    "ac",
+    "ad",
+    "ae",
+    "af",
+    "ag",
+    "ai",
+    "al",
+    "am",
+    "an",
+    "ao",
+    "aq",
+    "ar",
+    "as",
+    "at",
+    "au",
+    "aw",
+    "ax",
+    "az",
+    "ba",
+    "bb",
+    "bd",
+    "be",
+    "bf",
+    "bg",
+    "bh",
+    "bi",
+    "bj",
+    "bl",
+    "bm",
+    "bn",
+    "bo",
+    "bq",
+    "br",
+    "bs",
+    "bt",
+    "bu",
+    "bv",
+    "bw",
+    "by",
+    "bz",
+    "ca",
+    "cc",
+    "cd",
+    "cf",
+    "cg",
+    "ch",
+    "ci",
+    "ck",
+    "cl",
+    "cm",
+    "cn",
+    "co",
+    "cp",
+    "cq",
+    "cr",
+    "cs",
+    "cu",
+    "cv",
+    "cw",
+    "cx",
+    "cy",
+    "cz",
+    "dd",
+    "de",
+    "dg",
+    "dj",
+    "dk",
+    "dm",
+    "do",
+    "dz",
+    "ea",
+    "ec",
+    "ee",
+    "eg",
+    "eh",
+    "er",
+    "es",
+    "et",
+    "eu",
+    "ez",
+    "fi",
+    "fj",
+    "fk",
+    "fm",
+    "fo",
+    "fr",
+    "fx",
+    "ga",
+    "gb",
+    "gd",
+    "ge",
+    "gf",
+    "gg",
+    "gh",
+    "gi",
+    "gl",
+    "gm",
+    "gn",
+    "gp",
+    "gq",
+    "gr",
+    "gs",
+    "gt",
+    "gu",
+    "gw",
+    "gy",
+    "hk",
+    "hm",
+    "hn",
+    "hr",
+    "ht",
+    "hu",
+    "ic",
+    "id",
+    "ie",
+    "il",
+    "im",
+    "in",
+    "io",
+    "iq",
+    "ir",
+    "is",
+    "it",
+    "je",
+    "jm",
+    "jo",
+    "jp",
+    "ke",
+    "kg",
+    "kh",
+    "ki",
+    "km",
+    "kn",
+    "kp",
+    "kr",
+    "kw",
+    "ky",
+    "kz",
+    "la",
+    "lb",
+    "lc",
+    "li",
+    "lk",
+    "lr",
+    "ls",
+    "lt",
+    "lu",
+    "lv",
+    "ly",
+    "ma",
+    "mc",
+    "md",
+    "me",
+    "mf",
+    "mg",
+    "mh",
+    "mk",
+    "ml",
+    "mm",
+    "mn",
+    "mo",
+    "mp",
+    "mq",
+    "mr",
+    "ms",
+    "mt",
+    "mu",
+    "mv",
+    "mw",
+    "mx",
+    "my",
+    "mz",
+    "na",
+    "nc",
+    "ne",
+    "nf",
+    "ng",
+    "ni",
+    "nl",
+    "no",
+    "np",
+    "nr",
+    "nt",
+    "nu",
+    "nz",
+    "om",
+    "pa",
+    "pe",
+    "pf",
+    "pg",
+    "ph",
+    "pk",
+    "pl",
+    "pm",
+    "pn",
+    "pr",
+    "ps",
+    "pt",
+    "pw",
+    "py",
+    "qa",
+    "re",
+    "ro",
+    "rs",
+    "ru",
+    "rw",
+    "sa",
+    "sb",
+    "sc",
+    "sd",
+    "se",
+    "sg",
+    "sh",
+    "si",
+    "sj",
+    "sk",
+    "sl",
+    "sm",
+    "sn",
+    "so",
+    "sr",
+    "ss",
+    "st",
+    "su",
+    "sv",
+    "sx",
+    "sy",
+    "sz",
+    "ta",
+    "tc",
+    "td",
+    "tf",
+    "tg",
+    "th",
+    "tj",
+    "tk",
+    "tl",
+    "tm",
+    "tn",
+    "to",
+    "tp",
+    "tr",
+    "tt",
+    "tv",
+    "tw",
+    "tz",
+    "ua",
+    "ug",
+    "um",
+    "un",
+    "us",
+    "uy",
+    "uz",
+    "va",
+    "vc",
+    "ve",
+    "vg",
+    "vi",
+    "vn",
+    "vu",
+    "wf",
+    "ws",
+    "yd",
+    "ye",
+    "yt",
+    "yu",
+    "za",
+    "zm",
+    "zr",
+    "zw",
+    "zz",
+];
+/// Registered variant subtags, lower cased and sorted
+pub static VARIANT_SUBTAGS_ARRAY: &[&str] = &[
+    "1606nict",
+    "1694acad",
+    "1901",
+    "1959acad",
+    "1994",
+    "1996",
+    "abl1943",
+    "akhmimic",
+    "akuapem",
+    "alalc97",
+    "aluku",
+    "anpezo",
+    "ao1990",
+    "aranes",
+    "arevela",
+    "arevmda",
+    "arkaika",
+    "asante",
+    "auvern",
+    "baku1926",
+    "balanka",
+    "barla",
+    "basiceng",
+    "bauddha",
+    "bciav",
+    "bcizbl",
+    "biscayan",
+    "biske",
+    "blasl",
+    "bohairic",
+    "bohoric",
+    "boont",
+    "bornholm",
+    "cisaup",
+    "colb1945",
+    "cornu",
+    "creiss",
+    "dajnko",
+    "ekavsk",
+    "emodeng",
+    "fascia",
+    "fayyumic",
+    "fodom",
+    "fonipa",
+    "fonkirsh",
+    "fonnapa",
+    "fonupa",
+    "fonxsamp",
+    "gallo",
+    "gascon",
+    "gherd",
+    "grclass",
+    "grital",
+    "grmistr",
+    "hanoi",
+    "hepburn",
+    "heploc",
+    "hognorsk",
+    "hsistemo",
+    "huett",
+    "ijekavsk",
+    "itihasa",
+    "ivanchov",
+    "jauer",
+    "jyutping",
+    "kkcor",
+    "kleinsch",
+    "kociewie",
+    "kscor",
+    "laukika",
+    "leidentr",
+    "lemosin",
+    "lengadoc",
+    "lipaw",
+    "ltg1929",
+    "ltg2007",
+    "luna1918",
+    "lycopol",
+    "mdcegyp",
+    "mdctrans",
+    "mesokem",
+    "metelko",
+    "monoton",
+    "ndyuka",
+    "nedis",
+    "newfound",
+    "nicard",
+    "njiva",
+    "nulik",
+    "osojs",
+    "oxendict",
+    "pahawh2",
+    "pahawh3",
+    "pahawh4",
+    "pamaka",
+    "peano",
+    "pehoeji",
+    "petr1708",
+    "pinyin",
+    "polyton",
+    "provenc",
+    "puter",
+    "rigik",
+    "rozaj",
+    "rumgr",
+    "sahidic",
+    "saigon",
+    "scotland",
+    "scouse",
+    "simple",
+    "solba",
+    "sotav",
+    "spanglis",
+    "surmiran",
+    "sursilv",
+    "sutsilv",
+    "synnejyl",
+    "tailo",
+    "tarask",
+    "tongyong",
+    "tunumiit",
+    "uccor",
+    "ucrcor",
+    "ulster",
+    "unifon",
+    "vaidika",
+    "valbadia",
+    "valencia",
+    "vallader",
+    "vecdruka",
+    "viennese",
+    "vivaraup",
+    "wadegile",
+    "xsistemo",
+];
+/// Registered grandfathered subtags, lower cased and sorted
+pub static GRANDFATHERED_TAGS_ARRAY: &[&str] = &[
+    "art-lojban",
+    "cel-gaulish",
+    "en-gb-oed",
+    "i-ami",
+    "i-bnn",
+    "i-default",
+    "i-enochian",
+    "i-hak",
+    "i-klingon",
+    "i-lux",
+    "i-mingo",
+    "i-navajo",
+    "i-pwn",
+    "i-tao",
+    "i-tay",
+    "i-tsu",
+    "no-bok",
+    "no-nyn",
+    "sgn-be-fr",
+    "sgn-be-nl",
+    "sgn-ch-de",
+    "zh-guoyu",
+    "zh-hakka",
+    "zh-min",
+    "zh-min-nan",
+    "zh-xiang",
+];
+/// Subtags reserved for private use as (type, first, last), lower cased
+pub static PRIVATE_USE_RANGES: &[(&str, &str, &str)] = &[
+    ("language", "qaa", "qtz"),
+    ("script", "qaaa", "qabx"),
+    ("region", "aa", "aa"),
+    ("region", "qm", "qz"),
+    ("region", "xa", "xz"),
+    ("region", "zz", "zz"),
+];
 /// Checks if a given subtag is a valid language subtag.
 /// Lower cases the input before checking.
 pub fn is_valid_language_subtag(subtag: &str) -> bool {
     let sub = &subtag.to_lowercase();
     LANGUAGE_SUBTAGS_ARRAY.binary_search(&sub.as_str()).is_ok()
+        || is_private_use_subtag("language", sub)
+}
+/// Checks if a subtag of the given type (like `language` or `region`) is reserved for private use.
+/// Lower cases the input before checking.
+pub fn is_private_use_subtag(subtag_type: &str, subtag: &str) -> bool {
+    let sub = subtag.to_lowercase();
+    PRIVATE_USE_RANGES
+        .iter()
+        .any(|(range_type, first, last)| {
+            *range_type == subtag_type && sub.len() == first.len()
+                && *first <= sub.as_str() && sub.as_str() <= *last
+        })
 }
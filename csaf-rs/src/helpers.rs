@@ -2,6 +2,7 @@ use crate::csaf_traits::{CsafTrait, ProductGroupTrait, ProductTreeTrait};
 use crate::csaf2_1::ssvc_dp::DecisionPoint;
 use chrono::NaiveDate;
 use rust_embed::RustEmbed;
+use serde_json::Value;
 use std::collections::{BTreeSet, HashMap, HashSet};
 use std::ops::Deref;
 use std::sync::LazyLock;
@@ -17,6 +18,39 @@ pub static MAX_UUID: &Uuid = &uuid!("ffffffff-ffff-ffff-ffff-ffffffffffff");
 /// Special "nil" UUID value
 pub static NIL_UUID: &Uuid = &uuid!("00000000-0000-0000-0000-000000000000");
 
+/// Title of the document note explaining why a `csaf_withdrawn` document was withdrawn
+pub static NOTE_TITLE_REASONING_FOR_WITHDRAWAL: &str = "Reasoning for Withdrawal";
+/// Title of the document note explaining why a `csaf_superseded` document was superseded
+pub static NOTE_TITLE_REASONING_FOR_SUPERSESSION: &str = "Reasoning for Supersession";
+/// Summary of the document reference pointing to the document that supersedes a `csaf_superseded` document
+pub static REFERENCE_SUMMARY_SUPERSEDING_DOCUMENT: &str = "Superseding Document";
+/// Title of the document note holding the text of a license that is not on the SPDX license list
+pub static NOTE_TITLE_LICENSE: &str = "License";
+
+/// Translations of the fixed English texts above, by primary language subtag.
+static LANGUAGE_SPECIFIC_TEXTS: &[(&str, &str, &str)] = &[
+    ("de", "Reasoning for Withdrawal", "Begründung für die Zurückziehung"),
+    ("de", "Reasoning for Supersession", "Begründung für die Ersetzung"),
+    ("de", "Superseding Document", "Ersetzendes Dokument"),
+    ("de", "License", "Lizenz"),
+];
+
+/// Returns the translation of one of the fixed English texts into the document language `lang`.
+///
+/// Returns `None` for English documents and for languages without a known translation.
+pub fn language_specific_text(text: &str, lang: Option<&String>) -> Option<&'static str> {
+    let primary_subtag = lang?.split('-').next()?.to_ascii_lowercase();
+    LANGUAGE_SPECIFIC_TEXTS
+        .iter()
+        .find(|(l, english, _)| *l == primary_subtag && *english == text)
+        .map(|(_, _, translation)| *translation)
+}
+
+/// Checks whether `value` is the fixed English text or its translation into the document language `lang`.
+pub fn matches_fixed_text(value: &str, text: &str, lang: Option<&String>) -> bool {
+    value == text || language_specific_text(text, lang) == Some(value)
+}
+
 pub fn resolve_product_groups<'a, I>(doc: &impl CsafTrait, product_groups: I) -> Option<BTreeSet<String>>
 where
     I: IntoIterator<Item = &'a String>,
@@ -58,6 +92,91 @@ pub fn count_unescaped_stars(s: &str) -> u32 {
     count
 }
 
+/// Checks whether a value matches a model or serial number containing `*` wildcards.
+///
+/// An unescaped '*' matches any number of characters, `\*` and `\\` match a literal '*' and '\\',
+/// consistent with [count_unescaped_stars].
+pub fn matches_unescaped_stars(pattern: &str, value: &str) -> bool {
+    // Literal characters of the pattern, with `None` standing for a wildcard
+    let mut tokens: Vec<Option<char>> = Vec::new();
+    let mut escaped = false;
+    for c in pattern.chars() {
+        match c {
+            '\\' if !escaped => escaped = true,
+            '*' if !escaped => tokens.push(None),
+            _ => {
+                tokens.push(Some(c));
+                escaped = false;
+            },
+        }
+    }
+
+    // matches[j] is whether the first j tokens match the characters of the value seen so far
+    let mut matches = vec![false; tokens.len() + 1];
+    matches[0] = true;
+    for j in 0..tokens.len() {
+        matches[j + 1] = matches[j] && tokens[j].is_none();
+    }
+    for c in value.chars() {
+        let mut next = vec![false; tokens.len() + 1];
+        for (j, token) in tokens.iter().enumerate() {
+            next[j + 1] = match token {
+                None => next[j] || matches[j + 1],
+                Some(t) => matches[j] && *t == c,
+            };
+        }
+        matches = next;
+    }
+    matches[tokens.len()]
+}
+
+/// Returns the values matching a JSON pointer pattern together with their paths.
+///
+/// In the pattern, `*` stands for all items of an array and `**` for any number of nested
+/// properties and array items, e.g. `/product_tree/**/product_identification_helper/sbom_urls/*`.
+pub fn json_values_at<'a>(json: &'a Value, pattern: &str) -> Vec<(String, &'a Value)> {
+    let segments: Vec<&str> = pattern.split('/').skip(1).collect();
+    let mut values = Vec::new();
+    collect_json_values(json, &segments, String::new(), &mut values);
+    values
+}
+
+fn collect_json_values<'a>(value: &'a Value, pattern: &[&str], path: String, values: &mut Vec<(String, &'a Value)>) {
+    let Some((segment, rest)) = pattern.split_first() else {
+        values.push((path, value));
+        return;
+    };
+    match (*segment, value) {
+        ("**", _) => {
+            collect_json_values(value, rest, path.clone(), values);
+            match value {
+                Value::Array(items) => {
+                    for (i, item) in items.iter().enumerate() {
+                        collect_json_values(item, pattern, format!("{path}/{i}"), values);
+                    }
+                },
+                Value::Object(object) => {
+                    for (key, child) in object {
+                        collect_json_values(child, pattern, format!("{path}/{key}"), values);
+                    }
+                },
+                _ => {},
+            }
+        },
+        ("*", Value::Array(items)) => {
+            for (i, item) in items.iter().enumerate() {
+                collect_json_values(item, rest, format!("{path}/{i}"), values);
+            }
+        },
+        (key, Value::Object(object)) => {
+            if let Some(child) = object.get(key) {
+                collect_json_values(child, rest, format!("{path}/{key}"), values);
+            }
+        },
+        _ => {},
+    }
+}
+
 #[derive(RustEmbed)]
 #[folder = "assets/ssvc_decision_points/"]
 #[include = "*.json"]
@@ -74,7 +193,7 @@ pub static SSVC_DECISION_POINTS: LazyLock<SsvcDecisionPointsMap> = LazyLock::new
             let content = std::str::from_utf8(&file.data).unwrap();
             match serde_json::from_str::<DecisionPoint>(content) {
                 Ok(dp) => {
-                    println!(
+                    log::debug!(
                         "Loaded SSVC decision point '{}' ({}, version {})",
                         dp.key.deref(),
                         dp.name.deref(),
@@ -87,7 +206,7 @@ pub static SSVC_DECISION_POINTS: LazyLock<SsvcDecisionPointsMap> = LazyLock::new
                     );
                     decision_points.insert(key, dp);
                 },
-                Err(err) => eprintln!("Warning: Failed to parse decision point from file {filename}: {err}"),
+                Err(err) => log::warn!("Failed to parse decision point from file {filename}: {err}"),
             }
         }
     }
@@ -124,6 +243,18 @@ pub static REGISTERED_SSVC_NAMESPACES: LazyLock<HashSet<String>> = LazyLock::new
     namespaces
 });
 
+/// Prefix of SSVC namespaces that are not registered, e.g. private or experimental ones
+pub static SSVC_UNREGISTERED_NAMESPACE_PREFIX: &str = "x_";
+
+/// Splits an SSVC namespace into its base namespace and its optional extensions, which follow the
+/// first '/'.
+pub fn split_ssvc_namespace(namespace: &str) -> (&str, Option<&str>) {
+    match namespace.split_once('/') {
+        Some((base, extensions)) => (base, Some(extensions)),
+        None => (namespace, None),
+    }
+}
+
 #[derive(RustEmbed)]
 #[folder = "assets/cwe/"]
 #[include = "*.csv"]
@@ -164,3 +295,18 @@ pub static CWE_ENTRIES: LazyLock<HashMap<String, CweReleaseDateAndData>> = LazyL
 
     entries
 });
+
+/// Returns the latest CWE version that was released on or before `date`.
+///
+/// If `date` is `None`, the latest available CWE version is returned.
+pub fn get_latest_cwe_version(date: Option<NaiveDate>) -> Option<&'static String> {
+    let mut latest: Option<(&'static String, &NaiveDate)> = None;
+
+    for (version, (release_date, _)) in CWE_ENTRIES.iter() {
+        if date.is_none_or(|date| *release_date <= date) && latest.is_none_or(|latest| *release_date > *latest.1) {
+            latest = Some((version, release_date));
+        }
+    }
+
+    latest.map(|(version, _)| version)
+}
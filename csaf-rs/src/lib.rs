@@ -4,14 +4,28 @@ pub mod csaf;
 pub mod csaf2_0;
 pub mod csaf2_1;
 pub mod csaf_traits;
+pub mod cvss;
+pub mod diff;
 pub mod document_category_test_helper;
+pub mod fix;
 pub mod generated;
 pub mod helpers;
+pub mod product_status;
+pub mod sbom;
 pub mod schema;
+pub mod spell_check;
+pub mod successor;
+#[cfg(test)]
+pub(crate) mod test_documents;
+pub mod test_registry;
 pub mod test_result_comparison;
 pub mod test_structure;
 pub mod test_validation;
+pub mod url_resolver;
 pub mod validation;
 pub mod validations;
+pub mod vex;
 #[cfg(feature = "wasm")]
 pub mod wasm;
+
+pub use csaf::document::{CsafDocument, DocumentSource};
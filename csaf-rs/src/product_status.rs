@@ -0,0 +1,463 @@
+//! Effective status of products for the vulnerabilities of a document.
+//!
+//! The [ProductStatusResolver] answers "what is the status of product X for vulnerability Y?" by
+//! merging everything a document states about the product: its product status, flags, threats and
+//! remediations. Product groups are resolved, and a product defined by a relationship without a
+//! product status of its own inherits the statements about the product it references. Every
+//! statement comes with the JSON path it was taken from.
+//!
+//! ```no_run
+//! # use csaf::product_status::ProductStatusResolver;
+//! # fn example(doc: &csaf::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework) {
+//! let resolver = ProductStatusResolver::new(doc);
+//! if let Some(vulnerability) = resolver.find_vulnerability("CVE-2024-1234")
+//!     && let Some(verdict) = resolver.resolve(vulnerability, "CSAFPID-0001")
+//! {
+//!     println!("{:?}", verdict.status);
+//! }
+//! # }
+//! ```
+
+use crate::csaf_traits::{
+    CsafTrait, FlagTrait, ProductGroupTrait, ProductStatusGroup, ProductTrait, ProductTreeTrait, RelationshipTrait,
+    RemediationTrait, ThreatTrait, VulnerabilityIdTrait, VulnerabilityTrait, WithOptionalGroupIds,
+    WithOptionalProductIds,
+};
+use crate::helpers::resolve_product_groups;
+use crate::schema::csaf2_1::schema::{CategoryOfTheRemediation, CategoryOfTheThreat, LabelOfTheFlag};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// A statement about a product and where it was found
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Evidence<T> {
+    /// The stated value, like the status group or the label of a flag
+    pub value: T,
+    /// JSON path of the reference to the product or its product group
+    pub path: String,
+    /// The product group through which the product is referenced
+    pub group_id: Option<String>,
+    /// JSON path of the relationship through which the statement was inherited
+    pub relationship: Option<String>,
+}
+
+/// Status of a product for a vulnerability, merged from all statements about it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EffectiveStatus {
+    Affected,
+    NotAffected,
+    Fixed,
+    UnderInvestigation,
+    Unknown,
+    /// The product is listed in several status groups which contradict each other, see test 6.1.6
+    Contradicting(Vec<ProductStatusGroup>),
+    /// Nothing is stated about the status of the product
+    NotListed,
+}
+
+/// Everything a document states about a product for one vulnerability
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProductVerdict {
+    pub product_id: String,
+    /// Index of the vulnerability in `/vulnerabilities`
+    pub vulnerability_index: usize,
+    /// The status merged from the product status and flags
+    pub status: EffectiveStatus,
+    /// The product status groups listing the product, including `recommended`
+    pub product_status: Vec<Evidence<ProductStatusGroup>>,
+    pub flags: Vec<Evidence<LabelOfTheFlag>>,
+    pub threats: Vec<Evidence<CategoryOfTheThreat>>,
+    pub remediations: Vec<Evidence<CategoryOfTheRemediation>>,
+}
+
+impl ProductVerdict {
+    /// Returns whether the product is affected, including contradicting statements that say so.
+    pub fn is_affected(&self) -> bool {
+        match &self.status {
+            EffectiveStatus::Affected => true,
+            EffectiveStatus::Contradicting(groups) => groups.contains(&ProductStatusGroup::Affected),
+            _ => false,
+        }
+    }
+
+    /// Returns whether the product is recommended by the vendor for this vulnerability.
+    pub fn is_recommended(&self) -> bool {
+        self.product_status
+            .iter()
+            .any(|evidence| evidence.value == ProductStatusGroup::Recommended)
+    }
+
+    /// Returns whether a vendor fix is available for the product.
+    pub fn has_vendor_fix(&self) -> bool {
+        self.remediations
+            .iter()
+            .any(|evidence| evidence.value == CategoryOfTheRemediation::VendorFix)
+    }
+}
+
+/// The statements about one product, before they are merged into a verdict
+#[derive(Debug, Clone, Default)]
+struct Statements {
+    product_status: Vec<Evidence<ProductStatusGroup>>,
+    flags: Vec<Evidence<LabelOfTheFlag>>,
+    threats: Vec<Evidence<CategoryOfTheThreat>>,
+    remediations: Vec<Evidence<CategoryOfTheRemediation>>,
+}
+
+impl Statements {
+    fn inherit(&mut self, other: Statements, relationship: &str) {
+        fn via<T>(evidence: Vec<Evidence<T>>, relationship: &str) -> impl Iterator<Item = Evidence<T>> {
+            evidence.into_iter().map(move |evidence| Evidence {
+                relationship: evidence.relationship.or_else(|| Some(relationship.to_string())),
+                ..evidence
+            })
+        }
+        self.product_status.extend(via(other.product_status, relationship));
+        self.flags.extend(via(other.flags, relationship));
+        self.threats.extend(via(other.threats, relationship));
+        self.remediations.extend(via(other.remediations, relationship));
+    }
+}
+
+/// Returns the status group of a product status label like `known_affected`.
+fn status_group(label: &str) -> Option<ProductStatusGroup> {
+    match label {
+        "first_affected" | "known_affected" | "last_affected" => Some(ProductStatusGroup::Affected),
+        "known_not_affected" => Some(ProductStatusGroup::NotAffected),
+        "first_fixed" | "fixed" => Some(ProductStatusGroup::Fixed),
+        "under_investigation" => Some(ProductStatusGroup::UnderInvestigation),
+        "unknown" => Some(ProductStatusGroup::Unknown),
+        "recommended" => Some(ProductStatusGroup::Recommended),
+        _ => None,
+    }
+}
+
+/// Resolves the effective status of products, see the [module documentation](self).
+pub struct ProductStatusResolver<'a, D: CsafTrait> {
+    doc: &'a D,
+    /// Product IDs of each product group
+    groups: HashMap<String, BTreeSet<String>>,
+    /// Product IDs defined by relationships, with the product they reference and the relationship path
+    relationships: HashMap<String, Vec<(String, String)>>,
+}
+
+impl<'a, D: CsafTrait> ProductStatusResolver<'a, D> {
+    pub fn new(doc: &'a D) -> Self {
+        let mut groups = HashMap::new();
+        let mut relationships: HashMap<String, Vec<(String, String)>> = HashMap::new();
+        if let Some(product_tree) = doc.get_product_tree() {
+            for group in product_tree.get_product_groups() {
+                let group_id = group.get_group_id();
+                let product_ids = resolve_product_groups(doc, [group_id]).unwrap_or_default();
+                groups.insert(group_id.to_string(), product_ids);
+            }
+            for (i, relationship) in product_tree.get_relationships().iter().enumerate() {
+                relationships
+                    .entry(relationship.get_full_product_name().get_product_id().to_string())
+                    .or_default()
+                    .push((
+                        relationship.get_product_reference().to_string(),
+                        format!("/product_tree/relationships/{i}"),
+                    ));
+            }
+        }
+        Self {
+            doc,
+            groups,
+            relationships,
+        }
+    }
+
+    /// Returns the index of the vulnerability with a CVE or an ID (from `ids`) equal to `id`.
+    pub fn find_vulnerability(&self, id: &str) -> Option<usize> {
+        self.doc.get_vulnerabilities().iter().position(|vulnerability| {
+            vulnerability.get_cve().is_some_and(|cve| cve == id)
+                || vulnerability
+                    .get_ids()
+                    .iter()
+                    .flatten()
+                    .any(|vulnerability_id| vulnerability_id.get_text() == id)
+        })
+    }
+
+    /// Resolves the status of a product for the vulnerability at an index of `/vulnerabilities`.
+    ///
+    /// Returns `None` if there is no vulnerability at the index.
+    pub fn resolve(&self, vulnerability_index: usize, product_id: &str) -> Option<ProductVerdict> {
+        let vulnerability = self.doc.get_vulnerabilities().get(vulnerability_index)?;
+        let statements = self.collect_statements(vulnerability_index, vulnerability);
+        let merged = self.statements_for(product_id, &statements, &mut HashSet::new());
+        Some(to_verdict(product_id, vulnerability_index, merged))
+    }
+
+    /// Resolves the status of all products defined in the product tree or referenced by the
+    /// vulnerability at an index of `/vulnerabilities`, ordered by product ID.
+    pub fn resolve_vulnerability(&self, vulnerability_index: usize) -> Vec<ProductVerdict> {
+        let Some(vulnerability) = self.doc.get_vulnerabilities().get(vulnerability_index) else {
+            return Vec::new();
+        };
+        let statements = self.collect_statements(vulnerability_index, vulnerability);
+        let mut product_ids: BTreeSet<String> = statements.keys().cloned().collect();
+        if let Some(product_tree) = self.doc.get_product_tree() {
+            product_tree.visit_all_products(&mut |product, _| {
+                product_ids.insert(product.get_product_id().to_string());
+            });
+        }
+        product_ids
+            .into_iter()
+            .map(|product_id| {
+                let merged = self.statements_for(&product_id, &statements, &mut HashSet::new());
+                to_verdict(&product_id, vulnerability_index, merged)
+            })
+            .collect()
+    }
+
+    /// Returns the statements about a product, inheriting those about the product referenced by its
+    /// relationships if its status is not stated directly.
+    fn statements_for(
+        &self,
+        product_id: &str,
+        statements: &HashMap<String, Statements>,
+        visited: &mut HashSet<String>,
+    ) -> Statements {
+        let mut merged = statements.get(product_id).cloned().unwrap_or_default();
+        if !merged.product_status.is_empty() || !visited.insert(product_id.to_string()) {
+            return merged;
+        }
+        for (reference, relationship) in self.relationships.get(product_id).into_iter().flatten() {
+            let inherited = self.statements_for(reference, statements, visited);
+            merged.inherit(inherited, relationship);
+        }
+        merged
+    }
+
+    /// Collects the statements of a vulnerability by product ID, with product groups resolved.
+    fn collect_statements(
+        &self,
+        vulnerability_index: usize,
+        vulnerability: &D::VulnerabilityType,
+    ) -> HashMap<String, Statements> {
+        let prefix = format!("/vulnerabilities/{vulnerability_index}");
+        let mut statements: HashMap<String, Statements> = HashMap::new();
+
+        for (product_id, path) in vulnerability.get_product_status_product_references() {
+            let Some(group) = path.split('/').nth(1).and_then(status_group) else {
+                continue;
+            };
+            statements.entry(product_id).or_default().product_status.push(Evidence {
+                value: group,
+                path: format!("{prefix}/{path}"),
+                group_id: None,
+                relationship: None,
+            });
+        }
+        let flags = vulnerability.get_flags().iter().flatten();
+        for (product_id, evidence) in self.evidence(flags, &format!("{prefix}/flags"), FlagTrait::get_label) {
+            statements.entry(product_id).or_default().flags.push(evidence);
+        }
+        let threats = vulnerability.get_threats().iter();
+        for (product_id, evidence) in self.evidence(threats, &format!("{prefix}/threats"), ThreatTrait::get_category) {
+            statements.entry(product_id).or_default().threats.push(evidence);
+        }
+        let remediations = vulnerability.get_remediations().iter();
+        let remediation_evidence = self.evidence(
+            remediations,
+            &format!("{prefix}/remediations"),
+            RemediationTrait::get_category,
+        );
+        for (product_id, evidence) in remediation_evidence {
+            statements.entry(product_id).or_default().remediations.push(evidence);
+        }
+
+        statements
+    }
+
+    /// Returns the statements of items referencing products and product groups, by product ID.
+    fn evidence<'b, T, V>(
+        &self,
+        items: impl Iterator<Item = &'b T>,
+        prefix: &str,
+        value: impl Fn(&T) -> V,
+    ) -> Vec<(String, Evidence<V>)>
+    where
+        T: WithOptionalProductIds + WithOptionalGroupIds + 'b,
+        V: Clone,
+    {
+        let mut evidence = Vec::new();
+        for (i, item) in items.enumerate() {
+            let item_value = value(item);
+            for (j, product_id) in item.get_product_ids().into_iter().flatten().enumerate() {
+                evidence.push((
+                    product_id.to_string(),
+                    Evidence {
+                        value: item_value.clone(),
+                        path: format!("{prefix}/{i}/product_ids/{j}"),
+                        group_id: None,
+                        relationship: None,
+                    },
+                ));
+            }
+            for (j, group_id) in item.get_group_ids().into_iter().flatten().enumerate() {
+                for product_id in self.groups.get(group_id).into_iter().flatten() {
+                    evidence.push((
+                        product_id.to_string(),
+                        Evidence {
+                            value: item_value.clone(),
+                            path: format!("{prefix}/{i}/group_ids/{j}"),
+                            group_id: Some(group_id.to_string()),
+                            relationship: None,
+                        },
+                    ));
+                }
+            }
+        }
+        evidence
+    }
+}
+
+/// Merges the statements about a product into its verdict.
+///
+/// The status is taken from the product status groups, ignoring `recommended`. Products that are
+/// not listed there but have a flag are not affected, as flags justify why a product is not affected.
+fn to_verdict(product_id: &str, vulnerability_index: usize, statements: Statements) -> ProductVerdict {
+    let groups: BTreeSet<ProductStatusGroup> = statements
+        .product_status
+        .iter()
+        .map(|evidence| evidence.value.clone())
+        .filter(|group| *group != ProductStatusGroup::Recommended)
+        .collect();
+    let status = match groups.len() {
+        0 if !statements.flags.is_empty() => EffectiveStatus::NotAffected,
+        0 => EffectiveStatus::NotListed,
+        1 => match groups.into_iter().next() {
+            Some(ProductStatusGroup::Affected) => EffectiveStatus::Affected,
+            Some(ProductStatusGroup::NotAffected) => EffectiveStatus::NotAffected,
+            Some(ProductStatusGroup::Fixed) => EffectiveStatus::Fixed,
+            Some(ProductStatusGroup::UnderInvestigation) => EffectiveStatus::UnderInvestigation,
+            _ => EffectiveStatus::Unknown,
+        },
+        _ => EffectiveStatus::Contradicting(groups.into_iter().collect()),
+    };
+    ProductVerdict {
+        product_id: product_id.to_string(),
+        vulnerability_index,
+        status,
+        product_status: statements.product_status,
+        flags: statements.flags,
+        threats: statements.threats,
+        remediations: statements.remediations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_documents::csaf_2_1;
+    use serde_json::json;
+
+    fn evidence<T>(value: T, path: &str, group_id: Option<&str>, relationship: Option<&str>) -> Evidence<T> {
+        Evidence {
+            value,
+            path: path.to_string(),
+            group_id: group_id.map(str::to_string),
+            relationship: relationship.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_resolve() {
+        let doc = csaf_2_1(json!({
+            "product_tree": {
+                "full_product_names": [
+                    { "name": "Library 1.0", "product_id": "LIB-1" },
+                    { "name": "Library 2.0", "product_id": "LIB-2" },
+                    { "name": "Server", "product_id": "SERVER" },
+                    { "name": "Tool", "product_id": "TOOL" }
+                ],
+                "product_groups": [{ "group_id": "GROUP-LIB", "product_ids": ["LIB-1", "LIB-2"] }],
+                "relationships": [{
+                    "category": "installed_on",
+                    "full_product_name": { "name": "Library 1.0 on Server", "product_id": "LIB-1-ON-SERVER" },
+                    "product_reference": "LIB-1",
+                    "relates_to_product_reference": "SERVER"
+                }]
+            },
+            "vulnerabilities": [{
+                "cve": "CVE-2024-1234",
+                "product_status": {
+                    "known_affected": ["LIB-1"],
+                    "fixed": ["LIB-2"],
+                    "recommended": ["LIB-2"]
+                },
+                "flags": [{ "label": "component_not_present", "product_ids": ["TOOL"] }],
+                "remediations": [{
+                    "category": "vendor_fix",
+                    "details": "Update to version 2.0",
+                    "group_ids": ["GROUP-LIB"]
+                }]
+            }]
+        }));
+        let resolver = ProductStatusResolver::new(&doc);
+        assert_eq!(resolver.find_vulnerability("CVE-2024-1234"), Some(0));
+        assert_eq!(resolver.find_vulnerability("CVE-2024-0000"), None);
+
+        let verdict = resolver.resolve(0, "LIB-1-ON-SERVER").unwrap();
+        assert_eq!(verdict.status, EffectiveStatus::Affected);
+        assert!(verdict.is_affected() && verdict.has_vendor_fix());
+        let relationship = Some("/product_tree/relationships/0");
+        assert_eq!(
+            verdict.product_status,
+            vec![evidence(
+                ProductStatusGroup::Affected,
+                "/vulnerabilities/0/product_status/known_affected/0",
+                None,
+                relationship
+            )]
+        );
+        assert_eq!(
+            verdict.remediations,
+            vec![evidence(
+                CategoryOfTheRemediation::VendorFix,
+                "/vulnerabilities/0/remediations/0/group_ids/0",
+                Some("GROUP-LIB"),
+                relationship
+            )]
+        );
+
+        let verdicts = resolver.resolve_vulnerability(0);
+        let statuses: Vec<(&str, &EffectiveStatus)> = verdicts
+            .iter()
+            .map(|verdict| (verdict.product_id.as_str(), &verdict.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ("LIB-1", &EffectiveStatus::Affected),
+                ("LIB-1-ON-SERVER", &EffectiveStatus::Affected),
+                ("LIB-2", &EffectiveStatus::Fixed),
+                ("SERVER", &EffectiveStatus::NotListed),
+                ("TOOL", &EffectiveStatus::NotAffected),
+            ]
+        );
+        assert!(verdicts[2].is_recommended());
+        assert!(resolver.resolve(1, "LIB-1").is_none());
+    }
+
+    #[test]
+    fn test_resolve_contradicting() {
+        let doc = csaf_2_1(json!({
+            "product_tree": {
+                "full_product_names": [{ "name": "Product", "product_id": "CSAFPID-0001" }]
+            },
+            "vulnerabilities": [{
+                "product_status": { "known_affected": ["CSAFPID-0001"], "known_not_affected": ["CSAFPID-0001"] }
+            }]
+        }));
+        assert_eq!(
+            ProductStatusResolver::new(&doc)
+                .resolve(0, "CSAFPID-0001")
+                .unwrap()
+                .status,
+            EffectiveStatus::Contradicting(vec![ProductStatusGroup::Affected, ProductStatusGroup::NotAffected])
+        );
+    }
+}
@@ -0,0 +1,104 @@
+//! Components of CycloneDX JSON SBOMs.
+//!
+//! CycloneDX has no fields for model and serial numbers, they are read from the component
+//! properties named `model_number` and `serial_number`. SBOM URLs are read from the external
+//! references of type `bom`.
+
+use serde_json::Value;
+
+use crate::sbom::{Component, ComponentHash, Sbom, SbomFormat, array, string};
+
+pub(super) fn parse(json: &Value) -> Sbom {
+    let mut components = Vec::new();
+    if let Some(component) = json.pointer("/metadata/component") {
+        collect_components(component, "/metadata/component".to_string(), true, &mut components);
+    }
+    for (i, component) in array(json, "components").enumerate() {
+        collect_components(component, format!("/components/{i}"), false, &mut components);
+    }
+    Sbom {
+        format: SbomFormat::CycloneDx,
+        urls: Vec::new(),
+        components,
+    }
+}
+
+/// Adds a component and its nested components.
+fn collect_components(json: &Value, path: String, described: bool, components: &mut Vec<Component>) {
+    let properties = |name: &str| -> Vec<String> {
+        array(json, "properties")
+            .filter(|property| property.get("name").and_then(Value::as_str) == Some(name))
+            .filter_map(|property| string(property, "value"))
+            .collect()
+    };
+    components.push(Component {
+        path: path.clone(),
+        reference: string(json, "bom-ref"),
+        name: string(json, "name").unwrap_or_default(),
+        version: string(json, "version"),
+        purls: string(json, "purl").into_iter().collect(),
+        cpes: string(json, "cpe").into_iter().collect(),
+        hashes: array(json, "hashes")
+            .filter_map(|hash| {
+                Some(ComponentHash {
+                    algorithm: string(hash, "alg")?,
+                    value: string(hash, "content")?,
+                })
+            })
+            .collect(),
+        model_numbers: properties("model_number"),
+        serial_numbers: properties("serial_number"),
+        sbom_urls: array(json, "externalReferences")
+            .filter(|reference| reference.get("type").and_then(Value::as_str) == Some("bom"))
+            .filter_map(|reference| string(reference, "url"))
+            .collect(),
+        described,
+    });
+    for (i, component) in array(json, "components").enumerate() {
+        collect_components(component, format!("{path}/components/{i}"), false, components);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse() {
+        let sbom = parse(&json!({
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.6",
+            "metadata": { "component": { "bom-ref": "app", "name": "App", "version": "1.0" } },
+            "components": [{
+                "name": "openssl",
+                "version": "3.0.1",
+                "purl": "pkg:generic/openssl@3.0.1",
+                "hashes": [{ "alg": "SHA-256", "content": "ABCDEF" }],
+                "properties": [{ "name": "serial_number", "value": "SN-1" }],
+                "externalReferences": [{ "type": "bom", "url": "https://example.com/openssl.cdx.json" }],
+                "components": [{ "name": "libcrypto", "cpe": "cpe:2.3:a:openssl:openssl:3.0.1:*:*:*:*:*:*:*" }]
+            }]
+        }));
+        let paths: Vec<&str> = sbom.components.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec!["/metadata/component", "/components/0", "/components/0/components/0"]
+        );
+        assert!(sbom.components[0].described && !sbom.components[1].described);
+        assert_eq!(sbom.components[0].reference.as_deref(), Some("app"));
+
+        let openssl = &sbom.components[1];
+        assert_eq!(openssl.purls, vec!["pkg:generic/openssl@3.0.1"]);
+        assert_eq!(
+            openssl.hashes,
+            vec![ComponentHash {
+                algorithm: "SHA-256".to_string(),
+                value: "ABCDEF".to_string()
+            }]
+        );
+        assert_eq!(openssl.serial_numbers, vec!["SN-1"]);
+        assert_eq!(openssl.sbom_urls, vec!["https://example.com/openssl.cdx.json"]);
+        assert_eq!(sbom.components[2].cpes.len(), 1);
+    }
+}
@@ -0,0 +1,497 @@
+//! Matching of SBOM components against the products of CSAF documents.
+//!
+//! A product matches a component if their product identification helper and the component share
+//! - a purl, where purls without a version or qualifiers match all versions or qualifiers,
+//! - a CPE, where attributes of the product's CPE that are `*` or missing match any value,
+//! - a hash of the same algorithm,
+//! - a model or serial number, where `*` in the product's numbers matches any characters, or
+//! - an SBOM URL, of the component or of the SBOM describing the component.
+
+use std::str::FromStr;
+
+use packageurl::PackageUrl;
+
+use crate::CsafDocument;
+use crate::csaf::document::CsafVisitor;
+use crate::csaf_traits::{
+    CsafTrait, DocumentTrait, FileHashTrait, HashTrait, ProductIdentificationHelperTrait, ProductTrait,
+    ProductTreeTrait, TrackingTrait, VulnerabilityIdTrait, VulnerabilityTrait,
+};
+use crate::helpers::matches_unescaped_stars;
+use crate::product_status::{EffectiveStatus, ProductStatusResolver, ProductVerdict};
+use crate::sbom::{Component, Sbom};
+
+/// Product identification helper data a product and a component match on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MatchCriterion {
+    Purl,
+    Cpe,
+    Hash,
+    ModelNumber,
+    SerialNumber,
+    SbomUrl,
+}
+
+/// A component matching a product with a stated status for a vulnerability
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentMatch {
+    /// Index of the component in [Sbom::components]
+    pub component: usize,
+    /// Tracking ID of the CSAF document
+    pub document_id: String,
+    /// CVE or first ID of the vulnerability, if any
+    pub vulnerability_id: Option<String>,
+    /// JSON path of the product in the CSAF document
+    pub product_path: String,
+    /// The criteria the component matched the product on
+    pub criteria: Vec<MatchCriterion>,
+    /// Status of the product for the vulnerability
+    pub verdict: ProductVerdict,
+}
+
+/// Matches the components of an SBOM against CSAF documents, see the [module documentation](self).
+pub struct SbomMatcher<'a> {
+    sbom: &'a Sbom,
+    identifiers: Vec<Identifiers>,
+    matches: Vec<ComponentMatch>,
+}
+
+impl<'a> SbomMatcher<'a> {
+    pub fn new(sbom: &'a Sbom) -> Self {
+        Self {
+            sbom,
+            identifiers: sbom.components.iter().map(|c| Identifiers::new(sbom, c)).collect(),
+            matches: Vec::new(),
+        }
+    }
+
+    /// Matches the components against the products of a document.
+    pub fn add_document(&mut self, doc: &impl CsafTrait) {
+        let Some(product_tree) = doc.get_product_tree() else {
+            return;
+        };
+        // Products matching components, as (component, product ID, product path, criteria)
+        let mut matched_products = Vec::new();
+        product_tree.visit_all_products(&mut |product, path| {
+            let Some(helper) = product.get_product_identification_helper() else {
+                return;
+            };
+            for (component, identifiers) in self.identifiers.iter().enumerate() {
+                let criteria = identifiers.matching_criteria(helper);
+                if !criteria.is_empty() {
+                    matched_products.push((
+                        component,
+                        product.get_product_id().to_string(),
+                        path.to_string(),
+                        criteria,
+                    ));
+                }
+            }
+        });
+        if matched_products.is_empty() {
+            return;
+        }
+
+        let document_id = doc.get_document().get_tracking().get_id();
+        let resolver = ProductStatusResolver::new(doc);
+        for (i, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+            let vulnerability_id = vulnerability.get_cve().or_else(|| {
+                vulnerability
+                    .get_ids()
+                    .iter()
+                    .flatten()
+                    .next()
+                    .map(|vulnerability_id| vulnerability_id.get_text())
+            });
+            for (component, product_id, product_path, criteria) in &matched_products {
+                let Some(verdict) = resolver.resolve(i, product_id) else {
+                    continue;
+                };
+                if verdict.status == EffectiveStatus::NotListed {
+                    continue;
+                }
+                self.matches.push(ComponentMatch {
+                    component: *component,
+                    document_id: document_id.to_string(),
+                    vulnerability_id: vulnerability_id.cloned(),
+                    product_path: product_path.clone(),
+                    criteria: criteria.clone(),
+                    verdict,
+                });
+            }
+        }
+    }
+
+    /// Matches the components against the products of a document of any CSAF version.
+    ///
+    /// Returns the parse error if the document does not match the types of its CSAF version.
+    pub fn add_csaf_document<'d>(&mut self, doc: &'d CsafDocument) -> Result<(), &'d str> {
+        doc.visit(AddDocument(self))
+    }
+
+    /// Returns the SBOM whose components are matched.
+    pub fn sbom(&self) -> &'a Sbom {
+        self.sbom
+    }
+
+    /// Returns the matches of all documents added so far.
+    pub fn matches(&self) -> &[ComponentMatch] {
+        &self.matches
+    }
+
+    /// Returns the matches of components affected by a vulnerability.
+    pub fn affected(&self) -> impl Iterator<Item = &ComponentMatch> {
+        self.matches.iter().filter(|m| m.verdict.is_affected())
+    }
+
+    /// Returns the matches of components in which a vulnerability is fixed.
+    pub fn fixed(&self) -> impl Iterator<Item = &ComponentMatch> {
+        self.matches
+            .iter()
+            .filter(|m| m.verdict.status == EffectiveStatus::Fixed)
+    }
+
+    /// Returns the matches of components not affected by a vulnerability.
+    pub fn not_affected(&self) -> impl Iterator<Item = &ComponentMatch> {
+        self.matches
+            .iter()
+            .filter(|m| m.verdict.status == EffectiveStatus::NotAffected)
+    }
+
+    pub fn into_matches(self) -> Vec<ComponentMatch> {
+        self.matches
+    }
+}
+
+struct AddDocument<'m, 'a>(&'m mut SbomMatcher<'a>);
+
+impl CsafVisitor for AddDocument<'_, '_> {
+    type Output = ();
+
+    fn visit(self, doc: &impl CsafTrait) {
+        self.0.add_document(doc)
+    }
+}
+
+/// The identifiers of a component, normalized for matching
+struct Identifiers {
+    purls: Vec<PackageUrl<'static>>,
+    cpes: Vec<Vec<String>>,
+    /// Normalized hash algorithm and lower case hash value
+    hashes: Vec<(String, String)>,
+    model_numbers: Vec<String>,
+    serial_numbers: Vec<String>,
+    sbom_urls: Vec<String>,
+}
+
+impl Identifiers {
+    fn new(sbom: &Sbom, component: &Component) -> Self {
+        let mut sbom_urls = component.sbom_urls.clone();
+        if component.described {
+            sbom_urls.extend(sbom.urls.iter().cloned());
+        }
+        Self {
+            purls: component.purls.iter().filter_map(|purl| parse_purl(purl)).collect(),
+            cpes: component.cpes.iter().filter_map(|cpe| parse_cpe(cpe)).collect(),
+            hashes: component
+                .hashes
+                .iter()
+                .map(|hash| (normalize_algorithm(&hash.algorithm), hash.value.to_lowercase()))
+                .collect(),
+            model_numbers: component.model_numbers.clone(),
+            serial_numbers: component.serial_numbers.clone(),
+            sbom_urls,
+        }
+    }
+
+    /// Returns the criteria a product identification helper matches the component on.
+    fn matching_criteria(&self, helper: &impl ProductIdentificationHelperTrait) -> Vec<MatchCriterion> {
+        let mut criteria = Vec::new();
+        let mut purls = helper
+            .get_purls()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|purl| parse_purl(purl));
+        if purls.any(|purl| {
+            self.purls
+                .iter()
+                .any(|component_purl| purl_matches(&purl, component_purl))
+        }) {
+            criteria.push(MatchCriterion::Purl);
+        }
+        if let Some(cpe) = helper.get_cpe().and_then(|cpe| parse_cpe(cpe))
+            && self.cpes.iter().any(|component_cpe| cpe_matches(&cpe, component_cpe))
+        {
+            criteria.push(MatchCriterion::Cpe);
+        }
+        let mut file_hashes = helper.get_hashes().iter().flat_map(|hash| hash.get_file_hashes());
+        if file_hashes.any(|file_hash| {
+            let algorithm = normalize_algorithm(&file_hash.get_algorithm().to_string());
+            let value = file_hash.get_hash().to_lowercase();
+            self.hashes.iter().any(|(a, v)| *a == algorithm && *v == value)
+        }) {
+            criteria.push(MatchCriterion::Hash);
+        }
+        fn numbers_match<'p>(patterns: Option<impl Iterator<Item = &'p String>>, numbers: &[String]) -> bool {
+            patterns
+                .into_iter()
+                .flatten()
+                .any(|pattern| numbers.iter().any(|number| matches_unescaped_stars(pattern, number)))
+        }
+        if numbers_match(helper.get_model_numbers(), &self.model_numbers) {
+            criteria.push(MatchCriterion::ModelNumber);
+        }
+        if numbers_match(helper.get_serial_numbers(), &self.serial_numbers) {
+            criteria.push(MatchCriterion::SerialNumber);
+        }
+        if helper.get_sbom_urls().iter().any(|url| self.sbom_urls.contains(url)) {
+            criteria.push(MatchCriterion::SbomUrl);
+        }
+        criteria
+    }
+}
+
+/// Parses a purl, normalizing the names of types whose names are case-insensitive.
+fn parse_purl(purl: &str) -> Option<PackageUrl<'static>> {
+    let purl = PackageUrl::from_str(purl).ok()?;
+    let normalize: fn(&str) -> String = match purl.ty() {
+        "bitbucket" | "composer" | "github" => str::to_lowercase,
+        "pypi" => |name| name.to_lowercase().replace('_', "-"),
+        _ => return Some(purl),
+    };
+    let mut normalized = PackageUrl::new(purl.ty().to_string(), normalize(purl.name())).ok()?;
+    if let Some(namespace) = purl.namespace() {
+        normalized.with_namespace(normalize(namespace)).ok()?;
+    }
+    if let Some(version) = purl.version() {
+        normalized.with_version(version.to_string()).ok()?;
+    }
+    if let Some(subpath) = purl.subpath() {
+        normalized.with_subpath(subpath.to_string()).ok()?;
+    }
+    for (key, value) in purl.qualifiers() {
+        normalized.add_qualifier(key.to_string(), value.to_string()).ok()?;
+    }
+    Some(normalized)
+}
+
+/// Checks whether the purl of a product matches the purl of a component.
+fn purl_matches(product: &PackageUrl, component: &PackageUrl) -> bool {
+    product.ty() == component.ty()
+        && product.namespace() == component.namespace()
+        && product.name() == component.name()
+        && product.subpath() == component.subpath()
+        && product
+            .version()
+            .is_none_or(|version| component.version() == Some(version))
+        && product
+            .qualifiers()
+            .iter()
+            .all(|(key, value)| component.qualifiers().get(key) == Some(value))
+}
+
+/// Parses a CPE 2.3 formatted string or CPE 2.2 URI into its lower case attributes, starting with
+/// the part.
+fn parse_cpe(cpe: &str) -> Option<Vec<String>> {
+    let cpe = cpe.to_lowercase();
+    if let Some(attributes) = cpe.strip_prefix("cpe:2.3:") {
+        let mut values = Vec::new();
+        let mut value = String::new();
+        let mut escaped = false;
+        for c in attributes.chars() {
+            match c {
+                ':' if !escaped => values.push(std::mem::take(&mut value)),
+                _ => {
+                    escaped = c == '\\' && !escaped;
+                    value.push(c);
+                },
+            }
+        }
+        values.push(value);
+        Some(values)
+    } else {
+        // Attributes missing or empty in a URI match any value, like `*` in a formatted string
+        let attributes = cpe.strip_prefix("cpe:/")?;
+        Some(
+            attributes
+                .split(':')
+                .map(|value| if value.is_empty() { "*" } else { value }.to_string())
+                .collect(),
+        )
+    }
+}
+
+/// Checks whether the CPE attributes of a product match the CPE attributes of a component.
+fn cpe_matches(product: &[String], component: &[String]) -> bool {
+    product
+        .iter()
+        .enumerate()
+        .all(|(i, value)| value == "*" || component.get(i) == Some(value))
+}
+
+/// Normalizes the name of a hash algorithm, so `SHA-256` (CycloneDX), `SHA256` (SPDX) and `sha256`
+/// (CSAF) are equal.
+fn normalize_algorithm(algorithm: &str) -> String {
+    algorithm
+        .chars()
+        .filter(|c| !matches!(c, '-' | '_'))
+        .collect::<String>()
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_documents::{csaf_2_0, csaf_2_1};
+    use serde_json::json;
+
+    fn sbom() -> Sbom {
+        json!({
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.6",
+            "metadata": { "component": { "name": "Appliance", "properties": [{ "name": "serial_number", "value": "A-1234-X" }] } },
+            "components": [
+                { "name": "openssl", "version": "3.0.1", "purl": "pkg:generic/openssl@3.0.1?arch=x86_64" },
+                { "name": "requests", "version": "2.0.0", "purl": "pkg:pypi/Requests@2.0.0" },
+                { "name": "nginx", "cpe": "cpe:2.3:a:f5:nginx:1.25.0:*:*:*:*:*:*:*" },
+                { "name": "lib.so", "hashes": [{ "alg": "SHA-256", "content": "9F86D081884C7D659A2FEAA0C55AD015A3BF4F1B2B0B822CD15D6C15B0F00A08" }] }
+            ]
+        })
+        .to_string()
+        .parse::<Sbom>()
+        .unwrap()
+        .with_url("https://example.com/appliance.cdx.json")
+    }
+
+    #[test]
+    fn test_match_2_1() {
+        let sbom = sbom();
+        let doc = csaf_2_1(json!({
+            "product_tree": {
+                "full_product_names": [
+                    { "name": "OpenSSL 3.0.1", "product_id": "OPENSSL", "product_identification_helper": { "purls": ["pkg:generic/openssl@3.0.1"] } },
+                    { "name": "requests", "product_id": "REQUESTS", "product_identification_helper": { "purls": ["pkg:pypi/requests"] } },
+                    { "name": "nginx 1.25", "product_id": "NGINX", "product_identification_helper": { "cpe": "cpe:2.3:a:f5:nginx:1.25.0:*:*:*:*:*:*:*" } },
+                    { "name": "Library", "product_id": "LIB", "product_identification_helper": { "hashes": [{ "filename": "lib.so", "file_hashes": [{ "algorithm": "sha256", "value": "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08" }] }] } },
+                    { "name": "Appliance", "product_id": "APPLIANCE", "product_identification_helper": { "serial_numbers": ["A-*-X"], "sbom_urls": ["https://example.com/appliance.cdx.json"] } },
+                    { "name": "OpenSSL 1.1", "product_id": "OPENSSL-1.1", "product_identification_helper": { "purls": ["pkg:generic/openssl@1.1.1"] } }
+                ]
+            },
+            "vulnerabilities": [{
+                "cve": "CVE-2024-1234",
+                "product_status": {
+                    "known_affected": ["OPENSSL", "OPENSSL-1.1", "APPLIANCE"],
+                    "fixed": ["REQUESTS"],
+                    "known_not_affected": ["NGINX"]
+                },
+                "flags": [{ "label": "component_not_present", "product_ids": ["NGINX"] }]
+            }]
+        }));
+        let mut matcher = SbomMatcher::new(&sbom);
+        matcher.add_document(&doc);
+
+        let matched = |matches: Vec<&ComponentMatch>| -> Vec<(usize, String, Vec<MatchCriterion>)> {
+            matches
+                .into_iter()
+                .map(|m| (m.component, m.verdict.product_id.clone(), m.criteria.clone()))
+                .collect()
+        };
+        assert_eq!(
+            matched(matcher.affected().collect()),
+            vec![
+                (1, "OPENSSL".to_string(), vec![MatchCriterion::Purl]),
+                (
+                    0,
+                    "APPLIANCE".to_string(),
+                    vec![MatchCriterion::SerialNumber, MatchCriterion::SbomUrl]
+                ),
+            ]
+        );
+        assert_eq!(
+            matched(matcher.fixed().collect()),
+            vec![(2, "REQUESTS".to_string(), vec![MatchCriterion::Purl])]
+        );
+        assert_eq!(
+            matched(matcher.not_affected().collect()),
+            vec![(3, "NGINX".to_string(), vec![MatchCriterion::Cpe])]
+        );
+        assert_eq!(matcher.matches().len(), 4);
+        assert_eq!(matcher.matches()[0].vulnerability_id.as_deref(), Some("CVE-2024-1234"));
+        assert_eq!(matcher.matches()[0].document_id, "UNIT-TEST");
+
+        // Products without a status for a vulnerability are not reported
+        let lib = csaf_2_1(json!({
+            "product_tree": {
+                "full_product_names": [
+                    { "name": "Library", "product_id": "LIB", "product_identification_helper": { "hashes": [{ "filename": "lib.so", "file_hashes": [{ "algorithm": "sha256", "value": "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08" }] }] } }
+                ]
+            },
+            "vulnerabilities": [{ "cve": "CVE-2024-5678", "product_status": { "under_investigation": ["LIB"] } }, { "cve": "CVE-2024-9999" }]
+        }));
+        let mut matcher = SbomMatcher::new(&sbom);
+        matcher.add_document(&lib);
+        assert_eq!(
+            matched(matcher.matches().iter().collect()),
+            vec![(4, "LIB".to_string(), vec![MatchCriterion::Hash])]
+        );
+    }
+
+    #[test]
+    fn test_match_2_0() {
+        let sbom = sbom();
+        let doc = csaf_2_0(json!({
+            "product_tree": {
+                "full_product_names": [
+                    { "name": "OpenSSL", "product_id": "OPENSSL", "product_identification_helper": { "purl": "pkg:generic/openssl?arch=x86_64" } }
+                ]
+            },
+            "vulnerabilities": [{ "cve": "CVE-2024-1234", "product_status": { "known_affected": ["OPENSSL"] } }]
+        }));
+        let mut matcher = SbomMatcher::new(&sbom);
+        matcher
+            .add_csaf_document(&CsafDocument::from_str(&serde_json::to_string(&doc).unwrap()).unwrap())
+            .unwrap();
+        assert_eq!(matcher.affected().count(), 1);
+    }
+
+    #[test]
+    fn test_identifiers() {
+        assert!(matches_unescaped_stars("A-*-X", "A-1234-X"));
+        assert!(matches_unescaped_stars("*", ""));
+        assert!(matches_unescaped_stars("A\\*", "A*"));
+        assert!(!matches_unescaped_stars("A\\*", "AB"));
+        assert!(!matches_unescaped_stars("A-*-X", "A-1234-Y"));
+
+        let cpe = |cpe: &str| parse_cpe(cpe).unwrap();
+        assert!(cpe_matches(
+            &cpe("cpe:/a:f5:nginx"),
+            &cpe("cpe:2.3:a:f5:nginx:1.25.0:*:*:*:*:*:*:*")
+        ));
+        assert!(cpe_matches(
+            &cpe("cpe:2.3:a:F5:nginx:*:*:*:*:*:*:*:*"),
+            &cpe("cpe:/a:f5:nginx:1.25.0")
+        ));
+        assert!(!cpe_matches(
+            &cpe("cpe:2.3:a:f5:nginx:1.24.0"),
+            &cpe("cpe:/a:f5:nginx:1.25.0")
+        ));
+        assert_eq!(cpe("cpe:2.3:a:vendor:product\\:name:1.0")[2], "product\\:name");
+
+        let purl = |purl: &str| parse_purl(purl).unwrap();
+        assert!(purl_matches(
+            &purl("pkg:pypi/Django_Rest"),
+            &purl("pkg:pypi/django-rest@1.0")
+        ));
+        assert!(!purl_matches(
+            &purl("pkg:maven/org.example/Lib"),
+            &purl("pkg:maven/org.example/lib")
+        ));
+        assert!(!purl_matches(
+            &purl("pkg:npm/left-pad?os=linux"),
+            &purl("pkg:npm/left-pad")
+        ));
+
+        assert_eq!(normalize_algorithm("SHA3-256"), normalize_algorithm("sha3-256"));
+        assert_eq!(normalize_algorithm("SHA_256"), "sha256");
+    }
+}
@@ -0,0 +1,156 @@
+//! Software bills of materials (SBOMs) and the components they list.
+//!
+//! SBOMs are read from CycloneDX JSON or SPDX 2 JSON files. The [SbomMatcher] finds the products
+//! of CSAF documents among the components and reports whether they are affected, fixed or not
+//! affected by the vulnerabilities of the documents.
+//!
+//! ```no_run
+//! # use csaf::CsafDocument;
+//! # use csaf::sbom::{Sbom, SbomMatcher};
+//! let sbom = Sbom::load("bom.cdx.json".as_ref())?;
+//! let advisory = CsafDocument::load(std::path::Path::new("advisory.json"))?;
+//! let mut matcher = SbomMatcher::new(&sbom);
+//! matcher.add_csaf_document(&advisory).ok();
+//! for component_match in matcher.affected() {
+//!     println!("{} is affected", sbom.components[component_match.component].name);
+//! }
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod cyclonedx;
+mod matcher;
+mod spdx;
+
+pub use matcher::{ComponentMatch, MatchCriterion, SbomMatcher};
+
+use std::path::Path;
+use std::str::FromStr;
+
+use serde_json::Value;
+
+use crate::csaf::loader::{DEFAULT_MAX_SIZE, LoadError, read_file};
+
+/// Reasons why an SBOM cannot be loaded.
+#[derive(Debug, thiserror::Error)]
+pub enum SbomError {
+    #[error(transparent)]
+    Load(#[from] LoadError),
+    #[error("unknown SBOM format, supported formats are CycloneDX JSON and SPDX 2 JSON")]
+    UnknownFormat,
+}
+
+impl From<serde_json::Error> for SbomError {
+    fn from(err: serde_json::Error) -> Self {
+        SbomError::Load(err.into())
+    }
+}
+
+/// Format of an SBOM
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SbomFormat {
+    CycloneDx,
+    Spdx,
+}
+
+/// A hash of a component, with the algorithm as named by the SBOM format (like `SHA-256`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentHash {
+    pub algorithm: String,
+    pub value: String,
+}
+
+/// A component (CycloneDX) or package or file (SPDX) listed in an SBOM
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Component {
+    /// JSON path of the component in the SBOM
+    pub path: String,
+    /// The `bom-ref` (CycloneDX) or `SPDXID` (SPDX) of the component
+    pub reference: Option<String>,
+    pub name: String,
+    pub version: Option<String>,
+    pub purls: Vec<String>,
+    pub cpes: Vec<String>,
+    pub hashes: Vec<ComponentHash>,
+    pub model_numbers: Vec<String>,
+    pub serial_numbers: Vec<String>,
+    /// URLs of SBOMs describing the component
+    pub sbom_urls: Vec<String>,
+    /// Whether the SBOM as a whole describes this component
+    pub described: bool,
+}
+
+/// An SBOM, see the [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sbom {
+    pub format: SbomFormat,
+    /// URLs identifying the SBOM, matched against the `sbom_urls` of CSAF products
+    pub urls: Vec<String>,
+    pub components: Vec<Component>,
+}
+
+impl Sbom {
+    /// Loads the SBOM at `path`, detecting its format.
+    pub fn load(path: &Path) -> Result<Self, SbomError> {
+        read_file(path, DEFAULT_MAX_SIZE)?.parse()
+    }
+
+    /// Reads an SBOM from its JSON, detecting its format.
+    pub fn from_json(json: &Value) -> Result<Self, SbomError> {
+        if json.get("bomFormat").and_then(Value::as_str) == Some("CycloneDX") {
+            Ok(cyclonedx::parse(json))
+        } else if json
+            .get("spdxVersion")
+            .and_then(Value::as_str)
+            .is_some_and(|version| version.starts_with("SPDX-2."))
+        {
+            Ok(spdx::parse(json))
+        } else {
+            Err(SbomError::UnknownFormat)
+        }
+    }
+
+    /// Adds a URL the SBOM is published at, like the URL it was downloaded from.
+    pub fn with_url(mut self, url: &str) -> Self {
+        self.urls.push(url.to_string());
+        self
+    }
+}
+
+impl FromStr for Sbom {
+    type Err = SbomError;
+
+    fn from_str(json_str: &str) -> Result<Self, Self::Err> {
+        Sbom::from_json(&serde_json::from_str(json_str)?)
+    }
+}
+
+/// Returns the string at `key` of a JSON object.
+fn string(json: &Value, key: &str) -> Option<String> {
+    json.get(key).and_then(Value::as_str).map(str::to_string)
+}
+
+/// Returns the items of the array at `key` of a JSON object.
+fn array<'a>(json: &'a Value, key: &str) -> impl Iterator<Item = &'a Value> {
+    json.get(key).and_then(Value::as_array).into_iter().flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_format() {
+        let cyclonedx: Sbom = r#"{"bomFormat": "CycloneDX", "specVersion": "1.6"}"#.parse().unwrap();
+        assert_eq!(cyclonedx.format, SbomFormat::CycloneDx);
+        let spdx: Sbom = r#"{"spdxVersion": "SPDX-2.3"}"#.parse().unwrap();
+        assert_eq!(spdx.format, SbomFormat::Spdx);
+        assert!(matches!(
+            r#"{"document": {}}"#.parse::<Sbom>(),
+            Err(SbomError::UnknownFormat)
+        ));
+        assert!(matches!(
+            "{".parse::<Sbom>(),
+            Err(SbomError::Load(LoadError::Syntax { .. }))
+        ));
+    }
+}
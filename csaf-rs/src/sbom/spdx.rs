@@ -0,0 +1,110 @@
+//! Packages and files of SPDX 2 JSON SBOMs.
+//!
+//! The `documentNamespace` identifies the SBOM. Packages are identified by their `purl`, `cpe23Type`
+//! and `cpe22Type` external references, packages and files by their checksums.
+
+use serde_json::Value;
+
+use crate::sbom::{Component, ComponentHash, Sbom, SbomFormat, array, string};
+
+/// SPDX ID of the SBOM itself
+const DOCUMENT_ID: &str = "SPDXRef-DOCUMENT";
+
+pub(super) fn parse(json: &Value) -> Sbom {
+    // Elements the SBOM describes, listed in `documentDescribes` or by `DESCRIBES` relationships
+    let mut described: Vec<&str> = array(json, "documentDescribes").filter_map(Value::as_str).collect();
+    for relationship in array(json, "relationships") {
+        let field = |key: &str| relationship.get(key).and_then(Value::as_str);
+        if field("spdxElementId") == Some(DOCUMENT_ID)
+            && field("relationshipType") == Some("DESCRIBES")
+            && let Some(element) = field("relatedSpdxElement")
+        {
+            described.push(element);
+        }
+    }
+
+    let mut components = Vec::new();
+    for (key, name_key, version_key) in [("packages", "name", Some("versionInfo")), ("files", "fileName", None)] {
+        for (i, element) in array(json, key).enumerate() {
+            let reference = string(element, "SPDXID");
+            let external_references = |types: &[&str]| -> Vec<String> {
+                array(element, "externalRefs")
+                    .filter(|reference| {
+                        reference
+                            .get("referenceType")
+                            .and_then(Value::as_str)
+                            .is_some_and(|ty| types.contains(&ty))
+                    })
+                    .filter_map(|reference| string(reference, "referenceLocator"))
+                    .collect()
+            };
+            components.push(Component {
+                path: format!("/{key}/{i}"),
+                described: reference.as_deref().is_some_and(|id| described.contains(&id)),
+                reference,
+                name: string(element, name_key).unwrap_or_default(),
+                version: version_key.and_then(|version_key| string(element, version_key)),
+                purls: external_references(&["purl"]),
+                cpes: external_references(&["cpe23Type", "cpe22Type"]),
+                hashes: array(element, "checksums")
+                    .filter_map(|checksum| {
+                        Some(ComponentHash {
+                            algorithm: string(checksum, "algorithm")?,
+                            value: string(checksum, "checksumValue")?,
+                        })
+                    })
+                    .collect(),
+                ..Default::default()
+            });
+        }
+    }
+
+    Sbom {
+        format: SbomFormat::Spdx,
+        urls: string(json, "documentNamespace").into_iter().collect(),
+        components,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse() {
+        let sbom = parse(&json!({
+            "spdxVersion": "SPDX-2.3",
+            "SPDXID": "SPDXRef-DOCUMENT",
+            "documentNamespace": "https://example.com/spdx/app-1.0",
+            "packages": [
+                { "SPDXID": "SPDXRef-App", "name": "App", "versionInfo": "1.0" },
+                {
+                    "SPDXID": "SPDXRef-Lib",
+                    "name": "lib",
+                    "externalRefs": [
+                        { "referenceCategory": "PACKAGE-MANAGER", "referenceType": "purl", "referenceLocator": "pkg:npm/lib@1.2.3" },
+                        { "referenceCategory": "SECURITY", "referenceType": "cpe23Type", "referenceLocator": "cpe:2.3:a:example:lib:1.2.3:*:*:*:*:*:*:*" }
+                    ]
+                }
+            ],
+            "files": [{
+                "SPDXID": "SPDXRef-File",
+                "fileName": "./lib.so",
+                "checksums": [{ "algorithm": "SHA256", "checksumValue": "abcdef" }]
+            }],
+            "relationships": [
+                { "spdxElementId": "SPDXRef-DOCUMENT", "relationshipType": "DESCRIBES", "relatedSpdxElement": "SPDXRef-App" }
+            ]
+        }));
+        assert_eq!(sbom.urls, vec!["https://example.com/spdx/app-1.0"]);
+        let described: Vec<bool> = sbom.components.iter().map(|c| c.described).collect();
+        assert_eq!(described, vec![true, false, false]);
+        assert_eq!(sbom.components[0].version.as_deref(), Some("1.0"));
+        assert_eq!(sbom.components[1].purls, vec!["pkg:npm/lib@1.2.3"]);
+        assert_eq!(sbom.components[1].cpes.len(), 1);
+        assert_eq!(sbom.components[2].path, "/files/0");
+        assert_eq!(sbom.components[2].name, "./lib.so");
+        assert_eq!(sbom.components[2].hashes[0].algorithm, "SHA256");
+    }
+}
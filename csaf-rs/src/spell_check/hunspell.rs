@@ -0,0 +1,553 @@
+//! Reader for dictionaries in the Hunspell format (`.aff` and `.dic` files).
+//!
+//! Supports the parts of the format needed to check and correct words: flag types (`FLAG`), flag
+//! aliases (`AF`), prefixes and suffixes including their combination (`PFX`, `SFX`), `TRY`, `REP`,
+//! `NEEDAFFIX`, `ONLYINCOMPOUND`, `FORBIDDENWORD` and `NOSUGGEST`. Compounding and twofold affix
+//! stripping are not supported.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Maximum number of suggestions for a misspelled word
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Reasons why a dictionary cannot be loaded.
+#[derive(Debug, thiserror::Error)]
+pub enum DictionaryError {
+    #[error("failed to read the dictionary: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid {file} file in line {line}: {message}")]
+    Syntax {
+        /// The file containing the error, `aff` or `dic`
+        file: &'static str,
+        /// Line of the error, starting at 1
+        line: usize,
+        message: String,
+    },
+}
+
+type Flag = u32;
+
+/// Encoding of flags in the affix and dictionary files
+#[derive(Debug, Clone, Copy)]
+enum FlagType {
+    /// One character per flag (the default)
+    Char,
+    /// Two characters per flag
+    Long,
+    /// Comma separated numbers
+    Num,
+}
+
+impl FlagType {
+    fn parse(&self, flags: &str) -> Result<Vec<Flag>, String> {
+        match self {
+            FlagType::Char => Ok(flags.chars().map(Flag::from).collect()),
+            FlagType::Long => {
+                let chars: Vec<char> = flags.chars().collect();
+                if !chars.len().is_multiple_of(2) {
+                    return Err(format!("odd number of characters in long flags '{flags}'"));
+                }
+                Ok(chars
+                    .chunks(2)
+                    .map(|pair| (Flag::from(pair[0]) << 16) | Flag::from(pair[1]))
+                    .collect())
+            },
+            FlagType::Num => flags
+                .split(',')
+                .map(|flag| {
+                    flag.trim()
+                        .parse()
+                        .map_err(|_| format!("invalid numeric flag '{flag}'"))
+                })
+                .collect(),
+        }
+    }
+}
+
+/// One element of an affix condition
+#[derive(Debug, Clone)]
+enum ConditionElement {
+    Any,
+    Char(char),
+    Set { chars: Vec<char>, negated: bool },
+}
+
+impl ConditionElement {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            ConditionElement::Any => true,
+            ConditionElement::Char(expected) => c == *expected,
+            ConditionElement::Set { chars, negated } => chars.contains(&c) != *negated,
+        }
+    }
+}
+
+fn parse_condition(condition: &str) -> Result<Vec<ConditionElement>, String> {
+    if condition == "." {
+        return Ok(vec![]);
+    }
+    let mut elements = Vec::new();
+    let mut chars = condition.chars();
+    while let Some(c) = chars.next() {
+        elements.push(match c {
+            '.' => ConditionElement::Any,
+            '[' => {
+                let mut set: Vec<char> = Vec::new();
+                let mut closed = false;
+                for c in chars.by_ref() {
+                    if c == ']' {
+                        closed = true;
+                        break;
+                    }
+                    set.push(c);
+                }
+                if !closed {
+                    return Err(format!("unclosed bracket in condition '{condition}'"));
+                }
+                let negated = set.first() == Some(&'^');
+                if negated {
+                    set.remove(0);
+                }
+                ConditionElement::Set { chars: set, negated }
+            },
+            c => ConditionElement::Char(c),
+        });
+    }
+    Ok(elements)
+}
+
+/// A prefix or suffix rule
+#[derive(Debug, Clone)]
+struct Affix {
+    flag: Flag,
+    cross_product: bool,
+    strip: String,
+    add: String,
+    condition: Vec<ConditionElement>,
+}
+
+impl Affix {
+    /// Returns the stem of `word` if this suffix can be removed from it.
+    fn strip_suffix(&self, word: &str) -> Option<String> {
+        let base = word.strip_suffix(self.add.as_str())?;
+        if base.is_empty() && self.strip.is_empty() {
+            return None;
+        }
+        let stem = format!("{base}{}", self.strip);
+        let tail: Vec<char> = stem.chars().rev().take(self.condition.len()).collect();
+        let matches = tail.len() == self.condition.len()
+            && self
+                .condition
+                .iter()
+                .rev()
+                .zip(&tail)
+                .all(|(element, c)| element.matches(*c));
+        matches.then_some(stem)
+    }
+
+    /// Returns the stem of `word` if this prefix can be removed from it.
+    fn strip_prefix(&self, word: &str) -> Option<String> {
+        let base = word.strip_prefix(self.add.as_str())?;
+        if base.is_empty() && self.strip.is_empty() {
+            return None;
+        }
+        let stem = format!("{}{base}", self.strip);
+        let head: Vec<char> = stem.chars().take(self.condition.len()).collect();
+        let matches = head.len() == self.condition.len()
+            && self.condition.iter().zip(&head).all(|(element, c)| element.matches(*c));
+        matches.then_some(stem)
+    }
+}
+
+/// A Hunspell dictionary.
+#[derive(Debug, Clone, Default)]
+pub struct Dictionary {
+    /// Flags of all homonyms of a word
+    words: HashMap<String, Vec<Vec<Flag>>>,
+    prefixes: Vec<Affix>,
+    suffixes: Vec<Affix>,
+    try_chars: Vec<char>,
+    replacements: Vec<(String, String)>,
+    need_affix: Option<Flag>,
+    only_in_compound: Option<Flag>,
+    forbidden_word: Option<Flag>,
+    no_suggest: Option<Flag>,
+}
+
+impl Dictionary {
+    /// Loads a dictionary from its affix (`.aff`) and word list (`.dic`) file.
+    ///
+    /// The files are decoded as UTF-8 or, if the affix file declares another encoding with `SET`,
+    /// as ISO 8859-1.
+    pub fn load(aff_path: &Path, dic_path: &Path) -> Result<Self, DictionaryError> {
+        log::debug!("Loading dictionary {}", dic_path.display());
+
+        let aff = std::fs::read(aff_path)?;
+        let dic = std::fs::read(dic_path)?;
+        let is_utf8 = String::from_utf8_lossy(&aff)
+            .lines()
+            .find_map(|line| line.trim().strip_prefix("SET "))
+            .is_none_or(|encoding| encoding.trim().eq_ignore_ascii_case("UTF-8"));
+        let decode = |bytes: &[u8]| {
+            if is_utf8 {
+                String::from_utf8_lossy(bytes).into_owned()
+            } else {
+                bytes.iter().map(|b| char::from(*b)).collect()
+            }
+        };
+        Self::parse(&decode(&aff), &decode(&dic))
+    }
+
+    /// Parses a dictionary from the content of its affix and word list file.
+    pub fn parse(aff: &str, dic: &str) -> Result<Self, DictionaryError> {
+        let mut dictionary = Dictionary::default();
+        let (aliases, flag_type) = dictionary.parse_aff(aff)?;
+        dictionary.parse_dic(dic, &aliases, flag_type)?;
+        Ok(dictionary)
+    }
+
+    /// Parses the affix file, returns the flag aliases and the flag type.
+    fn parse_aff(&mut self, aff: &str) -> Result<(Vec<Vec<Flag>>, FlagType), DictionaryError> {
+        let syntax_error = |line: usize, message: String| DictionaryError::Syntax {
+            file: "aff",
+            line: line + 1,
+            message,
+        };
+
+        let mut flag_type = FlagType::Char;
+        let mut aliases = Vec::new();
+        let mut alias_header_seen = false;
+        // Whether the rules of an affix class can be combined with other affixes, by kind and flag
+        let mut cross_products: HashMap<(&str, Flag), bool> = HashMap::new();
+
+        for (i, line) in aff.lines().enumerate() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let parse_flag = |flag: &str| {
+                flag_type
+                    .parse(flag)
+                    .and_then(|flags| flags.first().copied().ok_or_else(|| "missing flag".to_string()))
+                    .map_err(|message| syntax_error(i, message))
+            };
+            match fields.as_slice() {
+                ["FLAG", "long", ..] => flag_type = FlagType::Long,
+                ["FLAG", "num", ..] => flag_type = FlagType::Num,
+                ["TRY", chars, ..] => self.try_chars = chars.chars().collect(),
+                ["REP", from, to, ..] => {
+                    self.replacements.push((from.replace('_', " "), to.replace('_', " ")));
+                },
+                // The first line only holds the number of aliases
+                ["AF", _] if !alias_header_seen => alias_header_seen = true,
+                ["AF", flags, ..] => {
+                    aliases.push(flag_type.parse(flags).map_err(|message| syntax_error(i, message))?);
+                },
+                ["NEEDAFFIX", flag, ..] => self.need_affix = Some(parse_flag(flag)?),
+                ["ONLYINCOMPOUND", flag, ..] => self.only_in_compound = Some(parse_flag(flag)?),
+                ["FORBIDDENWORD", flag, ..] => self.forbidden_word = Some(parse_flag(flag)?),
+                ["NOSUGGEST", flag, ..] => self.no_suggest = Some(parse_flag(flag)?),
+                [kind @ ("PFX" | "SFX"), flag, rest @ ..] => {
+                    let flag = parse_flag(flag)?;
+                    let Some(cross_product) = cross_products.get(&(*kind, flag)).copied() else {
+                        // The first line of an affix class is its header
+                        cross_products.insert((*kind, flag), rest.first() == Some(&"Y"));
+                        continue;
+                    };
+                    let [strip, add, condition @ ..] = rest else {
+                        return Err(syntax_error(i, format!("incomplete {kind} rule")));
+                    };
+                    // Continuation flags after the affix are not supported
+                    let add = add.split('/').next().unwrap_or_default();
+                    let condition = condition.first().copied().unwrap_or(".");
+                    let affix = Affix {
+                        flag,
+                        cross_product,
+                        strip: if *strip == "0" {
+                            String::new()
+                        } else {
+                            strip.to_string()
+                        },
+                        add: if add == "0" { String::new() } else { add.to_string() },
+                        condition: parse_condition(condition).map_err(|message| syntax_error(i, message))?,
+                    };
+                    if *kind == "PFX" {
+                        self.prefixes.push(affix);
+                    } else {
+                        self.suffixes.push(affix);
+                    }
+                },
+                _ => {},
+            }
+        }
+
+        Ok((aliases, flag_type))
+    }
+
+    fn parse_dic(&mut self, dic: &str, aliases: &[Vec<Flag>], flag_type: FlagType) -> Result<(), DictionaryError> {
+        // The first line holds the approximate number of words
+        for (i, line) in dic.lines().enumerate().skip(1) {
+            let Some(entry) = line.split_whitespace().next() else {
+                continue;
+            };
+            let (word, flags) = match entry.split_once('/') {
+                Some((word, flags)) if !word.is_empty() => (word, flags),
+                _ => (entry, ""),
+            };
+            let flags = if flags.is_empty() {
+                vec![]
+            } else if aliases.is_empty() {
+                flag_type.parse(flags).map_err(|message| DictionaryError::Syntax {
+                    file: "dic",
+                    line: i + 1,
+                    message,
+                })?
+            } else {
+                flags
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|alias| aliases.get(alias.wrapping_sub(1)))
+                    .cloned()
+                    .ok_or_else(|| DictionaryError::Syntax {
+                        file: "dic",
+                        line: i + 1,
+                        message: format!("unknown flag alias '{flags}'"),
+                    })?
+            };
+            self.words.entry(word.to_string()).or_default().push(flags);
+        }
+        Ok(())
+    }
+
+    /// Returns whether a word is spelled correctly.
+    ///
+    /// Capitalized and upper case words are also accepted if their lower case or capitalized form
+    /// is in the dictionary.
+    pub fn check(&self, word: &str) -> bool {
+        if self.check_exact(word) {
+            return true;
+        }
+        let lower = word.to_lowercase();
+        if lower == word {
+            return false;
+        }
+        if self.check_exact(&lower) {
+            return true;
+        }
+        let is_upper = word.chars().all(|c| !c.is_lowercase());
+        is_upper && self.check_exact(&capitalize(&lower))
+    }
+
+    fn check_exact(&self, word: &str) -> bool {
+        let Some(homonyms) = self.words.get(word) else {
+            return self.check_affixed(word);
+        };
+        if homonyms.iter().any(|flags| self.has(flags, self.forbidden_word)) {
+            return false;
+        }
+        homonyms
+            .iter()
+            .any(|flags| !self.has(flags, self.need_affix) && !self.has(flags, self.only_in_compound))
+            || self.check_affixed(word)
+    }
+
+    fn check_affixed(&self, word: &str) -> bool {
+        for suffix in &self.suffixes {
+            if let Some(stem) = suffix.strip_suffix(word)
+                && self.stem_has_flags(&stem, &[suffix.flag])
+            {
+                return true;
+            }
+        }
+        for prefix in &self.prefixes {
+            let Some(stem) = prefix.strip_prefix(word) else {
+                continue;
+            };
+            if self.stem_has_flags(&stem, &[prefix.flag]) {
+                return true;
+            }
+            if !prefix.cross_product {
+                continue;
+            }
+            for suffix in self.suffixes.iter().filter(|suffix| suffix.cross_product) {
+                if let Some(stem) = suffix.strip_suffix(&stem)
+                    && self.stem_has_flags(&stem, &[prefix.flag, suffix.flag])
+                {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    fn stem_has_flags(&self, stem: &str, required: &[Flag]) -> bool {
+        self.words.get(stem).is_some_and(|homonyms| {
+            homonyms
+                .iter()
+                .any(|flags| required.iter().all(|flag| flags.contains(flag)) && !self.has(flags, self.forbidden_word))
+        })
+    }
+
+    fn has(&self, flags: &[Flag], flag: Option<Flag>) -> bool {
+        flag.is_some_and(|flag| flags.contains(&flag))
+    }
+
+    /// Returns corrections for a misspelled word, the most likely first.
+    ///
+    /// Candidates are built with the replacement table (`REP`), by swapping, replacing, removing
+    /// and inserting characters from `TRY`, and by splitting the word in two.
+    pub fn suggest(&self, word: &str) -> Vec<String> {
+        let chars: Vec<char> = word.chars().collect();
+        let mut candidates: Vec<String> = Vec::new();
+
+        for (from, to) in &self.replacements {
+            for (i, _) in word.match_indices(from.as_str()) {
+                candidates.push(format!("{}{to}{}", &word[..i], &word[i + from.len()..]));
+            }
+        }
+        for i in 1..chars.len() {
+            let mut swapped = chars.clone();
+            swapped.swap(i - 1, i);
+            candidates.push(swapped.iter().collect());
+        }
+        for i in 0..chars.len() {
+            for c in &self.try_chars {
+                let mut replaced = chars.clone();
+                replaced[i] = *c;
+                candidates.push(replaced.iter().collect());
+            }
+        }
+        for i in 0..chars.len() {
+            let mut removed = chars.clone();
+            removed.remove(i);
+            candidates.push(removed.iter().collect());
+        }
+        for i in 0..=chars.len() {
+            for c in &self.try_chars {
+                let mut inserted = chars.clone();
+                inserted.insert(i, *c);
+                candidates.push(inserted.iter().collect());
+            }
+        }
+
+        let mut suggestions: Vec<String> = Vec::new();
+        for candidate in candidates {
+            if candidate != word && !suggestions.contains(&candidate) && self.is_suggestible(&candidate) {
+                suggestions.push(candidate);
+            }
+        }
+        for i in 1..chars.len() {
+            let (first, second): (String, String) = (chars[..i].iter().collect(), chars[i..].iter().collect());
+            if self.is_suggestible(&first) && self.is_suggestible(&second) {
+                suggestions.push(format!("{first} {second}"));
+            }
+        }
+        suggestions.truncate(MAX_SUGGESTIONS);
+        suggestions
+    }
+
+    fn is_suggestible(&self, word: &str) -> bool {
+        let no_suggest = self
+            .words
+            .get(word)
+            .is_some_and(|homonyms| homonyms.iter().any(|flags| self.has(flags, self.no_suggest)));
+        !no_suggest && self.check(word)
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AFF: &str = "SET UTF-8
+TRY esianrtolcdugmphbyfvkwz
+REP 1
+REP f ph
+NEEDAFFIX X
+
+PFX U Y 1
+PFX U 0 un .
+
+SFX S Y 3
+SFX S 0 s [^hy]
+SFX S 0 es h
+SFX S y ies y
+
+SFX D N 1
+SFX D 0 ed .
+";
+
+    const DIC: &str = "6
+patch/SU
+vulnerability/S
+exploit/D
+phone
+Paris
+affix/X
+";
+
+    #[test]
+    fn test_check() {
+        let dictionary = Dictionary::parse(AFF, DIC).unwrap();
+        for word in [
+            "patch",
+            "patches",
+            "unpatch",
+            "unpatches",
+            "vulnerabilities",
+            "exploited",
+            "Patch",
+            "PATCH",
+            "Paris",
+            "PARIS",
+        ] {
+            assert!(dictionary.check(word), "{word}");
+        }
+        for word in [
+            "patchs",
+            "vulnerabilitys",
+            "unexploited",
+            "paris",
+            "affix",
+            "patchd",
+            "fone",
+        ] {
+            assert!(!dictionary.check(word), "{word}");
+        }
+    }
+
+    #[test]
+    fn test_suggest() {
+        let dictionary = Dictionary::parse(AFF, DIC).unwrap();
+        assert_eq!(dictionary.suggest("fone"), vec!["phone"]);
+        assert_eq!(dictionary.suggest("ptach"), vec!["patch"]);
+        assert_eq!(dictionary.suggest("exploitd"), vec!["exploit", "exploited"]);
+        assert_eq!(dictionary.suggest("patchphone"), vec!["patch phone"]);
+    }
+
+    #[test]
+    fn test_flag_types_and_aliases() {
+        let aff = "FLAG long\nAF 1\nAF S1\nSFX S1 Y 1\nSFX S1 0 s .\n";
+        let dictionary = Dictionary::parse(aff, "1\npatch/1\n").unwrap();
+        assert!(dictionary.check("patchs"));
+
+        let aff = "FLAG num\nSFX 100 Y 1\nSFX 100 0 s .\n";
+        let dictionary = Dictionary::parse(aff, "1\npatch/7,100\n").unwrap();
+        assert!(dictionary.check("patchs"));
+
+        assert!(matches!(
+            Dictionary::parse(aff, "1\npatch/x\n"),
+            Err(DictionaryError::Syntax {
+                file: "dic",
+                line: 2,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,218 @@
+//! Offline spell checking of free-text fields, used by test 6.3.8.
+//!
+//! Words are checked against dictionaries in the Hunspell format, which are picked by the
+//! `/document/lang` of a document from local directories. Product names and vendor jargon can be
+//! accepted with an allowlist. The spell checker is attached to the document to validate, test 6.3.8
+//! is skipped for documents without one or without a dictionary for their language.
+//!
+//! ```no_run
+//! # use std::path::Path;
+//! # use std::sync::Arc;
+//! # use csaf::CsafDocument;
+//! # use csaf::spell_check::SpellChecker;
+//! # use csaf::validation::ValidationPreset;
+//! let spell_checker = SpellChecker::new()
+//!     .dictionary_dir("/usr/share/hunspell")
+//!     .allow(["OpenSSL", "backport"]);
+//! let document = CsafDocument::load(Path::new("advisory.json"))
+//!     .unwrap()
+//!     .with_spell_checker(Arc::new(spell_checker));
+//! let result = document.validate(ValidationPreset::Full);
+//! ```
+
+mod hunspell;
+
+pub use hunspell::{Dictionary, DictionaryError};
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Language of documents without `/document/lang`
+pub const DEFAULT_LANGUAGE: &str = "en";
+
+/// A possibly misspelled word
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Misspelling {
+    /// The word as written in the text
+    pub word: String,
+    /// Corrections for the word, the most likely first
+    pub suggestions: Vec<String>,
+}
+
+/// Checks texts with the dictionary of their language, see the [module documentation](self).
+#[derive(Debug, Default)]
+pub struct SpellChecker {
+    dictionary_dirs: Vec<PathBuf>,
+    /// Allowed words in lower case
+    allowlist: HashSet<String>,
+    /// Dictionaries by lower case language tag, `None` if there is no dictionary for the language
+    dictionaries: Mutex<HashMap<String, Option<Arc<Dictionary>>>>,
+}
+
+impl SpellChecker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a directory with dictionaries named by their language, like `en_US.aff` and `en_US.dic`.
+    ///
+    /// Dictionaries are loaded when they are used for the first time.
+    pub fn dictionary_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.dictionary_dirs.push(dir.into());
+        self
+    }
+
+    /// Uses a dictionary for a language tag, in favor of the dictionaries in the directories.
+    pub fn dictionary(self, lang: &str, dictionary: Dictionary) -> Self {
+        self.dictionaries
+            .lock()
+            .expect("dictionary cache is not poisoned")
+            .insert(lang.to_lowercase(), Some(Arc::new(dictionary)));
+        self
+    }
+
+    /// Accepts words regardless of the dictionary, ignoring case.
+    pub fn allow<S: AsRef<str>>(mut self, words: impl IntoIterator<Item = S>) -> Self {
+        self.allowlist
+            .extend(words.into_iter().map(|word| word.as_ref().to_lowercase()));
+        self
+    }
+
+    /// Accepts the words listed in a file, one word per line. Lines starting with `#` are ignored.
+    pub fn allowlist_file(self, path: &Path) -> std::io::Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        let words = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'));
+        Ok(self.allow(words))
+    }
+
+    /// Returns the dictionary for a language tag like `en-US`.
+    ///
+    /// Looks for a dictionary of the exact language and region (`en_US`), then of the language
+    /// (`en`), then of the language in any region (`en_GB`).
+    pub fn dictionary_for(&self, lang: &str) -> Option<Arc<Dictionary>> {
+        let key = lang.to_lowercase();
+        let mut dictionaries = self.dictionaries.lock().expect("dictionary cache is not poisoned");
+        dictionaries
+            .entry(key)
+            .or_insert_with(|| self.load_dictionary(lang).map(Arc::new))
+            .clone()
+    }
+
+    fn load_dictionary(&self, lang: &str) -> Option<Dictionary> {
+        let normalized = lang.replace('-', "_");
+        let language = normalized.split('_').next().unwrap_or_default();
+        let dic_path = self
+            .find_dic(|name| name.eq_ignore_ascii_case(&normalized))
+            .or_else(|| self.find_dic(|name| name.eq_ignore_ascii_case(language)))
+            .or_else(|| {
+                self.find_dic(|name| {
+                    name.split_once('_')
+                        .is_some_and(|(prefix, _)| prefix.eq_ignore_ascii_case(language))
+                })
+            })?;
+        match Dictionary::load(&dic_path.with_extension("aff"), &dic_path) {
+            Ok(dictionary) => Some(dictionary),
+            Err(err) => {
+                log::warn!("Failed to load dictionary {}: {err}", dic_path.display());
+                None
+            },
+        }
+    }
+
+    /// Returns the first `.dic` file in the dictionary directories whose name matches.
+    fn find_dic(&self, matches: impl Fn(&str) -> bool) -> Option<PathBuf> {
+        self.dictionary_dirs.iter().find_map(|dir| {
+            let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)
+                .inspect_err(|err| log::warn!("Failed to read dictionary directory {}: {err}", dir.display()))
+                .ok()?
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| {
+                    path.extension().is_some_and(|extension| extension == "dic")
+                        && path.file_stem().and_then(|stem| stem.to_str()).is_some_and(&matches)
+                })
+                .collect();
+            paths.sort();
+            paths.into_iter().next()
+        })
+    }
+
+    /// Returns the misspelled words of a text, each word once.
+    ///
+    /// Parts of the text that look like URLs, e-mail addresses, paths or identifiers are skipped, as
+    /// are single letters, words with mixed case (like `iPhone`) and allowed words.
+    pub fn check_text(&self, dictionary: &Dictionary, text: &str) -> Vec<Misspelling> {
+        let mut misspellings: Vec<Misspelling> = Vec::new();
+        for word in words(text) {
+            if word.chars().count() < 2
+                || is_mixed_case(&word)
+                || self.allowlist.contains(&word.to_lowercase())
+                || misspellings.iter().any(|misspelling| misspelling.word == word)
+                || dictionary.check(&word)
+            {
+                continue;
+            }
+            misspellings.push(Misspelling {
+                suggestions: dictionary.suggest(&word),
+                word,
+            });
+        }
+        misspellings
+    }
+}
+
+/// Splits a text into words, see [SpellChecker::check_text].
+fn words(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .filter(|chunk| !["://", "@", "/", "\\", "_"].iter().any(|marker| chunk.contains(marker)))
+        .flat_map(|chunk| chunk.split(|c: char| !c.is_alphabetic() && c != '\'' && c != '’'))
+        .map(|word| word.trim_matches(['\'', '’']).replace('’', "'"))
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+fn is_mixed_case(word: &str) -> bool {
+    let has_lower = word.chars().any(char::is_lowercase);
+    has_lower && word.chars().skip(1).any(char::is_uppercase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_text() {
+        let dictionary = Dictionary::parse("TRY eht\n", "6\nthe\nbuffer\noverflow\nin\nsee\nand\n").unwrap();
+        let spell_checker = SpellChecker::new().allow(["OpenSSL", "CSAF"]);
+        assert_eq!(
+            spell_checker.check_text(
+                &dictionary,
+                "Teh buffer overflow in OpenSSL, see https://example.com/advisory, a iPhone, csaf and Teh ’overflow’"
+            ),
+            vec![Misspelling {
+                word: "Teh".to_string(),
+                suggestions: vec!["The".to_string()],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_dictionary_for() {
+        let dir = std::env::temp_dir().join(format!("csaf-spell-check-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("de_AT.aff"), "").unwrap();
+        std::fs::write(dir.join("de_AT.dic"), "1\nSicherheitslücke\n").unwrap();
+        std::fs::write(dir.join("en.aff"), "").unwrap();
+        std::fs::write(dir.join("en.dic"), "1\nvulnerability\n").unwrap();
+
+        let spell_checker = SpellChecker::new().dictionary_dir(&dir);
+        assert!(spell_checker.dictionary_for("en-US").unwrap().check("vulnerability"));
+        assert!(spell_checker.dictionary_for("de-DE").unwrap().check("Sicherheitslücke"));
+        assert!(spell_checker.dictionary_for("fr").is_none());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,436 @@
+//! Consistency of a new revision of a document with its previously published revision.
+//!
+//! Tests 6.1.14 to 6.1.22 check the revision history of a document in isolation. The successor
+//! tests compare a new revision with the previous one instead:
+//!
+//! - `successor.initial_release_date`: the initial release date is unchanged
+//! - `successor.revision_history`: sorted by date and number, the previous revision history is an unchanged
+//!   prefix of the new one
+//! - `successor.version`: the version increases
+//! - `successor.current_release_date`: the current release date moves forward
+//! - `successor.product_ids`: all product IDs of the previous revision are still defined (warning)
+//!
+//! [validate_successor] runs them and returns a [ValidationResult], with the findings located in the
+//! new revision.
+
+use crate::CsafDocument;
+use crate::csaf::document::CsafVisitor;
+use crate::csaf::span::SpanIndex;
+use crate::csaf::types::csaf_version_number::CsafVersionNumber;
+use crate::csaf_traits::{
+    CsafTrait, DocumentTrait, ProductTrait, ProductTreeTrait, RevisionHistorySortable, RevisionTrait, TrackingTrait,
+    WithDate,
+};
+use crate::validation::{
+    TestResult, TestResultStatus, Validatable, ValidationError, ValidationPreset, ValidationResult, validate_by_tests,
+};
+use std::collections::BTreeSet;
+
+/// IDs of the successor tests, see the [module documentation](self)
+pub const SUCCESSOR_TESTS: [&str; 5] = [
+    "successor.initial_release_date",
+    "successor.revision_history",
+    "successor.version",
+    "successor.current_release_date",
+    "successor.product_ids",
+];
+
+/// Runs a successor test on a document and its previous revision.
+///
+/// Unknown tests are reported as [TestResultStatus::NotFound].
+pub fn run_successor_test(previous: &impl CsafTrait, document: &impl CsafTrait, test_id: &str) -> TestResult {
+    let status = match test_id {
+        "successor.initial_release_date" => errors(test_initial_release_date(previous, document)),
+        "successor.revision_history" => errors(test_revision_history(previous, document)),
+        "successor.version" => errors(test_version(previous, document)),
+        "successor.current_release_date" => errors(test_current_release_date(previous, document)),
+        "successor.product_ids" => match test_product_ids(previous, document) {
+            Ok(()) => TestResultStatus::Success,
+            Err(warnings) => TestResultStatus::Failure {
+                errors: vec![],
+                warnings,
+                infos: vec![],
+            },
+        },
+        _ => TestResultStatus::NotFound,
+    };
+    TestResult {
+        test_id: test_id.to_string(),
+        status,
+    }
+}
+
+fn errors(result: Result<(), Vec<ValidationError>>) -> TestResultStatus {
+    match result {
+        Ok(()) => TestResultStatus::Success,
+        Err(errors) => TestResultStatus::Failure {
+            errors,
+            warnings: vec![],
+            infos: vec![],
+        },
+    }
+}
+
+fn error(message: String, instance_path: &str) -> Result<(), Vec<ValidationError>> {
+    Err(vec![ValidationError {
+        message,
+        instance_path: instance_path.to_string(),
+    }])
+}
+
+fn test_initial_release_date(previous: &impl CsafTrait, document: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let previous_date = previous.get_document().get_tracking().get_initial_release_date();
+    let date = document.get_document().get_tracking().get_initial_release_date();
+    if previous_date.is_valid() && date.is_valid() && previous_date != date {
+        return error(
+            format!(
+                "The initial release date changed from {} to {}",
+                previous_date.get_raw_string(),
+                date.get_raw_string()
+            ),
+            "/document/tracking/initial_release_date",
+        );
+    }
+    Ok(())
+}
+
+fn test_revision_history(previous: &impl CsafTrait, document: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let previous_tracking = previous.get_document().get_tracking();
+    let tracking = document.get_document().get_tracking();
+    // Histories with invalid numbers or dates cannot be sorted, they are reported by the schema
+    // validation and test 6.1.37
+    if !has_sortable_revision_history(previous_tracking) || !has_sortable_revision_history(tracking) {
+        return Ok(());
+    }
+    let mut previous_history = previous_tracking.get_revision_history_tuples();
+    previous_history.inplace_sort_by_date_then_number();
+    let mut history = tracking.get_revision_history_tuples();
+    history.inplace_sort_by_date_then_number();
+
+    // The sorted previous history must be a prefix of the sorted new one
+    let mut errors = Vec::new();
+    for (i, previous_item) in previous_history.iter().enumerate() {
+        match history.get(i) {
+            None => errors.push(ValidationError {
+                message: format!(
+                    "The revision history lacks the item {} of the previous version",
+                    previous_item.path_index
+                ),
+                instance_path: "/document/tracking/revision_history".to_string(),
+            }),
+            Some(item)
+                if item.date_string != previous_item.date_string
+                    || item.number != previous_item.number
+                    || !same_revision(
+                        &previous_tracking.get_revision_history()[previous_item.path_index],
+                        &tracking.get_revision_history()[item.path_index],
+                    ) =>
+            {
+                errors.push(ValidationError {
+                    message: format!(
+                        "The revision history item {} differs from the previous version",
+                        item.path_index
+                    ),
+                    instance_path: format!("/document/tracking/revision_history/{}", item.path_index),
+                })
+            },
+            Some(_) => {},
+        }
+    }
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+fn has_sortable_revision_history(tracking: &impl TrackingTrait) -> bool {
+    tracking
+        .get_revision_history()
+        .iter()
+        .all(|revision| revision.get_date().is_valid() && revision.get_number().is_valid())
+}
+
+/// Compares the parts of two revisions that are not compared by their sort keys
+fn same_revision(previous: &impl RevisionTrait, revision: &impl RevisionTrait) -> bool {
+    previous.get_summary() == revision.get_summary() && previous.get_legacy_version() == revision.get_legacy_version()
+}
+
+fn test_version(previous: &impl CsafTrait, document: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let previous_version = previous.get_document().get_tracking().get_version();
+    let version = document.get_document().get_tracking().get_version();
+    // Invalid versions cannot be compared and are reported by the schema validation
+    if let (Some(ordering), CsafVersionNumber::Valid(valid_version)) =
+        (version.partial_cmp(&previous_version), &version)
+        && ordering.is_le()
+    {
+        return error(
+            format!("The version {valid_version} does not increase the version of the previous document"),
+            "/document/tracking/version",
+        );
+    }
+    Ok(())
+}
+
+fn test_current_release_date(previous: &impl CsafTrait, document: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let previous_date = previous.get_document().get_tracking().get_current_release_date();
+    let date = document.get_document().get_tracking().get_current_release_date();
+    if date
+        .partial_cmp(&previous_date)
+        .is_some_and(|ordering| ordering.is_le())
+    {
+        return error(
+            format!(
+                "The current release date {} is not after the current release date {} of the previous version",
+                date.get_raw_string(),
+                previous_date.get_raw_string()
+            ),
+            "/document/tracking/current_release_date",
+        );
+    }
+    Ok(())
+}
+
+fn product_ids(doc: &impl CsafTrait) -> BTreeSet<String> {
+    let mut ids = BTreeSet::new();
+    if let Some(product_tree) = doc.get_product_tree() {
+        product_tree.visit_all_products(&mut |product, _| {
+            ids.insert(product.get_product_id().clone());
+        });
+    }
+    ids
+}
+
+fn test_product_ids(previous: &impl CsafTrait, document: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let ids = product_ids(document);
+    let warnings: Vec<ValidationError> = product_ids(previous)
+        .into_iter()
+        .filter(|id| !ids.contains(id))
+        .map(|id| ValidationError {
+            message: format!("The product ID '{id}' of the previous version is not defined anymore"),
+            instance_path: "/product_tree".to_string(),
+        })
+        .collect();
+    if warnings.is_empty() { Ok(()) } else { Err(warnings) }
+}
+
+/// Checks a document against its previously published revision, see the [module documentation](self).
+pub fn validate_successor(
+    previous: &CsafDocument,
+    document: &CsafDocument,
+    preset: ValidationPreset,
+) -> ValidationResult {
+    Successor { previous, document }.validate(preset)
+}
+
+/// A document together with its previous revision, validated with the successor tests
+pub struct Successor<'a> {
+    pub previous: &'a CsafDocument,
+    pub document: &'a CsafDocument,
+}
+
+impl Successor<'_> {
+    /// Runs all successor tests.
+    pub fn validate(&self, preset: ValidationPreset) -> ValidationResult {
+        validate_by_tests(self, self.document.version(), preset, &SUCCESSOR_TESTS)
+    }
+}
+
+/// Runs a successor test with the previous revision, visiting the new revision
+struct RunWithPrevious<'a, P> {
+    previous: &'a P,
+    test_id: &'a str,
+}
+
+impl<P: CsafTrait> CsafVisitor for RunWithPrevious<'_, P> {
+    type Output = TestResult;
+
+    fn visit(self, doc: &impl CsafTrait) -> TestResult {
+        run_successor_test(self.previous, doc, self.test_id)
+    }
+}
+
+/// Runs a successor test, visiting the previous revision
+struct RunOnPrevious<'a> {
+    document: &'a CsafDocument,
+    test_id: &'a str,
+}
+
+impl CsafVisitor for RunOnPrevious<'_> {
+    type Output = Option<TestResult>;
+
+    fn visit(self, previous: &impl CsafTrait) -> Option<TestResult> {
+        self.document
+            .visit(RunWithPrevious {
+                previous,
+                test_id: self.test_id,
+            })
+            .ok()
+    }
+}
+
+impl Validatable for Successor<'_> {
+    fn tests_in_preset(_preset: &ValidationPreset) -> Vec<&str> {
+        SUCCESSOR_TESTS.to_vec()
+    }
+
+    /// Runs a successor test, which is skipped if one of the documents cannot be parsed.
+    fn run_test(&self, test_id: &str) -> TestResult {
+        let result = self.previous.visit(RunOnPrevious {
+            document: self.document,
+            test_id,
+        });
+        result.ok().flatten().unwrap_or_else(|| TestResult {
+            test_id: test_id.to_string(),
+            status: TestResultStatus::Skipped,
+        })
+    }
+
+    fn span_index(&self) -> Option<&SpanIndex> {
+        self.document.span_index()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework;
+    use crate::test_documents::{csaf_2_1, document as load, json_2_1};
+    use serde_json::json;
+
+    fn revision(number: &str, date: &str) -> serde_json::Value {
+        json!({ "date": date, "number": number, "summary": format!("Version {number}.") })
+    }
+
+    fn document(version: &str, current_release_date: &str, product_ids: &[&str]) -> CommonSecurityAdvisoryFramework {
+        let mut revision_history = vec![revision("1", "2024-01-24T10:00:00.000Z")];
+        if version == "2" {
+            revision_history.push(revision("2", current_release_date));
+        }
+        let products: Vec<_> = product_ids
+            .iter()
+            .map(|id| json!({ "name": id, "product_id": id }))
+            .collect();
+        csaf_2_1(json!({
+            "document": { "tracking": {
+                "current_release_date": current_release_date,
+                "revision_history": revision_history,
+                "version": version
+            } },
+            "product_tree": { "full_product_names": products }
+        }))
+    }
+
+    fn status(previous: &impl CsafTrait, document: &impl CsafTrait, test_id: &str) -> TestResultStatus {
+        run_successor_test(previous, document, test_id).status
+    }
+
+    #[test]
+    fn test_successor_tests() {
+        let previous = document("1", "2024-01-24T10:00:00.000Z", &["CSAFPID-0001", "CSAFPID-0002"]);
+        let successor = document("2", "2024-02-01T10:00:00.000Z", &["CSAFPID-0001", "CSAFPID-0002"]);
+        for test_id in SUCCESSOR_TESTS {
+            assert_eq!(
+                status(&previous, &successor, test_id),
+                TestResultStatus::Success,
+                "{test_id}"
+            );
+        }
+        assert_eq!(status(&previous, &successor, "6.1.1"), TestResultStatus::NotFound);
+
+        // The same revision again neither increases the version nor the current release date
+        for test_id in ["successor.version", "successor.current_release_date"] {
+            assert!(matches!(
+                status(&previous, &previous, test_id),
+                TestResultStatus::Failure { errors, .. } if errors.len() == 1
+            ));
+        }
+
+        // The new revision drops a product and rewrites the history
+        let mut rewritten = document("2", "2024-02-01T10:00:00.000Z", &["CSAFPID-0001"]);
+        rewritten.document.tracking.initial_release_date = "2024-01-25T10:00:00.000Z".to_string();
+        rewritten.document.tracking.revision_history[0].summary = "Initial version.".parse().unwrap();
+        assert_eq!(
+            status(&previous, &rewritten, "successor.product_ids"),
+            TestResultStatus::Failure {
+                errors: vec![],
+                warnings: vec![ValidationError {
+                    message: "The product ID 'CSAFPID-0002' of the previous version is not defined anymore".to_string(),
+                    instance_path: "/product_tree".to_string(),
+                }],
+                infos: vec![],
+            }
+        );
+        assert_eq!(
+            status(&previous, &rewritten, "successor.revision_history"),
+            TestResultStatus::Failure {
+                errors: vec![ValidationError {
+                    message: "The revision history item 0 differs from the previous version".to_string(),
+                    instance_path: "/document/tracking/revision_history/0".to_string(),
+                }],
+                warnings: vec![],
+                infos: vec![],
+            }
+        );
+        assert!(matches!(
+            status(&previous, &rewritten, "successor.initial_release_date"),
+            TestResultStatus::Failure { .. }
+        ));
+        // The history of the successor is not contained in the previous one
+        assert!(matches!(
+            status(&successor, &previous, "successor.revision_history"),
+            TestResultStatus::Failure { errors, .. } if errors[0].instance_path == "/document/tracking/revision_history"
+        ));
+        // The histories are compared sorted by date and number, not in the order of the document
+        let mut reordered = successor.clone();
+        reordered.document.tracking.revision_history.reverse();
+        assert_eq!(
+            status(&previous, &reordered, "successor.revision_history"),
+            TestResultStatus::Success
+        );
+        // A revision inserted between the previous ones
+        let mut inserted = document("2", "2024-02-01T10:00:00.000Z", &["CSAFPID-0001", "CSAFPID-0002"]);
+        inserted.document.tracking.revision_history[1].date = "2024-01-20T10:00:00.000Z".to_string();
+        assert_eq!(
+            status(&successor, &inserted, "successor.revision_history"),
+            TestResultStatus::Failure {
+                errors: vec![
+                    ValidationError {
+                        message: "The revision history item 1 differs from the previous version".to_string(),
+                        instance_path: "/document/tracking/revision_history/1".to_string(),
+                    },
+                    ValidationError {
+                        message: "The revision history item 0 differs from the previous version".to_string(),
+                        instance_path: "/document/tracking/revision_history/0".to_string(),
+                    },
+                ],
+                warnings: vec![],
+                infos: vec![],
+            }
+        );
+        // A changed legacy version
+        let mut legacy = successor.clone();
+        legacy.document.tracking.revision_history[0].legacy_version = Some("1.0".parse().unwrap());
+        assert!(matches!(
+            status(&previous, &legacy, "successor.revision_history"),
+            TestResultStatus::Failure { errors, .. } if errors[0].instance_path == "/document/tracking/revision_history/0"
+        ));
+    }
+
+    #[test]
+    fn test_validate_successor() {
+        let previous = load(&json_2_1(json!({})));
+        let result = validate_successor(&previous, &previous, ValidationPreset::Basic);
+        assert!(!result.success);
+        assert_eq!(result.num_errors, 2);
+        assert!(result.source_locations.contains_key("/document/tracking/version"));
+
+        let successor = load(&json_2_1(json!({ "document": { "tracking": {
+            "current_release_date": "2024-02-01T10:00:00.000Z",
+            "revision_history": [
+                { "date": "2024-01-24T10:00:00.000Z", "number": "1", "summary": "Initial version." },
+                { "date": "2024-02-01T10:00:00.000Z", "number": "2", "summary": "Update." }
+            ],
+            "version": "2"
+        } } })));
+        let result = validate_successor(&previous, &successor, ValidationPreset::Basic);
+        assert!(result.success, "{:?}", result.test_results);
+        assert_eq!(result.num_warnings, 0);
+    }
+}
@@ -0,0 +1,90 @@
+//! Minimal CSAF documents for unit tests that do not need a test case file.
+
+use serde_json::{Value, json};
+
+use crate::CsafDocument;
+use crate::csaf::document::DocumentSource;
+use crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+use crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+
+fn tracking() -> Value {
+    json!({
+        "current_release_date": "2024-01-24T10:00:00.000Z",
+        "id": "UNIT-TEST",
+        "initial_release_date": "2024-01-24T10:00:00.000Z",
+        "revision_history": [
+            { "date": "2024-01-24T10:00:00.000Z", "number": "1", "summary": "Initial version." }
+        ],
+        "status": "final",
+        "version": "1"
+    })
+}
+
+fn publisher() -> Value {
+    json!({
+        "category": "vendor",
+        "name": "Example Company",
+        "namespace": "https://example.com"
+    })
+}
+
+/// Merges `extra` into `base`. Objects are merged recursively, all other values of `extra` replace
+/// the ones in `base`.
+fn merge(base: &mut Value, extra: Value) {
+    match (base, extra) {
+        (Value::Object(base), Value::Object(extra)) => {
+            for (key, value) in extra {
+                merge(base.entry(key).or_insert(Value::Null), value);
+            }
+        },
+        (base, extra) => *base = extra,
+    }
+}
+
+/// Returns the JSON of a valid CSAF 2.0 base document, extended by the properties of `extra`.
+pub(crate) fn json_2_0(extra: Value) -> Value {
+    let mut base = json!({
+        "document": {
+            "category": "csaf_base",
+            "csaf_version": "2.0",
+            "distribution": { "tlp": { "label": "WHITE" } },
+            "publisher": publisher(),
+            "title": "Unit test",
+            "tracking": tracking()
+        }
+    });
+    merge(&mut base, extra);
+    base
+}
+
+/// Returns a CSAF 2.0 base document, extended by the properties of `extra`.
+pub(crate) fn csaf_2_0(extra: Value) -> Csaf20 {
+    serde_json::from_value(json_2_0(extra)).expect("test document must be valid CSAF 2.0")
+}
+
+/// Returns the JSON of a valid CSAF 2.1 base document, extended by the properties of `extra`.
+pub(crate) fn json_2_1(extra: Value) -> Value {
+    let mut base = json!({
+        "$schema": "https://docs.oasis-open.org/csaf/csaf/v2.1/schema/csaf.json",
+        "document": {
+            "category": "csaf_base",
+            "csaf_version": "2.1",
+            "distribution": { "tlp": { "label": "CLEAR" } },
+            "publisher": publisher(),
+            "title": "Unit test",
+            "tracking": tracking()
+        }
+    });
+    merge(&mut base, extra);
+    base
+}
+
+/// Returns a CSAF 2.1 base document, extended by the properties of `extra`.
+pub(crate) fn csaf_2_1(extra: Value) -> Csaf21 {
+    serde_json::from_value(json_2_1(extra)).expect("test document must be valid CSAF 2.1")
+}
+
+/// Returns a [CsafDocument] loaded from `json`, e.g. from [json_2_0] or [json_2_1].
+pub(crate) fn document(json: &Value) -> CsafDocument {
+    CsafDocument::load(DocumentSource::Str(&json.to_string())).expect("test document must be loadable")
+}
@@ -0,0 +1,348 @@
+//! Registry of the tests defined by the CSAF standard.
+//!
+//! Each CSAF version has one [TestRegistry] listing all of its tests with their group and their
+//! implementation. The registry is the single source of truth for running tests by ID, for the
+//! tests belonging to a [ValidationPreset] and for reporting tests that are not implemented yet.
+//!
+//! Tests with findings that have one obvious repair also register a fix function, see [crate::fix].
+//!
+//! The build script checks that every `TestValidator` implementation in [crate::validations] is
+//! referenced by the registry of its CSAF version, so an implemented test cannot be left unwired.
+
+use crate::csaf::raw::{HasParsed, RawDocument};
+use crate::csaf_traits::CsafVersion;
+use crate::fix::{Fix, FixFn};
+use crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+use crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+use crate::validation::{TestResult, TestResultStatus, ValidationError, ValidationPreset};
+
+/// Function running a test on a document
+pub type TestFn<D> = fn(&D) -> Result<(), Vec<ValidationError>>;
+
+/// Function telling whether a test can run on a document, e.g. whether an external service it needs is set up
+pub type PreconditionFn<D> = fn(&RawDocument<D>) -> bool;
+
+/// Group of a test in the CSAF standard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestGroup {
+    /// Mandatory tests (6.1.x)
+    Mandatory,
+    /// Recommended tests (6.2.x)
+    Recommended,
+    /// Informative tests (6.3.x)
+    Informative,
+}
+
+impl TestGroup {
+    /// Returns the severity of the findings of tests in this group.
+    pub fn severity(&self) -> Severity {
+        match self {
+            TestGroup::Mandatory => Severity::Error,
+            TestGroup::Recommended => Severity::Warning,
+            TestGroup::Informative => Severity::Info,
+        }
+    }
+
+    /// Returns whether tests in this group are part of a preset.
+    pub fn is_in_preset(&self, preset: &ValidationPreset) -> bool {
+        match self {
+            TestGroup::Mandatory => true,
+            TestGroup::Recommended => matches!(preset, ValidationPreset::Extended | ValidationPreset::Full),
+            TestGroup::Informative => matches!(preset, ValidationPreset::Full),
+        }
+    }
+}
+
+/// Severity of the findings of a test
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+    Info,
+}
+
+/// How a test is executed
+pub enum TestImplementation<D: 'static> {
+    /// The test runs on the parsed document.
+    Document(TestFn<D>),
+    /// The test runs on the raw JSON document, e.g. because it checks properties not known to the schema.
+    Raw(TestFn<RawDocument<D>>),
+    /// The test is defined by the standard, but not implemented yet.
+    NotImplemented,
+}
+
+/// A test of a CSAF version
+pub struct TestDefinition<D: 'static> {
+    /// The test ID, e.g. `6.1.1`
+    pub id: &'static str,
+    /// The group of the test
+    pub group: TestGroup,
+    /// The implementation of the test
+    pub implementation: TestImplementation<D>,
+    /// Function returning fixes for the findings of the test, if they can be repaired mechanically
+    pub fix: Option<FixFn<D>>,
+    /// Function telling whether the test can run, tests that cannot run are skipped
+    pub precondition: Option<PreconditionFn<D>>,
+}
+
+impl<D> TestDefinition<D> {
+    /// Creates a test running on the parsed document.
+    pub const fn document(id: &'static str, group: TestGroup, test: TestFn<D>) -> Self {
+        Self {
+            id,
+            group,
+            implementation: TestImplementation::Document(test),
+            fix: None,
+            precondition: None,
+        }
+    }
+
+    /// Creates a test running on the raw JSON document.
+    pub const fn raw(id: &'static str, group: TestGroup, test: TestFn<RawDocument<D>>) -> Self {
+        Self {
+            id,
+            group,
+            implementation: TestImplementation::Raw(test),
+            fix: None,
+            precondition: None,
+        }
+    }
+
+    /// Creates a test that is not implemented yet.
+    pub const fn not_implemented(id: &'static str, group: TestGroup) -> Self {
+        Self {
+            id,
+            group,
+            implementation: TestImplementation::NotImplemented,
+            fix: None,
+            precondition: None,
+        }
+    }
+
+    /// Adds a function returning fixes for the findings of the test.
+    pub const fn with_fix(mut self, fix: FixFn<D>) -> Self {
+        self.fix = Some(fix);
+        self
+    }
+
+    /// Adds a function telling whether the test can run on a document.
+    ///
+    /// Only tests running on the raw document have preconditions, they are reported as
+    /// [TestResultStatus::Skipped] if it does not hold.
+    pub const fn with_precondition(mut self, precondition: PreconditionFn<D>) -> Self {
+        self.precondition = Some(precondition);
+        self
+    }
+
+    /// Returns whether the test is implemented.
+    pub fn is_implemented(&self) -> bool {
+        !matches!(self.implementation, TestImplementation::NotImplemented)
+    }
+}
+
+/// Version independent description of a registered test
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestInfo {
+    /// The test ID, e.g. `6.1.1`
+    pub id: &'static str,
+    /// The group of the test
+    pub group: TestGroup,
+    /// The severity of the findings of the test
+    pub severity: Severity,
+    /// The CSAF version the test applies to
+    pub version: CsafVersion,
+    /// Whether the test is implemented
+    pub implemented: bool,
+}
+
+/// All tests of a CSAF version, see the [module documentation](self)
+pub struct TestRegistry<D: 'static> {
+    /// The CSAF version of the tests
+    pub version: CsafVersion,
+    /// The tests in the order of the standard
+    pub tests: &'static [TestDefinition<D>],
+}
+
+impl<D> TestRegistry<D> {
+    /// Looks up a test by its ID.
+    pub fn get(&self, test_id: &str) -> Option<&TestDefinition<D>> {
+        self.tests.iter().find(|test| test.id == test_id)
+    }
+
+    /// Returns the IDs of all tests belonging to a preset, including those not implemented yet.
+    pub fn tests_in_preset(&self, preset: &ValidationPreset) -> Vec<&'static str> {
+        self.tests
+            .iter()
+            .filter(|test| test.group.is_in_preset(preset))
+            .map(|test| test.id)
+            .collect()
+    }
+
+    /// Returns the IDs of all tests that are not implemented yet.
+    pub fn not_implemented(&self) -> Vec<&'static str> {
+        self.tests
+            .iter()
+            .filter(|test| !test.is_implemented())
+            .map(|test| test.id)
+            .collect()
+    }
+
+    /// Returns the version independent descriptions of all tests.
+    pub fn infos(&self) -> impl Iterator<Item = TestInfo> + '_ {
+        self.tests.iter().map(|test| TestInfo {
+            id: test.id,
+            group: test.group,
+            severity: test.group.severity(),
+            version: self.version.clone(),
+            implemented: test.is_implemented(),
+        })
+    }
+
+    /// Runs a test on the parsed document.
+    ///
+    /// Tests running on the raw document are skipped, see [TestRegistry::run_raw_test]. Unknown and
+    /// not implemented tests are reported as [TestResultStatus::NotFound].
+    pub fn run_test(&self, doc: &D, test_id: &str) -> TestResult {
+        let status = match self.get(test_id) {
+            Some(TestDefinition {
+                group,
+                implementation: TestImplementation::Document(test),
+                ..
+            }) => to_test_status(group.severity(), test(doc)),
+            Some(TestDefinition {
+                implementation: TestImplementation::Raw(_),
+                ..
+            }) => TestResultStatus::Skipped,
+            _ => TestResultStatus::NotFound,
+        };
+        TestResult {
+            test_id: test_id.to_string(),
+            status,
+        }
+    }
+
+    /// Runs a test on the raw document.
+    ///
+    /// Tests that do not run on the raw document are reported as [TestResultStatus::NotFound], tests
+    /// whose precondition does not hold as [TestResultStatus::Skipped].
+    pub fn run_raw_test(&self, doc: &RawDocument<D>, test_id: &str) -> TestResult {
+        let status = match self.get(test_id) {
+            Some(TestDefinition {
+                implementation: TestImplementation::Raw(_),
+                precondition: Some(precondition),
+                ..
+            }) if !precondition(doc) => TestResultStatus::Skipped,
+            Some(TestDefinition {
+                group,
+                implementation: TestImplementation::Raw(test),
+                ..
+            }) => to_test_status(group.severity(), test(doc)),
+            _ => TestResultStatus::NotFound,
+        };
+        TestResult {
+            test_id: test_id.to_string(),
+            status,
+        }
+    }
+}
+
+impl<D: serde::de::DeserializeOwned> TestRegistry<D> {
+    /// Returns the fixes for the findings of a test.
+    ///
+    /// Tests without a fix function and documents that cannot be parsed have no fixes.
+    pub fn fix(&self, doc: &RawDocument<D>, test_id: &str) -> Vec<Fix> {
+        let (Some(fix), Ok(parsed)) = (self.get(test_id).and_then(|test| test.fix), doc.get_parsed()) else {
+            return Vec::new();
+        };
+        fix(parsed, doc.get_json())
+            .into_iter()
+            .map(|fix| Fix {
+                test_id: Some(test_id.to_string()),
+                ..fix
+            })
+            .collect()
+    }
+}
+
+fn to_test_status(severity: Severity, result: Result<(), Vec<ValidationError>>) -> TestResultStatus {
+    match result {
+        Ok(()) => TestResultStatus::Success,
+        Err(data) => match severity {
+            Severity::Error => TestResultStatus::Failure {
+                errors: data,
+                warnings: vec![],
+                infos: vec![],
+            },
+            Severity::Warning => TestResultStatus::Failure {
+                errors: vec![],
+                warnings: data,
+                infos: vec![],
+            },
+            Severity::Info => TestResultStatus::Failure {
+                errors: vec![],
+                warnings: vec![],
+                infos: data,
+            },
+        },
+    }
+}
+
+/// Returns the registry of CSAF 2.0 tests.
+pub fn csaf_2_0() -> &'static TestRegistry<Csaf20> {
+    &crate::csaf2_0::validation::TEST_REGISTRY
+}
+
+/// Returns the registry of CSAF 2.1 tests.
+pub fn csaf_2_1() -> &'static TestRegistry<Csaf21> {
+    &crate::csaf2_1::validation::TEST_REGISTRY
+}
+
+/// Returns the descriptions of a test in all CSAF versions defining it.
+pub fn test_infos(test_id: &str) -> Vec<TestInfo> {
+    csaf_2_0()
+        .infos()
+        .chain(csaf_2_1().infos())
+        .filter(|info| info.id == test_id)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_matches_testcases<D>(registry: &TestRegistry<D>, group: TestGroup, testcases: Vec<&str>) {
+        let registered: Vec<&str> = registry
+            .tests
+            .iter()
+            .filter(|test| test.group == group)
+            .map(|test| test.id)
+            .collect();
+        assert_eq!(registered, testcases, "{group:?} tests of CSAF {:?}", registry.version);
+    }
+
+    #[test]
+    fn test_registries_match_testcases() {
+        use crate::csaf2_0::testcases as testcases_2_0;
+        use crate::csaf2_1::testcases as testcases_2_1;
+
+        assert_matches_testcases(csaf_2_0(), TestGroup::Mandatory, testcases_2_0::mandatory_tests());
+        assert_matches_testcases(csaf_2_0(), TestGroup::Recommended, testcases_2_0::recommended_tests());
+        assert_matches_testcases(csaf_2_0(), TestGroup::Informative, testcases_2_0::informative_tests());
+        assert_matches_testcases(csaf_2_1(), TestGroup::Mandatory, testcases_2_1::mandatory_tests());
+        assert_matches_testcases(csaf_2_1(), TestGroup::Recommended, testcases_2_1::recommended_tests());
+        assert_matches_testcases(csaf_2_1(), TestGroup::Informative, testcases_2_1::informative_tests());
+    }
+
+    #[test]
+    fn test_test_infos() {
+        let infos = test_infos("6.1.34");
+        assert_eq!(infos.len(), 1);
+        assert_eq!(infos[0].version, CsafVersion::X21);
+        assert_eq!(infos[0].severity, Severity::Error);
+
+        let versions: Vec<CsafVersion> = test_infos("6.2.8").into_iter().map(|info| info.version).collect();
+        assert_eq!(versions, vec![CsafVersion::X20, CsafVersion::X21]);
+        assert!(test_infos("6.2.8").iter().all(|info| info.implemented));
+        assert!(test_infos("6.9.9").is_empty());
+    }
+}
@@ -0,0 +1,270 @@
+//! Resolution of URLs, used by tests 6.3.6 and 6.3.7.
+//!
+//! Whether a URL resolves is decided by a [UrlResolver]. The [OfflineUrlResolver] answers from a
+//! map of known URLs and a local mirror directory, so validations can run without network access.
+//! With the `http` feature, the [HttpUrlResolver] requests the URLs from their servers.
+//!
+//! The resolver is attached to the documents to validate, tests 6.3.6 and 6.3.7 are skipped for
+//! documents without one.
+//!
+//! ```no_run
+//! # use std::path::Path;
+//! # use std::sync::Arc;
+//! # use csaf::CsafDocument;
+//! # use csaf::url_resolver::OfflineUrlResolver;
+//! # use csaf::validation::ValidationPreset;
+//! let resolver = OfflineUrlResolver::new()
+//!     .status("https://example.com/security/advisory.json", 200)
+//!     .directory("mirror");
+//! let document = CsafDocument::load(Path::new("advisory.json"))?.with_url_resolver(Arc::new(resolver));
+//! let result = document.validate(ValidationPreset::Full);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Error of a URL that could not be requested at all
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ResolveError {
+    #[error("URL is not available offline")]
+    NotAvailableOffline,
+    #[error("URL is not an HTTP(S) URL")]
+    UnsupportedUrl,
+    #[error("request failed: {0}")]
+    Request(String),
+}
+
+/// Looks up URLs, see the [module documentation](self).
+pub trait UrlResolver: Send + Sync {
+    /// Returns the HTTP status code the URL resolves with.
+    fn resolve(&self, url: &str) -> Result<u16, ResolveError>;
+}
+
+/// Returns whether an HTTP status code is in the 2xx (Successful) or 3xx (Redirection) class.
+pub fn is_resolved(status: u16) -> bool {
+    (200..400).contains(&status)
+}
+
+/// Resolves URLs without network access.
+///
+/// URLs are looked up in the map of known URLs first, then in the mirror directories, where
+/// `https://example.com/security/advisory.json` is expected at `<dir>/example.com/security/advisory.json`.
+/// Mirrored URLs resolve with status 200 if the file exists and with 404 otherwise. URLs that are
+/// neither known nor mirrored fail with [ResolveError::NotAvailableOffline].
+#[derive(Debug, Default)]
+pub struct OfflineUrlResolver {
+    statuses: HashMap<String, u16>,
+    directories: Vec<PathBuf>,
+}
+
+impl OfflineUrlResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the HTTP status code a URL resolves with.
+    pub fn status(mut self, url: &str, status: u16) -> Self {
+        self.statuses.insert(without_fragment(url).to_string(), status);
+        self
+    }
+
+    /// Adds a mirror directory with one subdirectory per host.
+    pub fn directory(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.directories.push(dir.into());
+        self
+    }
+}
+
+impl UrlResolver for OfflineUrlResolver {
+    fn resolve(&self, url: &str) -> Result<u16, ResolveError> {
+        let url = without_fragment(url);
+        if let Some(status) = self.statuses.get(url) {
+            return Ok(*status);
+        }
+        if self.directories.is_empty() {
+            return Err(ResolveError::NotAvailableOffline);
+        }
+        let (host, path) = split_http_url(url).ok_or(ResolveError::UnsupportedUrl)?;
+        let path = path.split('?').next().unwrap_or_default();
+        let path = if path.is_empty() || path.ends_with('/') {
+            format!("{path}index.html")
+        } else {
+            path.to_string()
+        };
+        if host == ".." || path.split('/').any(|segment| segment == "..") {
+            return Err(ResolveError::UnsupportedUrl);
+        }
+        let found = self
+            .directories
+            .iter()
+            .any(|dir| dir.join(host).join(path.trim_start_matches('/')).is_file());
+        Ok(if found { 200 } else { 404 })
+    }
+}
+
+/// Returns whether the URL uses the `http` or `https` scheme, other URIs cannot be resolved.
+pub fn is_http_url(url: &str) -> bool {
+    url.split_once("://")
+        .is_some_and(|(scheme, _)| scheme.eq_ignore_ascii_case("http") || scheme.eq_ignore_ascii_case("https"))
+}
+
+fn without_fragment(url: &str) -> &str {
+    url.split('#').next().unwrap_or_default()
+}
+
+/// Splits an `http` or `https` URL into its host (with port) and its path (with query).
+fn split_http_url(url: &str) -> Option<(&str, &str)> {
+    let rest = url.strip_prefix("https://").or_else(|| url.strip_prefix("http://"))?;
+    let (authority, path) = rest.find('/').map_or((rest, ""), |index| rest.split_at(index));
+    let host = authority.rsplit('@').next().unwrap_or_default();
+    (!host.is_empty()).then_some((host, path))
+}
+
+/// Resolves URLs by requesting them from their servers.
+///
+/// Redirects are not followed, as they already count as resolved.
+#[cfg(feature = "http")]
+#[derive(Debug, Clone)]
+pub struct HttpUrlResolver {
+    agent: ureq::Agent,
+}
+
+#[cfg(feature = "http")]
+impl Default for HttpUrlResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "http")]
+impl HttpUrlResolver {
+    pub fn new() -> Self {
+        Self::with_agent_timeout(std::time::Duration::from_secs(10))
+    }
+
+    /// Sets the maximum time of a request, 10 seconds by default.
+    pub fn timeout(self, timeout: std::time::Duration) -> Self {
+        Self::with_agent_timeout(timeout)
+    }
+
+    fn with_agent_timeout(timeout: std::time::Duration) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().timeout(timeout).redirects(0).build(),
+        }
+    }
+}
+
+#[cfg(feature = "http")]
+impl UrlResolver for HttpUrlResolver {
+    fn resolve(&self, url: &str) -> Result<u16, ResolveError> {
+        if !is_http_url(url) {
+            return Err(ResolveError::UnsupportedUrl);
+        }
+        match self.agent.get(url).call() {
+            Ok(response) => Ok(response.status()),
+            Err(ureq::Error::Status(status, _)) => Ok(status),
+            Err(ureq::Error::Transport(err)) => Err(ResolveError::Request(err.to_string())),
+        }
+    }
+}
+
+/// URL resolution in unit tests, without network access
+#[cfg(test)]
+pub(crate) mod testing {
+    use std::sync::Arc;
+
+    use super::{ResolveError, UrlResolver, split_http_url};
+    use crate::csaf::raw::RawDocument;
+    use crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+    use crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::test_validation::TestValidator;
+    use crate::validation::ValidationError;
+
+    /// Resolves all HTTP(S) URLs except those on the reserved top-level domain `.invalid` (RFC 2606), like
+    /// the URLs in the test cases of 6.3.6 and 6.3.7 that are meant to fail.
+    pub(crate) struct ReservedDomainResolver;
+
+    impl UrlResolver for ReservedDomainResolver {
+        fn resolve(&self, url: &str) -> Result<u16, ResolveError> {
+            let (host, _) = split_http_url(url).ok_or(ResolveError::UnsupportedUrl)?;
+            let hostname = host.split(':').next().unwrap_or_default();
+            if hostname == "invalid" || hostname.ends_with(".invalid") {
+                Err(ResolveError::Request(format!("failed to lookup address of {hostname}")))
+            } else {
+                Ok(200)
+            }
+        }
+    }
+
+    /// Runs the validator `V` with the [ReservedDomainResolver] attached to the document, used to run
+    /// the test cases of 6.3.6 and 6.3.7.
+    #[derive(Default)]
+    pub(crate) struct WithReservedDomainResolver<V>(V);
+
+    impl<V> TestValidator<RawDocument<Csaf20>> for WithReservedDomainResolver<V>
+    where
+        V: TestValidator<RawDocument<Csaf20>>,
+    {
+        fn validate(&self, doc: &RawDocument<Csaf20>) -> Result<(), Vec<ValidationError>> {
+            self.0
+                .validate(&doc.clone().with_url_resolver(Arc::new(ReservedDomainResolver)))
+        }
+    }
+
+    impl<V> TestValidator<RawDocument<Csaf21>> for WithReservedDomainResolver<V>
+    where
+        V: TestValidator<RawDocument<Csaf21>>,
+    {
+        fn validate(&self, doc: &RawDocument<Csaf21>) -> Result<(), Vec<ValidationError>> {
+            self.0
+                .validate(&doc.clone().with_url_resolver(Arc::new(ReservedDomainResolver)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_http_url() {
+        assert!(is_http_url("https://example.com/advisory.json"));
+        assert!(is_http_url("HTTP://example.com"));
+        assert!(!is_http_url("urn:example:product"));
+        assert!(!is_http_url("ftp://example.com/file"));
+    }
+
+    #[test]
+    fn test_offline_url_resolver() {
+        let dir = std::env::temp_dir().join(format!("csaf-url-resolver-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("example.com/security")).unwrap();
+        std::fs::write(dir.join("example.com/security/advisory.json"), "{}").unwrap();
+        std::fs::write(dir.join("example.com/index.html"), "").unwrap();
+
+        let resolver = OfflineUrlResolver::new()
+            .status("https://example.com/gone", 410)
+            .directory(&dir);
+        assert_eq!(resolver.resolve("https://example.com/gone#section"), Ok(410));
+        assert_eq!(
+            resolver.resolve("https://example.com/security/advisory.json?v=1"),
+            Ok(200)
+        );
+        assert_eq!(resolver.resolve("http://example.com"), Ok(200));
+        assert_eq!(resolver.resolve("https://example.com/security/other.json"), Ok(404));
+        assert_eq!(
+            resolver.resolve("https://example.com/../secret"),
+            Err(ResolveError::UnsupportedUrl)
+        );
+        assert_eq!(
+            resolver.resolve("ftp://example.com/file"),
+            Err(ResolveError::UnsupportedUrl)
+        );
+        assert_eq!(
+            OfflineUrlResolver::new().resolve("https://example.com/"),
+            Err(ResolveError::NotAvailableOffline)
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,5 +1,7 @@
+use crate::csaf::span::{Span, SpanIndex};
 use TestResultStatus::*;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 use tsify::Tsify;
@@ -62,6 +64,48 @@ pub struct ValidationResult {
     pub num_infos: usize,
     /// The total number of tests not found
     pub num_not_found: usize,
+    /// Positions of the instance paths of all findings in the JSON source, if the document was
+    /// loaded with a span index
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub source_locations: BTreeMap<String, Span>,
+}
+
+impl ValidationResult {
+    /// Looks up the positions of the instance paths of all findings in the span index.
+    ///
+    /// Instance paths pointing to missing values are mapped to their closest existing ancestor.
+    pub fn locate(&mut self, span_index: &SpanIndex) {
+        for test_result in &self.test_results {
+            if let Failure {
+                errors,
+                warnings,
+                infos,
+            } = &test_result.status
+            {
+                for finding in errors.iter().chain(warnings).chain(infos) {
+                    if let Some(span) = span_index.locate(&finding.instance_path) {
+                        self.source_locations.insert(finding.instance_path.clone(), *span);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Appends the results of another validation of the same document.
+    pub fn merge(&mut self, other: ValidationResult) {
+        self.success &= other.success;
+        self.test_results.extend(other.test_results);
+        self.num_errors += other.num_errors;
+        self.num_warnings += other.num_warnings;
+        self.num_infos += other.num_infos;
+        self.num_not_found += other.num_not_found;
+        self.source_locations.extend(other.source_locations);
+    }
+
+    /// Returns the position of a finding in the JSON source, see [`ValidationResult::locate`].
+    pub fn location_of(&self, finding: &ValidationError) -> Option<&Span> {
+        self.source_locations.get(&finding.instance_path)
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize, Tsify)]
@@ -115,8 +159,24 @@ pub trait Validatable {
     /// Returns the test IDs belonging to a preset
     fn tests_in_preset(preset: &ValidationPreset) -> Vec<&str>;
 
+    /// Returns the test IDs belonging to a preset for this instance.
+    ///
+    /// Defaults to [Validatable::tests_in_preset], targets whose tests are only known at runtime
+    /// (like a [crate::CsafDocument] of any version) override it.
+    fn preset_tests<'a>(&'a self, preset: &'a ValidationPreset) -> Vec<&'a str>
+    where
+        Self: Sized,
+    {
+        Self::tests_in_preset(preset)
+    }
+
     /// Runs a test by test ID
     fn run_test(&self, test_id: &str) -> TestResult;
+
+    /// Returns the span index of the JSON source, used to add source locations to validation results
+    fn span_index(&self) -> Option<&SpanIndex> {
+        None
+    }
 }
 
 /// Execute a single test and return the test result.
@@ -163,7 +223,7 @@ pub fn validate_by_tests(
         test_results.push(test_result);
     }
 
-    ValidationResult {
+    let mut result = ValidationResult {
         success: num_errors == 0,
         version: version.to_string(),
         num_errors,
@@ -172,13 +232,18 @@ pub fn validate_by_tests(
         num_not_found,
         preset,
         test_results,
+        source_locations: BTreeMap::new(),
+    };
+    if let Some(span_index) = target.span_index() {
+        result.locate(span_index);
     }
+    result
 }
 
 /// Validate document with a preset and return detailed results.
 pub fn validate_by_preset<V: Validatable>(target: &V, version: &str, preset: ValidationPreset) -> ValidationResult {
     // Retrieve the test IDs for the given preset
-    let test_ids: Vec<&str> = V::tests_in_preset(&preset);
+    let test_ids: Vec<&str> = target.preset_tests(&preset);
 
     // Forward them to validate_by_tests
     validate_by_tests(target, version, preset, &test_ids)
@@ -6,8 +6,8 @@ pub mod test_6_1_05;
 pub mod test_6_1_06;
 pub mod test_6_1_07;
 pub mod test_6_1_08;
-// pub mod test_6_1_09;
-// pub mod test_6_1_10;
+pub mod test_6_1_09;
+pub mod test_6_1_10;
 pub mod test_6_1_11;
 pub mod test_6_1_12;
 pub mod test_6_1_13;
@@ -35,6 +35,14 @@ pub mod test_6_1_27_08;
 pub mod test_6_1_27_09;
 pub mod test_6_1_27_10;
 pub mod test_6_1_27_11;
+pub mod test_6_1_27_12;
+pub mod test_6_1_27_13;
+pub mod test_6_1_27_14;
+pub mod test_6_1_27_15;
+pub mod test_6_1_27_16;
+pub mod test_6_1_27_17;
+pub mod test_6_1_27_18;
+pub mod test_6_1_27_19;
 
 pub mod test_6_1_28;
 pub mod test_6_1_29;
@@ -58,6 +66,13 @@ pub mod test_6_1_46;
 pub mod test_6_1_47;
 pub mod test_6_1_48;
 pub mod test_6_1_49;
+pub mod test_6_1_50;
+pub mod test_6_1_51;
+pub mod test_6_1_52;
+pub mod test_6_1_53;
+pub mod test_6_1_54;
+pub mod test_6_1_55;
+pub mod test_6_1_56;
 
 pub mod test_6_2_01;
 pub mod test_6_2_02;
@@ -72,22 +87,59 @@ pub mod test_6_2_10;
 pub mod test_6_2_11;
 pub mod test_6_2_12;
 pub mod test_6_2_13;
-// pub mod test_6_2_14;
+pub mod test_6_2_14;
 pub mod test_6_2_15;
 pub mod test_6_2_16;
 pub mod test_6_2_17;
 pub mod test_6_2_18;
 // pub mod test_6_2_19;
 pub mod test_6_2_20;
+pub mod test_6_2_21;
+pub mod test_6_2_22;
+pub mod test_6_2_23;
+pub mod test_6_2_24;
+pub mod test_6_2_25;
+pub mod test_6_2_26;
+pub mod test_6_2_27;
+pub mod test_6_2_28;
+pub mod test_6_2_29;
+pub mod test_6_2_30;
+pub mod test_6_2_31;
+pub mod test_6_2_32;
+pub mod test_6_2_33;
+pub mod test_6_2_34;
+pub mod test_6_2_35;
+pub mod test_6_2_36;
+pub mod test_6_2_37;
+pub mod test_6_2_38;
+pub mod test_6_2_39_1;
+pub mod test_6_2_39_2;
+pub mod test_6_2_39_3;
+pub mod test_6_2_39_4;
+pub mod test_6_2_40;
+pub mod test_6_2_41;
+pub mod test_6_2_42;
+pub mod test_6_2_43;
+pub mod test_6_2_44;
+pub mod test_6_2_45;
+pub mod test_6_2_46;
+pub mod test_6_2_47;
 
 pub mod test_6_3_1;
-// pub mod test_6_3_2;
+pub mod test_6_3_10;
+pub mod test_6_3_11;
+pub mod test_6_3_12;
+pub mod test_6_3_13;
+pub mod test_6_3_14;
+pub mod test_6_3_15;
+pub mod test_6_3_16;
+pub mod test_6_3_17;
+pub mod test_6_3_18;
+pub mod test_6_3_2;
 pub mod test_6_3_3;
 pub mod test_6_3_4;
 pub mod test_6_3_5;
-// pub mod test_6_3_6;
-// pub mod test_6_3_7;
-// pub mod test_6_3_8;
-// pub mod test_6_3_9;
-pub mod test_6_3_10;
-pub mod test_6_3_11;
+pub mod test_6_3_6;
+pub mod test_6_3_7;
+pub mod test_6_3_8;
+pub mod test_6_3_9;
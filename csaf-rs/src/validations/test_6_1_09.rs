@@ -0,0 +1,199 @@
+use std::str::FromStr;
+
+use serde_json::{Map, Value};
+
+use crate::csaf_traits::{ContentTrait, CsafTrait, MetricTrait, VulnerabilityTrait};
+use crate::cvss::{CvssSeverity, CvssV2, CvssV3, CvssV4};
+use crate::validation::ValidationError;
+
+fn create_score_error(property: &str, given: &Value, computed: f64, path: &str) -> ValidationError {
+    ValidationError {
+        message: format!("The {property} {given} does not match the computed score {computed:.1}"),
+        instance_path: format!("{path}/{property}"),
+    }
+}
+
+fn create_severity_error(property: &str, given: &Value, computed: CvssSeverity, path: &str) -> ValidationError {
+    ValidationError {
+        message: format!("The {property} {given} does not match the computed severity \"{computed}\""),
+        instance_path: format!("{path}/{property}"),
+    }
+}
+
+/// 6.1.9 Invalid CVSS computation
+///
+/// The scores and severities given in a CVSS object must match the ones computed from its vector string.
+pub fn test_6_1_09_invalid_cvss_computation(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Vec<ValidationError> = Vec::new();
+
+    for (i_v, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        let Some(metrics) = vulnerability.get_metrics() else {
+            continue;
+        };
+        for (i_m, metric) in metrics.iter().enumerate() {
+            let content = metric.get_content();
+            let content_path = content.get_content_json_path(i_v, i_m);
+
+            // Vectors that cannot be parsed are reported by 6.1.8
+            if let Some(cvss_v2) = content.get_cvss_v2()
+                && let Some(cvss) = parse_vector::<CvssV2>(cvss_v2)
+            {
+                let path = format!("{content_path}/cvss_v2");
+                check_score(cvss_v2, "baseScore", cvss.base_score(), &path, &mut errors);
+                check_score(cvss_v2, "temporalScore", cvss.temporal_score(), &path, &mut errors);
+                check_score(
+                    cvss_v2,
+                    "environmentalScore",
+                    cvss.environmental_score(),
+                    &path,
+                    &mut errors,
+                );
+            }
+
+            if let Some(cvss_v3) = content.get_cvss_v3()
+                && let Some(cvss) = parse_vector::<CvssV3>(cvss_v3)
+            {
+                let path = format!("{content_path}/cvss_v3");
+                for (score_property, severity_property, score) in [
+                    ("baseScore", "baseSeverity", cvss.base_score()),
+                    ("temporalScore", "temporalSeverity", cvss.temporal_score()),
+                    (
+                        "environmentalScore",
+                        "environmentalSeverity",
+                        cvss.environmental_score(),
+                    ),
+                ] {
+                    check_score(cvss_v3, score_property, score, &path, &mut errors);
+                    check_severity(cvss_v3, severity_property, CvssV3::severity(score), &path, &mut errors);
+                }
+            }
+
+            if let Some(cvss_v4) = content.get_cvss_v4()
+                && let Some(cvss) = parse_vector::<CvssV4>(cvss_v4)
+            {
+                // The CVSS v4.0 JSON schema only has a single score, which is computed from all metrics
+                let path = format!("{content_path}/cvss_v4");
+                let score = cvss.score();
+                check_score(cvss_v4, "baseScore", score, &path, &mut errors);
+                check_severity(cvss_v4, "baseSeverity", CvssV4::severity(score), &path, &mut errors);
+            }
+        }
+    }
+
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+fn parse_vector<T: FromStr>(cvss: &Map<String, Value>) -> Option<T> {
+    cvss.get("vectorString")
+        .and_then(Value::as_str)
+        .and_then(|vector| T::from_str(vector).ok())
+}
+
+fn check_score(
+    cvss: &Map<String, Value>,
+    property: &str,
+    computed: f64,
+    path: &str,
+    errors: &mut Vec<ValidationError>,
+) {
+    if let Some(given) = cvss.get(property) {
+        let matches = given.as_f64().is_some_and(|score| (score - computed).abs() < 1e-6);
+        if !matches {
+            errors.push(create_score_error(property, given, computed, path));
+        }
+    }
+}
+
+fn check_severity(
+    cvss: &Map<String, Value>,
+    property: &str,
+    computed: CvssSeverity,
+    path: &str,
+    errors: &mut Vec<ValidationError>,
+) {
+    if let Some(given) = cvss.get(property)
+        && given.as_str() != Some(computed.json_value())
+    {
+        errors.push(create_severity_error(property, given, computed, path));
+    }
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_0::testcases::ValidatorForTest6_1_9
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_1_09_invalid_cvss_computation(doc)
+    }
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_1_9
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_1_09_invalid_cvss_computation(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_0::testcases::TESTS_2_0;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_09() {
+        let base_score_error = |path: &str| create_score_error("baseScore", &json!(10.0), 6.5, path);
+        let base_severity_error =
+            |path: &str| create_severity_error("baseSeverity", &json!("LOW"), CvssSeverity::Medium, path);
+
+        // CSAF 2.0 has 6 test cases (01-03, 11-13)
+        TESTS_2_0.test_6_1_9.expect(
+            Err(vec![
+                base_score_error("/vulnerabilities/0/scores/0/cvss_v3"),
+                base_severity_error("/vulnerabilities/0/scores/0/cvss_v3"),
+            ]),
+            Err(vec![base_score_error("/vulnerabilities/0/scores/0/cvss_v3")]),
+            Err(vec![base_severity_error("/vulnerabilities/0/scores/0/cvss_v3")]),
+            Ok(()), // case_11
+            Ok(()), // case_12
+            Ok(()), // case_13
+        );
+
+        // CSAF 2.1 has 11 test cases (01-05, 11-16)
+        TESTS_2_1.test_6_1_9.expect(
+            Err(vec![
+                base_score_error("/vulnerabilities/0/metrics/0/content/cvss_v3"),
+                base_severity_error("/vulnerabilities/0/metrics/0/content/cvss_v3"),
+            ]),
+            Err(vec![base_score_error("/vulnerabilities/0/metrics/0/content/cvss_v3")]),
+            Err(vec![base_severity_error(
+                "/vulnerabilities/0/metrics/0/content/cvss_v3",
+            )]),
+            Err(vec![create_score_error(
+                "baseScore",
+                &json!(10.0),
+                9.3,
+                "/vulnerabilities/0/metrics/0/content/cvss_v4",
+            )]),
+            Err(vec![create_severity_error(
+                "baseSeverity",
+                &json!("LOW"),
+                CvssSeverity::Critical,
+                "/vulnerabilities/0/metrics/0/content/cvss_v4",
+            )]),
+            Ok(()), // case_11
+            Ok(()), // case_12
+            Ok(()), // case_13
+            Ok(()), // case_14
+            Ok(()), // case_15
+            Ok(()), // case_16
+        );
+    }
+}
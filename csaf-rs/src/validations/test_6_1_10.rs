@@ -0,0 +1,147 @@
+use std::str::FromStr;
+
+use serde_json::{Map, Value};
+
+use crate::csaf_traits::{ContentTrait, CsafTrait, MetricTrait, VulnerabilityTrait};
+use crate::cvss::{CvssV2, CvssV3, CvssV4};
+use crate::validation::ValidationError;
+
+fn create_inconsistent_property_error(property: &str, given: &Value, expected: &str, path: &str) -> ValidationError {
+    ValidationError {
+        message: format!("The value {given} of {property} is inconsistent with \"{expected}\" from the vector string"),
+        instance_path: format!("{path}/{property}"),
+    }
+}
+
+/// 6.1.10 Inconsistent CVSS
+///
+/// The metric properties given in a CVSS object must match the values of its vector string.
+/// Metrics that are not part of the vector string are considered `NOT_DEFINED`.
+pub fn test_6_1_10_inconsistent_cvss(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Vec<ValidationError> = Vec::new();
+
+    for (i_v, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        let Some(metrics) = vulnerability.get_metrics() else {
+            continue;
+        };
+        for (i_m, metric) in metrics.iter().enumerate() {
+            let content = metric.get_content();
+            let content_path = content.get_content_json_path(i_v, i_m);
+
+            // Vectors that cannot be parsed are reported by 6.1.8
+            if let Some(cvss_v2) = content.get_cvss_v2()
+                && let Some(cvss) = parse_vector::<CvssV2>(cvss_v2)
+            {
+                let path = format!("{content_path}/cvss_v2");
+                check_properties(cvss_v2, &cvss.json_properties(), &path, &mut errors);
+            }
+
+            if let Some(cvss_v3) = content.get_cvss_v3()
+                && let Some(cvss) = parse_vector::<CvssV3>(cvss_v3)
+            {
+                let path = format!("{content_path}/cvss_v3");
+                let mut properties = vec![("version", cvss.version.as_str())];
+                properties.extend(cvss.json_properties());
+                check_properties(cvss_v3, &properties, &path, &mut errors);
+            }
+
+            if let Some(cvss_v4) = content.get_cvss_v4()
+                && let Some(cvss) = parse_vector::<CvssV4>(cvss_v4)
+            {
+                let path = format!("{content_path}/cvss_v4");
+                check_properties(cvss_v4, &cvss.json_properties(), &path, &mut errors);
+            }
+        }
+    }
+
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+fn parse_vector<T: FromStr>(cvss: &Map<String, Value>) -> Option<T> {
+    cvss.get("vectorString")
+        .and_then(Value::as_str)
+        .and_then(|vector| T::from_str(vector).ok())
+}
+
+/// Compares all properties given in the CVSS object with the expected values from the vector string.
+fn check_properties(
+    cvss: &Map<String, Value>,
+    expected_properties: &[(&'static str, &'static str)],
+    path: &str,
+    errors: &mut Vec<ValidationError>,
+) {
+    for (property, expected) in expected_properties {
+        if let Some(given) = cvss.get(*property)
+            && given.as_str() != Some(*expected)
+        {
+            errors.push(create_inconsistent_property_error(property, given, expected, path));
+        }
+    }
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_0::testcases::ValidatorForTest6_1_10
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_1_10_inconsistent_cvss(doc)
+    }
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_1_10
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_1_10_inconsistent_cvss(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_0::testcases::TESTS_2_0;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_10() {
+        let attack_vector_error =
+            |path: &str| create_inconsistent_property_error("attackVector", &json!("LOCAL"), "NETWORK", path);
+
+        // CSAF 2.0 has 1 test case (01)
+        TESTS_2_0
+            .test_6_1_10
+            .expect(Err(vec![attack_vector_error("/vulnerabilities/0/scores/0/cvss_v3")]));
+
+        // CSAF 2.1 has 8 test cases (01-04, 11-14)
+        TESTS_2_1.test_6_1_10.expect(
+            Err(vec![attack_vector_error(
+                "/vulnerabilities/0/metrics/0/content/cvss_v3",
+            )]),
+            Err(vec![create_inconsistent_property_error(
+                "version",
+                &json!("3.0"),
+                "3.1",
+                "/vulnerabilities/0/metrics/0/content/cvss_v3",
+            )]),
+            Err(vec![create_inconsistent_property_error(
+                "accessVector",
+                &json!("LOCAL"),
+                "NETWORK",
+                "/vulnerabilities/0/metrics/0/content/cvss_v2",
+            )]),
+            Err(vec![attack_vector_error(
+                "/vulnerabilities/0/metrics/0/content/cvss_v4",
+            )]),
+            Ok(()), // case_11
+            Ok(()), // case_12
+            Ok(()), // case_13
+            Ok(()), // case_14
+        );
+    }
+}
@@ -1,8 +1,6 @@
-use chrono::NaiveDate;
-
 use crate::csaf::types::csaf_datetime::CsafDateTime;
 use crate::csaf_traits::{CsafTrait, Cwe, DocumentTrait, TrackingTrait, VulnerabilityTrait};
-use crate::helpers::CWE_ENTRIES;
+use crate::helpers::{CWE_ENTRIES, get_latest_cwe_version};
 use crate::validation::ValidationError;
 
 fn generate_incorrect_cwe_name_error(cwe: &str, name: &str, version: &str, path: &str) -> ValidationError {
@@ -38,18 +36,6 @@ fn check_cwe(cwe: &Cwe, version: &str, path: &str, errors: &mut Vec<ValidationEr
     }
 }
 
-fn get_latest_cwe_version(date: Option<NaiveDate>) -> Option<&'static String> {
-    let mut latest: Option<(&'static String, &NaiveDate)> = None;
-
-    for (version, (release_date, _)) in CWE_ENTRIES.iter() {
-        if date.is_none_or(|date| *release_date <= date) && latest.is_none_or(|latest| *release_date > *latest.1) {
-            latest = Some((version, release_date));
-        }
-    }
-
-    latest.map(|(version, _)| version)
-}
-
 pub fn test_6_1_11_cwe(doc: &impl CsafTrait, use_2_1: bool) -> Result<(), Vec<ValidationError>> {
     let vulnerabilities = doc.get_vulnerabilities();
     let mut errors = Vec::new();
@@ -1,52 +1,39 @@
+use crate::csaf::types::language_tag::{LanguageTag, LanguageTagError};
 use crate::csaf_traits::{CsafTrait, DocumentTrait};
-use crate::generated::language_subtags::is_valid_language_subtag;
 use crate::validation::ValidationError;
 
-fn generate_invalid_language_error(language: &str, subtag: &str, path: &str) -> ValidationError {
+fn generate_invalid_language_error(language: &str, error: &LanguageTagError, path: &str) -> ValidationError {
     ValidationError {
-        message: format!(
-            "Invalid language code '{language}': primary language subtag '{subtag}' is not a valid language subtag"
-        ),
+        message: format!("Invalid language code '{language}': {error}"),
         instance_path: path.to_string(),
     }
 }
 
+/// 6.1.12 Language
+///
+/// Tests that `/document/lang` and `/document/source_lang` are valid language tags according to
+/// BCP 47, i.e. well-formed and composed of subtags registered in the IANA Language Subtag Registry.
 pub fn test_6_1_12_language(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
     let document = doc.get_document();
 
     let mut errors: Option<Vec<ValidationError>> = None;
-    // Check /document/lang if it exists
-    if let Some(lang) = document.get_lang()
-        && let Err(e) = validate_language_code(lang, "/document/lang")
-    {
-        errors.get_or_insert_default().extend(e);
-    }
-
-    // Check /document/source_lang if it exists
-    if let Some(source_lang) = document.get_source_lang()
-        && let Err(e) = validate_language_code(source_lang, "/document/source_lang")
-    {
-        errors.get_or_insert_default().extend(e);
+    let languages = [
+        (document.get_lang(), "/document/lang"),
+        (document.get_source_lang(), "/document/source_lang"),
+    ];
+    for (lang, path) in languages {
+        if let Some(lang) = lang
+            && let Err(e) = lang.parse::<LanguageTag>().and_then(|tag| tag.validate())
+        {
+            errors
+                .get_or_insert_default()
+                .push(generate_invalid_language_error(lang, &e, path));
+        }
     }
 
     errors.map_or(Ok(()), Err)
 }
 
-fn validate_language_code(lang_code: &str, json_path: &str) -> Result<(), Vec<ValidationError>> {
-    // Extract the primary language subtag (everything before the first hyphen)
-    let primary_subtag = lang_code.split('-').next().unwrap_or(lang_code);
-
-    if !is_valid_language_subtag(primary_subtag) {
-        return Err(vec![generate_invalid_language_error(
-            lang_code,
-            primary_subtag,
-            json_path,
-        )]);
-    }
-
-    Ok(())
-}
-
 impl crate::test_validation::TestValidator<crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework>
     for crate::csaf2_0::testcases::ValidatorForTest6_1_12
 {
@@ -72,12 +59,20 @@ impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::Commo
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::csaf::types::language_tag::SubtagType;
     use crate::csaf2_0::testcases::TESTS_2_0;
     use crate::csaf2_1::testcases::TESTS_2_1;
 
     #[test]
     fn test_test_6_1_12() {
-        let case_01 = Err(vec![generate_invalid_language_error("EZ", "EZ", "/document/lang")]);
+        let case_01 = Err(vec![generate_invalid_language_error(
+            "EZ",
+            &LanguageTagError::UnknownSubtag {
+                subtag_type: SubtagType::Language,
+                subtag: "EZ".to_string(),
+            },
+            "/document/lang",
+        )]);
 
         TESTS_2_0.test_6_1_12.expect(case_01.clone());
         TESTS_2_1.test_6_1_12.expect(case_01);
@@ -1,5 +1,7 @@
 use crate::csaf_traits::{CsafTrait, DocumentTrait, RevisionHistorySortable, TrackingTrait};
+use crate::fix::Fix;
 use crate::validation::ValidationError;
+use serde_json::Value;
 
 fn create_revision_history_error(revision_number: impl std::fmt::Display, path_index: usize) -> ValidationError {
     ValidationError {
@@ -41,6 +43,40 @@ pub fn test_6_1_14_sorted_revision_history(doc: &impl CsafTrait) -> Result<(), V
     Ok(())
 }
 
+/// Fixes 6.1.14 by sorting the revision history by date and renumbering the items in this order.
+pub fn fix_6_1_14_sorted_revision_history(doc: &impl CsafTrait, json: &Value) -> Vec<Fix> {
+    if test_6_1_14_sorted_revision_history(doc).is_ok() {
+        return Vec::new();
+    }
+    let Some(revisions) = json
+        .pointer("/document/tracking/revision_history")
+        .and_then(Value::as_array)
+    else {
+        return Vec::new();
+    };
+
+    let mut rev_history_tuples_sort_by_date = doc.get_document().get_tracking().get_revision_history_tuples();
+    let mut rev_history_tuples_sort_by_number = rev_history_tuples_sort_by_date.clone();
+    rev_history_tuples_sort_by_date.inplace_sort_by_date_then_number();
+    rev_history_tuples_sort_by_number.inplace_sort_by_number();
+
+    // The i-th revision by date gets the i-th number
+    let sorted = rev_history_tuples_sort_by_date
+        .iter()
+        .zip(&rev_history_tuples_sort_by_number)
+        .map(|(by_date, by_number)| {
+            let mut revision = revisions[by_date.path_index].clone();
+            revision["number"] = revisions[by_number.path_index]["number"].clone();
+            revision
+        })
+        .collect();
+    vec![Fix::replace(
+        "Sort the revision history by date and renumber it in this order",
+        "/document/tracking/revision_history",
+        Value::Array(sorted),
+    )]
+}
+
 impl crate::test_validation::TestValidator<crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework>
     for crate::csaf2_0::testcases::ValidatorForTest6_1_14
 {
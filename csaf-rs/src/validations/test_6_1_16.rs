@@ -1,7 +1,9 @@
 use crate::csaf::types::csaf_version_number::{CsafVersionNumber, ValidVersionNumber};
 use crate::csaf_traits::{CsafTrait, DocumentTrait, RevisionHistorySortable, TrackingTrait};
+use crate::fix::Fix;
 use crate::schema::csaf2_1::schema::DocumentStatus;
 use crate::validation::ValidationError;
+use serde_json::Value;
 
 /// 6.1.16 Latest Document Version
 ///
@@ -26,10 +28,10 @@ pub fn test_6_1_16_latest_document_version(doc: &impl CsafTrait) -> Result<(), V
         // TODO also add validation errors for invalid revision history numbers here
         let doc_status = tracking.get_status();
         match (&latest_number, &doc_version) {
-            (ValidVersionNumber::IntVer(last_number), ValidVersionNumber::IntVer(doc_version)) => {
-                if doc_version == last_number {
-                    return Ok(());
-                }
+            (ValidVersionNumber::IntVer(last_number), ValidVersionNumber::IntVer(doc_version))
+                if doc_version == last_number =>
+            {
+                return Ok(());
             },
             (ValidVersionNumber::SemVer(last_number), ValidVersionNumber::SemVer(doc_version)) => {
                 // Manually compare the semver objs according to test req
@@ -54,6 +56,32 @@ pub fn test_6_1_16_latest_document_version(doc: &impl CsafTrait) -> Result<(), V
     errors.map_or(Ok(()), Err)
 }
 
+/// Fixes 6.1.16 by setting `/document/tracking/version` to the number of the latest revision.
+///
+/// As build metadata is ignored by the test and should not be used (6.2.4), it is left out.
+pub fn fix_6_1_16_latest_document_version(doc: &impl CsafTrait, json: &Value) -> Vec<Fix> {
+    if test_6_1_16_latest_document_version(doc).is_ok() {
+        return Vec::new();
+    }
+    let mut revision_history = doc.get_document().get_tracking().get_revision_history_tuples();
+    revision_history.inplace_sort_by_date_then_number();
+    let Some(latest_number) = revision_history.last().and_then(|latest| {
+        json.pointer(&format!(
+            "/document/tracking/revision_history/{}/number",
+            latest.path_index
+        ))
+        .and_then(Value::as_str)
+    }) else {
+        return Vec::new();
+    };
+    let version = latest_number.split('+').next().unwrap_or(latest_number);
+    vec![Fix::replace(
+        format!("Set the document version to the latest revision history number '{version}'"),
+        "/document/tracking/version",
+        Value::String(version.to_string()),
+    )]
+}
+
 fn test_6_1_16_err_generator(
     doc_version: &ValidVersionNumber,
     latest_number: &ValidVersionNumber,
@@ -1,6 +1,8 @@
 use crate::csaf::types::csaf_version_number::ValidVersionNumber;
 use crate::csaf_traits::{CsafTrait, DocumentTrait, RevisionHistorySortable, TrackingTrait};
+use crate::fix::{Fix, PatchOperation};
 use crate::validation::ValidationError;
+use serde_json::Value;
 
 /// 6.1.21 Missing Item in Revision History
 ///
@@ -53,6 +55,45 @@ pub fn test_6_1_21_missing_item_in_revision_history(doc: &impl CsafTrait) -> Res
     errors.map_or(Ok(()), Err)
 }
 
+/// Fixes 6.1.21 for integer versioning by renumbering the revision history consecutively in the order
+/// of the dates, starting with the first number or with 1 if the first number is larger.
+///
+/// Semantic versions are not fixed, as there is no obvious choice for their minor and patch versions.
+pub fn fix_6_1_21_missing_item_in_revision_history(doc: &impl CsafTrait, _json: &Value) -> Vec<Fix> {
+    if test_6_1_21_missing_item_in_revision_history(doc).is_ok() {
+        return Vec::new();
+    }
+    let mut rev_history_tuples = doc.get_document().get_tracking().get_revision_history_tuples();
+    rev_history_tuples.inplace_sort_by_date_then_number();
+    if !rev_history_tuples
+        .iter()
+        .all(|item| matches!(item.number, ValidVersionNumber::IntVer(_)))
+    {
+        return Vec::new();
+    }
+
+    let first_number = rev_history_tuples
+        .first()
+        .map_or(1, |item| item.number.get_major().min(1));
+    let patch: Vec<PatchOperation> = rev_history_tuples
+        .iter()
+        .zip(first_number..)
+        .filter(|(item, number)| item.number.get_major() != *number)
+        .map(|(item, number)| PatchOperation::Replace {
+            path: format!("/document/tracking/revision_history/{}/number", item.path_index),
+            value: Value::String(number.to_string()),
+        })
+        .collect();
+    if patch.is_empty() {
+        return Vec::new();
+    }
+    vec![Fix::new(
+        "Renumber the revision history consecutively in the order of the dates",
+        "/document/tracking/revision_history",
+        patch,
+    )]
+}
+
 impl crate::test_validation::TestValidator<crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework>
     for crate::csaf2_0::testcases::ValidatorForTest6_1_21
 {
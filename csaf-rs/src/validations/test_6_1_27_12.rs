@@ -0,0 +1,87 @@
+use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
+use crate::csaf_traits::{CsafTrait, DocumentTrait, ProductStatusTrait, VulnerabilityTrait};
+use crate::document_category_test_helper::DocumentCategoryTestConfig;
+use crate::validation::ValidationError;
+
+fn create_missing_affected_products_error(doc_category: &CsafDocumentCategory, i_v: usize) -> ValidationError {
+    ValidationError {
+        message: format!(
+            "Vulnerability in document with category '{doc_category}' must list at least one product as 'first_affected', 'known_affected' or 'last_affected'"
+        ),
+        instance_path: format!("/vulnerabilities/{i_v}/product_status"),
+    }
+}
+
+const PROFILE_TEST_CONFIG: DocumentCategoryTestConfig = DocumentCategoryTestConfig::new().csaf20(&[]).csaf21(&[
+    CsafDocumentCategory::CsafSecurityAdvisory,
+    CsafDocumentCategory::CsafDeprecatedSecurityAdvisory,
+]);
+
+/// 6.1.27.12 Affected Products
+///
+/// This test only applies to documents with `/document/category` with value `csaf_security_advisory` or
+/// `csaf_deprecated_security_advisory` for `/document/csaf_version` `2.1`.
+///
+/// In documents with these categories each `/vulnerabilities[]/product_status` must list at least one product
+/// in `first_affected`, `known_affected` or `last_affected`.
+pub fn test_6_1_27_12_affected_products(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let doc_category = doc.get_document().get_category();
+
+    if !PROFILE_TEST_CONFIG.matches_category_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category) {
+        return Ok(());
+    }
+
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (i_v, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        let has_affected_products = vulnerability
+            .get_product_status()
+            .as_ref()
+            .is_some_and(|product_status| {
+                product_status
+                    .get_first_affected()
+                    .is_some_and(|mut p| p.next().is_some())
+                    || product_status
+                        .get_known_affected()
+                        .is_some_and(|mut p| p.next().is_some())
+                    || product_status
+                        .get_last_affected()
+                        .is_some_and(|mut p| p.next().is_some())
+            });
+        if !has_affected_products {
+            errors
+                .get_or_insert_with(Vec::new)
+                .push(create_missing_affected_products_error(&doc_category, i_v));
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_1_27_12
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_1_27_12_affected_products(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_1_27_12() {
+        // Only CSAF 2.1 has this test with 2 test cases (1 error case, 1 success case)
+        TESTS_2_1.test_6_1_27_12.expect(
+            Err(vec![create_missing_affected_products_error(
+                &CsafDocumentCategory::CsafSecurityAdvisory,
+                0,
+            )]),
+            Ok(()),
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
+use crate::csaf_traits::{
+    BranchTrait, CsafTrait, DocumentTrait, ProductStatusTrait, ProductTrait, ProductTreeTrait, RelationshipTrait,
+    VulnerabilityTrait,
+};
+use crate::document_category_test_helper::DocumentCategoryTestConfig;
+use crate::validation::ValidationError;
+use std::collections::HashMap;
+
+fn create_missing_corresponding_affected_product_error(
+    product_id: &str,
+    i_v: usize,
+    status: &str,
+    i_p: usize,
+) -> ValidationError {
+    ValidationError {
+        message: format!("Fixed product '{product_id}' has no corresponding affected product"),
+        instance_path: format!("/vulnerabilities/{i_v}/product_status/{status}/{i_p}"),
+    }
+}
+
+const PROFILE_TEST_CONFIG: DocumentCategoryTestConfig = DocumentCategoryTestConfig::new().csaf20(&[]).csaf21(&[
+    CsafDocumentCategory::CsafSecurityAdvisory,
+    CsafDocumentCategory::CsafDeprecatedSecurityAdvisory,
+]);
+
+/// 6.1.27.13 Corresponding Affected Products
+///
+/// This test only applies to documents with `/document/category` with value `csaf_security_advisory` or
+/// `csaf_deprecated_security_advisory` for `/document/csaf_version` `2.1`.
+///
+/// For each product in `/vulnerabilities[]/product_status/first_fixed` or `fixed` a corresponding product must
+/// be listed in `first_affected`, `known_affected` or `last_affected` of the same vulnerability. Products
+/// correspond if they are defined below the same parent branch, i.e. only differ in the last branch (usually
+/// their version). Relationships correspond via their `product_reference`, and products defined outside of
+/// branches correspond to any affected product.
+pub fn test_6_1_27_13_corresponding_affected_products(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let doc_category = doc.get_document().get_category();
+
+    if !PROFILE_TEST_CONFIG.matches_category_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category) {
+        return Ok(());
+    }
+
+    let families = product_families(doc);
+    let family_of = |product_id: &String| families.get(product_id).map(String::as_str);
+
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (i_v, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        let Some(product_status) = vulnerability.get_product_status() else {
+            continue;
+        };
+        let affected: Vec<&String> = product_status
+            .get_first_affected()
+            .into_iter()
+            .flatten()
+            .chain(product_status.get_known_affected().into_iter().flatten())
+            .chain(product_status.get_last_affected().into_iter().flatten())
+            .collect();
+
+        let fixed: [(&str, Vec<&String>); 2] = [
+            (
+                "first_fixed",
+                product_status.get_first_fixed().into_iter().flatten().collect(),
+            ),
+            ("fixed", product_status.get_fixed().into_iter().flatten().collect()),
+        ];
+        for (status, products) in fixed {
+            for (i_p, product_id) in products.into_iter().enumerate() {
+                let has_corresponding = match family_of(product_id) {
+                    Some(family) => affected.iter().any(|a| family_of(a) == Some(family)),
+                    None => !affected.is_empty(),
+                };
+                if !has_corresponding {
+                    errors
+                        .get_or_insert_with(Vec::new)
+                        .push(create_missing_corresponding_affected_product_error(
+                            product_id, i_v, status, i_p,
+                        ));
+                }
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+/// Maps the product IDs defined in branches to the path of their parent branch, which identifies their
+/// product family. Products of relationships inherit the family of their `product_reference`.
+fn product_families(doc: &impl CsafTrait) -> HashMap<String, String> {
+    let mut families = HashMap::new();
+    let Some(product_tree) = doc.get_product_tree() else {
+        return families;
+    };
+
+    product_tree.visit_all_branches(&mut |branch, path| {
+        if let Some(product) = branch.get_product()
+            && let Some((parent, _)) = path.rsplit_once("/branches/")
+        {
+            families.insert(product.get_product_id().to_owned(), parent.to_owned());
+        }
+    });
+    for relationship in product_tree.get_relationships() {
+        if let Some(family) = families.get(relationship.get_product_reference()).cloned() {
+            families.insert(relationship.get_full_product_name().get_product_id().to_owned(), family);
+        }
+    }
+
+    families
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_1_27_13
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_1_27_13_corresponding_affected_products(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_1_27_13() {
+        // Only CSAF 2.1 has this test with 10 test cases (4 error cases, 6 success cases)
+        TESTS_2_1.test_6_1_27_13.expect(
+            Err(vec![create_missing_corresponding_affected_product_error(
+                "CSAFPID-9080701",
+                0,
+                "fixed",
+                0,
+            )]),
+            Err(vec![create_missing_corresponding_affected_product_error(
+                "CSAFPID-9080701",
+                0,
+                "first_fixed",
+                0,
+            )]),
+            Err(vec![create_missing_corresponding_affected_product_error(
+                "CSAFPID-9080702",
+                0,
+                "fixed",
+                1,
+            )]),
+            Err(vec![create_missing_corresponding_affected_product_error(
+                "CSAFPID-9080701",
+                1,
+                "fixed",
+                0,
+            )]),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
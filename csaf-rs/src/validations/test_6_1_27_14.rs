@@ -0,0 +1,77 @@
+use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
+use crate::csaf_traits::{CsafTrait, DocumentTrait, NoteTrait};
+use crate::document_category_test_helper::DocumentCategoryTestConfig;
+use crate::schema::csaf2_1::schema::NoteCategory;
+use crate::validation::ValidationError;
+
+fn create_missing_description_note_error(doc_category: &CsafDocumentCategory) -> ValidationError {
+    ValidationError {
+        message: format!(
+            "Document with category '{doc_category}' must have at least one document note with category 'description'"
+        ),
+        instance_path: "/document/notes".to_string(),
+    }
+}
+
+const PROFILE_TEST_CONFIG: DocumentCategoryTestConfig = DocumentCategoryTestConfig::new().csaf20(&[]).csaf21(&[
+    CsafDocumentCategory::CsafWithdrawn,
+    CsafDocumentCategory::CsafSuperseded,
+]);
+
+/// 6.1.27.14 Document Notes
+///
+/// This test only applies to documents with `/document/category` with value `csaf_withdrawn` or
+/// `csaf_superseded` for `/document/csaf_version` `2.1`.
+///
+/// Documents with these categories must have at least one entry in `/document/notes` with `category` value
+/// `description`.
+pub fn test_6_1_27_14_document_notes(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let doc_category = doc.get_document().get_category();
+
+    if !PROFILE_TEST_CONFIG.matches_category_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category) {
+        return Ok(());
+    }
+
+    let has_description = doc.get_document().get_notes().is_some_and(|notes| {
+        notes
+            .iter()
+            .any(|note| note.get_category() == NoteCategory::Description)
+    });
+    if !has_description {
+        return Err(vec![create_missing_description_note_error(&doc_category)]);
+    }
+
+    Ok(())
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_1_27_14
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_1_27_14_document_notes(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_1_27_14() {
+        // Only CSAF 2.1 has this test with 4 test cases (2 error cases, 2 success cases)
+        TESTS_2_1.test_6_1_27_14.expect(
+            Err(vec![create_missing_description_note_error(
+                &CsafDocumentCategory::CsafWithdrawn,
+            )]),
+            Err(vec![create_missing_description_note_error(
+                &CsafDocumentCategory::CsafSuperseded,
+            )]),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
+use crate::csaf_traits::{CsafTrait, DocumentTrait};
+use crate::document_category_test_helper::DocumentCategoryTestConfig;
+use crate::validation::ValidationError;
+
+fn create_must_not_have_product_tree_error(doc_category: &CsafDocumentCategory) -> ValidationError {
+    ValidationError {
+        message: format!("Document with category '{doc_category}' must not have a '/product_tree' element"),
+        instance_path: "/product_tree".to_string(),
+    }
+}
+
+const PROFILE_TEST_CONFIG: DocumentCategoryTestConfig = DocumentCategoryTestConfig::new().csaf20(&[]).csaf21(&[
+    CsafDocumentCategory::CsafWithdrawn,
+    CsafDocumentCategory::CsafSuperseded,
+]);
+
+/// 6.1.27.15 Product Tree
+///
+/// This test only applies to documents with `/document/category` with value `csaf_withdrawn` or
+/// `csaf_superseded` for `/document/csaf_version` `2.1`.
+///
+/// Documents with these categories must not have a `/product_tree` element.
+pub fn test_6_1_27_15_product_tree(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let doc_category = doc.get_document().get_category();
+
+    if !PROFILE_TEST_CONFIG.matches_category_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category) {
+        return Ok(());
+    }
+
+    if doc.get_product_tree().is_some() {
+        return Err(vec![create_must_not_have_product_tree_error(&doc_category)]);
+    }
+
+    Ok(())
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_1_27_15
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_1_27_15_product_tree(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_1_27_15() {
+        // Only CSAF 2.1 has this test with 4 test cases (2 error cases, 2 success cases)
+        TESTS_2_1.test_6_1_27_15.expect(
+            Err(vec![create_must_not_have_product_tree_error(
+                &CsafDocumentCategory::CsafWithdrawn,
+            )]),
+            Err(vec![create_must_not_have_product_tree_error(
+                &CsafDocumentCategory::CsafSuperseded,
+            )]),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
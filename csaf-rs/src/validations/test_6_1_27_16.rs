@@ -0,0 +1,71 @@
+use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
+use crate::csaf_traits::{CsafTrait, DocumentTrait, TrackingTrait};
+use crate::document_category_test_helper::DocumentCategoryTestConfig;
+use crate::validation::ValidationError;
+
+fn create_too_few_revisions_error(doc_category: &CsafDocumentCategory) -> ValidationError {
+    ValidationError {
+        message: format!("Document with category '{doc_category}' must have at least two revision history entries"),
+        instance_path: "/document/tracking/revision_history".to_string(),
+    }
+}
+
+const PROFILE_TEST_CONFIG: DocumentCategoryTestConfig = DocumentCategoryTestConfig::new().csaf20(&[]).csaf21(&[
+    CsafDocumentCategory::CsafWithdrawn,
+    CsafDocumentCategory::CsafSuperseded,
+]);
+
+/// 6.1.27.16 Revision History
+///
+/// This test only applies to documents with `/document/category` with value `csaf_withdrawn` or
+/// `csaf_superseded` for `/document/csaf_version` `2.1`.
+///
+/// A document is only withdrawn or superseded after it has been published, so documents with these
+/// categories must have at least two entries in `/document/tracking/revision_history`.
+pub fn test_6_1_27_16_revision_history(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let doc_category = doc.get_document().get_category();
+
+    if !PROFILE_TEST_CONFIG.matches_category_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category) {
+        return Ok(());
+    }
+
+    if doc.get_document().get_tracking().get_revision_history().len() < 2 {
+        return Err(vec![create_too_few_revisions_error(&doc_category)]);
+    }
+
+    Ok(())
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_1_27_16
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_1_27_16_revision_history(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_1_27_16() {
+        // Only CSAF 2.1 has this test with 6 test cases (2 error cases, 4 success cases)
+        TESTS_2_1.test_6_1_27_16.expect(
+            Err(vec![create_too_few_revisions_error(
+                &CsafDocumentCategory::CsafWithdrawn,
+            )]),
+            Err(vec![create_too_few_revisions_error(
+                &CsafDocumentCategory::CsafSuperseded,
+            )]),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
+use crate::csaf_traits::{CsafTrait, DocumentTrait, NoteTrait};
+use crate::document_category_test_helper::DocumentCategoryTestConfig;
+use crate::helpers::{NOTE_TITLE_REASONING_FOR_WITHDRAWAL, language_specific_text, matches_fixed_text};
+use crate::schema::csaf2_1::schema::NoteCategory;
+use crate::validation::ValidationError;
+
+pub(crate) fn create_missing_reasoning_note_error(doc_category: &CsafDocumentCategory, title: &str) -> ValidationError {
+    ValidationError {
+        message: format!(
+            "Document with category '{doc_category}' must have a document note with category 'description' and title '{title}'"
+        ),
+        instance_path: "/document/notes".to_string(),
+    }
+}
+
+pub(crate) fn create_multiple_reasoning_notes_error(title: &str, i_n: usize) -> ValidationError {
+    ValidationError {
+        message: format!("Document must not have more than one document note with title '{title}'"),
+        instance_path: format!("/document/notes/{i_n}"),
+    }
+}
+
+const PROFILE_TEST_CONFIG: DocumentCategoryTestConfig = DocumentCategoryTestConfig::new()
+    .csaf20(&[])
+    .csaf21(&[CsafDocumentCategory::CsafWithdrawn]);
+
+/// 6.1.27.17 Reasoning for Withdrawal
+///
+/// This test only applies to documents with `/document/category` with value `csaf_withdrawn` for
+/// `/document/csaf_version` `2.1`.
+///
+/// Documents with this category must have exactly one entry in `/document/notes` with `category` value
+/// `description` and the title `Reasoning for Withdrawal`, or its translation into the document language.
+pub fn test_6_1_27_17_reasoning_for_withdrawal(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let doc_category = doc.get_document().get_category();
+
+    if !PROFILE_TEST_CONFIG.matches_category_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category) {
+        return Ok(());
+    }
+
+    check_reasoning_note(doc, &doc_category, NOTE_TITLE_REASONING_FOR_WITHDRAWAL)
+}
+
+/// Checks that there is exactly one document note with category `description` and the given fixed title,
+/// also used by 6.1.27.18.
+pub(crate) fn check_reasoning_note(
+    doc: &impl CsafTrait,
+    doc_category: &CsafDocumentCategory,
+    title: &str,
+) -> Result<(), Vec<ValidationError>> {
+    let lang = doc.get_document().get_lang();
+    let reasoning_notes: Vec<usize> = doc
+        .get_document()
+        .get_notes()
+        .into_iter()
+        .flatten()
+        .enumerate()
+        .filter(|(_, note)| {
+            note.get_category() == NoteCategory::Description
+                && note.get_title().is_some_and(|t| matches_fixed_text(t, title, lang))
+        })
+        .map(|(i_n, _)| i_n)
+        .collect();
+
+    let expected_title = language_specific_text(title, lang).unwrap_or(title);
+    match reasoning_notes.as_slice() {
+        [] => Err(vec![create_missing_reasoning_note_error(doc_category, expected_title)]),
+        [_] => Ok(()),
+        [_, rest @ ..] => Err(rest
+            .iter()
+            .map(|i_n| create_multiple_reasoning_notes_error(expected_title, *i_n))
+            .collect()),
+    }
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_1_27_17
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_1_27_17_reasoning_for_withdrawal(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_1_27_17() {
+        let category = CsafDocumentCategory::CsafWithdrawn;
+        let missing = Err(vec![create_missing_reasoning_note_error(
+            &category,
+            NOTE_TITLE_REASONING_FOR_WITHDRAWAL,
+        )]);
+
+        // Only CSAF 2.1 has this test with 8 test cases (5 error cases, 3 success cases)
+        TESTS_2_1.test_6_1_27_17.expect(
+            missing.clone(),
+            missing.clone(),
+            missing,
+            Err(vec![create_multiple_reasoning_notes_error(
+                NOTE_TITLE_REASONING_FOR_WITHDRAWAL,
+                1,
+            )]),
+            Err(vec![create_missing_reasoning_note_error(
+                &category,
+                "Begründung für die Zurückziehung",
+            )]),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
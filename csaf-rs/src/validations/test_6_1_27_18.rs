@@ -0,0 +1,74 @@
+use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
+use crate::csaf_traits::{CsafTrait, DocumentTrait};
+use crate::document_category_test_helper::DocumentCategoryTestConfig;
+use crate::helpers::NOTE_TITLE_REASONING_FOR_SUPERSESSION;
+use crate::validation::ValidationError;
+use crate::validations::test_6_1_27_17::check_reasoning_note;
+
+const PROFILE_TEST_CONFIG: DocumentCategoryTestConfig = DocumentCategoryTestConfig::new()
+    .csaf20(&[])
+    .csaf21(&[CsafDocumentCategory::CsafSuperseded]);
+
+/// 6.1.27.18 Reasoning for Supersession
+///
+/// This test only applies to documents with `/document/category` with value `csaf_superseded` for
+/// `/document/csaf_version` `2.1`.
+///
+/// Documents with this category must have exactly one entry in `/document/notes` with `category` value
+/// `description` and the title `Reasoning for Supersession`, or its translation into the document language.
+pub fn test_6_1_27_18_reasoning_for_supersession(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let doc_category = doc.get_document().get_category();
+
+    if !PROFILE_TEST_CONFIG.matches_category_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category) {
+        return Ok(());
+    }
+
+    check_reasoning_note(doc, &doc_category, NOTE_TITLE_REASONING_FOR_SUPERSESSION)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_1_27_18
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_1_27_18_reasoning_for_supersession(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+    use crate::validations::test_6_1_27_17::{
+        create_missing_reasoning_note_error, create_multiple_reasoning_notes_error,
+    };
+
+    #[test]
+    fn test_test_6_1_27_18() {
+        let category = CsafDocumentCategory::CsafSuperseded;
+        let missing = Err(vec![create_missing_reasoning_note_error(
+            &category,
+            NOTE_TITLE_REASONING_FOR_SUPERSESSION,
+        )]);
+
+        // Only CSAF 2.1 has this test with 8 test cases (5 error cases, 3 success cases)
+        TESTS_2_1.test_6_1_27_18.expect(
+            missing.clone(),
+            missing.clone(),
+            missing,
+            Err(vec![create_multiple_reasoning_notes_error(
+                NOTE_TITLE_REASONING_FOR_SUPERSESSION,
+                1,
+            )]),
+            Err(vec![create_missing_reasoning_note_error(
+                &category,
+                "Begründung für die Ersetzung",
+            )]),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
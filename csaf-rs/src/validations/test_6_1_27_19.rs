@@ -0,0 +1,80 @@
+use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
+use crate::csaf_traits::{CsafTrait, DocumentReferenceTrait, DocumentTrait};
+use crate::document_category_test_helper::DocumentCategoryTestConfig;
+use crate::helpers::{REFERENCE_SUMMARY_SUPERSEDING_DOCUMENT, language_specific_text, matches_fixed_text};
+use crate::schema::csaf2_1::schema::CategoryOfReference;
+use crate::validation::ValidationError;
+
+fn create_missing_superseding_reference_error(doc_category: &CsafDocumentCategory, summary: &str) -> ValidationError {
+    ValidationError {
+        message: format!(
+            "Document with category '{doc_category}' must have a document reference with category 'external' and summary '{summary}'"
+        ),
+        instance_path: "/document/references".to_string(),
+    }
+}
+
+const PROFILE_TEST_CONFIG: DocumentCategoryTestConfig = DocumentCategoryTestConfig::new()
+    .csaf20(&[])
+    .csaf21(&[CsafDocumentCategory::CsafSuperseded]);
+
+/// 6.1.27.19 Reference to Superseding Document
+///
+/// This test only applies to documents with `/document/category` with value `csaf_superseded` for
+/// `/document/csaf_version` `2.1`.
+///
+/// Documents with this category must have at least one entry in `/document/references` with `category` value
+/// `external` and the summary `Superseding Document`, or its translation into the document language.
+pub fn test_6_1_27_19_reference_to_superseding_document(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let document = doc.get_document();
+    let doc_category = document.get_category();
+
+    if !PROFILE_TEST_CONFIG.matches_category_with_csaf_version(document.get_csaf_version(), &doc_category) {
+        return Ok(());
+    }
+
+    let lang = document.get_lang();
+    let has_superseding_reference = document.get_references().is_some_and(|references| {
+        references.iter().any(|reference| {
+            *reference.get_category() == CategoryOfReference::External
+                && matches_fixed_text(reference.get_summary(), REFERENCE_SUMMARY_SUPERSEDING_DOCUMENT, lang)
+        })
+    });
+    if !has_superseding_reference {
+        let summary = language_specific_text(REFERENCE_SUMMARY_SUPERSEDING_DOCUMENT, lang)
+            .unwrap_or(REFERENCE_SUMMARY_SUPERSEDING_DOCUMENT);
+        return Err(vec![create_missing_superseding_reference_error(&doc_category, summary)]);
+    }
+
+    Ok(())
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_1_27_19
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_1_27_19_reference_to_superseding_document(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_1_27_19() {
+        let missing = Err(vec![create_missing_superseding_reference_error(
+            &CsafDocumentCategory::CsafSuperseded,
+            REFERENCE_SUMMARY_SUPERSEDING_DOCUMENT,
+        )]);
+
+        // Only CSAF 2.1 has this test with 5 test cases (2 error cases, 3 success cases)
+        TESTS_2_1
+            .test_6_1_27_19
+            .expect(missing.clone(), missing, Ok(()), Ok(()), Ok(()));
+    }
+}
@@ -60,7 +60,7 @@ pub fn test_6_1_45_inconsistent_disclosure_date(doc: &impl CsafTrait) -> Result<
         };
         chrono::DateTime::parse_from_rfc3339(&date)
             .map(|rev_datetime| {
-                println!("rev_datetime: {rev_datetime:?}, newest_revision_date: {newest_revision_date:?}");
+                log::trace!("rev_datetime: {rev_datetime:?}, newest_revision_date: {newest_revision_date:?}");
                 newest_revision_date = match newest_revision_date {
                     None => Some(rev_datetime),
                     Some(prev_max) => Some(prev_max.max(rev_datetime)),
@@ -79,7 +79,7 @@ pub fn test_6_1_45_inconsistent_disclosure_date(doc: &impl CsafTrait) -> Result<
                 };
                 match chrono::DateTime::parse_from_rfc3339(&disclosure_date) {
                     Ok(disclosure_datetime) => {
-                        println!("disclosure_datetime: {disclosure_datetime:?}, newest_date: {newest_date:?}");
+                        log::trace!("disclosure_datetime: {disclosure_datetime:?}, newest_date: {newest_date:?}");
                         if disclosure_datetime > newest_date {
                             return Err(vec![create_disclosure_date_too_late_error(i_v)]);
                         }
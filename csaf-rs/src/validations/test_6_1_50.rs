@@ -0,0 +1,167 @@
+use crate::csaf_traits::{BranchTrait, CategoryOfTheBranch, CsafTrait, ProductTreeTrait};
+use crate::validation::ValidationError;
+use std::collections::HashSet;
+
+fn create_product_version_range_rule_error(version_range: &str, reason: &str, path: &str) -> ValidationError {
+    ValidationError {
+        message: format!("Product version range '{version_range}' violates the vers rules: {reason}"),
+        instance_path: format!("{path}/name"),
+    }
+}
+
+const COMPARATORS: [&str; 6] = [">=", "<=", "!=", "<", ">", "="];
+
+/// 6.1.50 Product Version Range Rules
+///
+/// For each branch with the category `product_version_range` whose `name` uses vers, it is tested that it
+/// follows the vers rules: the URI scheme and versioning scheme are lowercase, the constraints contain no
+/// whitespace, `*` is only used as the single constraint, each constraint has a valid comparator and a
+/// non-empty version, and no version is used twice.
+///
+/// Names not using vers are reported by 6.2.18.
+pub fn test_6_1_50_product_version_range_rules(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    if let Some(product_tree) = doc.get_product_tree().as_ref() {
+        product_tree.visit_all_branches(&mut |branch, path| {
+            if branch.get_category() != &CategoryOfTheBranch::ProductVersionRange {
+                return;
+            }
+            let name = branch.get_name();
+            if let Err(reason) = check_vers(name) {
+                errors
+                    .get_or_insert_with(Vec::new)
+                    .push(create_product_version_range_rule_error(name, reason, path));
+            }
+        });
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+/// Checks a version range against the vers rules, names without the `vers:` URI scheme are not checked.
+/// Also used by 6.3.12, which only looks at valid version ranges.
+pub(crate) fn check_vers(name: &str) -> Result<(), &'static str> {
+    if !name.get(..5).is_some_and(|scheme| scheme.eq_ignore_ascii_case("vers:")) {
+        return Ok(());
+    }
+    let Some(specifier) = name.strip_prefix("vers:") else {
+        return Err("the URI scheme must be lowercase 'vers'");
+    };
+    if name.chars().any(char::is_whitespace) {
+        return Err("it must not contain whitespace");
+    }
+    let Some((scheme, constraints)) = specifier.split_once('/') else {
+        return Err("the versioning scheme must be followed by '/' and the version constraints");
+    };
+    if scheme.is_empty()
+        || !scheme
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || ".-+".contains(c))
+    {
+        return Err("the versioning scheme must be given in lowercase");
+    }
+    if constraints.is_empty() {
+        return Err("at least one version constraint must be given");
+    }
+    if constraints == "*" {
+        return Ok(());
+    }
+
+    let mut versions = HashSet::new();
+    for constraint in constraints.split('|') {
+        if constraint.is_empty() {
+            return Err("version constraints must not be empty");
+        }
+        if constraint.contains('*') {
+            return Err("'*' must be the only version constraint");
+        }
+        let version = COMPARATORS
+            .iter()
+            .find_map(|comparator| constraint.strip_prefix(comparator))
+            .unwrap_or(constraint);
+        if version.is_empty() || version.starts_with(['<', '>', '=', '!']) {
+            return Err("each version constraint must consist of a valid comparator and a version");
+        }
+        if !versions.insert(version) {
+            return Err("each version must only be used once");
+        }
+    }
+
+    Ok(())
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_1_50
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_1_50_product_version_range_rules(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    const PATH: &str = "/product_tree/branches/0/branches/0/branches/0";
+
+    #[test]
+    fn test_test_6_1_50() {
+        // Only CSAF 2.1 has this test with 12 test cases (6 error cases, 6 success cases)
+        TESTS_2_1.test_6_1_50.expect(
+            Err(vec![create_product_version_range_rule_error(
+                "vers:generic/>=2.0.0|<2.0.0",
+                "each version must only be used once",
+                PATH,
+            )]),
+            Err(vec![create_product_version_range_rule_error(
+                "vers:generic/>= 4.1.0|<4.2.0",
+                "it must not contain whitespace",
+                PATH,
+            )]),
+            Err(vec![create_product_version_range_rule_error(
+                "VERS:generic/>=4.1.0|<4.2.0",
+                "the URI scheme must be lowercase 'vers'",
+                PATH,
+            )]),
+            Err(vec![create_product_version_range_rule_error(
+                "vers:Generic/>=4.1.0|<4.2.0",
+                "the versioning scheme must be given in lowercase",
+                PATH,
+            )]),
+            Err(vec![create_product_version_range_rule_error(
+                "vers:generic/*|>=4.1.0",
+                "'*' must be the only version constraint",
+                PATH,
+            )]),
+            Err(vec![create_product_version_range_rule_error(
+                "vers:generic/=>4.1.0|<4.2.0",
+                "each version constraint must consist of a valid comparator and a version",
+                PATH,
+            )]),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+        );
+    }
+
+    #[test]
+    fn test_check_vers() {
+        assert_eq!(check_vers(">=4.1.0 <4.2.0"), Ok(()));
+        assert_eq!(check_vers("vers:semver/*"), Ok(()));
+        assert_eq!(check_vers("vers:npm/1.2.3|>=2.0.0|<5.0.0"), Ok(()));
+        assert_eq!(check_vers("vers:npm/!=1.2.3"), Ok(()));
+        assert!(check_vers("vers:npm").is_err());
+        assert!(check_vers("vers:/1.0").is_err());
+        assert!(check_vers("vers:npm/").is_err());
+        assert!(check_vers("vers:npm/1.0||2.0").is_err());
+        assert!(check_vers("vers:npm/<").is_err());
+    }
+}
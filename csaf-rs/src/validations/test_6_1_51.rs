@@ -0,0 +1,102 @@
+use crate::csaf::types::csaf_datetime::CsafDateTime;
+use crate::csaf_traits::{ContentTrait, CsafTrait, DocumentTrait, MetricTrait, TrackingTrait, VulnerabilityTrait};
+use crate::schema::csaf2_1::schema::DocumentStatus;
+use crate::validation::ValidationError;
+
+fn create_epss_timestamp_too_late_error(
+    timestamp: &str,
+    newest_revision_date: &str,
+    i_v: usize,
+    i_m: usize,
+) -> ValidationError {
+    ValidationError {
+        message: format!(
+            "EPSS timestamp ({timestamp}) is later than the newest revision date ({newest_revision_date})"
+        ),
+        instance_path: format!("/vulnerabilities/{i_v}/metrics/{i_m}/content/epss/timestamp"),
+    }
+}
+
+/// 6.1.51 Inconsistent EPSS Timestamp
+///
+/// For each EPSS metric, it is tested that its `timestamp` is earlier or equal to the `date` of the
+/// newest item in the `revision_history` if the document status is `final` or `interim`.
+pub fn test_6_1_51_inconsistent_epss_timestamp(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let tracking = doc.get_document().get_tracking();
+    let status = tracking.get_status();
+    if status != DocumentStatus::Final && status != DocumentStatus::Interim {
+        return Ok(());
+    }
+    // Empty revision histories and invalid dates are reported by other tests
+    let Some(newest_revision_date) = tracking.get_newest_revision_date() else {
+        return Ok(());
+    };
+
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (i_v, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        for (i_m, metric) in vulnerability.get_metrics().into_iter().flatten().enumerate() {
+            let Some(epss) = metric.get_content().get_epss() else {
+                continue;
+            };
+            if let CsafDateTime::Valid(timestamp) = CsafDateTime::from(&epss.timestamp)
+                && timestamp > newest_revision_date
+            {
+                errors
+                    .get_or_insert_with(Vec::new)
+                    .push(create_epss_timestamp_too_late_error(
+                        &epss.timestamp,
+                        newest_revision_date.get_raw_string(),
+                        i_v,
+                        i_m,
+                    ));
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_1_51
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_1_51_inconsistent_epss_timestamp(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_1_51() {
+        // Only CSAF 2.1 has this test with 6 test cases (3 error cases, 3 success cases)
+        TESTS_2_1.test_6_1_51.expect(
+            Err(vec![create_epss_timestamp_too_late_error(
+                "2024-07-13T10:00:00.000Z",
+                "2024-01-24T10:00:00.000Z",
+                0,
+                0,
+            )]),
+            Err(vec![create_epss_timestamp_too_late_error(
+                "2024-02-29T10:30:00.000Z",
+                "2024-02-29T10:00:00.000Z",
+                0,
+                0,
+            )]),
+            Err(vec![create_epss_timestamp_too_late_error(
+                "2024-02-29T10:30:00.000Z",
+                "2024-02-29T10:00:00.000Z",
+                0,
+                0,
+            )]),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use crate::csaf::types::csaf_datetime::CsafDateTime;
+use crate::csaf_traits::{CsafTrait, DocumentTrait, TrackingTrait, VulnerabilityTrait, WithDate};
+use crate::schema::csaf2_1::schema::DocumentStatus;
+use crate::validation::ValidationError;
+
+fn create_exploitation_information_too_late_error(
+    date: &str,
+    newest_revision_date: &str,
+    i_v: usize,
+    i_d: usize,
+) -> ValidationError {
+    ValidationError {
+        message: format!(
+            "Date of the first known exploitation information ({date}) is later than the newest revision date ({newest_revision_date})"
+        ),
+        instance_path: format!("/vulnerabilities/{i_v}/first_known_exploitation_dates/{i_d}/date"),
+    }
+}
+
+/// 6.1.52 Inconsistent First Known Exploitation Dates
+///
+/// For each item of the first known exploitation dates, it is tested that its `date` is earlier or
+/// equal to the `date` of the newest item in the `revision_history` if the document status is
+/// `final` or `interim`.
+pub fn test_6_1_52_inconsistent_first_known_exploitation_dates(
+    doc: &impl CsafTrait,
+) -> Result<(), Vec<ValidationError>> {
+    let tracking = doc.get_document().get_tracking();
+    let status = tracking.get_status();
+    if status != DocumentStatus::Final && status != DocumentStatus::Interim {
+        return Ok(());
+    }
+    // Empty revision histories and invalid dates are reported by other tests
+    let Some(newest_revision_date) = tracking.get_newest_revision_date() else {
+        return Ok(());
+    };
+
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (i_v, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        for (i_d, exploitation) in vulnerability
+            .get_first_known_exploitation_dates()
+            .into_iter()
+            .flatten()
+            .enumerate()
+        {
+            if let CsafDateTime::Valid(date) = exploitation.get_date()
+                && date > newest_revision_date
+            {
+                errors
+                    .get_or_insert_with(Vec::new)
+                    .push(create_exploitation_information_too_late_error(
+                        date.get_raw_string(),
+                        newest_revision_date.get_raw_string(),
+                        i_v,
+                        i_d,
+                    ));
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_1_52
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_1_52_inconsistent_first_known_exploitation_dates(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_1_52() {
+        // Only CSAF 2.1 has this test with 4 test cases (2 error cases, 2 success cases)
+        TESTS_2_1.test_6_1_52.expect(
+            Err(vec![create_exploitation_information_too_late_error(
+                "2024-07-13T10:00:00.000Z",
+                "2024-01-24T10:00:00.000Z",
+                0,
+                0,
+            )]),
+            Err(vec![create_exploitation_information_too_late_error(
+                "2024-02-29T10:30:00.000Z",
+                "2024-02-29T10:00:00.000Z",
+                0,
+                0,
+            )]),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use crate::csaf::types::csaf_datetime::CsafDateTime;
+use crate::csaf_traits::{CsafTrait, FirstKnownExploitationDatesTrait, VulnerabilityTrait, WithDate};
+use crate::validation::ValidationError;
+
+fn create_exploitation_after_information_error(
+    exploitation_date: &str,
+    date: &str,
+    i_v: usize,
+    i_d: usize,
+) -> ValidationError {
+    ValidationError {
+        message: format!("Exploitation date ({exploitation_date}) is later than the date of the information ({date})"),
+        instance_path: format!("/vulnerabilities/{i_v}/first_known_exploitation_dates/{i_d}/exploitation_date"),
+    }
+}
+
+/// 6.1.53 Inconsistent Exploitation Date
+///
+/// For each item of the first known exploitation dates, it is tested that the `exploitation_date`
+/// is earlier or equal to the `date` of the information.
+pub fn test_6_1_53_inconsistent_exploitation_date(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    for (i_v, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        for (i_d, exploitation) in vulnerability
+            .get_first_known_exploitation_dates()
+            .into_iter()
+            .flatten()
+            .enumerate()
+        {
+            // Invalid dates are reported by 6.1.37
+            if let (CsafDateTime::Valid(exploitation_date), CsafDateTime::Valid(date)) =
+                (exploitation.get_exploitation_date(), exploitation.get_date())
+                && exploitation_date > date
+            {
+                errors
+                    .get_or_insert_with(Vec::new)
+                    .push(create_exploitation_after_information_error(
+                        exploitation_date.get_raw_string(),
+                        date.get_raw_string(),
+                        i_v,
+                        i_d,
+                    ));
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_1_53
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_1_53_inconsistent_exploitation_date(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_1_53() {
+        // Only CSAF 2.1 has this test with 4 test cases (2 error cases, 2 success cases)
+        TESTS_2_1.test_6_1_53.expect(
+            Err(vec![create_exploitation_after_information_error(
+                "2024-01-24T10:00:00.000Z",
+                "2024-01-23T10:00:00.000Z",
+                0,
+                0,
+            )]),
+            Err(vec![create_exploitation_after_information_error(
+                "2024-02-29T10:30:00.000Z",
+                "2024-02-29T10:00:00.000Z",
+                0,
+                0,
+            )]),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use crate::csaf_traits::{CsafTrait, DocumentTrait};
+use crate::validation::ValidationError;
+
+fn create_invalid_license_expression_error(license_expression: &str) -> ValidationError {
+    ValidationError {
+        message: format!("License expression '{license_expression}' is not a valid SPDX license expression"),
+        instance_path: "/document/license_expression".to_string(),
+    }
+}
+
+/// 6.1.54 License Expression
+///
+/// Tests that the `/document/license_expression` is a syntactically valid SPDX license expression.
+pub fn test_6_1_54_license_expression(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let Some(license_expression) = doc.get_document().get_license_expression() else {
+        return Ok(());
+    };
+
+    if !is_valid_license_expression(license_expression) {
+        return Err(vec![create_invalid_license_expression_error(license_expression)]);
+    }
+
+    Ok(())
+}
+
+/// Checks the syntax of an SPDX license expression, see
+/// <https://spdx.github.io/spdx-spec/v2.3/SPDX-license-expressions/>.
+fn is_valid_license_expression(expression: &str) -> bool {
+    let tokens = tokenize(expression);
+    let mut position = 0;
+    parse_or_expression(&tokens, &mut position) && position == tokens.len()
+}
+
+/// Splits an expression at whitespace, keeping parentheses as separate tokens, also used by 6.2.42 and 6.2.43.
+pub(crate) fn tokenize(expression: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    for word in expression.split_whitespace() {
+        let mut start = 0;
+        for (i, c) in word.char_indices() {
+            if c == '(' || c == ')' {
+                if start < i {
+                    tokens.push(&word[start..i]);
+                }
+                tokens.push(&word[i..i + 1]);
+                start = i + 1;
+            }
+        }
+        if start < word.len() {
+            tokens.push(&word[start..]);
+        }
+    }
+    tokens
+}
+
+/// Operators are matched either all uppercase or all lowercase.
+fn is_operator(token: Option<&&str>, operator: &str) -> bool {
+    token.is_some_and(|t| *t == operator || *t == operator.to_lowercase())
+}
+
+fn parse_or_expression(tokens: &[&str], position: &mut usize) -> bool {
+    if !parse_and_expression(tokens, position) {
+        return false;
+    }
+    while is_operator(tokens.get(*position), "OR") {
+        *position += 1;
+        if !parse_and_expression(tokens, position) {
+            return false;
+        }
+    }
+    true
+}
+
+fn parse_and_expression(tokens: &[&str], position: &mut usize) -> bool {
+    if !parse_with_expression(tokens, position) {
+        return false;
+    }
+    while is_operator(tokens.get(*position), "AND") {
+        *position += 1;
+        if !parse_with_expression(tokens, position) {
+            return false;
+        }
+    }
+    true
+}
+
+fn parse_with_expression(tokens: &[&str], position: &mut usize) -> bool {
+    match tokens.get(*position) {
+        Some(&"(") => {
+            *position += 1;
+            if !parse_or_expression(tokens, position) || tokens.get(*position) != Some(&")") {
+                return false;
+            }
+            *position += 1;
+            true
+        },
+        Some(token) if is_simple_expression(token) => {
+            *position += 1;
+            if is_operator(tokens.get(*position), "WITH") {
+                *position += 1;
+                let Some(exception) = tokens.get(*position) else {
+                    return false;
+                };
+                if !is_exception(exception) {
+                    return false;
+                }
+                *position += 1;
+            }
+            true
+        },
+        _ => false,
+    }
+}
+
+fn is_idstring(token: &str) -> bool {
+    !token.is_empty() && token.chars().all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-')
+}
+
+fn is_license_ref(token: &str) -> bool {
+    token.strip_prefix("LicenseRef-").is_some_and(is_idstring)
+}
+
+/// A license id or `LicenseRef-` (both optionally followed by `+`), or a `DocumentRef-` reference.
+fn is_simple_expression(token: &str) -> bool {
+    if let Some(document_ref) = token.strip_prefix("DocumentRef-") {
+        return document_ref
+            .split_once(':')
+            .is_some_and(|(document, license)| is_idstring(document) && is_license_ref(license));
+    }
+    let license = token.strip_suffix('+').unwrap_or(token);
+    if license.starts_with("LicenseRef-") {
+        return is_license_ref(license);
+    }
+    is_idstring(license) && !["AND", "OR", "WITH", "and", "or", "with"].contains(&token)
+}
+
+fn is_exception(token: &str) -> bool {
+    if let Some(document_ref) = token.strip_prefix("DocumentRef-") {
+        return document_ref.split_once(':').is_some_and(|(document, addition)| {
+            is_idstring(document) && addition.strip_prefix("AdditionRef-").is_some_and(is_idstring)
+        });
+    }
+    is_idstring(token)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_1_54
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_1_54_license_expression(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_is_valid_license_expression() {
+        for valid in [
+            "CC-BY-4.0",
+            "LicenseRef-www.example.org-Example-CSAF-License-3.0+",
+            "LicenseRef-scancode-public-domain",
+            "MIT OR any-OSI",
+            "GPL-2.0-or-later WITH Classpath-exception-2.0",
+            "(MIT AND BSD-3-Clause) or Apache-2.0+",
+            "DocumentRef-spdx-tool-1.2:LicenseRef-MIT-Style-2",
+        ] {
+            assert!(is_valid_license_expression(valid), "{valid}");
+        }
+        for invalid in [
+            "",
+            "MIT OR",
+            "AND MIT",
+            "(MIT",
+            "MIT)",
+            "MIT Apache-2.0",
+            "MIT And Apache-2.0",
+            "MIT WITH",
+            "LicenseRef-",
+            "My License",
+        ] {
+            assert!(!is_valid_license_expression(invalid), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_test_6_1_54() {
+        // Only CSAF 2.1 has this test with 6 test cases (3 error cases, 3 success cases)
+        TESTS_2_1.test_6_1_54.expect(
+            Err(vec![create_invalid_license_expression_error(
+                "LicenseRef-www.example.org-Example-CSAF-License-3.0+ OR",
+            )]),
+            Err(vec![create_invalid_license_expression_error("MIT Apache-2.0")]),
+            Err(vec![create_invalid_license_expression_error("(MIT OR Apache-2.0")]),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use crate::csaf_traits::{CsafTrait, DocumentTrait, NoteTrait};
+use crate::helpers::{NOTE_TITLE_LICENSE, language_specific_text, matches_fixed_text};
+use crate::schema::csaf2_1::schema::NoteCategory;
+use crate::validation::ValidationError;
+
+fn create_missing_license_text_error(license_ref: &str, title: &str) -> ValidationError {
+    ValidationError {
+        message: format!(
+            "License '{license_ref}' is not on the SPDX license list, its text must be given in a document note with category 'legal_disclaimer' and title '{title}'"
+        ),
+        instance_path: "/document/notes".to_string(),
+    }
+}
+
+/// 6.1.55 License Text
+///
+/// If the `/document/license_expression` uses a license that is not on the SPDX license list, i.e. a
+/// `LicenseRef-` identifier, it is tested that the document has a note with category `legal_disclaimer` and
+/// the title `License`, or its translation into the document language, holding the license text.
+pub fn test_6_1_55_license_text(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let document = doc.get_document();
+    let Some(license_expression) = document.get_license_expression() else {
+        return Ok(());
+    };
+    let Some(license_ref) = license_expression
+        .split(|c: char| c.is_whitespace() || c == '(' || c == ')')
+        .find(|token| token.starts_with("LicenseRef-") || token.starts_with("DocumentRef-"))
+    else {
+        return Ok(());
+    };
+
+    let lang = document.get_lang();
+    let has_license_note = document.get_notes().is_some_and(|notes| {
+        notes.iter().any(|note| {
+            note.get_category() == NoteCategory::LegalDisclaimer
+                && note
+                    .get_title()
+                    .is_some_and(|t| matches_fixed_text(t, NOTE_TITLE_LICENSE, lang))
+        })
+    });
+    if !has_license_note {
+        let title = language_specific_text(NOTE_TITLE_LICENSE, lang).unwrap_or(NOTE_TITLE_LICENSE);
+        return Err(vec![create_missing_license_text_error(license_ref, title)]);
+    }
+
+    Ok(())
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_1_55
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_1_55_license_text(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_1_55() {
+        let case_01 = Err(vec![create_missing_license_text_error(
+            "LicenseRef-www.example.org-Example-CSAF-License-3.0+",
+            NOTE_TITLE_LICENSE,
+        )]);
+
+        // Only CSAF 2.1 has this test with 4 test cases (2 error cases, 2 success cases)
+        TESTS_2_1.test_6_1_55.expect(case_01.clone(), case_01, Ok(()), Ok(()));
+    }
+}
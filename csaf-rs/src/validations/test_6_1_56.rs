@@ -0,0 +1,157 @@
+use crate::csaf_traits::{
+    CsafTrait, FileHashTrait, HashTrait, ProductIdentificationHelperTrait, ProductTrait, ProductTreeTrait,
+};
+use crate::validation::ValidationError;
+use std::collections::HashMap;
+
+fn create_same_helper_error(
+    helper: &str,
+    value: &str,
+    product_id: &str,
+    other_product_id: &str,
+    path: &str,
+) -> ValidationError {
+    ValidationError {
+        message: format!(
+            "The {helper} '{value}' of product '{product_id}' is already used to identify product '{other_product_id}'"
+        ),
+        instance_path: path.to_string(),
+    }
+}
+
+/// 6.1.56 Use of Same Product Identification Helper for Different Products
+///
+/// It is tested that the CPE, the purls, the serial numbers and the file hashes in the product identification
+/// helpers each identify only one product, i.e. are not used in the product identification helpers of
+/// products with different product IDs.
+///
+/// Model numbers are not checked, as different versions of a hardware product share its model number.
+pub fn test_6_1_56_use_of_same_product_identification_helper(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let Some(product_tree) = doc.get_product_tree() else {
+        return Ok(());
+    };
+
+    // Maps (helper, value) to the ID of the first product using it
+    let mut first_use: HashMap<(&str, String), String> = HashMap::new();
+    let mut errors: Option<Vec<ValidationError>> = None;
+    product_tree.visit_all_products(&mut |product, path| {
+        let Some(helper) = product.get_product_identification_helper() else {
+            return;
+        };
+        let helper_path = format!("{path}/product_identification_helper");
+        let mut values: Vec<(&str, String, String)> = Vec::new();
+        if let Some(cpe) = helper.get_cpe() {
+            values.push(("CPE", cpe.to_owned(), format!("{helper_path}/cpe")));
+        }
+        for (i, purl) in helper.get_purls().unwrap_or_default().iter().enumerate() {
+            values.push(("purl", purl.to_owned(), format!("{helper_path}/purls/{i}")));
+        }
+        for (i, serial_number) in helper.get_serial_numbers().into_iter().flatten().enumerate() {
+            values.push((
+                "serial number",
+                serial_number.to_owned(),
+                format!("{helper_path}/serial_numbers/{i}"),
+            ));
+        }
+        for (i_h, hash) in helper.get_hashes().iter().enumerate() {
+            for (i_f, file_hash) in hash.get_file_hashes().iter().enumerate() {
+                values.push((
+                    "file hash",
+                    file_hash.get_hash().to_lowercase(),
+                    format!("{helper_path}/hashes/{i_h}/file_hashes/{i_f}/value"),
+                ));
+            }
+        }
+
+        let product_id = product.get_product_id();
+        for (kind, value, value_path) in values {
+            match first_use.get(&(kind, value.clone())) {
+                // The same product ID defined twice is reported by 6.1.2
+                Some(other_product_id) if other_product_id != product_id => {
+                    errors.get_or_insert_with(Vec::new).push(create_same_helper_error(
+                        kind,
+                        &value,
+                        product_id,
+                        other_product_id,
+                        &value_path,
+                    ));
+                },
+                Some(_) => {},
+                None => {
+                    first_use.insert((kind, value), product_id.to_owned());
+                },
+            }
+        }
+    });
+
+    errors.map_or(Ok(()), Err)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_1_56
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_1_56_use_of_same_product_identification_helper(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    const HELPER_PATH: &str = "/product_tree/full_product_names/1/product_identification_helper";
+
+    #[test]
+    fn test_test_6_1_56() {
+        // Only CSAF 2.1 has this test with 13 test cases (5 error cases, 8 success cases)
+        TESTS_2_1.test_6_1_56.expect(
+            Err(vec![create_same_helper_error(
+                "CPE",
+                "cpe:/a:example:cms:4.2",
+                "CSAFPID-9080701",
+                "CSAFPID-9080700",
+                &format!("{HELPER_PATH}/cpe"),
+            )]),
+            Err(vec![create_same_helper_error(
+                "purl",
+                "pkg:maven/org.example/cms@4.2",
+                "CSAFPID-9080701",
+                "CSAFPID-9080700",
+                &format!("{HELPER_PATH}/purls/0"),
+            )]),
+            Err(vec![create_same_helper_error(
+                "serial number",
+                "143-D-354",
+                "CSAFPID-9080701",
+                "CSAFPID-9080700",
+                &format!("{HELPER_PATH}/serial_numbers/0"),
+            )]),
+            Err(vec![create_same_helper_error(
+                "file hash",
+                "026a37919b182ef7c63791e82c9645e2f897a3f0b73c7a6028c7febf62e93838",
+                "CSAFPID-9080701",
+                "CSAFPID-9080700",
+                &format!("{HELPER_PATH}/hashes/0/file_hashes/0/value"),
+            )]),
+            Err(vec![create_same_helper_error(
+                "purl",
+                "pkg:maven/org.example/cms@4.2",
+                "CSAFPID-9080701",
+                "CSAFPID-9080700",
+                "/product_tree/branches/0/branches/0/branches/1/product/product_identification_helper/purls/0",
+            )]),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
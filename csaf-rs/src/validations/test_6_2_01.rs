@@ -1,7 +1,10 @@
 use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
 use crate::csaf_traits::{CsafTrait, DocumentTrait, ProductTrait, ProductTreeTrait};
 use crate::document_category_test_helper::DocumentCategoryTestConfig;
+use crate::fix::{Fix, PatchOperation};
 use crate::validation::ValidationError;
+use serde_json::Value;
+use std::collections::BTreeMap;
 
 fn create_unused_product_id_error(product_id: &str, path: &str) -> ValidationError {
     ValidationError {
@@ -41,6 +44,78 @@ pub fn test_6_2_01_unused_definition_of_product_id(doc: &impl CsafTrait) -> Resu
     errors.map_or(Ok(()), Err)
 }
 
+/// Fixes 6.2.1 by removing the unused products from `/product_tree/full_product_names` and
+/// `/product_tree/relationships`.
+///
+/// Unused products in branches are kept, as removing them would leave the branch without a product.
+pub fn fix_6_2_01_unused_definition_of_product_id(doc: &impl CsafTrait, json: &Value) -> Vec<Fix> {
+    let Err(errors) = test_6_2_01_unused_definition_of_product_id(doc) else {
+        return Vec::new();
+    };
+
+    // Indexes of the unused items by array
+    let mut unused: BTreeMap<&str, Vec<usize>> = BTreeMap::new();
+    for error in &errors {
+        let path = error.instance_path.trim_end_matches("/product_id");
+        let path = path.trim_end_matches("/full_product_name");
+        for array in ["/product_tree/full_product_names", "/product_tree/relationships"] {
+            if let Some(index) = path.strip_prefix(array).and_then(|i| i.strip_prefix('/'))
+                && let Ok(index) = index.parse()
+            {
+                unused.entry(array).or_default().push(index);
+            }
+        }
+    }
+
+    let is_emptied = |array: &str, indexes: &Vec<usize>| {
+        json.pointer(array).and_then(Value::as_array).map_or(0, Vec::len) == indexes.len()
+    };
+    // A product tree that would be left empty is removed as a whole, as it must not be empty
+    let product_tree_keys = json
+        .pointer("/product_tree")
+        .and_then(Value::as_object)
+        .map_or(0, |o| o.len());
+    let emptied_arrays = unused
+        .iter()
+        .filter(|(array, indexes)| is_emptied(array, indexes))
+        .count();
+    if emptied_arrays == product_tree_keys {
+        let count = unused.values().map(Vec::len).sum::<usize>();
+        return vec![Fix::new(
+            format!("Remove the product tree, as none of its {count} product(s) is used"),
+            "/product_tree",
+            vec![PatchOperation::Remove {
+                path: "/product_tree".to_string(),
+            }],
+        )];
+    }
+
+    let mut fixes = Vec::new();
+    for (array, mut indexes) in unused {
+        // An array that would be left empty is removed as a whole, as arrays must not be empty
+        let patch = if is_emptied(array, &indexes) {
+            vec![PatchOperation::Remove {
+                path: array.to_string(),
+            }]
+        } else {
+            // Removing from the end keeps the indexes of the items still to be removed
+            indexes.sort_unstable_by(|a, b| b.cmp(a));
+            indexes
+                .iter()
+                .map(|index| PatchOperation::Remove {
+                    path: format!("{array}/{index}"),
+                })
+                .collect()
+        };
+        fixes.push(Fix::new(
+            format!("Remove {} unused product(s) from {array}", indexes.len()),
+            array,
+            patch,
+        ));
+    }
+    fixes
+}
+
 impl crate::test_validation::TestValidator<crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework>
     for crate::csaf2_0::testcases::ValidatorForTest6_2_1
 {
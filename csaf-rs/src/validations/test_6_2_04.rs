@@ -1,6 +1,8 @@
 use crate::csaf::types::csaf_version_number::{CsafVersionNumber, SemVerVersion, ValidVersionNumber};
 use crate::csaf_traits::{CsafTrait, DocumentTrait, RevisionTrait, TrackingTrait};
+use crate::fix::Fix;
 use crate::validation::ValidationError;
+use serde_json::Value;
 
 /// 6.2.4 Build Metadata in Revision History
 ///
@@ -39,6 +41,36 @@ pub fn test_6_2_04_build_metadata_in_rev_history(doc: &impl CsafTrait) -> Result
     errors.map_or(Ok(()), Err)
 }
 
+/// Fixes 6.2.4 by removing the build metadata from the revision history numbers.
+pub fn fix_6_2_04_build_metadata_in_rev_history(doc: &impl CsafTrait, json: &Value) -> Vec<Fix> {
+    let mut fixes = Vec::new();
+    for (revision_index, revision) in doc
+        .get_document()
+        .get_tracking()
+        .get_revision_history()
+        .iter()
+        .enumerate()
+    {
+        let CsafVersionNumber::Valid(ValidVersionNumber::SemVer(semver)) = revision.get_number() else {
+            continue;
+        };
+        let path = format!("/document/tracking/revision_history/{revision_index}/number");
+        if semver.has_build_metadata()
+            && let Some((number, _)) = json
+                .pointer(&path)
+                .and_then(Value::as_str)
+                .and_then(|n| n.split_once('+'))
+        {
+            fixes.push(Fix::replace(
+                format!("Remove the build metadata from revision history number '{semver}'"),
+                &path,
+                Value::String(number.to_string()),
+            ));
+        }
+    }
+    fixes
+}
+
 fn create_build_metadata_in_rev_history_error(number: &SemVerVersion, revision_index: &usize) -> ValidationError {
     ValidationError {
         message: format!("Revision history item with number '{number}' contains build metadata"),
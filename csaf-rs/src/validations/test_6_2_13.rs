@@ -1,5 +1,6 @@
+use crate::fix::{Fix, escape_pointer_token};
 use crate::{csaf::raw::RawDocument, validation::ValidationError};
-use serde_json::Value;
+use serde_json::{Map, Value};
 
 /// 6.2.13 Sorting
 ///
@@ -47,6 +48,37 @@ fn check_sorted_recursive(value: &Value, path: &str, errors: &mut Vec<Validation
     }
 }
 
+/// Fixes 6.2.13 by sorting the keys of all objects alphabetically.
+pub fn fix_6_2_13_sorting(json: &Value) -> Vec<Fix> {
+    let mut fixes = Vec::new();
+    collect_sorting_fixes(json, "", &mut fixes);
+    fixes
+}
+
+fn collect_sorting_fixes(value: &Value, path: &str, fixes: &mut Vec<Fix>) {
+    let children: Box<dyn Iterator<Item = (String, &Value)>> = match value {
+        Value::Object(map) => {
+            if map.keys().zip(map.keys().skip(1)).any(|(a, b)| a > b) {
+                // Only this object is sorted, its children are replaced by their own fixes
+                let mut entries: Vec<(&String, &Value)> = map.iter().collect();
+                entries.sort_by_key(|(key, _)| *key);
+                let sorted: Map<String, Value> = entries.into_iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+                fixes.push(Fix::replace(
+                    "Sort the keys alphabetically",
+                    path,
+                    Value::Object(sorted),
+                ));
+            }
+            Box::new(map.iter().map(|(key, value)| (escape_pointer_token(key), value)))
+        },
+        Value::Array(arr) => Box::new(arr.iter().enumerate().map(|(i, value)| (i.to_string(), value))),
+        _ => return,
+    };
+    for (token, child) in children {
+        collect_sorting_fixes(child, &format!("{path}/{token}"), fixes);
+    }
+}
+
 fn create_unsorted_keys_error(path: &str) -> ValidationError {
     ValidationError {
         message: "The keys in the CSAF document are not sorted alphabetically".to_string(),
@@ -90,4 +122,22 @@ mod tests {
         TESTS_2_0.test_6_2_13.expect(err.clone());
         TESTS_2_1.test_6_2_13.expect(err);
     }
+
+    #[test]
+    fn test_fix_6_2_13() {
+        let json = serde_json::json!({ "b": [{ "y": 1, "x": 2 }], "a~/": { "a": 1 }, "c": { "e": 1, "d": 2 } });
+        let fixes = fix_6_2_13_sorting(&json);
+        let paths: Vec<&str> = fixes.iter().map(|fix| fix.instance_path.as_str()).collect();
+        assert_eq!(paths, vec!["", "/b/0", "/c"]);
+
+        let mut fixed = json.clone();
+        for fix in &fixes {
+            crate::fix::apply_patch(&mut fixed, &fix.patch).unwrap();
+        }
+        assert_eq!(
+            serde_json::to_string(&fixed).unwrap(),
+            r#"{"a~/":{"a":1},"b":[{"x":2,"y":1}],"c":{"d":2,"e":1}}"#
+        );
+        assert!(fix_6_2_13_sorting(&fixed).is_empty());
+    }
 }
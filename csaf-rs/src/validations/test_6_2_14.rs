@@ -0,0 +1,121 @@
+use crate::csaf::types::language_tag::LanguageTag;
+use crate::csaf_traits::{CsafTrait, DocumentTrait};
+use crate::validation::ValidationError;
+
+fn create_private_language_error(language: &str, private_use_subtags: &[&str], path: &str) -> ValidationError {
+    ValidationError {
+        message: format!(
+            "The language code '{language}' uses subtags reserved for private use: {}",
+            private_use_subtags.join(", ")
+        ),
+        instance_path: path.to_string(),
+    }
+}
+
+/// 6.2.14 Use of Private Language
+///
+/// Tests that the language tags in `/document/lang` and `/document/source_lang` contain no subtags
+/// reserved for private use, like the language `qtx`, the region `XA` or anything after `x-`.
+/// Language tags that are not well-formed are left to 6.1.12.
+pub fn test_6_2_14_use_of_private_language(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+    let document = doc.get_document();
+    let languages = [
+        (document.get_lang(), "/document/lang"),
+        (document.get_source_lang(), "/document/source_lang"),
+    ];
+    for (lang, path) in languages {
+        let Some(tag) = lang.and_then(|lang| lang.parse::<LanguageTag>().ok()) else {
+            continue;
+        };
+        let private_use_subtags = tag.private_use_subtags();
+        if !private_use_subtags.is_empty() {
+            errors.get_or_insert_with(Vec::new).push(create_private_language_error(
+                &tag.to_string(),
+                &private_use_subtags,
+                path,
+            ));
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_0::testcases::ValidatorForTest6_2_14
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_14_use_of_private_language(doc)
+    }
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_14
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_14_use_of_private_language(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_0::testcases::TESTS_2_0;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_14() {
+        let case_01 = Err(vec![create_private_language_error("qtx", &["qtx"], "/document/lang")]);
+        let case_02 = Err(vec![create_private_language_error(
+            "qtx",
+            &["qtx"],
+            "/document/source_lang",
+        )]);
+        let case_03 = Err(vec![create_private_language_error("en-AA", &["AA"], "/document/lang")]);
+        let case_04 = Err(vec![create_private_language_error("en-XP", &["XP"], "/document/lang")]);
+        let case_05 = Err(vec![create_private_language_error("en-QM", &["QM"], "/document/lang")]);
+        let case_06 = Err(vec![create_private_language_error("en-ZZ", &["ZZ"], "/document/lang")]);
+        let case_07 = Err(vec![create_private_language_error(
+            "en-Qaaa",
+            &["Qaaa"],
+            "/document/lang",
+        )]);
+        let case_08 = Err(vec![create_private_language_error(
+            "en-x-klingon",
+            &["klingon"],
+            "/document/lang",
+        )]);
+
+        // Both CSAF 2.0 and 2.1 have 10 test cases (8 error cases, 2 success cases)
+        TESTS_2_0.test_6_2_14.expect(
+            case_01.clone(),
+            case_02.clone(),
+            case_03.clone(),
+            case_04.clone(),
+            case_05.clone(),
+            case_06.clone(),
+            case_07.clone(),
+            case_08.clone(),
+            Ok(()),
+            Ok(()),
+        );
+        TESTS_2_1.test_6_2_14.expect(
+            case_01,
+            case_02,
+            case_03,
+            case_04,
+            case_05,
+            case_06,
+            case_07,
+            case_08,
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
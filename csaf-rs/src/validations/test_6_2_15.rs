@@ -1,26 +1,25 @@
+use crate::csaf::types::language_tag::LanguageTag;
 use crate::csaf_traits::{CsafTrait, DocumentTrait};
 use crate::validation::ValidationError;
 
 /// 6.2.15 Use of Default Language
 ///
-/// The language tag in `/document/lang` and `/document/source_lang` must not contain the default language code `i-default`.
+/// The language tag in `/document/lang` and `/document/source_lang` must not be the default language tag `i-default`.
 pub fn test_6_2_15_use_of_default_language(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
     let mut errors: Option<Vec<ValidationError>> = None;
-    let default_lang_tag = "i-default";
     let document = doc.get_document();
-    if let Some(lang) = document.get_lang()
-        && lang == default_lang_tag
-    {
-        errors
-            .get_or_insert_with(Vec::new)
-            .push(create_default_language_error("/document/lang"));
-    }
-    if let Some(source_lang) = document.get_source_lang()
-        && source_lang == default_lang_tag
-    {
-        errors
-            .get_or_insert_with(Vec::new)
-            .push(create_default_language_error("/document/source_lang"));
+    let languages = [
+        (document.get_lang(), "/document/lang"),
+        (document.get_source_lang(), "/document/source_lang"),
+    ];
+    for (lang, path) in languages {
+        if let Some(lang) = lang
+            && lang.parse::<LanguageTag>().is_ok_and(|tag| tag.is_default())
+        {
+            errors
+                .get_or_insert_with(Vec::new)
+                .push(create_default_language_error(path));
+        }
     }
 
     errors.map_or(Ok(()), Err)
@@ -0,0 +1,76 @@
+use crate::csaf_traits::{CsafTrait, DocumentTrait, TrackingTrait, WithDate};
+use crate::validation::ValidationError;
+
+fn create_same_timestamp_error(date: &str, other_index: usize, index: usize) -> ValidationError {
+    ValidationError {
+        message: format!(
+            "Revision history item has the same timestamp ({date}) as the revision history item at index {other_index}"
+        ),
+        instance_path: format!("/document/tracking/revision_history/{index}/date"),
+    }
+}
+
+/// 6.2.21 Same Timestamps in Revision History
+///
+/// Tests that no two items of the revision history have the same timestamp. Timestamps are compared
+/// by their value, so the same point in time given in different time zones is also reported.
+pub fn test_6_2_21_same_timestamps_in_revision_history(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    let dates: Vec<_> = doc
+        .get_document()
+        .get_tracking()
+        .get_revision_history()
+        .iter()
+        .map(|revision| revision.get_date())
+        .collect();
+    for (i_r, date) in dates.iter().enumerate() {
+        // Invalid dates are reported by 6.1.37 and never compare equal
+        if let Some(other_index) = dates.iter().position(|other| other == date)
+            && other_index != i_r
+        {
+            errors.get_or_insert_with(Vec::new).push(create_same_timestamp_error(
+                date.get_raw_string(),
+                other_index,
+                i_r,
+            ));
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_21
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_21_same_timestamps_in_revision_history(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_21() {
+        // Only CSAF 2.1 has this test with 8 test cases (5 error cases, 3 success cases)
+        TESTS_2_1.test_6_2_21.expect(
+            Err(vec![create_same_timestamp_error("2024-01-24T10:00:00.000Z", 0, 1)]),
+            Err(vec![create_same_timestamp_error("2024-01-24T10:00:00.000Z", 0, 2)]),
+            Err(vec![create_same_timestamp_error("2024-01-24T11:00:00.000+01:00", 0, 1)]),
+            Err(vec![create_same_timestamp_error("2024-01-24T09:00:00.000-01:00", 0, 1)]),
+            Err(vec![
+                create_same_timestamp_error("2024-01-24T10:00:00.000Z", 0, 1),
+                create_same_timestamp_error("2024-01-24T10:00:00.000Z", 0, 2),
+            ]),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use crate::csaf_traits::{CsafTrait, DocumentTrait, TrackingTrait};
+use crate::validation::ValidationError;
+
+fn create_tracking_id_in_title_error(tracking_id: &str) -> ValidationError {
+    ValidationError {
+        message: format!("Document title contains the document tracking ID '{tracking_id}'"),
+        instance_path: "/document/title".to_string(),
+    }
+}
+
+/// 6.2.22 Document Tracking ID in Title
+///
+/// Tests that the `/document/title` does not contain the `/document/tracking/id`.
+pub fn test_6_2_22_document_tracking_id_in_title(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let document = doc.get_document();
+    let tracking_id = document.get_tracking().get_id();
+
+    if document.get_title().contains(tracking_id.as_str()) {
+        return Err(vec![create_tracking_id_in_title_error(tracking_id)]);
+    }
+
+    Ok(())
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_22
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_22_document_tracking_id_in_title(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_22() {
+        let case_01 = Err(vec![create_tracking_id_in_title_error(
+            "OASIS_CSAF_TC-CSAF_2.1-2024-6-2-22-01",
+        )]);
+
+        // Only CSAF 2.1 has this test with 6 test cases (4 error cases, 2 success cases)
+        TESTS_2_1.test_6_2_22.expect(
+            case_01,
+            Err(vec![create_tracking_id_in_title_error(
+                "OASIS_CSAF_TC-CSAF_2.1-2024-6-2-22-02",
+            )]),
+            Err(vec![create_tracking_id_in_title_error(
+                "OASIS_CSAF_TC-CSAF_2.1-2024-6-2-22-03",
+            )]),
+            Err(vec![create_tracking_id_in_title_error(
+                "OASIS_CSAF_TC-CSAF_2.1-2024-6-2-22-04",
+            )]),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
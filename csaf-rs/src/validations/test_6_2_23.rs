@@ -0,0 +1,70 @@
+use crate::csaf_traits::{CsafTrait, VulnerabilityTrait};
+use crate::helpers::CWE_ENTRIES;
+use crate::validation::ValidationError;
+
+/// Prefix of the names of deprecated weaknesses in the CWE lists
+const DEPRECATED_CWE_PREFIX: &str = "DEPRECATED:";
+
+fn create_deprecated_cwe_error(cwe: &str, version: &str, i_v: usize, i_c: usize) -> ValidationError {
+    ValidationError {
+        message: format!("Weakness '{cwe}' is deprecated in CWE version {version}"),
+        instance_path: format!("/vulnerabilities/{i_v}/cwes/{i_c}/id"),
+    }
+}
+
+/// 6.2.23 Usage of Deprecated CWE
+///
+/// For each CWE of a vulnerability, tests that the weakness is not deprecated in the given CWE
+/// version. Unknown versions and weaknesses are reported by 6.1.11.
+pub fn test_6_2_23_usage_of_deprecated_cwe(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    for (i_v, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        for (i_c, cwe) in vulnerability.get_cwe().unwrap_or_default().iter().enumerate() {
+            let Some(version) = cwe.version.as_deref() else {
+                continue;
+            };
+            let is_deprecated = CWE_ENTRIES
+                .get(version)
+                .and_then(|(_, entries)| entries.get(&cwe.id))
+                .is_some_and(|name| name.starts_with(DEPRECATED_CWE_PREFIX));
+            if is_deprecated {
+                errors
+                    .get_or_insert_with(Vec::new)
+                    .push(create_deprecated_cwe_error(&cwe.id, version, i_v, i_c));
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_23
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_23_usage_of_deprecated_cwe(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_23() {
+        // Only CSAF 2.1 has this test with 6 test cases (3 error cases, 3 success cases)
+        TESTS_2_1.test_6_2_23.expect(
+            Err(vec![create_deprecated_cwe_error("CWE-596", "4.13", 0, 0)]),
+            Err(vec![create_deprecated_cwe_error("CWE-71", "4.13", 0, 0)]),
+            Err(vec![create_deprecated_cwe_error("CWE-596", "4.13", 0, 1)]),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
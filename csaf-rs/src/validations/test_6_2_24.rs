@@ -0,0 +1,74 @@
+use crate::csaf::types::csaf_datetime::CsafDateTime;
+use crate::csaf_traits::{CsafTrait, DocumentTrait, TrackingTrait, VulnerabilityTrait};
+use crate::helpers::get_latest_cwe_version;
+use crate::validation::ValidationError;
+
+fn create_non_latest_cwe_version_error(version: &str, latest: &str, i_v: usize, i_c: usize) -> ValidationError {
+    ValidationError {
+        message: format!(
+            "CWE version {version} is not the latest CWE version {latest} at the current release date of the document"
+        ),
+        instance_path: format!("/vulnerabilities/{i_v}/cwes/{i_c}/version"),
+    }
+}
+
+/// 6.2.24 Usage of Non-Latest CWE Version
+///
+/// For each CWE of a vulnerability, tests that the latest CWE version released before the current
+/// release date of the document is used.
+pub fn test_6_2_24_usage_of_non_latest_cwe_version(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    // Invalid dates are reported by 6.1.37
+    let CsafDateTime::Valid(current_release_date) = doc.get_document().get_tracking().get_current_release_date() else {
+        return Ok(());
+    };
+    let Some(latest) = get_latest_cwe_version(Some(current_release_date.get_as_utc().date_naive())) else {
+        return Ok(());
+    };
+
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (i_v, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        for (i_c, cwe) in vulnerability.get_cwe().unwrap_or_default().iter().enumerate() {
+            if let Some(version) = cwe.version.as_deref()
+                && version != latest
+            {
+                errors
+                    .get_or_insert_with(Vec::new)
+                    .push(create_non_latest_cwe_version_error(version, latest, i_v, i_c));
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_24
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_24_usage_of_non_latest_cwe_version(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_24() {
+        // Only CSAF 2.1 has this test with 8 test cases (4 error cases, 4 success cases)
+        TESTS_2_1.test_6_2_24.expect(
+            Err(vec![create_non_latest_cwe_version_error("4.12", "4.13", 0, 0)]),
+            Err(vec![create_non_latest_cwe_version_error("4.11", "4.13", 0, 0)]),
+            Err(vec![create_non_latest_cwe_version_error("4.12", "4.13", 0, 1)]),
+            Err(vec![create_non_latest_cwe_version_error("4.13", "4.14", 0, 0)]),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
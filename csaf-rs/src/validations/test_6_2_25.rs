@@ -0,0 +1,68 @@
+use crate::csaf_traits::{CsafTrait, VulnerabilityTrait};
+use crate::validation::ValidationError;
+
+/// CWE pillars, whose usage for vulnerability mapping is not allowed by the CWE
+const CWE_PILLARS: &[&str] = &[
+    "CWE-284", "CWE-435", "CWE-664", "CWE-682", "CWE-691", "CWE-693", "CWE-697", "CWE-703", "CWE-707", "CWE-710",
+];
+
+fn create_cwe_not_allowed_error(cwe: &str, i_v: usize, i_c: usize) -> ValidationError {
+    ValidationError {
+        message: format!("Weakness '{cwe}' is not allowed for vulnerability mapping"),
+        instance_path: format!("/vulnerabilities/{i_v}/cwes/{i_c}/id"),
+    }
+}
+
+/// 6.2.25 Usage of CWE Not Allowed for Vulnerability Mapping
+///
+/// For each CWE of a vulnerability, tests that the weakness is not one whose usage for vulnerability
+/// mapping is not allowed by the CWE, i.e. one of the CWE pillars.
+pub fn test_6_2_25_usage_of_cwe_not_allowed_for_vulnerability_mapping(
+    doc: &impl CsafTrait,
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    for (i_v, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        for (i_c, cwe) in vulnerability.get_cwe().unwrap_or_default().iter().enumerate() {
+            if CWE_PILLARS.contains(&cwe.id.as_str()) {
+                errors
+                    .get_or_insert_with(Vec::new)
+                    .push(create_cwe_not_allowed_error(&cwe.id, i_v, i_c));
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_25
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_25_usage_of_cwe_not_allowed_for_vulnerability_mapping(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_25() {
+        // Only CSAF 2.1 has this test with 8 test cases (4 error cases, 4 success cases)
+        TESTS_2_1.test_6_2_25.expect(
+            Err(vec![create_cwe_not_allowed_error("CWE-664", 0, 0)]),
+            Err(vec![create_cwe_not_allowed_error("CWE-284", 0, 0)]),
+            Err(vec![create_cwe_not_allowed_error("CWE-703", 0, 0)]),
+            Err(vec![create_cwe_not_allowed_error("CWE-710", 0, 0)]),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use crate::csaf_traits::{CsafTrait, VulnerabilityTrait};
+use crate::validation::ValidationError;
+
+/// CWE classes whose usage for vulnerability mapping is only allowed with review by the CWE
+const CWE_ALLOWED_WITH_REVIEW: &[&str] = &[
+    "CWE-20", "CWE-74", "CWE-119", "CWE-200", "CWE-269", "CWE-287", "CWE-400", "CWE-668",
+];
+
+fn create_cwe_allowed_with_review_error(cwe: &str, i_v: usize, i_c: usize) -> ValidationError {
+    ValidationError {
+        message: format!(
+            "Weakness '{cwe}' is only allowed with review for vulnerability mapping, a more specific weakness should be used if possible"
+        ),
+        instance_path: format!("/vulnerabilities/{i_v}/cwes/{i_c}/id"),
+    }
+}
+
+/// 6.2.26 Usage of CWE Allowed with Review for Vulnerability Mapping
+///
+/// For each CWE of a vulnerability, tests that the weakness is not one whose usage for vulnerability
+/// mapping is only allowed with review by the CWE.
+pub fn test_6_2_26_usage_of_cwe_allowed_with_review_for_vulnerability_mapping(
+    doc: &impl CsafTrait,
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    for (i_v, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        for (i_c, cwe) in vulnerability.get_cwe().unwrap_or_default().iter().enumerate() {
+            if CWE_ALLOWED_WITH_REVIEW.contains(&cwe.id.as_str()) {
+                errors
+                    .get_or_insert_with(Vec::new)
+                    .push(create_cwe_allowed_with_review_error(&cwe.id, i_v, i_c));
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_26
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_26_usage_of_cwe_allowed_with_review_for_vulnerability_mapping(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_26() {
+        // Only CSAF 2.1 has this test with 6 test cases (3 error cases, 3 success cases)
+        TESTS_2_1.test_6_2_26.expect(
+            Err(vec![create_cwe_allowed_with_review_error("CWE-20", 0, 0)]),
+            Err(vec![create_cwe_allowed_with_review_error("CWE-200", 0, 0)]),
+            Err(vec![create_cwe_allowed_with_review_error("CWE-400", 0, 0)]),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
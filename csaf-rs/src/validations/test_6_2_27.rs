@@ -0,0 +1,119 @@
+use crate::csaf_traits::{CsafTrait, ProductStatusGroup, ProductStatusTrait, RemediationTrait, VulnerabilityTrait};
+use crate::schema::csaf2_1::schema::CategoryOfTheRemediation;
+use crate::validation::ValidationError;
+
+/// Remediation categories that are discouraged, but not contradicting (see 6.1.36), for a product status group.
+const DISCOURAGED_COMBINATIONS: &[(ProductStatusGroup, &[CategoryOfTheRemediation])] = &[
+    (
+        ProductStatusGroup::UnderInvestigation,
+        &[
+            CategoryOfTheRemediation::NoFixPlanned,
+            CategoryOfTheRemediation::OptionalPatch,
+            CategoryOfTheRemediation::VendorFix,
+        ],
+    ),
+    (ProductStatusGroup::NotAffected, &[CategoryOfTheRemediation::FixPlanned]),
+];
+
+fn create_discouraged_combination_error(
+    product_id: &str,
+    status: &ProductStatusGroup,
+    category: &CategoryOfTheRemediation,
+    i_v: usize,
+    i_r: usize,
+) -> ValidationError {
+    ValidationError {
+        message: format!(
+            "Product {product_id} is listed as {status} and has the discouraged remediation category {category}"
+        ),
+        instance_path: format!("/vulnerabilities/{i_v}/remediations/{i_r}"),
+    }
+}
+
+/// 6.2.27 Discouraged Product Status Remediation Combination
+///
+/// For each remediation, tests that none of its products has a product status for which the category of
+/// the remediation is discouraged. Contradicting combinations are reported by 6.1.36.
+pub fn test_6_2_27_discouraged_product_status_remediation_combination(
+    doc: &impl CsafTrait,
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    for (i_v, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        let Some(product_status) = vulnerability.get_product_status() else {
+            continue;
+        };
+        let all_by_status = product_status.get_all_by_product_status();
+        for (i_r, remediation) in vulnerability.get_remediations().iter().enumerate() {
+            let Some(product_ids) = remediation.get_all_product_ids(doc) else {
+                continue;
+            };
+            let category = remediation.get_category();
+            for (status, categories) in DISCOURAGED_COMBINATIONS {
+                if !categories.contains(&category) {
+                    continue;
+                }
+                let Some(products) = all_by_status.get(status) else {
+                    continue;
+                };
+                for product_id in product_ids.iter().filter(|p| products.contains(p)) {
+                    errors
+                        .get_or_insert_with(Vec::new)
+                        .push(create_discouraged_combination_error(
+                            product_id, status, &category, i_v, i_r,
+                        ));
+                }
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_27
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_27_discouraged_product_status_remediation_combination(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_27() {
+        // Only CSAF 2.1 has this test with 6 test cases (3 error cases, 3 success cases)
+        TESTS_2_1.test_6_2_27.expect(
+            Err(vec![create_discouraged_combination_error(
+                "CSAFPID-9080700",
+                &ProductStatusGroup::UnderInvestigation,
+                &CategoryOfTheRemediation::VendorFix,
+                0,
+                0,
+            )]),
+            Err(vec![create_discouraged_combination_error(
+                "CSAFPID-9080700",
+                &ProductStatusGroup::UnderInvestigation,
+                &CategoryOfTheRemediation::NoFixPlanned,
+                0,
+                0,
+            )]),
+            Err(vec![create_discouraged_combination_error(
+                "CSAFPID-9080700",
+                &ProductStatusGroup::NotAffected,
+                &CategoryOfTheRemediation::FixPlanned,
+                0,
+                0,
+            )]),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use std::sync::LazyLock;
+
+use crate::csaf_traits::{CsafTrait, DistributionTrait, DocumentTrait, SharingGroupTrait};
+use crate::helpers::MAX_UUID;
+use crate::validation::ValidationError;
+
+static MAX_UUID_ERROR: LazyLock<ValidationError> = LazyLock::new(|| ValidationError {
+    message: "The max UUID should not be used as sharing group ID, TLP CLEAR documents do not need a sharing group"
+        .to_string(),
+    instance_path: "/document/distribution/sharing_group/id".to_string(),
+});
+
+/// 6.2.28 Usage of Max UUID
+///
+/// Tests that the max UUID is not used as sharing group ID.
+pub fn test_6_2_28_usage_of_max_uuid(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let distribution = doc.get_document().get_distribution_21().map_err(|e| vec![e])?;
+
+    if let Some(sharing_group) = distribution.get_sharing_group()
+        && sharing_group.get_id() == MAX_UUID
+    {
+        return Err(vec![MAX_UUID_ERROR.clone()]);
+    }
+
+    Ok(())
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_28
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_28_usage_of_max_uuid(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_28() {
+        // Only CSAF 2.1 has this test with 3 test cases (1 error case, 2 success cases)
+        TESTS_2_1
+            .test_6_2_28
+            .expect(Err(vec![MAX_UUID_ERROR.clone()]), Ok(()), Ok(()));
+    }
+}
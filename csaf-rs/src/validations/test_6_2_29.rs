@@ -0,0 +1,51 @@
+use std::sync::LazyLock;
+
+use crate::csaf_traits::{CsafTrait, DistributionTrait, DocumentTrait, SharingGroupTrait};
+use crate::helpers::NIL_UUID;
+use crate::validation::ValidationError;
+
+static NIL_UUID_ERROR: LazyLock<ValidationError> = LazyLock::new(|| ValidationError {
+    message: "The nil UUID should not be used as sharing group ID, it is meant for documents which are not shared"
+        .to_string(),
+    instance_path: "/document/distribution/sharing_group/id".to_string(),
+});
+
+/// 6.2.29 Usage of Nil UUID
+///
+/// Tests that the nil UUID is not used as sharing group ID.
+pub fn test_6_2_29_usage_of_nil_uuid(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let distribution = doc.get_document().get_distribution_21().map_err(|e| vec![e])?;
+
+    if let Some(sharing_group) = distribution.get_sharing_group()
+        && sharing_group.get_id() == NIL_UUID
+    {
+        return Err(vec![NIL_UUID_ERROR.clone()]);
+    }
+
+    Ok(())
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_29
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_29_usage_of_nil_uuid(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_29() {
+        // Only CSAF 2.1 has this test with 3 test cases (1 error case, 2 success cases)
+        TESTS_2_1
+            .test_6_2_29
+            .expect(Err(vec![NIL_UUID_ERROR.clone()]), Ok(()), Ok(()));
+    }
+}
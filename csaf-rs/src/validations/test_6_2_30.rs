@@ -0,0 +1,50 @@
+use std::sync::LazyLock;
+
+use crate::csaf_traits::{CsafTrait, DistributionTrait, DocumentTrait, TlpTrait};
+use crate::schema::csaf2_1::schema::LabelOfTlp::Clear;
+use crate::validation::ValidationError;
+
+static SHARING_GROUP_ON_TLP_CLEAR_ERROR: LazyLock<ValidationError> = LazyLock::new(|| ValidationError {
+    message: "Documents with TLP CLEAR should not have a sharing group".to_string(),
+    instance_path: "/document/distribution/sharing_group".to_string(),
+});
+
+/// 6.2.30 Usage of Sharing Group on TLP:CLEAR
+///
+/// Tests that documents with the TLP label `CLEAR` do not have a sharing group.
+pub fn test_6_2_30_usage_of_sharing_group_on_tlp_clear(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let distribution = doc.get_document().get_distribution_21().map_err(|e| vec![e])?;
+
+    if distribution.get_sharing_group().is_some()
+        && distribution.get_tlp_21().map_err(|e| vec![e])?.get_label() == Clear
+    {
+        return Err(vec![SHARING_GROUP_ON_TLP_CLEAR_ERROR.clone()]);
+    }
+
+    Ok(())
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_30
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_30_usage_of_sharing_group_on_tlp_clear(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_30() {
+        // Only CSAF 2.1 has this test with 3 test cases (1 error case, 2 success cases)
+        TESTS_2_1
+            .test_6_2_30
+            .expect(Err(vec![SHARING_GROUP_ON_TLP_CLEAR_ERROR.clone()]), Ok(()), Ok(()));
+    }
+}
@@ -0,0 +1,57 @@
+use std::sync::LazyLock;
+
+use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
+use crate::csaf_traits::{CsafTrait, DocumentTrait};
+use crate::document_category_test_helper::DocumentCategoryTestConfig;
+use crate::validation::ValidationError;
+
+static DEPRECATED_PROFILE_ERROR: LazyLock<ValidationError> = LazyLock::new(|| ValidationError {
+    message: format!(
+        "The profile '{}' is deprecated, the profile '{}' should be used instead",
+        CsafDocumentCategory::CsafDeprecatedSecurityAdvisory,
+        CsafDocumentCategory::CsafSecurityAdvisory
+    ),
+    instance_path: "/document/category".to_string(),
+});
+
+const PROFILE_TEST_CONFIG: DocumentCategoryTestConfig = DocumentCategoryTestConfig::new()
+    .csaf20(&[])
+    .csaf21(&[CsafDocumentCategory::CsafDeprecatedSecurityAdvisory]);
+
+/// 6.2.31 Usage of Deprecated Profile
+///
+/// Tests that the document does not use the deprecated profile `csaf_deprecated_security_advisory`.
+pub fn test_6_2_31_usage_of_deprecated_profile(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let document = doc.get_document();
+
+    if PROFILE_TEST_CONFIG.matches_category_with_csaf_version(document.get_csaf_version(), &document.get_category()) {
+        return Err(vec![DEPRECATED_PROFILE_ERROR.clone()]);
+    }
+
+    Ok(())
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_31
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_31_usage_of_deprecated_profile(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_31() {
+        // Only CSAF 2.1 has this test with 4 test cases (1 error case, 3 success cases)
+        TESTS_2_1
+            .test_6_2_31
+            .expect(Err(vec![DEPRECATED_PROFILE_ERROR.clone()]), Ok(()), Ok(()), Ok(()));
+    }
+}
@@ -0,0 +1,78 @@
+use crate::csaf_traits::{BranchTrait, CategoryOfTheBranch, CsafTrait, ProductTreeTrait};
+use crate::validation::ValidationError;
+
+fn create_all_versions_error(version_range: &str, path: &str) -> ValidationError {
+    ValidationError {
+        message: format!("Product version range '{version_range}' matches all versions"),
+        instance_path: format!("{path}/name"),
+    }
+}
+
+/// 6.2.32 Product Version Range Matching All Versions
+///
+/// For each branch with the category `product_version_range`, tests that its vers `name` does not use
+/// the constraint `*`, which matches all versions of the product.
+pub fn test_6_2_32_product_version_range_matching_all_versions(
+    doc: &impl CsafTrait,
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    if let Some(product_tree) = doc.get_product_tree().as_ref() {
+        product_tree.visit_all_branches(&mut |branch, path| {
+            if branch.get_category() != &CategoryOfTheBranch::ProductVersionRange {
+                return;
+            }
+            let name = branch.get_name();
+            // Violations of the vers rules are reported by 6.1.50
+            let matches_all = name
+                .strip_prefix("vers:")
+                .and_then(|specifier| specifier.split_once('/'))
+                .is_some_and(|(_, constraints)| constraints.trim() == "*");
+            if matches_all {
+                errors
+                    .get_or_insert_with(Vec::new)
+                    .push(create_all_versions_error(name, path));
+            }
+        });
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_32
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_32_product_version_range_matching_all_versions(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_32() {
+        // Only CSAF 2.1 has this test with 5 test cases (3 error cases, 2 success cases)
+        TESTS_2_1.test_6_2_32.expect(
+            Err(vec![create_all_versions_error(
+                "vers:generic/*",
+                "/product_tree/branches/0/branches/0/branches/0",
+            )]),
+            Err(vec![create_all_versions_error(
+                "vers:semver/*",
+                "/product_tree/branches/0/branches/0/branches/0",
+            )]),
+            Err(vec![create_all_versions_error(
+                "vers:intdot/*",
+                "/product_tree/branches/0/branches/0/branches/0",
+            )]),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use crate::csaf_traits::{ContentTrait, CsafTrait, MetricTrait, VulnerabilityTrait};
+use crate::helpers::{REGISTERED_SSVC_NAMESPACES, SSVC_UNREGISTERED_NAMESPACE_PREFIX, split_ssvc_namespace};
+use crate::validation::ValidationError;
+
+fn create_unknown_base_namespace_error(base_namespace: &str, i_v: usize, i_m: usize, i_s: usize) -> ValidationError {
+    ValidationError {
+        message: format!("The SSVC decision point base namespace '{base_namespace}' is unknown"),
+        instance_path: format!("/vulnerabilities/{i_v}/metrics/{i_m}/content/ssvc_v2/selections/{i_s}/namespace"),
+    }
+}
+
+/// 6.2.33 Usage of Unknown SSVC Decision Point Base Namespace
+///
+/// For each SSVC decision point, tests that its base namespace is either a registered namespace known to
+/// this library or an unregistered one, i.e. starting with `x_`.
+pub fn test_6_2_33_usage_of_unknown_ssvc_decision_point_base_namespace(
+    doc: &impl CsafTrait,
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    for (i_v, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        for (i_m, metric) in vulnerability.get_metrics().into_iter().flatten().enumerate() {
+            // Invalid SSVC objects are reported by 6.1.46
+            let Ok(ssvc) = metric.get_content().get_ssvc() else {
+                continue;
+            };
+            for (i_s, selection) in ssvc.selections.iter().enumerate() {
+                let (base_namespace, _) = split_ssvc_namespace(&selection.namespace);
+                if !base_namespace.starts_with(SSVC_UNREGISTERED_NAMESPACE_PREFIX)
+                    && !REGISTERED_SSVC_NAMESPACES.contains(base_namespace)
+                {
+                    errors
+                        .get_or_insert_with(Vec::new)
+                        .push(create_unknown_base_namespace_error(base_namespace, i_v, i_m, i_s));
+                }
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_33
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_33_usage_of_unknown_ssvc_decision_point_base_namespace(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_33() {
+        // Only CSAF 2.1 has this test with 6 test cases (3 error cases, 3 success cases)
+        TESTS_2_1.test_6_2_33.expect(
+            Err(vec![create_unknown_base_namespace_error("example", 0, 0, 0)]),
+            Err(vec![create_unknown_base_namespace_error("ssvc-extended", 0, 0, 0)]),
+            Err(vec![create_unknown_base_namespace_error("cvss4", 0, 0, 1)]),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
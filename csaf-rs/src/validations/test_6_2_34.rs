@@ -0,0 +1,95 @@
+use crate::csaf_traits::{
+    ContentTrait, CsafTrait, DistributionTrait, DocumentTrait, MetricTrait, TlpTrait, VulnerabilityTrait,
+};
+use crate::helpers::{SSVC_UNREGISTERED_NAMESPACE_PREFIX, split_ssvc_namespace};
+use crate::schema::csaf2_1::schema::LabelOfTlp::Clear;
+use crate::validation::ValidationError;
+
+fn create_unregistered_base_namespace_error(
+    base_namespace: &str,
+    i_v: usize,
+    i_m: usize,
+    i_s: usize,
+) -> ValidationError {
+    ValidationError {
+        message: format!(
+            "The unregistered SSVC decision point base namespace '{base_namespace}' should not be used in TLP:CLEAR documents"
+        ),
+        instance_path: format!("/vulnerabilities/{i_v}/metrics/{i_m}/content/ssvc_v2/selections/{i_s}/namespace"),
+    }
+}
+
+/// 6.2.34 Usage of Unregistered SSVC Decision Point Base Namespace in TLP:CLEAR Document
+///
+/// For documents with the TLP label `CLEAR`, tests that no SSVC decision point uses an unregistered base
+/// namespace, i.e. one starting with `x_`, as others may not be able to resolve it.
+pub fn test_6_2_34_usage_of_unregistered_ssvc_base_namespace_in_tlp_clear(
+    doc: &impl CsafTrait,
+) -> Result<(), Vec<ValidationError>> {
+    let distribution = doc.get_document().get_distribution_21().map_err(|e| vec![e])?;
+    if distribution.get_tlp_21().map_err(|e| vec![e])?.get_label() != Clear {
+        return Ok(());
+    }
+
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (i_v, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        for (i_m, metric) in vulnerability.get_metrics().into_iter().flatten().enumerate() {
+            // Invalid SSVC objects are reported by 6.1.46
+            let Ok(ssvc) = metric.get_content().get_ssvc() else {
+                continue;
+            };
+            for (i_s, selection) in ssvc.selections.iter().enumerate() {
+                let (base_namespace, _) = split_ssvc_namespace(&selection.namespace);
+                if base_namespace.starts_with(SSVC_UNREGISTERED_NAMESPACE_PREFIX) {
+                    errors
+                        .get_or_insert_with(Vec::new)
+                        .push(create_unregistered_base_namespace_error(base_namespace, i_v, i_m, i_s));
+                }
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_34
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_34_usage_of_unregistered_ssvc_base_namespace_in_tlp_clear(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_34() {
+        let case_01 = Err(vec![create_unregistered_base_namespace_error(
+            "x_example.test#test",
+            0,
+            0,
+            0,
+        )]);
+
+        // Only CSAF 2.1 has this test with 6 test cases (3 error cases, 3 success cases)
+        TESTS_2_1.test_6_2_34.expect(
+            case_01.clone(),
+            case_01,
+            Err(vec![create_unregistered_base_namespace_error(
+                "x_com.yahooinc#prioritized-risk-remediation",
+                0,
+                0,
+                0,
+            )]),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
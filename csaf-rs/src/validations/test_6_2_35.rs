@@ -0,0 +1,84 @@
+use crate::csaf_traits::{
+    ContentTrait, CsafTrait, DistributionTrait, DocumentTrait, MetricTrait, TlpTrait, VulnerabilityTrait,
+};
+use crate::helpers::split_ssvc_namespace;
+use crate::schema::csaf2_1::schema::LabelOfTlp::Clear;
+use crate::validation::ValidationError;
+
+fn create_namespace_with_extension_error(namespace: &str, i_v: usize, i_m: usize, i_s: usize) -> ValidationError {
+    ValidationError {
+        message: format!(
+            "The SSVC decision point namespace '{namespace}' uses an extension and should not be used in TLP:CLEAR documents"
+        ),
+        instance_path: format!("/vulnerabilities/{i_v}/metrics/{i_m}/content/ssvc_v2/selections/{i_s}/namespace"),
+    }
+}
+
+/// 6.2.35 Usage of SSVC Decision Point Namespace with Extension in TLP:CLEAR Document
+///
+/// For documents with the TLP label `CLEAR`, tests that no SSVC decision point uses a namespace with an
+/// extension, i.e. one containing a `/`.
+pub fn test_6_2_35_usage_of_ssvc_namespace_with_extension_in_tlp_clear(
+    doc: &impl CsafTrait,
+) -> Result<(), Vec<ValidationError>> {
+    let distribution = doc.get_document().get_distribution_21().map_err(|e| vec![e])?;
+    if distribution.get_tlp_21().map_err(|e| vec![e])?.get_label() != Clear {
+        return Ok(());
+    }
+
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (i_v, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        for (i_m, metric) in vulnerability.get_metrics().into_iter().flatten().enumerate() {
+            // Invalid SSVC objects are reported by 6.1.46
+            let Ok(ssvc) = metric.get_content().get_ssvc() else {
+                continue;
+            };
+            for (i_s, selection) in ssvc.selections.iter().enumerate() {
+                if let (_, Some(_)) = split_ssvc_namespace(&selection.namespace) {
+                    errors
+                        .get_or_insert_with(Vec::new)
+                        .push(create_namespace_with_extension_error(
+                            &selection.namespace,
+                            i_v,
+                            i_m,
+                            i_s,
+                        ));
+                }
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_35
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_35_usage_of_ssvc_namespace_with_extension_in_tlp_clear(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_35() {
+        // Only CSAF 2.1 has this test with 3 test cases (1 error case, 2 success cases)
+        TESTS_2_1.test_6_2_35.expect(
+            Err(vec![create_namespace_with_extension_error(
+                "ssvc/de-DE/example.organization#reference-arch-1",
+                0,
+                0,
+                0,
+            )]),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use crate::csaf_traits::{
+    ContentTrait, CsafTrait, MetricTrait, ProductStatusGroup, ProductStatusTrait, VulnerabilityTrait,
+};
+use crate::validation::ValidationError;
+
+fn create_epss_for_fixed_product_error(product_id: &str, i_v: usize, i_m: usize, i_p: usize) -> ValidationError {
+    ValidationError {
+        message: format!("EPSS metric is given for the fixed product {product_id}"),
+        instance_path: format!("/vulnerabilities/{i_v}/metrics/{i_m}/products/{i_p}"),
+    }
+}
+
+/// 6.2.36 EPSS for Fixed Products
+///
+/// For each EPSS metric, tests that none of its products is listed with a fixed product status, as the
+/// EPSS describes the probability of exploitation of affected products.
+pub fn test_6_2_36_epss_for_fixed_products(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    for (i_v, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        let Some(product_status) = vulnerability.get_product_status() else {
+            continue;
+        };
+        let all_by_status = product_status.get_all_by_product_status();
+        let Some(fixed_products) = all_by_status.get(&ProductStatusGroup::Fixed) else {
+            continue;
+        };
+        for (i_m, metric) in vulnerability.get_metrics().into_iter().flatten().enumerate() {
+            if !metric.get_content().has_epss() {
+                continue;
+            }
+            for (i_p, product_id) in metric.get_products().enumerate() {
+                if fixed_products.contains(product_id) {
+                    errors
+                        .get_or_insert_with(Vec::new)
+                        .push(create_epss_for_fixed_product_error(product_id, i_v, i_m, i_p));
+                }
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_36
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_36_epss_for_fixed_products(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_36() {
+        // Only CSAF 2.1 has this test with 5 test cases (2 error cases, 3 success cases)
+        TESTS_2_1.test_6_2_36.expect(
+            Err(vec![create_epss_for_fixed_product_error("CSAFPID-9080700", 0, 0, 0)]),
+            Err(vec![create_epss_for_fixed_product_error("CSAFPID-9080701", 0, 0, 1)]),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use std::sync::LazyLock;
+
+use crate::csaf_traits::{CsafTrait, DocumentTrait};
+use crate::validation::ValidationError;
+
+static MISSING_LICENSE_EXPRESSION_ERROR: LazyLock<ValidationError> = LazyLock::new(|| ValidationError {
+    message: "The document has no license expression".to_string(),
+    instance_path: "/document".to_string(),
+});
+
+/// 6.2.38 Missing License Expression
+///
+/// Tests that the document has a `/document/license_expression`.
+pub fn test_6_2_38_missing_license_expression(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    if doc.get_document().get_license_expression().is_none() {
+        return Err(vec![MISSING_LICENSE_EXPRESSION_ERROR.clone()]);
+    }
+
+    Ok(())
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_38
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_38_missing_license_expression(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_38() {
+        // Only CSAF 2.1 has this test with 2 test cases (1 error case, 1 success case)
+        TESTS_2_1
+            .test_6_2_38
+            .expect(Err(vec![MISSING_LICENSE_EXPRESSION_ERROR.clone()]), Ok(()));
+    }
+}
@@ -0,0 +1,91 @@
+use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
+use crate::csaf_traits::{CsafTrait, DocumentTrait, NoteTrait};
+use crate::document_category_test_helper::DocumentCategoryTestConfig;
+use crate::helpers::{NOTE_TITLE_REASONING_FOR_WITHDRAWAL, language_specific_text};
+use crate::schema::csaf2_1::schema::NoteCategory;
+use crate::validation::ValidationError;
+
+pub(crate) fn create_english_note_title_error(title: &str, translation: &str, i_n: usize) -> ValidationError {
+    ValidationError {
+        message: format!("The note title '{title}' should be given in the document language as '{translation}'"),
+        instance_path: format!("/document/notes/{i_n}/title"),
+    }
+}
+
+const PROFILE_TEST_CONFIG: DocumentCategoryTestConfig = DocumentCategoryTestConfig::new()
+    .csaf20(&[])
+    .csaf21(&[CsafDocumentCategory::CsafWithdrawn]);
+
+/// 6.2.39.1 Language Specific Reasoning for Withdrawal
+///
+/// This test only applies to documents with `/document/category` with value `csaf_withdrawn` for
+/// `/document/csaf_version` `2.1`.
+///
+/// If a translation into the document language is known, tests that the document note with category
+/// `description` does not use the English title `Reasoning for Withdrawal`.
+pub fn test_6_2_39_1_language_specific_reasoning_for_withdrawal(
+    doc: &impl CsafTrait,
+) -> Result<(), Vec<ValidationError>> {
+    let document = doc.get_document();
+
+    if !PROFILE_TEST_CONFIG.matches_category_with_csaf_version(document.get_csaf_version(), &document.get_category()) {
+        return Ok(());
+    }
+
+    check_language_specific_note_title(doc, NoteCategory::Description, NOTE_TITLE_REASONING_FOR_WITHDRAWAL)
+}
+
+/// Checks that no document note with the given category uses the fixed English `title` if a translation
+/// into the document language is known, also used by 6.2.39.2 and 6.2.39.4.
+pub(crate) fn check_language_specific_note_title(
+    doc: &impl CsafTrait,
+    category: NoteCategory,
+    title: &str,
+) -> Result<(), Vec<ValidationError>> {
+    let document = doc.get_document();
+    let Some(translation) = language_specific_text(title, document.get_lang()) else {
+        return Ok(());
+    };
+
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (i_n, note) in document.get_notes().into_iter().flatten().enumerate() {
+        if note.get_category() == category && note.get_title().is_some_and(|t| t == title) {
+            errors
+                .get_or_insert_with(Vec::new)
+                .push(create_english_note_title_error(title, translation, i_n));
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_39_1
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_39_1_language_specific_reasoning_for_withdrawal(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_39_1() {
+        let case_01 = Err(vec![create_english_note_title_error(
+            NOTE_TITLE_REASONING_FOR_WITHDRAWAL,
+            "Begründung für die Zurückziehung",
+            0,
+        )]);
+
+        // Only CSAF 2.1 has this test with 5 test cases (2 error cases, 3 success cases)
+        TESTS_2_1
+            .test_6_2_39_1
+            .expect(case_01.clone(), case_01, Ok(()), Ok(()), Ok(()));
+    }
+}
@@ -0,0 +1,61 @@
+use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
+use crate::csaf_traits::{CsafTrait, DocumentTrait};
+use crate::document_category_test_helper::DocumentCategoryTestConfig;
+use crate::helpers::NOTE_TITLE_REASONING_FOR_SUPERSESSION;
+use crate::schema::csaf2_1::schema::NoteCategory;
+use crate::validation::ValidationError;
+use crate::validations::test_6_2_39_1::check_language_specific_note_title;
+
+const PROFILE_TEST_CONFIG: DocumentCategoryTestConfig = DocumentCategoryTestConfig::new()
+    .csaf20(&[])
+    .csaf21(&[CsafDocumentCategory::CsafSuperseded]);
+
+/// 6.2.39.2 Language Specific Reasoning for Supersession
+///
+/// This test only applies to documents with `/document/category` with value `csaf_superseded` for
+/// `/document/csaf_version` `2.1`.
+///
+/// If a translation into the document language is known, tests that the document note with category
+/// `description` does not use the English title `Reasoning for Supersession`.
+pub fn test_6_2_39_2_language_specific_reasoning_for_supersession(
+    doc: &impl CsafTrait,
+) -> Result<(), Vec<ValidationError>> {
+    let document = doc.get_document();
+
+    if !PROFILE_TEST_CONFIG.matches_category_with_csaf_version(document.get_csaf_version(), &document.get_category()) {
+        return Ok(());
+    }
+
+    check_language_specific_note_title(doc, NoteCategory::Description, NOTE_TITLE_REASONING_FOR_SUPERSESSION)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_39_2
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_39_2_language_specific_reasoning_for_supersession(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+    use crate::validations::test_6_2_39_1::create_english_note_title_error;
+
+    #[test]
+    fn test_test_6_2_39_2() {
+        // Only CSAF 2.1 has this test with 2 test cases (1 error case, 1 success case)
+        TESTS_2_1.test_6_2_39_2.expect(
+            Err(vec![create_english_note_title_error(
+                NOTE_TITLE_REASONING_FOR_SUPERSESSION,
+                "Begründung für die Ersetzung",
+                0,
+            )]),
+            Ok(()),
+        );
+    }
+}
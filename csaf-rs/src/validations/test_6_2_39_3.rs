@@ -0,0 +1,84 @@
+use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
+use crate::csaf_traits::{CsafTrait, DocumentReferenceTrait, DocumentTrait};
+use crate::document_category_test_helper::DocumentCategoryTestConfig;
+use crate::helpers::{REFERENCE_SUMMARY_SUPERSEDING_DOCUMENT, language_specific_text};
+use crate::schema::csaf2_1::schema::CategoryOfReference;
+use crate::validation::ValidationError;
+
+fn create_english_reference_summary_error(summary: &str, translation: &str, i_r: usize) -> ValidationError {
+    ValidationError {
+        message: format!(
+            "The reference summary '{summary}' should be given in the document language as '{translation}'"
+        ),
+        instance_path: format!("/document/references/{i_r}/summary"),
+    }
+}
+
+const PROFILE_TEST_CONFIG: DocumentCategoryTestConfig = DocumentCategoryTestConfig::new()
+    .csaf20(&[])
+    .csaf21(&[CsafDocumentCategory::CsafSuperseded]);
+
+/// 6.2.39.3 Language Specific Superseding Document
+///
+/// This test only applies to documents with `/document/category` with value `csaf_superseded` for
+/// `/document/csaf_version` `2.1`.
+///
+/// If a translation into the document language is known, tests that the document reference with category
+/// `external` does not use the English summary `Superseding Document`.
+pub fn test_6_2_39_3_language_specific_superseding_document(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let document = doc.get_document();
+
+    if !PROFILE_TEST_CONFIG.matches_category_with_csaf_version(document.get_csaf_version(), &document.get_category()) {
+        return Ok(());
+    }
+    let Some(translation) = language_specific_text(REFERENCE_SUMMARY_SUPERSEDING_DOCUMENT, document.get_lang()) else {
+        return Ok(());
+    };
+
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (i_r, reference) in document.get_references().into_iter().flatten().enumerate() {
+        if *reference.get_category() == CategoryOfReference::External
+            && reference.get_summary() == REFERENCE_SUMMARY_SUPERSEDING_DOCUMENT
+        {
+            errors
+                .get_or_insert_with(Vec::new)
+                .push(create_english_reference_summary_error(
+                    REFERENCE_SUMMARY_SUPERSEDING_DOCUMENT,
+                    translation,
+                    i_r,
+                ));
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_39_3
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_39_3_language_specific_superseding_document(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_39_3() {
+        // Only CSAF 2.1 has this test with 2 test cases (1 error case, 1 success case)
+        TESTS_2_1.test_6_2_39_3.expect(
+            Err(vec![create_english_reference_summary_error(
+                REFERENCE_SUMMARY_SUPERSEDING_DOCUMENT,
+                "Ersetzendes Dokument",
+                0,
+            )]),
+            Ok(()),
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use crate::csaf_traits::CsafTrait;
+use crate::helpers::NOTE_TITLE_LICENSE;
+use crate::schema::csaf2_1::schema::NoteCategory;
+use crate::validation::ValidationError;
+use crate::validations::test_6_2_39_1::check_language_specific_note_title;
+
+/// 6.2.39.4 Language Specific License
+///
+/// If a translation into the document language is known, tests that the document note with category
+/// `legal_disclaimer` holding the license text does not use the English title `License`.
+pub fn test_6_2_39_4_language_specific_license(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    check_language_specific_note_title(doc, NoteCategory::LegalDisclaimer, NOTE_TITLE_LICENSE)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_39_4
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_39_4_language_specific_license(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+    use crate::validations::test_6_2_39_1::create_english_note_title_error;
+
+    #[test]
+    fn test_test_6_2_39_4() {
+        // Only CSAF 2.1 has this test with 3 test cases (1 error case, 2 success cases)
+        TESTS_2_1.test_6_2_39_4.expect(
+            Err(vec![create_english_note_title_error(NOTE_TITLE_LICENSE, "Lizenz", 1)]),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use std::sync::LazyLock;
+
+use crate::csaf_traits::{CsafTrait, DistributionTrait, DocumentTrait, SharingGroupTrait, TlpTrait};
+use crate::helpers::SG_NAME_PUBLIC;
+use crate::schema::csaf2_1::schema::LabelOfTlp::Clear;
+use crate::validation::ValidationError;
+
+static PUBLIC_SHARING_GROUP_ERROR: LazyLock<ValidationError> = LazyLock::new(|| ValidationError {
+    message: format!("The sharing group name '{SG_NAME_PUBLIC}' should only be used in TLP:CLEAR documents"),
+    instance_path: "/document/distribution/sharing_group/name".to_string(),
+});
+
+/// 6.2.40 Usage of Public Sharing Group on non-TLP:CLEAR
+///
+/// Tests that documents whose TLP label is not `CLEAR` do not use the sharing group name `Public`.
+pub fn test_6_2_40_usage_of_public_sharing_group_on_non_tlp_clear(
+    doc: &impl CsafTrait,
+) -> Result<(), Vec<ValidationError>> {
+    let distribution = doc.get_document().get_distribution_21().map_err(|e| vec![e])?;
+
+    if let Some(sharing_group) = distribution.get_sharing_group()
+        && sharing_group.get_name().is_some_and(|name| name == SG_NAME_PUBLIC)
+        && distribution.get_tlp_21().map_err(|e| vec![e])?.get_label() != Clear
+    {
+        return Err(vec![PUBLIC_SHARING_GROUP_ERROR.clone()]);
+    }
+
+    Ok(())
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_40
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_40_usage_of_public_sharing_group_on_non_tlp_clear(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_40() {
+        let case_01 = Err(vec![PUBLIC_SHARING_GROUP_ERROR.clone()]);
+
+        // Only CSAF 2.1 has this test with 6 test cases (3 error cases, 3 success cases)
+        TESTS_2_1
+            .test_6_2_40
+            .expect(case_01.clone(), case_01.clone(), case_01, Ok(()), Ok(()), Ok(()));
+    }
+}
@@ -0,0 +1,88 @@
+use chrono::TimeDelta;
+
+use crate::csaf::types::csaf_datetime::CsafDateTime;
+use crate::csaf_traits::{ContentTrait, CsafTrait, DocumentTrait, MetricTrait, TrackingTrait, VulnerabilityTrait};
+use crate::validation::ValidationError;
+
+/// Maximum age of an EPSS value relative to the current release date of the document
+const MAX_EPSS_AGE_DAYS: i64 = 15;
+
+fn create_old_epss_error(timestamp: &str, current_release_date: &str, i_v: usize, i_m: usize) -> ValidationError {
+    ValidationError {
+        message: format!(
+            "EPSS timestamp ({timestamp}) is more than {MAX_EPSS_AGE_DAYS} days older than the current release date ({current_release_date})"
+        ),
+        instance_path: format!("/vulnerabilities/{i_v}/metrics/{i_m}/content/epss/timestamp"),
+    }
+}
+
+/// 6.2.41 Old EPSS
+///
+/// For each EPSS metric, tests that its `timestamp` is not more than 15 days older than the
+/// `current_release_date` of the document.
+pub fn test_6_2_41_old_epss(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    // Invalid dates are reported by 6.1.37
+    let CsafDateTime::Valid(current_release_date) = doc.get_document().get_tracking().get_current_release_date() else {
+        return Ok(());
+    };
+    let oldest_allowed = current_release_date.get_as_utc() - TimeDelta::days(MAX_EPSS_AGE_DAYS);
+
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (i_v, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        for (i_m, metric) in vulnerability.get_metrics().into_iter().flatten().enumerate() {
+            let Some(epss) = metric.get_content().get_epss() else {
+                continue;
+            };
+            if let CsafDateTime::Valid(timestamp) = CsafDateTime::from(&epss.timestamp)
+                && timestamp.get_as_utc() < oldest_allowed
+            {
+                errors.get_or_insert_with(Vec::new).push(create_old_epss_error(
+                    &epss.timestamp,
+                    current_release_date.get_raw_string(),
+                    i_v,
+                    i_m,
+                ));
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_41
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_41_old_epss(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_41() {
+        // Only CSAF 2.1 has this test with 4 test cases (2 error cases, 2 success cases)
+        TESTS_2_1.test_6_2_41.expect(
+            Err(vec![create_old_epss_error(
+                "2024-01-08T10:00:00.000Z",
+                "2024-01-24T10:00:00.000Z",
+                0,
+                0,
+            )]),
+            Err(vec![create_old_epss_error(
+                "2023-12-24T10:00:00.000Z",
+                "2024-01-24T10:00:00.000Z",
+                0,
+                1,
+            )]),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use crate::csaf_traits::{CsafTrait, DocumentTrait};
+use crate::validation::ValidationError;
+use crate::validations::test_6_1_54::tokenize;
+
+/// License and exception identifiers marked as deprecated on the SPDX license list
+const DEPRECATED_LICENSE_IDS: &[&str] = &[
+    "AGPL-1.0",
+    "AGPL-3.0",
+    "BSD-2-Clause-FreeBSD",
+    "BSD-2-Clause-NetBSD",
+    "bzip2-1.0.5",
+    "eCos-2.0",
+    "GFDL-1.1",
+    "GFDL-1.2",
+    "GFDL-1.3",
+    "GPL-1.0",
+    "GPL-1.0+",
+    "GPL-2.0",
+    "GPL-2.0+",
+    "GPL-2.0-with-autoconf-exception",
+    "GPL-2.0-with-bison-exception",
+    "GPL-2.0-with-classpath-exception",
+    "GPL-2.0-with-font-exception",
+    "GPL-2.0-with-GCC-exception",
+    "GPL-3.0",
+    "GPL-3.0+",
+    "GPL-3.0-with-autoconf-exception",
+    "GPL-3.0-with-GCC-exception",
+    "LGPL-2.0",
+    "LGPL-2.0+",
+    "LGPL-2.1",
+    "LGPL-2.1+",
+    "LGPL-3.0",
+    "LGPL-3.0+",
+    "Net-SNMP",
+    "Nokia-Qt-exception-1.1",
+    "Nunit",
+    "StandardML-NJ",
+    "wxWindows",
+];
+
+fn create_deprecated_license_error(license_id: &str) -> ValidationError {
+    ValidationError {
+        message: format!("License identifier '{license_id}' is deprecated on the SPDX license list"),
+        instance_path: "/document/license_expression".to_string(),
+    }
+}
+
+/// 6.2.42 Usage of Deprecated License Identifier
+///
+/// Tests that the `/document/license_expression` does not use a license or exception identifier that is
+/// deprecated on the SPDX license list. Identifiers are compared case-insensitively, as in SPDX.
+pub fn test_6_2_42_usage_of_deprecated_license_identifier(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let Some(license_expression) = doc.get_document().get_license_expression() else {
+        return Ok(());
+    };
+
+    let errors: Vec<ValidationError> = tokenize(license_expression)
+        .into_iter()
+        .filter(|token| DEPRECATED_LICENSE_IDS.iter().any(|id| id.eq_ignore_ascii_case(token)))
+        .map(create_deprecated_license_error)
+        .collect();
+
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_42
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_42_usage_of_deprecated_license_identifier(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_42() {
+        // Only CSAF 2.1 has this test with 4 test cases (2 error cases, 2 success cases)
+        TESTS_2_1.test_6_2_42.expect(
+            Err(vec![create_deprecated_license_error("GPL-3.0+")]),
+            Err(vec![create_deprecated_license_error("LGPL-2.1")]),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
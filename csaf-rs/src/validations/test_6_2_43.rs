@@ -0,0 +1,56 @@
+use crate::csaf_traits::{CsafTrait, DocumentTrait};
+use crate::validation::ValidationError;
+use crate::validations::test_6_1_54::tokenize;
+
+fn create_non_spdx_license_error(license_ref: &str) -> ValidationError {
+    ValidationError {
+        message: format!("License '{license_ref}' is not on the SPDX license list"),
+        instance_path: "/document/license_expression".to_string(),
+    }
+}
+
+/// 6.2.43 Usage of Non-SPDX License
+///
+/// Tests that the `/document/license_expression` only uses licenses from the SPDX license list, i.e. no
+/// `LicenseRef-` or `DocumentRef-` identifiers.
+pub fn test_6_2_43_usage_of_non_spdx_license(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let Some(license_expression) = doc.get_document().get_license_expression() else {
+        return Ok(());
+    };
+
+    let errors: Vec<ValidationError> = tokenize(license_expression)
+        .into_iter()
+        .filter(|token| token.starts_with("LicenseRef-") || token.starts_with("DocumentRef-"))
+        .map(create_non_spdx_license_error)
+        .collect();
+
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_43
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_43_usage_of_non_spdx_license(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_43() {
+        // Only CSAF 2.1 has this test with 2 test cases (1 error case, 1 success case)
+        TESTS_2_1.test_6_2_43.expect(
+            Err(vec![create_non_spdx_license_error(
+                "LicenseRef-www.example.org-Example-CSAF-License-3.0+",
+            )]),
+            Ok(()),
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use crate::csaf_traits::{CsafTrait, DocumentTrait, TrackingTrait, VulnerabilityTrait};
+use crate::schema::csaf2_1::schema::DocumentStatus;
+use crate::validation::ValidationError;
+
+fn create_missing_disclosure_date_error(i_v: usize) -> ValidationError {
+    ValidationError {
+        message: "Vulnerability in a final or interim document has no disclosure date".to_string(),
+        instance_path: format!("/vulnerabilities/{i_v}"),
+    }
+}
+
+/// 6.2.44 Missing Disclosure Date
+///
+/// For documents with status `final` or `interim`, tests that each vulnerability has a `disclosure_date`.
+pub fn test_6_2_44_missing_disclosure_date(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let status = doc.get_document().get_tracking().get_status();
+    if status != DocumentStatus::Final && status != DocumentStatus::Interim {
+        return Ok(());
+    }
+
+    let errors: Vec<ValidationError> = doc
+        .get_vulnerabilities()
+        .iter()
+        .enumerate()
+        .filter(|(_, vulnerability)| vulnerability.get_disclosure_date().is_none())
+        .map(|(i_v, _)| create_missing_disclosure_date_error(i_v))
+        .collect();
+
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_44
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_44_missing_disclosure_date(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_44() {
+        let case_01 = Err(vec![create_missing_disclosure_date_error(0)]);
+
+        // Only CSAF 2.1 has this test with 4 test cases (2 error cases, 2 success cases)
+        TESTS_2_1.test_6_2_44.expect(case_01.clone(), case_01, Ok(()), Ok(()));
+    }
+}
@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use semver::Version;
+
+use crate::csaf_traits::{ContentTrait, CsafTrait, MetricTrait, VulnerabilityTrait};
+use crate::helpers::SSVC_DECISION_POINTS;
+use crate::validation::ValidationError;
+
+/// Latest version of each known SSVC decision point, indexed by its (namespace, key) tuple
+static LATEST_DECISION_POINT_VERSIONS: LazyLock<HashMap<(String, String), Version>> = LazyLock::new(|| {
+    let mut latest: HashMap<(String, String), Version> = HashMap::new();
+
+    for (namespace, key, version) in SSVC_DECISION_POINTS.keys() {
+        let Ok(version) = Version::parse(version) else {
+            continue;
+        };
+        let entry = latest
+            .entry((namespace.to_owned(), key.to_owned()))
+            .or_insert(version.clone());
+        if version > *entry {
+            *entry = version;
+        }
+    }
+
+    latest
+});
+
+fn create_non_latest_version_error(
+    namespace: &str,
+    key: &str,
+    version: &str,
+    latest: &Version,
+    i_v: usize,
+    i_m: usize,
+    i_s: usize,
+) -> ValidationError {
+    ValidationError {
+        message: format!(
+            "The SSVC decision point '{namespace}::{key}' is used in version {version}, but the latest version is {latest}"
+        ),
+        instance_path: format!("/vulnerabilities/{i_v}/metrics/{i_m}/content/ssvc_v2/selections/{i_s}/version"),
+    }
+}
+
+/// 6.2.45 Usage of Non-Latest SSVC Decision Point Version
+///
+/// For each SSVC decision point known to this library, tests that its latest version is used. Unknown
+/// decision points are reported by 6.1.48.
+pub fn test_6_2_45_usage_of_non_latest_ssvc_decision_point_version(
+    doc: &impl CsafTrait,
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    for (i_v, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        for (i_m, metric) in vulnerability.get_metrics().into_iter().flatten().enumerate() {
+            // Invalid SSVC objects are reported by 6.1.46
+            let Ok(ssvc) = metric.get_content().get_ssvc() else {
+                continue;
+            };
+            for (i_s, selection) in ssvc.selections.iter().enumerate() {
+                let dp_key = (selection.namespace.to_string(), selection.key.to_string());
+                let Some(latest) = LATEST_DECISION_POINT_VERSIONS.get(&dp_key) else {
+                    continue;
+                };
+                if Version::parse(&selection.version).is_ok_and(|version| version < *latest) {
+                    errors
+                        .get_or_insert_with(Vec::new)
+                        .push(create_non_latest_version_error(
+                            &selection.namespace,
+                            &selection.key,
+                            &selection.version,
+                            latest,
+                            i_v,
+                            i_m,
+                            i_s,
+                        ));
+                }
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_45
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_45_usage_of_non_latest_ssvc_decision_point_version(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_45() {
+        // Only CSAF 2.1 has this test with 4 test cases (2 error cases, 2 success cases)
+        TESTS_2_1.test_6_2_45.expect(
+            Err(vec![create_non_latest_version_error(
+                "ssvc",
+                "E",
+                "1.0.0",
+                &Version::new(1, 1, 0),
+                0,
+                0,
+                0,
+            )]),
+            Err(vec![create_non_latest_version_error(
+                "ssvc",
+                "MI",
+                "1.0.0",
+                &Version::new(2, 0, 0),
+                0,
+                0,
+                1,
+            )]),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use crate::csaf_traits::{CsafTrait, ProductTreeTrait, RelationshipTrait};
+use crate::validation::ValidationError;
+
+fn create_relationship_self_reference_error(product_id: &str, i_r: usize) -> ValidationError {
+    ValidationError {
+        message: format!("Relationship relates the product {product_id} to itself"),
+        instance_path: format!("/product_tree/relationships/{i_r}/relates_to_product_reference"),
+    }
+}
+
+/// 6.2.46 Relationship with Self Reference
+///
+/// For each relationship, tests that its `product_reference` differs from its
+/// `relates_to_product_reference`.
+pub fn test_6_2_46_relationship_with_self_reference(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let Some(product_tree) = doc.get_product_tree().as_ref() else {
+        return Ok(());
+    };
+
+    let errors: Vec<ValidationError> = product_tree
+        .get_relationships()
+        .iter()
+        .enumerate()
+        .filter(|(_, relationship)| {
+            relationship.get_product_reference() == relationship.get_relates_to_product_reference()
+        })
+        .map(|(i_r, relationship)| create_relationship_self_reference_error(relationship.get_product_reference(), i_r))
+        .collect();
+
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_46
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_46_relationship_with_self_reference(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_46() {
+        // Only CSAF 2.1 has this test with 2 test cases (1 error case, 1 success case)
+        TESTS_2_1.test_6_2_46.expect(
+            Err(vec![create_relationship_self_reference_error("CSAFPID-9080700", 0)]),
+            Ok(()),
+        );
+    }
+}
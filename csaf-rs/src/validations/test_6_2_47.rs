@@ -0,0 +1,88 @@
+use crate::csaf_traits::{CsafTrait, DocumentTrait, NoteTrait, VulnerabilityTrait};
+use crate::validation::ValidationError;
+
+fn create_same_category_and_title_error(title: &str, other_index: usize, path: &str) -> ValidationError {
+    ValidationError {
+        message: format!(
+            "Note has the same category and title '{title}' as the note at index {other_index}, their content should be combined"
+        ),
+        instance_path: format!("{path}/title"),
+    }
+}
+
+/// 6.2.47 Same Category and Title in Notes
+///
+/// Tests that no two notes of the document, or of the same vulnerability, have the same `category` and
+/// `title`.
+pub fn test_6_2_47_same_category_and_title_in_notes(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    check_notes(doc.get_document().get_notes(), "/document/notes", &mut errors);
+    for (i_v, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        check_notes(
+            vulnerability.get_notes(),
+            &format!("/vulnerabilities/{i_v}/notes"),
+            &mut errors,
+        );
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+/// Reports each note whose category and title equal those of an earlier note in the same list.
+fn check_notes(notes: Option<&Vec<impl NoteTrait>>, path: &str, errors: &mut Option<Vec<ValidationError>>) {
+    let notes: Vec<_> = notes.into_iter().flatten().collect();
+    for (i_n, note) in notes.iter().enumerate() {
+        let Some(title) = note.get_title() else {
+            continue;
+        };
+        if let Some(other_index) = notes[..i_n]
+            .iter()
+            .position(|other| other.get_category() == note.get_category() && other.get_title() == Some(title))
+        {
+            errors
+                .get_or_insert_with(Vec::new)
+                .push(create_same_category_and_title_error(
+                    title,
+                    other_index,
+                    &format!("{path}/{i_n}"),
+                ));
+        }
+    }
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_2_47
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_2_47_same_category_and_title_in_notes(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_47() {
+        // Only CSAF 2.1 has this test with 4 test cases (2 error cases, 2 success cases)
+        TESTS_2_1.test_6_2_47.expect(
+            Err(vec![create_same_category_and_title_error(
+                "Summary",
+                0,
+                "/document/notes/1",
+            )]),
+            Err(vec![create_same_category_and_title_error(
+                "Details",
+                0,
+                "/vulnerabilities/0/notes/1",
+            )]),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use crate::csaf_traits::{BranchTrait, CategoryOfTheBranch, CsafTrait, ProductTreeTrait};
+use crate::validation::ValidationError;
+use crate::validations::test_6_1_50::check_vers;
+use std::cmp::Ordering;
+
+fn create_non_canonical_vers_error(version_range: &str, canonical: &str, path: &str) -> ValidationError {
+    ValidationError {
+        message: format!(
+            "Product version range '{version_range}' does not list its constraints sorted by version, \
+            the canonical form is '{canonical}'"
+        ),
+        instance_path: format!("{path}/name"),
+    }
+}
+
+/// 6.3.12 Use of Non-Canonical vers
+///
+/// For each branch with the category `product_version_range` whose `name` is a valid vers, tests that
+/// its version constraints are sorted by version, as required for the canonical form of a vers.
+/// Invalid version ranges are reported by 6.1.50.
+pub fn test_6_3_12_use_of_non_canonical_vers(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    if let Some(product_tree) = doc.get_product_tree().as_ref() {
+        product_tree.visit_all_branches(&mut |branch, path| {
+            if branch.get_category() != &CategoryOfTheBranch::ProductVersionRange {
+                return;
+            }
+            let name = branch.get_name();
+            if check_vers(name).is_err() {
+                return;
+            }
+            if let Some(canonical) = canonical_vers(name)
+                && canonical != *name
+            {
+                errors
+                    .get_or_insert_with(Vec::new)
+                    .push(create_non_canonical_vers_error(name, &canonical, path));
+            }
+        });
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+/// Returns the given vers with its constraints sorted by version, or `None` if `name` is no vers.
+fn canonical_vers(name: &str) -> Option<String> {
+    let (scheme, constraints) = name.strip_prefix("vers:")?.split_once('/')?;
+    let mut constraints: Vec<&str> = constraints.split('|').collect();
+    constraints.sort_by(|a, b| compare_versions(constraint_version(a), constraint_version(b)));
+    Some(format!("vers:{scheme}/{}", constraints.join("|")))
+}
+
+fn constraint_version(constraint: &str) -> &str {
+    constraint.trim_start_matches(['<', '>', '=', '!'])
+}
+
+/// Compares two versions segment by segment, where runs of digits are compared numerically and
+/// all other segments lexicographically.
+fn compare_versions(a: &str, b: &str) -> Ordering {
+    let (mut a, mut b) = (version_segments(a), version_segments(b));
+    loop {
+        match (a.next(), b.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a), Some(b)) => {
+                let ordering = match (a.parse::<u64>(), b.parse::<u64>()) {
+                    (Ok(a), Ok(b)) => a.cmp(&b),
+                    _ => a.cmp(b),
+                };
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            },
+        }
+    }
+}
+
+/// Splits a version into runs of digits and runs of other alphanumeric characters, skipping separators.
+fn version_segments(version: &str) -> impl Iterator<Item = &str> {
+    version
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .flat_map(|part| {
+            let mut segments = Vec::new();
+            let mut start = 0;
+            for (i, c) in part.char_indices().skip(1) {
+                let previous = part.as_bytes()[i - 1];
+                if previous.is_ascii_digit() != c.is_ascii_digit() {
+                    segments.push(&part[start..i]);
+                    start = i;
+                }
+            }
+            segments.push(&part[start..]);
+            segments
+        })
+        .filter(|segment| !segment.is_empty())
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_3_12
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_3_12_use_of_non_canonical_vers(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    const PATH: &str = "/product_tree/branches/0/branches/0/branches/0";
+
+    #[test]
+    fn test_test_6_3_12() {
+        // Only CSAF 2.1 has this test with 11 test cases (5 error cases, 6 success cases)
+        TESTS_2_1.test_6_3_12.expect(
+            Err(vec![create_non_canonical_vers_error(
+                "vers:generic/<4.2.0|>=4.1.0",
+                "vers:generic/>=4.1.0|<4.2.0",
+                PATH,
+            )]),
+            Err(vec![create_non_canonical_vers_error(
+                "vers:semver/<2.0.0|>=1.10.0",
+                "vers:semver/>=1.10.0|<2.0.0",
+                PATH,
+            )]),
+            Err(vec![create_non_canonical_vers_error(
+                "vers:intdot/<10|>=9",
+                "vers:intdot/>=9|<10",
+                PATH,
+            )]),
+            Err(vec![create_non_canonical_vers_error(
+                "vers:npm/<3.0.0|1.2.3|>=2.0.0",
+                "vers:npm/1.2.3|>=2.0.0|<3.0.0",
+                PATH,
+            )]),
+            Err(vec![create_non_canonical_vers_error(
+                "vers:generic/!=4.1.5|>=4.1.0|<4.2.0",
+                "vers:generic/>=4.1.0|!=4.1.5|<4.2.0",
+                PATH,
+            )]),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+        );
+    }
+
+    #[test]
+    fn test_compare_versions() {
+        assert_eq!(compare_versions("1.10.0", "1.9.0"), Ordering::Greater);
+        assert_eq!(compare_versions("4.1.0", "4.1.0"), Ordering::Equal);
+        assert_eq!(compare_versions("2.0", "2.0.1"), Ordering::Less);
+        assert_eq!(compare_versions("1.0rc2", "1.0rc10"), Ordering::Less);
+        assert_eq!(canonical_vers("vers:semver/*"), Some("vers:semver/*".to_string()));
+    }
+}
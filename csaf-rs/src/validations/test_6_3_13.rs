@@ -0,0 +1,55 @@
+use crate::csaf_traits::{ContentTrait, CsafTrait, MetricTrait, VulnerabilityTrait};
+use crate::validation::ValidationError;
+
+fn create_missing_ssvc_error(i_v: usize) -> ValidationError {
+    ValidationError {
+        message: "Vulnerability has no SSVC metric".to_string(),
+        instance_path: format!("/vulnerabilities/{i_v}"),
+    }
+}
+
+/// 6.3.13 Missing SSVC
+///
+/// Tests that each vulnerability has at least one metric containing an SSVC decision.
+pub fn test_6_3_13_missing_ssvc(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let errors: Vec<ValidationError> = doc
+        .get_vulnerabilities()
+        .iter()
+        .enumerate()
+        .filter(|(_, vulnerability)| {
+            !vulnerability
+                .get_metrics()
+                .into_iter()
+                .flatten()
+                .any(|metric| metric.get_content().has_ssvc())
+        })
+        .map(|(i_v, _)| create_missing_ssvc_error(i_v))
+        .collect();
+
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_3_13
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_3_13_missing_ssvc(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_3_13() {
+        // Only CSAF 2.1 has this test with 2 test cases (1 error case, 1 success case)
+        TESTS_2_1
+            .test_6_3_13
+            .expect(Err(vec![create_missing_ssvc_error(0)]), Ok(()));
+    }
+}
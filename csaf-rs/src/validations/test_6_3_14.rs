@@ -0,0 +1,60 @@
+use crate::csaf_traits::{ContentTrait, CsafTrait, MetricTrait, VulnerabilityTrait};
+use crate::validation::ValidationError;
+
+fn create_missing_epss_error(cve: &str, i_v: usize) -> ValidationError {
+    ValidationError {
+        message: format!("Vulnerability {cve} has no EPSS metric"),
+        instance_path: format!("/vulnerabilities/{i_v}"),
+    }
+}
+
+/// 6.3.14 Missing EPSS
+///
+/// For each vulnerability with a CVE, tests that it has at least one metric containing an EPSS score.
+/// Vulnerabilities without a CVE are skipped, as EPSS scores are only published for CVEs.
+pub fn test_6_3_14_missing_epss(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    for (i_v, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        let Some(cve) = vulnerability.get_cve() else {
+            continue;
+        };
+        if !vulnerability
+            .get_metrics()
+            .into_iter()
+            .flatten()
+            .any(|metric| metric.get_content().has_epss())
+        {
+            errors
+                .get_or_insert_with(Vec::new)
+                .push(create_missing_epss_error(cve, i_v));
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_3_14
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_3_14_missing_epss(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_3_14() {
+        // Only CSAF 2.1 has this test with 2 test cases (1 error case, 1 success case)
+        TESTS_2_1
+            .test_6_3_14
+            .expect(Err(vec![create_missing_epss_error("CVE-1900-0001", 0)]), Ok(()));
+    }
+}
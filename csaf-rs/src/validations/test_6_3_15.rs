@@ -0,0 +1,85 @@
+use crate::csaf_traits::{ContentTrait, CsafTrait, MetricTrait, VulnerabilityTrait};
+use crate::validation::ValidationError;
+use std::collections::HashSet;
+
+fn create_cvss_v3_without_cvss_v4_error(product_ids: &[&String], path: &str) -> ValidationError {
+    ValidationError {
+        message: format!(
+            "CVSS v3 is given without a CVSS v4 metric for the product(s) {}",
+            product_ids.iter().map(|id| id.as_str()).collect::<Vec<_>>().join(", ")
+        ),
+        instance_path: format!("{path}/cvss_v3"),
+    }
+}
+
+/// 6.3.15 Use of CVSS v3 without CVSS v4
+///
+/// For each metric containing CVSS v3, tests that the same vulnerability has a CVSS v4 metric for each
+/// of its products.
+pub fn test_6_3_15_use_of_cvss_v3_without_cvss_v4(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    for (i_v, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        let metrics: Vec<_> = vulnerability.get_metrics().into_iter().flatten().collect();
+        let cvss_v4_products: HashSet<&String> = metrics
+            .iter()
+            .filter(|metric| metric.get_content().has_cvss_v4())
+            .flat_map(|metric| metric.get_products())
+            .collect();
+
+        for (i_m, metric) in metrics.iter().enumerate() {
+            if !metric.get_content().has_cvss_v3() {
+                continue;
+            }
+            let missing: Vec<&String> = metric
+                .get_products()
+                .filter(|product_id| !cvss_v4_products.contains(product_id))
+                .collect();
+            if !missing.is_empty() {
+                errors
+                    .get_or_insert_with(Vec::new)
+                    .push(create_cvss_v3_without_cvss_v4_error(
+                        &missing,
+                        &metric.get_content().get_content_json_path(i_v, i_m),
+                    ));
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_3_15
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_3_15_use_of_cvss_v3_without_cvss_v4(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_3_15() {
+        let product_id = "CSAFPID-9080700".to_string();
+
+        // Only CSAF 2.1 has this test with 3 test cases (2 error cases, 1 success case)
+        TESTS_2_1.test_6_3_15.expect(
+            Err(vec![create_cvss_v3_without_cvss_v4_error(
+                &[&product_id],
+                "/vulnerabilities/0/metrics/0/content",
+            )]),
+            Err(vec![create_cvss_v3_without_cvss_v4_error(
+                &[&product_id],
+                "/vulnerabilities/0/metrics/1/content",
+            )]),
+            Ok(()),
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use crate::csaf_traits::{CsafTrait, VulnerabilityTrait};
+use crate::validation::ValidationError;
+
+fn create_missing_involvements_error(i_v: usize) -> ValidationError {
+    ValidationError {
+        message: "Vulnerability has no involvements".to_string(),
+        instance_path: format!("/vulnerabilities/{i_v}"),
+    }
+}
+
+/// 6.3.17 Missing Involvements
+///
+/// Tests that each vulnerability has at least one entry in `involvements`.
+pub fn test_6_3_17_missing_involvements(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let errors: Vec<ValidationError> = doc
+        .get_vulnerabilities()
+        .iter()
+        .enumerate()
+        .filter(|(_, vulnerability)| {
+            vulnerability
+                .get_involvements()
+                .as_ref()
+                .is_none_or(|involvements| involvements.is_empty())
+        })
+        .map(|(i_v, _)| create_missing_involvements_error(i_v))
+        .collect();
+
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_3_17
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_3_17_missing_involvements(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_3_17() {
+        // Only CSAF 2.1 has this test with 2 test cases (1 error case, 1 success case)
+        TESTS_2_1
+            .test_6_3_17
+            .expect(Err(vec![create_missing_involvements_error(0)]), Ok(()));
+    }
+}
@@ -0,0 +1,55 @@
+use crate::csaf_traits::{CsafTrait, RemediationTrait, VulnerabilityTrait};
+use crate::schema::csaf2_1::schema::CategoryOfTheRemediation;
+use crate::validation::ValidationError;
+
+fn create_missing_vendor_fix_url_error(i_v: usize, i_r: usize) -> ValidationError {
+    ValidationError {
+        message: "Remediation of category 'vendor_fix' has no URL".to_string(),
+        instance_path: format!("/vulnerabilities/{i_v}/remediations/{i_r}"),
+    }
+}
+
+/// 6.3.18 Missing URL in Vendor Fix
+///
+/// For each remediation with the category `vendor_fix`, tests that it has a `url` where the fix
+/// can be obtained.
+pub fn test_6_3_18_missing_url_in_vendor_fix(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    for (i_v, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        for (i_r, remediation) in vulnerability.get_remediations().iter().enumerate() {
+            if remediation.get_category() == CategoryOfTheRemediation::VendorFix && remediation.get_url().is_none() {
+                errors
+                    .get_or_insert_with(Vec::new)
+                    .push(create_missing_vendor_fix_url_error(i_v, i_r));
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_3_18
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_3_18_missing_url_in_vendor_fix(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_3_18() {
+        // Only CSAF 2.1 has this test with 2 test cases (1 error case, 1 success case)
+        TESTS_2_1
+            .test_6_3_18
+            .expect(Err(vec![create_missing_vendor_fix_url_error(0, 0)]), Ok(()));
+    }
+}
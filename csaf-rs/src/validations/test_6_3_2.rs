@@ -0,0 +1,92 @@
+use crate::csaf::types::csaf_vuln_metric::CsafVulnerabilityMetric;
+use crate::csaf_traits::{ContentTrait, CsafTrait, MetricTrait, VulnerabilityTrait};
+use crate::validation::ValidationError;
+
+fn create_cvss_v3_0_error(instance_path: String) -> ValidationError {
+    ValidationError {
+        message: "Vulnerability metric uses CVSS v3.0, CVSS v3.1 or newer should be used instead".to_string(),
+        instance_path,
+    }
+}
+
+/// 6.3.2 Use of CVSS v3.0
+///
+/// For each vulnerability, tests that no metric contains a CVSS v3 object using version 3.0.
+pub fn test_6_3_2_use_of_cvss_v3_0(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    for (v_i, vuln) in doc.get_vulnerabilities().iter().enumerate() {
+        let Some(metrics) = vuln.get_metrics() else {
+            continue;
+        };
+        for (m_i, metric) in metrics.iter().enumerate() {
+            let content = metric.get_content();
+            let uses_cvss_v3_0 = content
+                .get_vulnerability_metric_types()
+                .iter()
+                .any(|metric_type| matches!(metric_type, CsafVulnerabilityMetric::CvssV3(version) if version == "3.0"));
+            if uses_cvss_v3_0 {
+                errors.get_or_insert_with(Vec::new).push(create_cvss_v3_0_error(format!(
+                    "{}/cvss_v3/version",
+                    content.get_content_json_path(v_i, m_i)
+                )));
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_0::testcases::ValidatorForTest6_3_2
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_3_2_use_of_cvss_v3_0(doc)
+    }
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_3_2
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_3_2_use_of_cvss_v3_0(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_0::testcases::TESTS_2_0;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_3_2() {
+        // Both CSAF 2.0 and 2.1 have 4 test cases (2 error cases, 2 success cases)
+        TESTS_2_0.test_6_3_2.expect(
+            Err(vec![create_cvss_v3_0_error(
+                "/vulnerabilities/0/scores/0/cvss_v3/version".to_string(),
+            )]),
+            Err(vec![create_cvss_v3_0_error(
+                "/vulnerabilities/0/scores/1/cvss_v3/version".to_string(),
+            )]),
+            Ok(()),
+            Ok(()),
+        );
+        TESTS_2_1.test_6_3_2.expect(
+            Err(vec![create_cvss_v3_0_error(
+                "/vulnerabilities/0/metrics/0/content/cvss_v3/version".to_string(),
+            )]),
+            Err(vec![create_cvss_v3_0_error(
+                "/vulnerabilities/0/metrics/1/content/cvss_v3/version".to_string(),
+            )]),
+            Ok(()),
+            Ok(()),
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use crate::csaf::raw::RawDocument;
+use crate::helpers::json_values_at;
+use crate::url_resolver::{ResolveError, UrlResolver, is_http_url, is_resolved};
+use crate::validation::ValidationError;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Fields containing URLs, as patterns for [json_values_at]
+const URL_FIELDS: &[&str] = &[
+    "/document/acknowledgments/*/urls/*",
+    "/document/aggregate_severity/namespace",
+    "/document/distribution/tlp/url",
+    "/document/publisher/namespace",
+    "/product_tree/**/product_identification_helper/sbom_urls/*",
+    "/product_tree/**/product_identification_helper/x_generic_uris/*/namespace",
+    "/product_tree/**/product_identification_helper/x_generic_uris/*/uri",
+    "/vulnerabilities/*/acknowledgments/*/urls/*",
+    "/vulnerabilities/*/remediations/*/url",
+];
+
+/// References, whose URL is self-referencing if their category is `self`
+const REFERENCES: &[&str] = &["/document/references/*", "/vulnerabilities/*/references/*"];
+
+fn create_unresolved_url_error(url: &str, status: u16, path: &str) -> ValidationError {
+    ValidationError {
+        message: format!("The URL {url} resolves with HTTP status code {status}"),
+        instance_path: path.to_string(),
+    }
+}
+
+pub(crate) fn create_unresolvable_url_error(url: &str, err: &ResolveError, path: &str) -> ValidationError {
+    ValidationError {
+        message: format!("The URL {url} could not be resolved: {err}"),
+        instance_path: path.to_string(),
+    }
+}
+
+/// Returns the URLs of the references with their paths, either the self-referencing ones or all others.
+pub(crate) fn reference_urls(json: &Value, self_referencing: bool) -> Vec<(String, &str)> {
+    REFERENCES
+        .iter()
+        .flat_map(|pattern| json_values_at(json, pattern))
+        .filter(|(_, reference)| {
+            (reference.get("category").and_then(Value::as_str) == Some("self")) == self_referencing
+        })
+        .filter_map(|(path, reference)| {
+            let url = reference.get("url")?.as_str()?;
+            Some((format!("{path}/url"), url))
+        })
+        .collect()
+}
+
+/// Resolves the URLs and reports those not resolving with a 2xx or 3xx status code.
+///
+/// Each distinct URL is resolved once. URIs with other schemes than `http` and `https`, like URNs in
+/// `x_generic_uris`, are not resolved.
+pub(crate) fn check_urls(resolver: &dyn UrlResolver, urls: Vec<(String, &str)>) -> Result<(), Vec<ValidationError>> {
+    let mut results: HashMap<&str, Result<u16, ResolveError>> = HashMap::new();
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (path, url) in urls.into_iter().filter(|(_, url)| is_http_url(url)) {
+        let result = results.entry(url).or_insert_with(|| resolver.resolve(url));
+        let error = match result {
+            Ok(status) if is_resolved(*status) => continue,
+            Ok(status) => create_unresolved_url_error(url, *status, &path),
+            Err(err) => create_unresolvable_url_error(url, err, &path),
+        };
+        errors.get_or_insert_with(Vec::new).push(error);
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+/// 6.3.6 Usage of Non-Self Referencing URLs Failing to Resolve
+///
+/// Tests that all HTTP(S) URLs, except those of references in the category `self`, resolve with an
+/// HTTP status code from the 2xx (Successful) or 3xx (Redirection) class.
+pub fn test_6_3_6_non_self_referencing_urls(
+    json: &Value,
+    resolver: &dyn UrlResolver,
+) -> Result<(), Vec<ValidationError>> {
+    let mut urls: Vec<(String, &str)> = URL_FIELDS
+        .iter()
+        .flat_map(|field| json_values_at(json, field))
+        .filter_map(|(path, value)| value.as_str().map(|url| (path, url)))
+        .collect();
+    urls.extend(reference_urls(json, false));
+    check_urls(resolver, urls)
+}
+
+/// Precondition of 6.3.6 and 6.3.7, which are skipped unless a URL resolver is attached with
+/// [RawDocument::with_url_resolver].
+pub fn has_url_resolver<D>(document: &RawDocument<D>) -> bool {
+    document.get_url_resolver().is_some()
+}
+
+/// Runs 6.3.6 with the URL resolver attached to the document, passes without one.
+fn validate_with_url_resolver<D>(document: &RawDocument<D>) -> Result<(), Vec<ValidationError>> {
+    match document.get_url_resolver() {
+        Some(resolver) => test_6_3_6_non_self_referencing_urls(document.get_json(), resolver),
+        None => Ok(()),
+    }
+}
+
+impl crate::test_validation::TestValidator<RawDocument<crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework>>
+    for crate::csaf2_0::testcases::ValidatorForTest6_3_6
+{
+    fn validate(
+        &self,
+        document: &RawDocument<crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework>,
+    ) -> Result<(), Vec<ValidationError>> {
+        validate_with_url_resolver(document)
+    }
+}
+
+impl crate::test_validation::TestValidator<RawDocument<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>>
+    for crate::csaf2_1::testcases::ValidatorForTest6_3_6
+{
+    fn validate(
+        &self,
+        document: &RawDocument<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>,
+    ) -> Result<(), Vec<ValidationError>> {
+        validate_with_url_resolver(document)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_0::testcases::TESTS_2_0;
+    use crate::csaf2_0::testcases::{Test6_3_6 as Test2_0, ValidatorForTest6_3_6 as Validator2_0};
+    use crate::csaf2_1::testcases::TESTS_2_1;
+    use crate::csaf2_1::testcases::{Test6_3_6 as Test2_1, ValidatorForTest6_3_6 as Validator2_1};
+    use crate::test_registry;
+    use crate::url_resolver::OfflineUrlResolver;
+    use crate::url_resolver::testing::WithReservedDomainResolver;
+    use crate::validation::TestResultStatus;
+    use serde_json::json;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_test_6_3_6() {
+        let unresolvable = |path: &str| {
+            create_unresolvable_url_error(
+                "https://example.invalid",
+                &ResolveError::Request("failed to lookup address of example.invalid".to_string()),
+                path,
+            )
+        };
+        let case_01 = Err(vec![unresolvable("/document/references/0/url")]);
+        let case_02 = Err(vec![unresolvable("/vulnerabilities/0/references/0/url")]);
+
+        // Both CSAF 2.0 and 2.1 have 3 test cases (2 error cases, 1 success case)
+        Test2_0::<WithReservedDomainResolver<Validator2_0>>::new().expect(case_01.clone(), case_02.clone(), Ok(()));
+        Test2_1::<WithReservedDomainResolver<Validator2_1>>::new().expect(case_01, case_02, Ok(()));
+
+        // Without a URL resolver, the test passes and is skipped by the registries
+        TESTS_2_0.test_6_3_6.expect(Ok(()), Ok(()), Ok(()));
+        TESTS_2_1.test_6_3_6.expect(Ok(()), Ok(()), Ok(()));
+    }
+
+    #[test]
+    fn test_url_resolver_per_document() {
+        let registry = test_registry::csaf_2_1();
+        let document = RawDocument::new(json!({ "document": { "publisher": { "namespace": "https://example.com" } } }));
+        assert_eq!(
+            registry.run_raw_test(&document, "6.3.6").status,
+            TestResultStatus::Skipped
+        );
+
+        let resolver = |status| Arc::new(OfflineUrlResolver::new().status("https://example.com", status));
+        let resolved = document.clone().with_url_resolver(resolver(200));
+        let unresolved = document.with_url_resolver(resolver(404));
+        assert_eq!(
+            registry.run_raw_test(&resolved, "6.3.6").status,
+            TestResultStatus::Success
+        );
+        assert!(matches!(
+            registry.run_raw_test(&unresolved, "6.3.6").status,
+            TestResultStatus::Failure { .. }
+        ));
+    }
+}
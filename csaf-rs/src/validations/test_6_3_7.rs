@@ -0,0 +1,79 @@
+use crate::csaf::raw::RawDocument;
+use crate::url_resolver::UrlResolver;
+use crate::validation::ValidationError;
+use crate::validations::test_6_3_6::{check_urls, reference_urls};
+use serde_json::Value;
+
+/// 6.3.7 Usage of Self Referencing URLs Failing to Resolve
+///
+/// Tests that the URLs of references in the category `self`, like the canonical URL of the
+/// document, resolve with an HTTP status code from the 2xx (Successful) or 3xx (Redirection) class.
+pub fn test_6_3_7_self_referencing_urls(json: &Value, resolver: &dyn UrlResolver) -> Result<(), Vec<ValidationError>> {
+    check_urls(resolver, reference_urls(json, true))
+}
+
+/// Runs 6.3.7 with the URL resolver attached to the document, passes without one.
+fn validate_with_url_resolver<D>(document: &RawDocument<D>) -> Result<(), Vec<ValidationError>> {
+    match document.get_url_resolver() {
+        Some(resolver) => test_6_3_7_self_referencing_urls(document.get_json(), resolver),
+        None => Ok(()),
+    }
+}
+
+impl crate::test_validation::TestValidator<RawDocument<crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework>>
+    for crate::csaf2_0::testcases::ValidatorForTest6_3_7
+{
+    fn validate(
+        &self,
+        document: &RawDocument<crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework>,
+    ) -> Result<(), Vec<ValidationError>> {
+        validate_with_url_resolver(document)
+    }
+}
+
+impl crate::test_validation::TestValidator<RawDocument<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>>
+    for crate::csaf2_1::testcases::ValidatorForTest6_3_7
+{
+    fn validate(
+        &self,
+        document: &RawDocument<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>,
+    ) -> Result<(), Vec<ValidationError>> {
+        validate_with_url_resolver(document)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf2_0::testcases::TESTS_2_0;
+    use crate::csaf2_0::testcases::{Test6_3_7 as Test2_0, ValidatorForTest6_3_7 as Validator2_0};
+    use crate::csaf2_1::testcases::TESTS_2_1;
+    use crate::csaf2_1::testcases::{Test6_3_7 as Test2_1, ValidatorForTest6_3_7 as Validator2_1};
+    use crate::url_resolver::ResolveError;
+    use crate::url_resolver::testing::WithReservedDomainResolver;
+    use crate::validations::test_6_3_6::create_unresolvable_url_error;
+
+    #[test]
+    fn test_test_6_3_7() {
+        let unresolvable = |url: &str| {
+            Err(vec![create_unresolvable_url_error(
+                url,
+                &ResolveError::Request("failed to lookup address of example.invalid".to_string()),
+                "/document/references/0/url",
+            )])
+        };
+
+        // Both CSAF 2.0 and 2.1 have 2 test cases (1 error case, 1 success case)
+        Test2_0::<WithReservedDomainResolver<Validator2_0>>::new().expect(
+            unresolvable("https://example.invalid/security/data/csaf/2021/oasis_csaf_tc-csaf_2_0-2021-6-3-07-01.json"),
+            Ok(()),
+        );
+        Test2_1::<WithReservedDomainResolver<Validator2_1>>::new().expect(
+            unresolvable("https://example.invalid/security/data/csaf/2024/oasis_csaf_tc-csaf_2_1-2024-6-3-07-01.json"),
+            Ok(()),
+        );
+
+        // Without a URL resolver, the test passes and is skipped by the registries
+        TESTS_2_0.test_6_3_7.expect(Ok(()), Ok(()));
+        TESTS_2_1.test_6_3_7.expect(Ok(()), Ok(()));
+    }
+}
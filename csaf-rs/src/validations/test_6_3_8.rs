@@ -0,0 +1,186 @@
+use crate::csaf::raw::RawDocument;
+use crate::helpers::json_values_at;
+use crate::spell_check::{DEFAULT_LANGUAGE, Misspelling, SpellChecker};
+use crate::validation::ValidationError;
+use serde_json::Value;
+
+/// Free-text fields that are spell checked, as patterns for [json_values_at]
+const TEXT_FIELDS: &[&str] = &[
+    "/document/acknowledgments/*/summary",
+    "/document/aggregate_severity/text",
+    "/document/distribution/text",
+    "/document/notes/*/audience",
+    "/document/notes/*/text",
+    "/document/notes/*/title",
+    "/document/publisher/issuing_authority",
+    "/document/references/*/summary",
+    "/document/title",
+    "/document/tracking/revision_history/*/summary",
+    "/vulnerabilities/*/acknowledgments/*/summary",
+    "/vulnerabilities/*/involvements/*/summary",
+    "/vulnerabilities/*/notes/*/audience",
+    "/vulnerabilities/*/notes/*/text",
+    "/vulnerabilities/*/notes/*/title",
+    "/vulnerabilities/*/references/*/summary",
+    "/vulnerabilities/*/remediations/*/details",
+    "/vulnerabilities/*/remediations/*/entitlements/*",
+    "/vulnerabilities/*/remediations/*/restart_required/details",
+    "/vulnerabilities/*/threats/*/details",
+    "/vulnerabilities/*/title",
+];
+
+fn create_misspelling_error(misspelling: &Misspelling, path: &str) -> ValidationError {
+    let suggestions = if misspelling.suggestions.is_empty() {
+        "no suggestions".to_string()
+    } else {
+        format!("did you mean {}?", misspelling.suggestions.join(", "))
+    };
+    ValidationError {
+        message: format!("Possibly misspelled word '{}', {suggestions}", misspelling.word),
+        instance_path: path.to_string(),
+    }
+}
+
+/// 6.3.8 Spell Check
+///
+/// Tests that the free-text fields contain no misspelled words, using the dictionary for the
+/// `/document/lang` of the document (or `en`, if there is none). Documents in a language without a
+/// dictionary are not checked, the registry reports the test as skipped for them.
+pub fn test_6_3_8_spell_check(json: &Value, spell_checker: &SpellChecker) -> Result<(), Vec<ValidationError>> {
+    let Some(dictionary) = spell_checker.dictionary_for(document_language(json)) else {
+        return Ok(());
+    };
+
+    let texts = TEXT_FIELDS
+        .iter()
+        .flat_map(|field| json_values_at(json, field))
+        .filter_map(|(path, value)| value.as_str().map(|text| (path, text)));
+
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (path, text) in texts {
+        for misspelling in spell_checker.check_text(&dictionary, text) {
+            errors
+                .get_or_insert_with(Vec::new)
+                .push(create_misspelling_error(&misspelling, &path));
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+fn document_language(json: &Value) -> &str {
+    json.pointer("/document/lang")
+        .and_then(Value::as_str)
+        .unwrap_or(DEFAULT_LANGUAGE)
+}
+
+/// Precondition of 6.3.8, which is skipped unless the document has a spell checker with a dictionary
+/// for its language.
+pub fn has_dictionary<D>(document: &RawDocument<D>) -> bool {
+    document.get_spell_checker().is_some_and(|spell_checker| {
+        let lang = document_language(document.get_json());
+        let available = spell_checker.dictionary_for(lang).is_some();
+        if !available {
+            log::warn!("No dictionary for language '{lang}', skipping spell check");
+        }
+        available
+    })
+}
+
+/// Runs 6.3.8 with the spell checker attached to the document, passes without one.
+fn validate_with_spell_checker<D>(document: &RawDocument<D>) -> Result<(), Vec<ValidationError>> {
+    match document.get_spell_checker() {
+        Some(spell_checker) => test_6_3_8_spell_check(document.get_json(), spell_checker),
+        None => Ok(()),
+    }
+}
+
+impl crate::test_validation::TestValidator<RawDocument<crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework>>
+    for crate::csaf2_0::testcases::ValidatorForTest6_3_8
+{
+    fn validate(
+        &self,
+        document: &RawDocument<crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework>,
+    ) -> Result<(), Vec<ValidationError>> {
+        validate_with_spell_checker(document)
+    }
+}
+
+impl crate::test_validation::TestValidator<RawDocument<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>>
+    for crate::csaf2_1::testcases::ValidatorForTest6_3_8
+{
+    fn validate(
+        &self,
+        document: &RawDocument<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>,
+    ) -> Result<(), Vec<ValidationError>> {
+        validate_with_spell_checker(document)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_0::testcases::TESTS_2_0;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+    use crate::spell_check::Dictionary;
+    use crate::test_registry;
+    use crate::validation::TestResultStatus;
+    use serde_json::json;
+    use std::sync::Arc;
+
+    fn dictionary() -> Dictionary {
+        Dictionary::parse(
+            "TRY eorfl\nSFX S Y 1\nSFX S 0 s .\n",
+            "9\nunit\ntest\nbuffer/S\noverflow/S\nin\nupdate\nto\ninitial\nversion\n",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_test_6_3_8() {
+        // Without a spell checker attached to the documents, the test passes
+        // Both CSAF 2.0 and 2.1 have 4 test cases (2 error cases, 2 success cases)
+        TESTS_2_0.test_6_3_8.expect(Ok(()), Ok(()), Ok(()), Ok(()));
+        TESTS_2_1.test_6_3_8.expect(Ok(()), Ok(()), Ok(()), Ok(()));
+    }
+
+    #[test]
+    fn test_spell_check() {
+        let spell_checker = SpellChecker::new().dictionary("en", dictionary()).allow(["Acme"]);
+        let texts = json!({
+            "document": {
+                "title": "Buffer overflows in Acme",
+                "notes": [{ "category": "summary", "text": "Unit tset" }]
+            },
+            "vulnerabilities": [{ "remediations": [{ "category": "vendor_fix", "details": "Update to 1.2" }] }]
+        });
+        assert_eq!(
+            test_6_3_8_spell_check(&texts, &spell_checker),
+            Err(vec![create_misspelling_error(
+                &Misspelling {
+                    word: "tset".to_string(),
+                    suggestions: vec!["test".to_string()],
+                },
+                "/document/notes/0/text"
+            )])
+        );
+    }
+
+    #[test]
+    fn test_skipped_without_dictionary() {
+        let registry = test_registry::csaf_2_1();
+        let document = RawDocument::new(json!({ "document": { "lang": "de", "title": "Pufferüberlauf" } }));
+        assert_eq!(
+            registry.run_raw_test(&document, "6.3.8").status,
+            TestResultStatus::Skipped
+        );
+
+        // There is no dictionary for German
+        let spell_checker = Arc::new(SpellChecker::new().dictionary("en", dictionary()));
+        let document = document.with_spell_checker(spell_checker);
+        assert_eq!(
+            registry.run_raw_test(&document, "6.3.8").status,
+            TestResultStatus::Skipped
+        );
+    }
+}
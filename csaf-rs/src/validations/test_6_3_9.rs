@@ -0,0 +1,159 @@
+use crate::csaf_traits::{BranchTrait, CategoryOfTheBranch, CsafTrait, ProductTrait, ProductTreeTrait};
+use crate::validation::ValidationError;
+
+/// Branch categories which are expected along the path to a product, in this order
+const RECOMMENDED_CATEGORY_SEQUENCE: [CategoryOfTheBranch; 3] = [
+    CategoryOfTheBranch::Vendor,
+    CategoryOfTheBranch::ProductName,
+    CategoryOfTheBranch::ProductVersion,
+];
+
+fn create_branch_categories_error(path: &str) -> ValidationError {
+    ValidationError {
+        message: "The branches leading to this product do not use the branch categories \
+            'vendor', 'product_name' and 'product_version' in this order"
+            .to_string(),
+        instance_path: format!("{path}/product"),
+    }
+}
+
+/// Whether the recommended categories appear in `categories` in the recommended order.
+/// Other categories may appear before, between or after them.
+fn follows_recommended_sequence(categories: &[&CategoryOfTheBranch]) -> bool {
+    let mut expected = RECOMMENDED_CATEGORY_SEQUENCE.iter().peekable();
+    for category in categories {
+        if expected.peek() == Some(category) {
+            expected.next();
+        }
+    }
+    expected.peek().is_none()
+}
+
+fn check_branch_categories<'a, B, P>(
+    branch: &'a B,
+    path: &str,
+    ancestors: &mut Vec<&'a CategoryOfTheBranch>,
+    errors: &mut Option<Vec<ValidationError>>,
+) where
+    B: BranchTrait<P>,
+    P: ProductTrait,
+{
+    ancestors.push(branch.get_category());
+    if branch.get_product().is_some() && !follows_recommended_sequence(ancestors) {
+        errors
+            .get_or_insert_with(Vec::new)
+            .push(create_branch_categories_error(path));
+    }
+    if let Some(branches) = branch.get_branches() {
+        for (i, child) in branches.iter().enumerate() {
+            check_branch_categories(child, &format!("{path}/branches/{i}"), ancestors, errors);
+        }
+    }
+    ancestors.pop();
+}
+
+/// 6.3.9 Branch Categories
+///
+/// For each product defined in the branches of the product tree, tests that the path from the root
+/// of the product tree to the product uses the branch categories `vendor`, `product_name` and
+/// `product_version` in this order. Other branch categories may be used in between.
+pub fn test_6_3_9_branch_categories(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    if let Some(product_tree) = doc.get_product_tree().as_ref()
+        && let Some(branches) = product_tree.get_branches()
+    {
+        for (i, branch) in branches.iter().enumerate() {
+            check_branch_categories(
+                branch,
+                &format!("/product_tree/branches/{i}"),
+                &mut Vec::new(),
+                &mut errors,
+            );
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_0::testcases::ValidatorForTest6_3_9
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_3_9_branch_categories(doc)
+    }
+}
+
+impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
+    for crate::csaf2_1::testcases::ValidatorForTest6_3_9
+{
+    fn validate(
+        &self,
+        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+    ) -> Result<(), Vec<ValidationError>> {
+        test_6_3_9_branch_categories(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_0::testcases::TESTS_2_0;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_3_9() {
+        let case_three_levels = Err(vec![create_branch_categories_error(
+            "/product_tree/branches/0/branches/0/branches/0",
+        )]);
+        let case_two_levels = Err(vec![create_branch_categories_error(
+            "/product_tree/branches/0/branches/0",
+        )]);
+
+        // Both CSAF 2.0 and 2.1 have 11 test cases (6 error cases, 5 success cases)
+        TESTS_2_0.test_6_3_9.expect(
+            case_three_levels.clone(),
+            case_three_levels.clone(),
+            case_three_levels.clone(),
+            case_two_levels.clone(),
+            case_two_levels.clone(),
+            case_three_levels.clone(),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+        );
+        TESTS_2_1.test_6_3_9.expect(
+            case_three_levels.clone(),
+            case_three_levels.clone(),
+            case_three_levels.clone(),
+            case_two_levels.clone(),
+            case_two_levels,
+            case_three_levels,
+            Ok(()),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+            Ok(()),
+        );
+    }
+
+    #[test]
+    fn test_follows_recommended_sequence() {
+        assert!(follows_recommended_sequence(&[
+            &CategoryOfTheBranch::Vendor,
+            &CategoryOfTheBranch::ProductName,
+            &CategoryOfTheBranch::ProductVersion,
+            &CategoryOfTheBranch::Architecture,
+        ]));
+        assert!(!follows_recommended_sequence(&[
+            &CategoryOfTheBranch::ProductName,
+            &CategoryOfTheBranch::Vendor,
+            &CategoryOfTheBranch::ProductVersion,
+        ]));
+    }
+}
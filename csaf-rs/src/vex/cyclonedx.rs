@@ -0,0 +1,762 @@
+//! CycloneDX VEX documents, see <https://cyclonedx.org/capabilities/vex/>.
+//!
+//! The products are exported as components, with their CSAF product ID as `bom-ref`. Each statement
+//! becomes a vulnerability with an analysis affecting its products. CycloneDX has no justification
+//! for absent components and for code that cannot be controlled by an adversary, the flags
+//! `component_not_present` and `vulnerable_code_cannot_be_controlled_by_adversary` are approximated
+//! by `code_not_present` and `requires_environment`.
+//!
+//! The [import] identifies the affected components by their `bom-ref`. The tracking ID is taken from
+//! the serial number and the publisher from the supplier or the first author. Justifications and
+//! states without CSAF equivalent are approximated, the state `false_positive` by `not_affected`.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::CsafDocument;
+use crate::csaf::document::with_parsed;
+use crate::csaf_traits::CsafTrait;
+use crate::schema::csaf2_1::schema::{CategoryOfTheRemediation, LabelOfTheFlag};
+use crate::vex::import::{ImportOptions, VexImport, add_product, build, date_time, system_name};
+use crate::vex::{Action, Statement, Status, Vex, VexError, VexExport, Vulnerability, collect};
+
+/// The CycloneDX version of exported documents
+pub const CYCLONEDX_SPEC_VERSION: &str = "1.6";
+
+/// A CycloneDX BOM containing VEX information
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycloneDxVex {
+    pub bom_format: String,
+    pub spec_version: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub serial_number: Option<String>,
+    pub version: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<CycloneDxMetadata>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<CycloneDxComponent>,
+    #[serde(default)]
+    pub vulnerabilities: Vec<CycloneDxVulnerability>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CycloneDxMetadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub authors: Vec<CycloneDxContact>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub supplier: Option<CycloneDxContact>,
+}
+
+/// An author or the supplier of the BOM, of which only the name is used
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CycloneDxContact {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CycloneDxComponent {
+    #[serde(rename = "type")]
+    pub component_type: String,
+    #[serde(rename = "bom-ref", default, skip_serializing_if = "Option::is_none")]
+    pub bom_ref: Option<String>,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub purl: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpe: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CycloneDxVulnerability {
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<CycloneDxReference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recommendation: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workaround: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub analysis: Option<CycloneDxAnalysis>,
+    #[serde(default)]
+    pub affects: Vec<CycloneDxAffects>,
+}
+
+/// Another ID of a vulnerability
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CycloneDxReference {
+    pub id: String,
+    pub source: CycloneDxSource,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CycloneDxSource {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CycloneDxAnalysis {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<CycloneDxState>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub justification: Option<CycloneDxJustification>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub response: Vec<CycloneDxResponse>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+/// A component affected by the vulnerability, referenced by its `bom-ref`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CycloneDxAffects {
+    #[serde(rename = "ref")]
+    pub reference: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CycloneDxState {
+    Resolved,
+    ResolvedWithPedigree,
+    Exploitable,
+    InTriage,
+    FalsePositive,
+    NotAffected,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CycloneDxJustification {
+    CodeNotPresent,
+    CodeNotReachable,
+    RequiresConfiguration,
+    RequiresDependency,
+    RequiresEnvironment,
+    ProtectedByCompiler,
+    ProtectedAtRuntime,
+    ProtectedAtPerimeter,
+    ProtectedByMitigatingControl,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CycloneDxResponse {
+    CanNotFix,
+    WillNotFix,
+    Update,
+    Rollback,
+    WorkaroundAvailable,
+}
+
+impl From<Status> for CycloneDxState {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Affected => CycloneDxState::Exploitable,
+            Status::NotAffected => CycloneDxState::NotAffected,
+            Status::Fixed => CycloneDxState::Resolved,
+            Status::UnderInvestigation => CycloneDxState::InTriage,
+        }
+    }
+}
+
+/// Returns the name of a value in CycloneDX documents.
+fn name(value: impl Serialize) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(name)) => name,
+        _ => String::new(),
+    }
+}
+
+/// Returns the status of a state and whether it is an approximation.
+fn status(state: CycloneDxState) -> (Status, bool) {
+    match state {
+        CycloneDxState::Exploitable => (Status::Affected, false),
+        CycloneDxState::NotAffected => (Status::NotAffected, false),
+        CycloneDxState::FalsePositive => (Status::NotAffected, true),
+        CycloneDxState::Resolved | CycloneDxState::ResolvedWithPedigree => (Status::Fixed, false),
+        CycloneDxState::InTriage => (Status::UnderInvestigation, false),
+    }
+}
+
+/// Returns the flag of a justification and whether it is an approximation.
+fn label(justification: CycloneDxJustification) -> (LabelOfTheFlag, bool) {
+    match justification {
+        CycloneDxJustification::CodeNotPresent => (LabelOfTheFlag::VulnerableCodeNotPresent, false),
+        CycloneDxJustification::CodeNotReachable => (LabelOfTheFlag::VulnerableCodeNotInExecutePath, false),
+        CycloneDxJustification::RequiresDependency => (LabelOfTheFlag::ComponentNotPresent, true),
+        CycloneDxJustification::RequiresConfiguration | CycloneDxJustification::RequiresEnvironment => {
+            (LabelOfTheFlag::VulnerableCodeCannotBeControlledByAdversary, true)
+        },
+        CycloneDxJustification::ProtectedByMitigatingControl => (LabelOfTheFlag::InlineMitigationsAlreadyExist, false),
+        CycloneDxJustification::ProtectedByCompiler
+        | CycloneDxJustification::ProtectedAtRuntime
+        | CycloneDxJustification::ProtectedAtPerimeter => (LabelOfTheFlag::InlineMitigationsAlreadyExist, true),
+    }
+}
+
+/// Returns the justification of a flag and whether it is an approximation.
+fn justification(label: LabelOfTheFlag) -> (CycloneDxJustification, bool) {
+    match label {
+        LabelOfTheFlag::ComponentNotPresent => (CycloneDxJustification::CodeNotPresent, true),
+        LabelOfTheFlag::VulnerableCodeNotPresent => (CycloneDxJustification::CodeNotPresent, false),
+        LabelOfTheFlag::VulnerableCodeNotInExecutePath => (CycloneDxJustification::CodeNotReachable, false),
+        LabelOfTheFlag::VulnerableCodeCannotBeControlledByAdversary => {
+            (CycloneDxJustification::RequiresEnvironment, true)
+        },
+        LabelOfTheFlag::InlineMitigationsAlreadyExist => (CycloneDxJustification::ProtectedByMitigatingControl, false),
+    }
+}
+
+/// Exports a CSAF VEX document as CycloneDX VEX document, see the [module documentation](self).
+///
+/// Fails if the document is no CSAF VEX document or does not match the types of its CSAF version.
+pub fn export(document: &CsafDocument) -> Result<VexExport<CycloneDxVex>, VexError> {
+    with_parsed!(document, doc => export_parsed(doc)).map_err(|err| VexError::InvalidDocument(err.to_string()))?
+}
+
+/// Exports a parsed CSAF VEX document as CycloneDX VEX document, see the [module documentation](self).
+pub fn export_parsed(doc: &impl CsafTrait) -> Result<VexExport<CycloneDxVex>, VexError> {
+    let mut vex = collect(doc)?;
+    let statements = std::mem::take(&mut vex.statements);
+
+    let mut product_ids = BTreeSet::new();
+    let mut components = Vec::new();
+    for product_id in statements.iter().flat_map(|statement| &statement.product_ids) {
+        if product_ids.insert(product_id) {
+            components.push(component(&mut vex, product_id));
+        }
+    }
+    let vulnerabilities = statements
+        .iter()
+        .map(|statement| vulnerability(&mut vex, statement))
+        .collect();
+
+    let document = CycloneDxVex {
+        bom_format: "CycloneDX".to_string(),
+        spec_version: CYCLONEDX_SPEC_VERSION.to_string(),
+        serial_number: None,
+        version: vex.revisions as u64,
+        metadata: Some(CycloneDxMetadata {
+            timestamp: Some(vex.current_release_date.clone()),
+            authors: vec![],
+            supplier: None,
+        }),
+        components,
+        vulnerabilities,
+    };
+    Ok(VexExport {
+        document,
+        warnings: vex.warnings,
+    })
+}
+
+/// Returns the component of a CSAF product.
+fn component(vex: &mut Vex, product_id: &str) -> CycloneDxComponent {
+    let Some(product) = vex.products.get(product_id).cloned() else {
+        return CycloneDxComponent {
+            component_type: "application".to_string(),
+            bom_ref: Some(product_id.to_string()),
+            name: product_id.to_string(),
+            version: None,
+            purl: None,
+            cpe: None,
+        };
+    };
+    if product.purls.len() > 1 {
+        vex.warn(
+            format!("Only the first purl of product '{product_id}' is exported"),
+            &format!("{}/product_identification_helper/purls", product.path),
+        );
+    }
+    CycloneDxComponent {
+        component_type: "application".to_string(),
+        bom_ref: Some(product_id.to_string()),
+        name: product.name,
+        version: None,
+        purl: product.purls.into_iter().next(),
+        cpe: product.cpe,
+    }
+}
+
+/// Returns the vulnerability with the analysis of a statement.
+fn vulnerability(vex: &mut Vex, statement: &Statement) -> CycloneDxVulnerability {
+    let justification = statement.justification.map(|label| {
+        let (justification, approximated) = justification(label);
+        if approximated {
+            vex.warn(
+                format!("The flag '{label}' has no CycloneDX equivalent and is approximated"),
+                &statement.vulnerability.path,
+            );
+        }
+        justification
+    });
+    let details = |category: CategoryOfTheRemediation| {
+        let details: Vec<&str> = statement
+            .actions
+            .iter()
+            .filter(|action| action.category == category)
+            .map(|action| action.details.as_str())
+            .collect();
+        (!details.is_empty()).then(|| details.join("\n\n"))
+    };
+    let recommendation = details(CategoryOfTheRemediation::VendorFix);
+    let workaround = details(CategoryOfTheRemediation::Workaround);
+    let mut response = Vec::new();
+    if recommendation.is_some() {
+        response.push(CycloneDxResponse::Update);
+    }
+    if workaround.is_some() {
+        response.push(CycloneDxResponse::WorkaroundAvailable);
+    }
+
+    CycloneDxVulnerability {
+        id: statement.vulnerability.name.clone(),
+        references: statement
+            .vulnerability
+            .aliases
+            .iter()
+            .map(|(system_name, id)| CycloneDxReference {
+                id: id.clone(),
+                source: CycloneDxSource {
+                    name: Some(system_name.clone()),
+                    url: None,
+                },
+            })
+            .collect(),
+        description: None,
+        recommendation,
+        workaround,
+        analysis: Some(CycloneDxAnalysis {
+            state: Some(statement.status.into()),
+            justification,
+            response,
+            detail: statement.impact_statement.clone(),
+        }),
+        affects: statement
+            .product_ids
+            .iter()
+            .map(|product_id| CycloneDxAffects {
+                reference: product_id.clone(),
+            })
+            .collect(),
+    }
+}
+
+/// Imports a CycloneDX VEX document as CSAF 2.1 document, see the [import module](crate::vex::import).
+pub fn import(doc: &CycloneDxVex, options: &ImportOptions) -> Result<VexImport, VexError> {
+    let metadata = doc.metadata.as_ref();
+    let Some(timestamp) = metadata.and_then(|metadata| metadata.timestamp.as_ref()) else {
+        return Err(VexError::InvalidDocument(
+            "the CycloneDX document has no timestamp in /metadata/timestamp".to_string(),
+        ));
+    };
+    let date = date_time(timestamp, "/metadata/timestamp")?;
+    let author = metadata
+        .and_then(|metadata| {
+            let supplier = metadata.supplier.iter().chain(&metadata.authors);
+            supplier.filter_map(|contact| contact.name.clone()).next()
+        })
+        .unwrap_or_default();
+    let mut vex = Vex {
+        tracking_id: doc
+            .serial_number
+            .as_deref()
+            .map(|serial_number| serial_number.trim_start_matches("urn:uuid:").to_string())
+            .unwrap_or_default(),
+        author,
+        namespace: options.publisher_namespace.clone(),
+        initial_release_date: date.clone(),
+        current_release_date: date,
+        revisions: doc.version as usize,
+        statements: Vec::new(),
+        products: BTreeMap::new(),
+        warnings: Vec::new(),
+    };
+
+    let components: BTreeMap<&str, (usize, &CycloneDxComponent)> = doc
+        .components
+        .iter()
+        .enumerate()
+        .filter_map(|(i, component)| Some((component.bom_ref.as_deref()?, (i, component))))
+        .collect();
+    for (v_i, vulnerability) in doc.vulnerabilities.iter().enumerate() {
+        let path = format!("/vulnerabilities/{v_i}");
+        let analysis = vulnerability.analysis.as_ref();
+        let Some(state) = analysis.and_then(|analysis| analysis.state) else {
+            vex.warn(
+                "The vulnerability has no analysis state and is not imported".to_string(),
+                &path,
+            );
+            continue;
+        };
+        let (status, approximated) = status(state);
+        if approximated {
+            vex.warn(
+                format!("The state '{}' has no CSAF equivalent and is approximated", name(state)),
+                &format!("{path}/analysis/state"),
+            );
+        }
+        let justification = analysis
+            .and_then(|analysis| analysis.justification)
+            .map(|justification| {
+                let (label, approximated) = label(justification);
+                if approximated {
+                    vex.warn(
+                        format!(
+                            "The justification '{}' has no CSAF equivalent and is approximated",
+                            name(justification)
+                        ),
+                        &format!("{path}/analysis/justification"),
+                    );
+                }
+                label
+            });
+        let detail = analysis.and_then(|analysis| analysis.detail.clone());
+        let (impact_statement, status_notes) = match status {
+            Status::NotAffected => (detail, None),
+            _ => (None, detail),
+        };
+
+        let mut actions = Vec::new();
+        if let Some(details) = &vulnerability.recommendation {
+            actions.push(Action {
+                category: CategoryOfTheRemediation::VendorFix,
+                details: details.clone(),
+            });
+        }
+        if let Some(details) = &vulnerability.workaround {
+            actions.push(Action {
+                category: CategoryOfTheRemediation::Workaround,
+                details: details.clone(),
+            });
+        }
+        for (r_i, response) in analysis.iter().flat_map(|analysis| &analysis.response).enumerate() {
+            let details = match response {
+                CycloneDxResponse::CanNotFix => "The vulnerability cannot be fixed.",
+                CycloneDxResponse::WillNotFix => "The vulnerability will not be fixed.",
+                CycloneDxResponse::Rollback => {
+                    vex.warn(
+                        "The response 'rollback' has no CSAF equivalent and is not imported".to_string(),
+                        &format!("{path}/analysis/response/{r_i}"),
+                    );
+                    continue;
+                },
+                // Updates and workarounds are described by the recommendation and the workaround
+                CycloneDxResponse::Update | CycloneDxResponse::WorkaroundAvailable => continue,
+            };
+            actions.push(Action {
+                category: CategoryOfTheRemediation::NoFixPlanned,
+                details: details.to_string(),
+            });
+        }
+
+        let mut product_ids = Vec::new();
+        for (a_i, affects) in vulnerability.affects.iter().enumerate() {
+            let key = match components.get(affects.reference.as_str()) {
+                Some((c_i, component)) => {
+                    let name = match &component.version {
+                        Some(version) => format!("{} {version}", component.name),
+                        None => component.name.clone(),
+                    };
+                    add_product(
+                        &mut vex,
+                        &affects.reference,
+                        component.purl.clone(),
+                        component.cpe.clone(),
+                        Some(name),
+                        &format!("/components/{c_i}"),
+                    )
+                },
+                None => add_product(
+                    &mut vex,
+                    &affects.reference,
+                    None,
+                    None,
+                    None,
+                    &format!("{path}/affects/{a_i}"),
+                ),
+            };
+            if !product_ids.contains(&key) {
+                product_ids.push(key);
+            }
+        }
+        if product_ids.is_empty() {
+            vex.warn(
+                "The vulnerability affects no components and is not imported".to_string(),
+                &path,
+            );
+            continue;
+        }
+
+        vex.statements.push(Statement {
+            vulnerability: Vulnerability {
+                name: vulnerability.id.clone(),
+                path,
+                aliases: vulnerability
+                    .references
+                    .iter()
+                    .map(|reference| {
+                        let system_name = match &reference.source.name {
+                            Some(name) => name.clone(),
+                            None => system_name(&reference.id, "CycloneDX"),
+                        };
+                        (system_name, reference.id.clone())
+                    })
+                    .collect(),
+                description: vulnerability.description.clone(),
+            },
+            product_ids,
+            status,
+            justification,
+            impact_statement,
+            actions,
+            status_notes,
+        });
+    }
+    build(vex, "CycloneDX", options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::csaf2_1::schema::LabelOfTlp;
+    use crate::test_documents::csaf_2_1;
+    use crate::vex::VexWarningKind;
+    use crate::vex::tests::{assert_valid, import_options};
+    use serde_json::json;
+
+    #[test]
+    fn test_export() {
+        let doc = csaf_2_1(json!({
+            "document": { "category": "csaf_vex" },
+            "product_tree": {
+                "full_product_names": [
+                    { "name": "App 1.0", "product_id": "APP-1", "product_identification_helper": { "purls": ["pkg:npm/app@1.0", "pkg:github/example/app@1.0"] } },
+                    { "name": "App 2.0", "product_id": "APP-2" },
+                    { "name": "App 3.0", "product_id": "APP-3" }
+                ]
+            },
+            "vulnerabilities": [{
+                "ids": [{ "system_name": "GHSA", "text": "GHSA-xxxx-xxxx-xxxx" }],
+                "product_status": { "known_affected": ["APP-1"], "known_not_affected": ["APP-2", "APP-3"] },
+                "flags": [{ "label": "component_not_present", "product_ids": ["APP-2", "APP-3"] }],
+                "remediations": [
+                    { "category": "vendor_fix", "details": "Update to 2.0.", "product_ids": ["APP-1"] },
+                    { "category": "workaround", "details": "Disable the feature.", "product_ids": ["APP-1"] }
+                ]
+            }]
+        }));
+        let export = export_parsed(&doc).unwrap();
+        assert_eq!(
+            serde_json::to_value(&export.document).unwrap(),
+            json!({
+                "bomFormat": "CycloneDX",
+                "specVersion": "1.6",
+                "version": 1,
+                "metadata": { "timestamp": "2024-01-24T10:00:00.000Z" },
+                "components": [
+                    { "type": "application", "bom-ref": "APP-1", "name": "App 1.0", "purl": "pkg:npm/app@1.0" },
+                    { "type": "application", "bom-ref": "APP-2", "name": "App 2.0" },
+                    { "type": "application", "bom-ref": "APP-3", "name": "App 3.0" }
+                ],
+                "vulnerabilities": [
+                    {
+                        "id": "GHSA-xxxx-xxxx-xxxx",
+                        "recommendation": "Update to 2.0.",
+                        "workaround": "Disable the feature.",
+                        "analysis": { "state": "exploitable", "response": ["update", "workaround_available"] },
+                        "affects": [{ "ref": "APP-1" }]
+                    },
+                    {
+                        "id": "GHSA-xxxx-xxxx-xxxx",
+                        "analysis": { "state": "not_affected", "justification": "code_not_present" },
+                        "affects": [{ "ref": "APP-2" }, { "ref": "APP-3" }]
+                    }
+                ]
+            })
+        );
+        let warning_paths: Vec<&str> = export
+            .warnings
+            .iter()
+            .map(|warning| warning.instance_path.as_str())
+            .collect();
+        assert_eq!(
+            warning_paths,
+            vec![
+                "/product_tree/full_product_names/0/product_identification_helper/purls",
+                "/vulnerabilities/0",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_import() {
+        let doc: CycloneDxVex = serde_json::from_value(json!({
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.6",
+            "serialNumber": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79",
+            "version": 3,
+            "metadata": {
+                "timestamp": "2024-03-01T12:00:00Z",
+                "supplier": { "name": "Example Supplier" }
+            },
+            "components": [
+                { "type": "library", "bom-ref": "lib", "name": "lib", "version": "1.2.0", "purl": "pkg:maven/org.example/lib@1.2.0" },
+                { "type": "application", "bom-ref": "app", "name": "app", "version": "2.0" }
+            ],
+            "vulnerabilities": [
+                {
+                    "id": "CVE-2024-1234",
+                    "references": [{ "id": "GHSA-aaaa-bbbb-cccc", "source": { "name": "GitHub" } }],
+                    "description": "Deserialization of untrusted data.",
+                    "recommendation": "Upgrade to 1.2.1.",
+                    "analysis": { "state": "exploitable", "response": ["update", "rollback"], "detail": "Reachable via the API." },
+                    "affects": [{ "ref": "lib" }]
+                },
+                {
+                    "id": "CVE-2024-1234",
+                    "analysis": { "state": "not_affected", "justification": "requires_environment", "detail": "Only on Windows." },
+                    "affects": [{ "ref": "app" }]
+                },
+                {
+                    "id": "CVE-2024-5678",
+                    "analysis": { "state": "false_positive" },
+                    "affects": [{ "ref": "lib" }, { "ref": "urn:cdx:unknown" }]
+                },
+                {
+                    "id": "CVE-2024-9999",
+                    "affects": [{ "ref": "lib" }]
+                }
+            ]
+        }))
+        .unwrap();
+        let options = ImportOptions {
+            tlp_label: LabelOfTlp::Green,
+            ..import_options()
+        };
+        let import = import(&doc, &options).unwrap();
+        assert_valid(&import.document);
+
+        let json = serde_json::to_value(&import.document).unwrap();
+        assert_eq!(json["document"]["publisher"]["name"], "Example Supplier");
+        assert_eq!(
+            json["document"]["tracking"]["id"],
+            "3e671687-395b-41f5-a30f-a58921a69b79"
+        );
+        assert_eq!(json["document"]["distribution"]["tlp"]["label"], "GREEN");
+        assert_eq!(
+            json["product_tree"]["full_product_names"],
+            json!([
+                { "name": "app 2.0", "product_id": "CSAFPID-0001" },
+                {
+                    "name": "lib 1.2.0",
+                    "product_id": "CSAFPID-0002",
+                    "product_identification_helper": { "purls": ["pkg:maven/org.example/lib@1.2.0"] }
+                },
+                { "name": "urn:cdx:unknown", "product_id": "CSAFPID-0003" }
+            ])
+        );
+        let vulnerabilities = json["vulnerabilities"].as_array().unwrap();
+        assert_eq!(vulnerabilities.len(), 2);
+        assert_eq!(
+            vulnerabilities[0]["ids"],
+            json!([{ "system_name": "GitHub", "text": "GHSA-aaaa-bbbb-cccc" }])
+        );
+        assert_eq!(
+            vulnerabilities[0]["product_status"],
+            json!({ "known_affected": ["CSAFPID-0002"], "known_not_affected": ["CSAFPID-0001"] })
+        );
+        assert_eq!(
+            vulnerabilities[0]["flags"],
+            json!([{ "label": "vulnerable_code_cannot_be_controlled_by_adversary", "product_ids": ["CSAFPID-0001"] }])
+        );
+        assert_eq!(
+            vulnerabilities[0]["threats"],
+            json!([{ "category": "impact", "details": "Only on Windows.", "product_ids": ["CSAFPID-0001"] }])
+        );
+        assert_eq!(
+            vulnerabilities[0]["remediations"],
+            json!([{ "category": "vendor_fix", "details": "Upgrade to 1.2.1.", "product_ids": ["CSAFPID-0002"] }])
+        );
+        assert_eq!(vulnerabilities[0]["notes"][1]["text"], "Reachable via the API.");
+        assert_eq!(
+            vulnerabilities[1]["product_status"],
+            json!({ "known_not_affected": ["CSAFPID-0002", "CSAFPID-0003"] })
+        );
+
+        let warnings: Vec<(VexWarningKind, &str)> = import
+            .warnings
+            .iter()
+            .map(|warning| (warning.kind, warning.instance_path.as_str()))
+            .collect();
+        assert_eq!(
+            warnings,
+            vec![
+                (VexWarningKind::Lossy, "/vulnerabilities/0/analysis/response/1"),
+                (VexWarningKind::Lossy, "/vulnerabilities/1/analysis/justification"),
+                (VexWarningKind::Lossy, "/vulnerabilities/2/analysis/state"),
+                (VexWarningKind::Lossy, "/vulnerabilities/3"),
+                (VexWarningKind::Heuristic, "/vulnerabilities/2"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_import_exported() {
+        let doc = csaf_2_1(json!({
+            "document": { "category": "csaf_vex" },
+            "product_tree": {
+                "full_product_names": [
+                    { "name": "App 1.0", "product_id": "APP-1", "product_identification_helper": { "purls": ["pkg:npm/app@1.0"] } },
+                    { "name": "App 2.0", "product_id": "APP-2", "product_identification_helper": { "purls": ["pkg:npm/app@2.0"] } }
+                ]
+            },
+            "vulnerabilities": [{
+                "cve": "CVE-2024-0001",
+                "product_status": { "known_affected": ["APP-1"], "fixed": ["APP-2"] },
+                "remediations": [{ "category": "vendor_fix", "details": "Update to 2.0.", "product_ids": ["APP-1"] }]
+            }]
+        }));
+        let export = export_parsed(&doc).unwrap();
+        // The export has neither a serial number nor an author
+        assert_eq!(
+            import(&export.document, &import_options()),
+            Err(VexError::MissingInformation("author"))
+        );
+        let options = ImportOptions {
+            publisher_name: Some("Example Company".to_string()),
+            tracking_id: Some("UNIT-TEST".to_string()),
+            ..import_options()
+        };
+        let import = import(&export.document, &options).unwrap();
+        assert_valid(&import.document);
+        assert_eq!(import.warnings, vec![]);
+
+        // The product IDs are generated on import, so only the references differ
+        let reexport = super::export_parsed(&import.document).unwrap();
+        let without_references = |vex: &CycloneDxVex| {
+            vex.vulnerabilities
+                .iter()
+                .map(|vulnerability| CycloneDxVulnerability {
+                    affects: vec![],
+                    ..vulnerability.clone()
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            without_references(&reexport.document),
+            without_references(&export.document)
+        );
+    }
+}
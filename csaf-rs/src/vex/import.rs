@@ -0,0 +1,516 @@
+//! Import of OpenVEX and CycloneDX VEX documents as CSAF 2.1 documents with category `csaf_vex`.
+//!
+//! The statements are mapped the other way round as in the [export](super), with these additions:
+//!
+//! - Products are identified by their purl, their CPE or, if they have neither, by their identifier
+//!   in the VEX document. They get the product IDs `CSAFPID-0001`, `CSAFPID-0002` etc. in the order
+//!   of these identifiers, so importing the same statements always yields the same IDs.
+//! - A vulnerability is named by its CVE if it or one of its aliases is one, all other names become
+//!   IDs of the vulnerability.
+//! - The action statement of OpenVEX becomes a remediation with category `workaround`.
+//! - Status notes become notes of the vulnerability for the products of the statement.
+//! - If a product has several statements for a vulnerability, the last one is imported.
+//!
+//! Neither format carries all information CSAF requires, the namespace of the publisher and the TLP
+//! label are given by the [ImportOptions]. Required statements the VEX document lacks, like the
+//! justification of a product that is not affected, are filled in and reported as heuristic
+//! [VexWarning].
+//!
+//! ```no_run
+//! # use csaf::schema::csaf2_1::schema::{CategoryOfPublisher, LabelOfTlp};
+//! # use csaf::vex::import::ImportOptions;
+//! # use csaf::vex::openvex::{self, OpenVexDocument};
+//! let vex: OpenVexDocument = serde_json::from_str(&std::fs::read_to_string("vex.openvex.json")?)?;
+//! let options = ImportOptions {
+//!     publisher_name: None,
+//!     publisher_namespace: "https://example.com".to_string(),
+//!     publisher_category: CategoryOfPublisher::Vendor,
+//!     tracking_id: None,
+//!     tlp_label: LabelOfTlp::Clear,
+//! };
+//! let import = openvex::import(&vex, &options)?;
+//! println!("{}", serde_json::to_string_pretty(&import.document)?);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+use std::str::FromStr;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use packageurl::PackageUrl;
+
+use crate::schema::csaf2_1::schema::{
+    CategoryOfPublisher, CategoryOfTheRemediation, CategoryOfTheThreat, CommonPlatformEnumerationRepresentation,
+    CommonSecurityAdvisoryFramework, Cve, DocumentLevelMetaData, Flag, FullProductNameT, HelperToIdentifyTheProduct,
+    Id, JsonSchema, LabelOfTlp, Note, NoteCategory, NotesT, ProductStatus, ProductTree, ProductsT, Publisher,
+    Remediation, Revision, RulesForDocumentSharing, Threat, Tracking, TrafficLightProtocolTlp,
+    Vulnerability as CsafVulnerability,
+};
+use crate::vex::{Action, Product, Statement, Status, Vex, VexError, VexWarning, Vulnerability};
+
+/// Information of the imported document that the VEX document does not contain
+#[derive(Debug, Clone)]
+pub struct ImportOptions {
+    /// Name of the publisher, defaults to the author of the VEX document
+    pub publisher_name: Option<String>,
+    pub publisher_namespace: String,
+    pub publisher_category: CategoryOfPublisher,
+    /// Tracking ID of the document, defaults to the last segment of the OpenVEX `@id` or the serial
+    /// number of the CycloneDX BOM
+    pub tracking_id: Option<String>,
+    pub tlp_label: LabelOfTlp,
+}
+
+/// Result of an import, the CSAF document and the information lost or guessed on the way
+#[derive(Debug, Clone, PartialEq)]
+pub struct VexImport {
+    pub document: CommonSecurityAdvisoryFramework,
+    pub warnings: Vec<VexWarning>,
+}
+
+fn invalid(err: impl Display) -> VexError {
+    VexError::InvalidDocument(err.to_string())
+}
+
+/// Parses a timestamp of a VEX document, returning it in UTC as CSAF does.
+pub(super) fn date_time(timestamp: &str, path: &str) -> Result<String, VexError> {
+    let date_time = DateTime::parse_from_rfc3339(timestamp)
+        .map_err(|err| VexError::InvalidDocument(format!("invalid timestamp '{timestamp}' at {path}: {err}")))?;
+    Ok(date_time
+        .with_timezone(&Utc)
+        .to_rfc3339_opts(SecondsFormat::Millis, true))
+}
+
+/// Returns the system of a vulnerability ID, which is the prefix of IDs like `GHSA-xxxx-xxxx-xxxx`.
+pub(super) fn system_name(id: &str, format: &str) -> String {
+    match id.split_once('-') {
+        Some((prefix, _)) if !prefix.is_empty() && !prefix.contains(':') => prefix.to_string(),
+        _ => format.to_string(),
+    }
+}
+
+/// Returns the name of the package of a purl, like `org.example/app 1.0`.
+fn purl_name(purl: &PackageUrl) -> String {
+    let name = match purl.namespace() {
+        Some(namespace) => format!("{namespace}/{}", purl.name()),
+        None => purl.name().to_string(),
+    };
+    match purl.version() {
+        Some(version) => format!("{name} {version}"),
+        None => name,
+    }
+}
+
+/// Adds a product of the VEX document and returns the identifier it is known by in [Vex::products].
+///
+/// Invalid purls and CPEs are dropped with a warning, as they would make the CSAF document invalid.
+pub(super) fn add_product(
+    vex: &mut Vex,
+    id: &str,
+    purl: Option<String>,
+    cpe: Option<String>,
+    name: Option<String>,
+    path: &str,
+) -> String {
+    let purl = purl.and_then(|purl| match PackageUrl::from_str(&purl) {
+        Ok(parsed) => Some((purl_name(&parsed), purl)),
+        Err(err) => {
+            vex.warn(format!("The purl '{purl}' is invalid and not imported: {err}"), path);
+            None
+        },
+    });
+    let cpe = cpe.filter(|cpe| {
+        let valid = CommonPlatformEnumerationRepresentation::from_str(cpe).is_ok();
+        if !valid {
+            vex.warn(format!("The CPE '{cpe}' is invalid and not imported"), path);
+        }
+        valid
+    });
+    let key = match (&purl, &cpe) {
+        (Some((_, purl)), _) => purl.clone(),
+        (None, Some(cpe)) => cpe.clone(),
+        (None, None) => id.to_string(),
+    };
+    if !vex.products.contains_key(&key) {
+        let name = name
+            .or_else(|| purl.as_ref().map(|(name, _)| name.clone()))
+            .unwrap_or_else(|| key.clone());
+        let product = Product {
+            name,
+            path: path.to_string(),
+            purls: purl.into_iter().map(|(_, purl)| purl).collect(),
+            cpe,
+        };
+        vex.products.insert(key.clone(), product);
+    }
+    key
+}
+
+/// Builds the CSAF document of the statements imported from a VEX document in the given format.
+pub(super) fn build(mut vex: Vex, format: &str, options: &ImportOptions) -> Result<VexImport, VexError> {
+    let publisher_name = match &options.publisher_name {
+        Some(name) => name.clone(),
+        None if !vex.author.is_empty() => vex.author.clone(),
+        None => return Err(VexError::MissingInformation("author")),
+    };
+    let tracking_id = match &options.tracking_id {
+        Some(id) => id.clone(),
+        None if !vex.tracking_id.is_empty() => vex.tracking_id.clone(),
+        None => return Err(VexError::MissingInformation("document ID")),
+    };
+    let merged_vulnerabilities = merge_vulnerabilities(&vex.statements, format);
+    let statements = latest_statements(&mut vex);
+
+    // Only products with statements are imported, as unused products are reported by test 6.2.1
+    let used_products: BTreeSet<&String> = statements.iter().flat_map(|statement| &statement.product_ids).collect();
+    let product_ids: BTreeMap<String, String> = used_products
+        .into_iter()
+        .enumerate()
+        .map(|(i, key)| (key.clone(), format!("CSAFPID-{:04}", i + 1)))
+        .collect();
+    let full_product_names = product_ids
+        .iter()
+        .map(|(key, product_id)| full_product_name(&vex.products[key], product_id))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut vulnerabilities = Vec::new();
+    for merged in &merged_vulnerabilities {
+        let statements: Vec<&Statement> = statements
+            .iter()
+            .filter(|statement| vulnerability_key(&statement.vulnerability) == vulnerability_key(merged))
+            .collect();
+        vulnerabilities.push(vulnerability(&mut vex, format, merged, &statements, &product_ids)?);
+    }
+
+    let document = CommonSecurityAdvisoryFramework::builder()
+        .document(document(&vex, format, options, publisher_name, tracking_id)?)
+        .schema(JsonSchema::HttpsDocsOasisOpenOrgCsafCsafV21SchemaCsafJson)
+        .product_tree(Some(
+            ProductTree::builder()
+                .full_product_names(full_product_names)
+                .try_into()
+                .map_err(invalid)?,
+        ))
+        .vulnerabilities(vulnerabilities)
+        .try_into()
+        .map_err(invalid)?;
+    Ok(VexImport {
+        document,
+        warnings: vex.warnings,
+    })
+}
+
+/// Returns the identifier the statements about the same vulnerability share, its CVE if it has one.
+///
+/// Statements may name a vulnerability differently, e.g. by its CVE or by a GHSA ID with the CVE as alias,
+/// but test 6.1.23 forbids several vulnerabilities with the same CVE.
+fn vulnerability_key(vulnerability: &Vulnerability) -> &str {
+    std::iter::once(&vulnerability.name)
+        .chain(vulnerability.aliases.iter().map(|(_, alias)| alias))
+        .find(|name| Cve::from_str(name).is_ok())
+        .unwrap_or(&vulnerability.name)
+}
+
+/// Merges the names, aliases and descriptions of all statements about the same vulnerability.
+fn merge_vulnerabilities(statements: &[Statement], format: &str) -> Vec<Vulnerability> {
+    let mut merged: Vec<Vulnerability> = Vec::new();
+    for statement in statements {
+        let vulnerability = &statement.vulnerability;
+        let key = vulnerability_key(vulnerability);
+        let Some(existing) = merged.iter_mut().find(|existing| vulnerability_key(existing) == key) else {
+            merged.push(vulnerability.clone());
+            continue;
+        };
+        let name = (system_name(&vulnerability.name, format), vulnerability.name.clone());
+        for alias in std::iter::once(&name).chain(&vulnerability.aliases) {
+            if alias.1 != existing.name && !existing.aliases.iter().any(|(_, id)| id == &alias.1) {
+                existing.aliases.push(alias.clone());
+            }
+        }
+        if existing.description.is_none() {
+            existing.description = vulnerability.description.clone();
+        }
+    }
+    merged
+}
+
+/// Takes the statements of the VEX document, keeping only the last statement for each product and
+/// vulnerability.
+fn latest_statements(vex: &mut Vex) -> Vec<Statement> {
+    let mut statements = std::mem::take(&mut vex.statements);
+    let mut seen = BTreeSet::new();
+    for statement in statements.iter_mut().rev() {
+        let name = vulnerability_key(&statement.vulnerability).to_string();
+        statement.product_ids.retain(|key| {
+            let latest = seen.insert((name.clone(), key.clone()));
+            if !latest {
+                vex.warn(
+                    format!(
+                        "The statement about '{key}' for '{name}' is superseded by a later statement and not imported"
+                    ),
+                    &statement.vulnerability.path,
+                );
+            }
+            latest
+        });
+    }
+    statements.retain(|statement| !statement.product_ids.is_empty());
+    statements
+}
+
+fn document(
+    vex: &Vex,
+    format: &str,
+    options: &ImportOptions,
+    publisher_name: String,
+    tracking_id: String,
+) -> Result<DocumentLevelMetaData, VexError> {
+    let imported = format!("Imported from {format} document version {}.", vex.revisions);
+    let mut revision_history = vec![(vex.initial_release_date.clone(), imported.clone())];
+    if vex.current_release_date != vex.initial_release_date {
+        revision_history[0].1 = format!("Initial release of the {format} document.");
+        revision_history.push((vex.current_release_date.clone(), imported));
+    }
+    let version = revision_history.len().to_string();
+    let revision_history = revision_history
+        .into_iter()
+        .enumerate()
+        .map(|(i, (date, summary))| {
+            Revision::builder()
+                .number((i + 1).to_string())
+                .date(date)
+                .summary(summary)
+                .try_into()
+        })
+        .collect::<Result<Vec<Revision>, _>>()
+        .map_err(invalid)?;
+
+    DocumentLevelMetaData::builder()
+        .title(format!("VEX statements of {publisher_name}"))
+        .category("csaf_vex")
+        .csaf_version("2.1")
+        .distribution(
+            RulesForDocumentSharing::builder().tlp(TrafficLightProtocolTlp::builder().label(options.tlp_label)),
+        )
+        .publisher(
+            Publisher::builder()
+                .category(options.publisher_category)
+                .name(publisher_name)
+                .namespace(options.publisher_namespace.clone()),
+        )
+        .tracking(
+            Tracking::builder()
+                .id(tracking_id)
+                .current_release_date(vex.current_release_date.clone())
+                .initial_release_date(vex.initial_release_date.clone())
+                .status("final")
+                .version(version)
+                .revision_history(revision_history),
+        )
+        .try_into()
+        .map_err(invalid)
+}
+
+fn full_product_name(product: &Product, product_id: &str) -> Result<FullProductNameT, VexError> {
+    let helper = if product.purls.is_empty() && product.cpe.is_none() {
+        None
+    } else {
+        let cpe = product
+            .cpe
+            .as_deref()
+            .map(CommonPlatformEnumerationRepresentation::from_str)
+            .transpose()
+            .map_err(invalid)?;
+        let helper: HelperToIdentifyTheProduct = HelperToIdentifyTheProduct::builder()
+            .purls((!product.purls.is_empty()).then(|| product.purls.clone()))
+            .cpe(cpe)
+            .try_into()
+            .map_err(invalid)?;
+        Some(helper)
+    };
+    FullProductNameT::builder()
+        .name(product.name.clone())
+        .product_id(product_id)
+        .product_identification_helper(helper)
+        .try_into()
+        .map_err(invalid)
+}
+
+fn products(keys: &[String], product_ids: &BTreeMap<String, String>) -> Result<ProductsT, VexError> {
+    keys.iter()
+        .map(|key| product_ids[key].parse())
+        .collect::<Result<Vec<_>, _>>()
+        .map(ProductsT)
+        .map_err(invalid)
+}
+
+/// Builds a vulnerability from its statements.
+fn vulnerability(
+    vex: &mut Vex,
+    format: &str,
+    merged: &Vulnerability,
+    statements: &[&Statement],
+    product_ids: &BTreeMap<String, String>,
+) -> Result<CsafVulnerability, VexError> {
+    let mut names = vec![(system_name(&merged.name, format), merged.name.clone())];
+    names.extend(merged.aliases.iter().cloned());
+    let cve_index = names.iter().position(|(_, name)| Cve::from_str(name).is_ok());
+    let cve = cve_index
+        .map(|i| Cve::from_str(&names.remove(i).1))
+        .transpose()
+        .map_err(invalid)?;
+    let ids = names
+        .into_iter()
+        .map(|(system_name, text)| Id::builder().system_name(system_name).text(text).try_into())
+        .collect::<Result<Vec<Id>, _>>()
+        .map_err(invalid)?;
+
+    // Test 6.1.27.5 requires notes for each vulnerability of a VEX document
+    let mut notes = vec![match &merged.description {
+        Some(description) => note(NoteCategory::Description, description.clone(), None)?,
+        None => note(
+            NoteCategory::General,
+            format!("The status of the products is imported from {format}."),
+            None,
+        )?,
+    }];
+
+    let mut status_lists: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    let mut flags = Vec::new();
+    let mut threats = Vec::new();
+    let mut remediations = Vec::new();
+    for statement in statements {
+        let list = match statement.status {
+            Status::Affected => "known_affected",
+            Status::NotAffected => "known_not_affected",
+            Status::Fixed => "fixed",
+            Status::UnderInvestigation => "under_investigation",
+        };
+        status_lists
+            .entry(list)
+            .or_default()
+            .extend(statement.product_ids.iter().cloned());
+        let products = || products(&statement.product_ids, product_ids);
+
+        if let Some(status_notes) = &statement.status_notes {
+            notes.push(note(NoteCategory::Details, status_notes.clone(), Some(products()?))?);
+        }
+        if let Some(label) = statement.justification {
+            flags.push(
+                Flag::builder()
+                    .label(label)
+                    .product_ids(Some(products()?))
+                    .try_into()
+                    .map_err(invalid)?,
+            );
+        }
+        let mut impact_statement = statement.impact_statement.clone();
+        if statement.status == Status::NotAffected && statement.justification.is_none() && impact_statement.is_none() {
+            // Test 6.1.27.9 requires a flag or an impact statement
+            vex.guess(
+                format!(
+                    "The statement for '{}' has neither a justification nor an impact statement, an impact statement is added",
+                    statement.vulnerability.name
+                ),
+                &statement.vulnerability.path,
+            );
+            impact_statement = Some(format!(
+                "The {format} document does not state why the products are not affected."
+            ));
+        }
+        if let Some(details) = impact_statement {
+            threats.push(
+                Threat::builder()
+                    .category(CategoryOfTheThreat::Impact)
+                    .details(details)
+                    .product_ids(Some(products()?))
+                    .try_into()
+                    .map_err(invalid)?,
+            );
+        }
+        let mut actions = statement.actions.clone();
+        if statement.status == Status::Affected && actions.is_empty() {
+            // Test 6.1.27.10 requires a remediation
+            vex.guess(
+                format!(
+                    "The statement for '{}' has no action statement, a remediation with category 'none_available' is added",
+                    statement.vulnerability.name
+                ),
+                &statement.vulnerability.path,
+            );
+            actions.push(Action {
+                category: CategoryOfTheRemediation::NoneAvailable,
+                details: format!("The {format} document does not state an action."),
+            });
+        }
+        for action in actions {
+            remediations.push(
+                Remediation::builder()
+                    .category(action.category)
+                    .details(action.details)
+                    .product_ids(Some(products()?))
+                    .try_into()
+                    .map_err(invalid)?,
+            );
+        }
+    }
+
+    let list = |name: &str| {
+        status_lists
+            .get(name)
+            .map(|keys| products(keys, product_ids))
+            .transpose()
+    };
+    let product_status: ProductStatus = ProductStatus::builder()
+        .known_affected(list("known_affected")?)
+        .known_not_affected(list("known_not_affected")?)
+        .fixed(list("fixed")?)
+        .under_investigation(list("under_investigation")?)
+        .try_into()
+        .map_err(invalid)?;
+
+    CsafVulnerability::builder()
+        .cve(cve)
+        .ids((!ids.is_empty()).then_some(ids))
+        .notes(Some(NotesT(notes)))
+        .product_status(Some(product_status))
+        .flags((!flags.is_empty()).then_some(flags))
+        .threats(threats)
+        .remediations(remediations)
+        .try_into()
+        .map_err(invalid)
+}
+
+fn note(category: NoteCategory, text: String, products: Option<ProductsT>) -> Result<Note, VexError> {
+    Note::builder()
+        .category(category)
+        .text(text)
+        .product_ids(products)
+        .try_into()
+        .map_err(invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_date_time() {
+        assert_eq!(
+            date_time("2023-01-08T18:02:03.647787998-06:00", "/timestamp").unwrap(),
+            "2023-01-09T00:02:03.647Z"
+        );
+        assert!(matches!(
+            date_time("yesterday", "/timestamp"),
+            Err(VexError::InvalidDocument(_))
+        ));
+    }
+
+    #[test]
+    fn test_system_name() {
+        assert_eq!(system_name("GHSA-xxxx-xxxx-xxxx", "OpenVEX"), "GHSA");
+        assert_eq!(system_name("https://example.com/vuln-1", "OpenVEX"), "OpenVEX");
+        assert_eq!(system_name("12345", "CycloneDX"), "CycloneDX");
+    }
+}
@@ -0,0 +1,605 @@
+//! Export of CSAF VEX documents to OpenVEX and CycloneDX VEX, and import the other way round.
+//!
+//! Both formats state the status of products for vulnerabilities, like the product status of a
+//! CSAF document with category `csaf_vex`. The status of each product is taken from the
+//! [ProductStatusResolver], which resolves product groups and relationships, and mapped as follows:
+//!
+//! | CSAF                            | OpenVEX               | CycloneDX                            |
+//! |---------------------------------|-----------------------|--------------------------------------|
+//! | `first_affected` etc.           | `affected`            | `exploitable`                        |
+//! | `known_not_affected`            | `not_affected`        | `not_affected`                       |
+//! | `first_fixed`, `fixed`          | `fixed`               | `resolved`                           |
+//! | `under_investigation`           | `under_investigation` | `in_triage`                          |
+//! | flag                            | `justification`       | `analysis/justification`             |
+//! | threat with category `impact`   | `impact_statement`    | `analysis/detail`                    |
+//! | remediation `vendor_fix`        | `action_statement`    | `recommendation`, `update`           |
+//! | remediation `workaround`        | `action_statement`    | `workaround`, `workaround_available` |
+//!
+//! Flags and impact statements are exported for products that are not affected, action statements
+//! for affected products, as required by tests 6.1.27.9 and 6.1.27.10. Everything that cannot be
+//! expressed in the target format, like the status `unknown`, other remediation categories or
+//! contradicting product statuses, is reported as [VexWarning]. Products with the same statement
+//! for a vulnerability are exported together.
+//!
+//! ```no_run
+//! # use csaf::CsafDocument;
+//! let advisory = CsafDocument::load(std::path::Path::new("vex.json"))?;
+//! let export = csaf::vex::openvex::export(&advisory)?;
+//! for warning in &export.warnings {
+//!     eprintln!("{warning}");
+//! }
+//! println!("{}", serde_json::to_string_pretty(&export.document)?);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! The [import] builds CSAF 2.1 documents from OpenVEX and CycloneDX VEX documents, using the same
+//! mapping.
+
+pub mod cyclonedx;
+pub mod import;
+pub mod openvex;
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use serde::Serialize;
+
+use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
+use crate::csaf_traits::{
+    CsafTrait, DocumentTrait, ProductIdentificationHelperTrait, ProductStatusGroup, ProductTrait, ProductTreeTrait,
+    PublisherTrait, RemediationTrait, ThreatTrait, TrackingTrait, VulnerabilityIdTrait, VulnerabilityTrait,
+};
+use crate::product_status::{EffectiveStatus, Evidence, ProductStatusResolver, ProductVerdict};
+use crate::schema::csaf2_1::schema::{CategoryOfTheRemediation, CategoryOfTheThreat, LabelOfTheFlag};
+
+/// Reasons why a document cannot be exported or imported.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum VexError {
+    #[error("only documents with category 'csaf_vex' can be exported, found '{0}'")]
+    NotVex(CsafDocumentCategory),
+    #[error("invalid document: {0}")]
+    InvalidDocument(String),
+    #[error("the VEX document has no {0}, it has to be given in the import options")]
+    MissingInformation(&'static str),
+}
+
+/// Kind of a warning of the export or import
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum VexWarningKind {
+    /// Information of the source document was dropped.
+    Lossy,
+    /// A value was derived or guessed, as the source document does not contain the required information.
+    Heuristic,
+}
+
+impl Display for VexWarningKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VexWarningKind::Lossy => write!(f, "lossy"),
+            VexWarningKind::Heuristic => write!(f, "heuristic"),
+        }
+    }
+}
+
+/// Information of the source document that is lost or guessed in the export or import
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VexWarning {
+    pub kind: VexWarningKind,
+    pub message: String,
+    /// JSON pointer into the source document
+    pub instance_path: String,
+}
+
+impl Display for VexWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} [{}]: {}", self.kind, self.instance_path, self.message)
+    }
+}
+
+/// Result of an export, the exported document and the information lost on the way
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VexExport<T> {
+    pub document: T,
+    pub warnings: Vec<VexWarning>,
+}
+
+/// Status of a product in both VEX formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Affected,
+    NotAffected,
+    Fixed,
+    UnderInvestigation,
+}
+
+/// A vulnerability, named by its CVE or its first ID
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Vulnerability {
+    name: String,
+    /// JSON path of the vulnerability in the source document
+    path: String,
+    /// The IDs besides the name, with the name of their system
+    aliases: Vec<(String, String)>,
+    description: Option<String>,
+}
+
+/// A remediation exported as action statement
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Action {
+    category: CategoryOfTheRemediation,
+    details: String,
+}
+
+/// What the document states about a set of products for a vulnerability, in terms of VEX
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Statement {
+    vulnerability: Vulnerability,
+    product_ids: Vec<String>,
+    status: Status,
+    justification: Option<LabelOfTheFlag>,
+    impact_statement: Option<String>,
+    actions: Vec<Action>,
+    /// Additional information about the status, only present in imported statements
+    status_notes: Option<String>,
+}
+
+impl Statement {
+    fn action_statement(&self) -> Option<String> {
+        let details: Vec<&str> = self.actions.iter().map(|action| action.details.as_str()).collect();
+        (!details.is_empty()).then(|| details.join("\n\n"))
+    }
+}
+
+/// A product of the product tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Product {
+    name: String,
+    /// JSON path of the product in the source document
+    path: String,
+    purls: Vec<String>,
+    cpe: Option<String>,
+}
+
+/// The contents of a CSAF VEX document shared by both formats
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Vex {
+    tracking_id: String,
+    author: String,
+    namespace: String,
+    initial_release_date: String,
+    current_release_date: String,
+    /// Number of revisions, which is the integer version of the VEX document
+    revisions: usize,
+    statements: Vec<Statement>,
+    products: BTreeMap<String, Product>,
+    warnings: Vec<VexWarning>,
+}
+
+impl Vex {
+    fn warn(&mut self, message: String, instance_path: &str) {
+        self.warnings.push(VexWarning {
+            kind: VexWarningKind::Lossy,
+            message,
+            instance_path: instance_path.to_string(),
+        });
+    }
+
+    fn guess(&mut self, message: String, instance_path: &str) {
+        self.warnings.push(VexWarning {
+            kind: VexWarningKind::Heuristic,
+            message,
+            instance_path: instance_path.to_string(),
+        });
+    }
+}
+
+/// Returns the index of the item an evidence path like `/vulnerabilities/0/threats/2/product_ids/0`
+/// points into.
+fn item_index(path: &str, items: &str) -> Option<usize> {
+    let mut segments = path.split('/');
+    segments.find(|segment| *segment == items)?;
+    segments.next()?.parse().ok()
+}
+
+/// Collects the statements of a CSAF VEX document.
+fn collect(doc: &impl CsafTrait) -> Result<Vex, VexError> {
+    let document = doc.get_document();
+    let category = document.get_category();
+    if category != CsafDocumentCategory::CsafVex {
+        return Err(VexError::NotVex(category));
+    }
+    let tracking = document.get_tracking();
+    let mut vex = Vex {
+        tracking_id: tracking.get_id().to_string(),
+        author: document.get_publisher().get_name().to_string(),
+        namespace: document.get_publisher().get_namespace().to_string(),
+        initial_release_date: tracking.get_initial_release_date().get_raw_string().to_string(),
+        current_release_date: tracking.get_current_release_date().get_raw_string().to_string(),
+        revisions: tracking.get_revision_history().len(),
+        statements: Vec::new(),
+        products: BTreeMap::new(),
+        warnings: Vec::new(),
+    };
+
+    if let Some(product_tree) = doc.get_product_tree() {
+        product_tree.visit_all_products(&mut |product, path| {
+            let helper = product.get_product_identification_helper().as_ref();
+            vex.products.insert(
+                product.get_product_id().to_string(),
+                Product {
+                    name: product.get_name().to_string(),
+                    path: path.to_string(),
+                    purls: helper
+                        .and_then(|helper| helper.get_purls())
+                        .unwrap_or_default()
+                        .to_vec(),
+                    cpe: helper.and_then(|helper| helper.get_cpe()).cloned(),
+                },
+            );
+        });
+    }
+
+    let resolver = ProductStatusResolver::new(doc);
+    for (v_i, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        let mut ids = vulnerability
+            .get_ids()
+            .iter()
+            .flatten()
+            .map(|id| (id.get_system_name().to_string(), id.get_text().to_string()));
+        let name = match vulnerability.get_cve() {
+            Some(cve) => cve.to_string(),
+            None => match ids.next() {
+                Some((_, text)) => text,
+                None => {
+                    vex.warn(
+                        "The vulnerability has neither a CVE nor an ID and is not exported".to_string(),
+                        &format!("/vulnerabilities/{v_i}"),
+                    );
+                    continue;
+                },
+            },
+        };
+        let vulnerability_name = Vulnerability {
+            name,
+            path: format!("/vulnerabilities/{v_i}"),
+            aliases: ids.collect(),
+            description: None,
+        };
+        for verdict in resolver.resolve_vulnerability(v_i) {
+            if let Some(statement) = to_statement(&mut vex, vulnerability, &vulnerability_name, &verdict) {
+                add_statement(&mut vex.statements, statement);
+            }
+        }
+    }
+    Ok(vex)
+}
+
+/// Adds a statement, merging it into an existing one that only differs in the products.
+fn add_statement(statements: &mut Vec<Statement>, statement: Statement) {
+    let same = |existing: &&mut Statement| {
+        existing.vulnerability == statement.vulnerability
+            && existing.status == statement.status
+            && existing.justification == statement.justification
+            && existing.impact_statement == statement.impact_statement
+            && existing.actions == statement.actions
+    };
+    match statements.iter_mut().find(same) {
+        Some(existing) => existing.product_ids.extend(statement.product_ids),
+        None => statements.push(statement),
+    }
+}
+
+/// Maps the verdict about a product to a statement, warning about the statements that get lost.
+fn to_statement(
+    vex: &mut Vex,
+    vulnerability: &impl VulnerabilityTrait,
+    vulnerability_name: &Vulnerability,
+    verdict: &ProductVerdict,
+) -> Option<Statement> {
+    let product_id = &verdict.product_id;
+    let status_path = verdict
+        .product_status
+        .first()
+        .map(|evidence| evidence.path.clone())
+        .unwrap_or_else(|| format!("/vulnerabilities/{}", verdict.vulnerability_index));
+    let status = match &verdict.status {
+        EffectiveStatus::Affected => Status::Affected,
+        EffectiveStatus::NotAffected => Status::NotAffected,
+        EffectiveStatus::Fixed => Status::Fixed,
+        EffectiveStatus::UnderInvestigation => Status::UnderInvestigation,
+        EffectiveStatus::Unknown => {
+            vex.warn(
+                format!(
+                    "The status 'unknown' of product '{product_id}' has no VEX equivalent, the product is not exported"
+                ),
+                &status_path,
+            );
+            return None;
+        },
+        EffectiveStatus::Contradicting(_) => {
+            vex.warn(
+                format!("Product '{product_id}' has contradicting product statuses and is not exported"),
+                &status_path,
+            );
+            return None;
+        },
+        EffectiveStatus::NotListed => return None,
+    };
+    if let Some(evidence) = verdict
+        .product_status
+        .iter()
+        .find(|evidence| evidence.value == ProductStatusGroup::Recommended)
+    {
+        vex.warn(
+            format!("Product '{product_id}' is recommended, which cannot be expressed in VEX"),
+            &evidence.path,
+        );
+    }
+
+    let mut justification = None;
+    for flag in &verdict.flags {
+        if status != Status::NotAffected {
+            vex.warn(
+                format!(
+                    "The flag '{}' of product '{product_id}' is not exported, as the product is not 'known_not_affected'",
+                    flag.value
+                ),
+                &flag.path,
+            );
+        } else if justification.is_none() {
+            justification = Some(flag.value);
+        } else if justification != Some(flag.value) {
+            vex.warn(
+                format!(
+                    "The flag '{}' of product '{product_id}' is not exported, only one justification is supported",
+                    flag.value
+                ),
+                &flag.path,
+            );
+        }
+    }
+
+    let mut impacts: Vec<String> = Vec::new();
+    let threats = vulnerability.get_threats();
+    for threat in verdict
+        .threats
+        .iter()
+        .filter(|threat| threat.value == CategoryOfTheThreat::Impact)
+    {
+        let Some(item) = item_index(&threat.path, "threats").and_then(|i| threats.get(i)) else {
+            continue;
+        };
+        if status != Status::NotAffected {
+            vex.warn(
+                format!(
+                    "The impact of product '{product_id}' is not exported, as the product is not 'known_not_affected'"
+                ),
+                &threat.path,
+            );
+        } else if !impacts.contains(item.get_details()) {
+            impacts.push(item.get_details().to_string());
+        }
+    }
+
+    let mut actions: Vec<Action> = Vec::new();
+    let remediations = vulnerability.get_remediations();
+    for evidence in &verdict.remediations {
+        let Some(remediation) = item_index(&evidence.path, "remediations").and_then(|i| remediations.get(i)) else {
+            continue;
+        };
+        let exported = matches!(
+            evidence.value,
+            CategoryOfTheRemediation::VendorFix | CategoryOfTheRemediation::Workaround
+        );
+        if status != Status::Affected || !exported {
+            warn_remediation(vex, product_id, evidence);
+            continue;
+        }
+        let details = match remediation.get_url() {
+            Some(url) => format!("{} ({url})", remediation.get_details()),
+            None => remediation.get_details().to_string(),
+        };
+        let action = Action {
+            category: evidence.value,
+            details,
+        };
+        if !actions.contains(&action) {
+            actions.push(action);
+        }
+    }
+
+    if status == Status::NotAffected && justification.is_none() && impacts.is_empty() {
+        vex.warn(
+            format!(
+                "Product '{product_id}' is not affected, but has neither a flag nor an impact statement (see 6.1.27.9)"
+            ),
+            &status_path,
+        );
+    }
+    if status == Status::Affected && actions.is_empty() {
+        vex.warn(
+            format!(
+                "Product '{product_id}' is affected, but has no remediation of category 'vendor_fix' or 'workaround' (see 6.1.27.10)"
+            ),
+            &status_path,
+        );
+    }
+
+    Some(Statement {
+        vulnerability: vulnerability_name.clone(),
+        product_ids: vec![product_id.clone()],
+        status,
+        justification,
+        impact_statement: (!impacts.is_empty()).then(|| impacts.join("\n\n")),
+        actions,
+        status_notes: None,
+    })
+}
+
+fn warn_remediation(vex: &mut Vex, product_id: &str, evidence: &Evidence<CategoryOfTheRemediation>) {
+    vex.warn(
+        format!(
+            "The remediation of category '{}' for product '{product_id}' is not exported, only vendor fixes and \
+            workarounds of affected products are exported as action statements",
+            evidence.value
+        ),
+        &evidence.path,
+    );
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::CsafDocument;
+    use crate::csaf::document::DocumentSource;
+    use crate::schema::csaf2_1::schema::{CategoryOfPublisher, CommonSecurityAdvisoryFramework, LabelOfTlp};
+    use crate::test_documents::{csaf_2_1, document, json_2_0};
+    use crate::validation::{TestResultStatus, ValidationPreset};
+    use crate::vex::import::ImportOptions;
+    use serde_json::json;
+
+    pub(crate) fn import_options() -> ImportOptions {
+        ImportOptions {
+            publisher_name: None,
+            publisher_namespace: "https://example.com".to_string(),
+            publisher_category: CategoryOfPublisher::Vendor,
+            tracking_id: None,
+            tlp_label: LabelOfTlp::Clear,
+        }
+    }
+
+    /// Asserts that an imported document passes the full validation, including the JSON schema.
+    pub(crate) fn assert_valid(document: &CommonSecurityAdvisoryFramework) {
+        let json = serde_json::to_string(document).unwrap();
+        let result = CsafDocument::load(DocumentSource::Str(&json))
+            .unwrap()
+            .validate(ValidationPreset::Full);
+        let failures: Vec<_> = result
+            .test_results
+            .iter()
+            .filter(|test_result| matches!(test_result.status, TestResultStatus::Failure { .. }))
+            .collect();
+        assert!(result.success, "{failures:#?}");
+    }
+
+    #[test]
+    fn test_collect() {
+        let doc = csaf_2_1(json!({
+            "document": { "category": "csaf_vex" },
+            "product_tree": {
+                "full_product_names": [
+                    { "name": "App 1.0", "product_id": "APP-1", "product_identification_helper": { "purls": ["pkg:npm/app@1.0"] } },
+                    { "name": "App 2.0", "product_id": "APP-2", "product_identification_helper": { "purls": ["pkg:npm/app@2.0"] } },
+                    { "name": "App 3.0", "product_id": "APP-3" },
+                    { "name": "App 4.0", "product_id": "APP-4" }
+                ]
+            },
+            "vulnerabilities": [
+                {
+                    "cve": "CVE-2024-0001",
+                    "ids": [{ "system_name": "GHSA", "text": "GHSA-xxxx-xxxx-xxxx" }],
+                    "product_status": {
+                        "known_affected": ["APP-1"],
+                        "known_not_affected": ["APP-2", "APP-3"],
+                        "unknown": ["APP-4"]
+                    },
+                    "flags": [{ "label": "vulnerable_code_not_present", "product_ids": ["APP-2", "APP-3"] }],
+                    "remediations": [
+                        { "category": "vendor_fix", "details": "Update to 2.0.", "product_ids": ["APP-1"] },
+                        { "category": "mitigation", "details": "Disable the feature.", "product_ids": ["APP-1"] }
+                    ]
+                },
+                { "notes": [{ "category": "description", "text": "Unnamed." }] }
+            ]
+        }));
+        let vex = collect(&doc).unwrap();
+        assert_eq!(vex.tracking_id, "UNIT-TEST");
+        assert_eq!(vex.author, "Example Company");
+        assert_eq!(vex.products["APP-1"].purls, vec!["pkg:npm/app@1.0"]);
+
+        let vulnerability = Vulnerability {
+            name: "CVE-2024-0001".to_string(),
+            path: "/vulnerabilities/0".to_string(),
+            aliases: vec![("GHSA".to_string(), "GHSA-xxxx-xxxx-xxxx".to_string())],
+            description: None,
+        };
+        assert_eq!(
+            vex.statements,
+            vec![
+                Statement {
+                    vulnerability: vulnerability.clone(),
+                    product_ids: vec!["APP-1".to_string()],
+                    status: Status::Affected,
+                    justification: None,
+                    impact_statement: None,
+                    actions: vec![Action {
+                        category: CategoryOfTheRemediation::VendorFix,
+                        details: "Update to 2.0.".to_string(),
+                    }],
+                    status_notes: None,
+                },
+                Statement {
+                    vulnerability,
+                    product_ids: vec!["APP-2".to_string(), "APP-3".to_string()],
+                    status: Status::NotAffected,
+                    justification: Some(LabelOfTheFlag::VulnerableCodeNotPresent),
+                    impact_statement: None,
+                    actions: vec![],
+                    status_notes: None,
+                },
+            ]
+        );
+
+        let warning_paths: Vec<&str> = vex
+            .warnings
+            .iter()
+            .map(|warning| warning.instance_path.as_str())
+            .collect();
+        assert_eq!(
+            warning_paths,
+            vec![
+                "/vulnerabilities/0/remediations/1/product_ids/0",
+                "/vulnerabilities/0/product_status/unknown/0",
+                "/vulnerabilities/1",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_collect_requires_vex() {
+        let doc = csaf_2_1(json!({}));
+        assert_eq!(collect(&doc), Err(VexError::NotVex(CsafDocumentCategory::CsafBase)));
+    }
+
+    #[test]
+    fn test_item_index() {
+        assert_eq!(
+            item_index("/vulnerabilities/0/threats/2/group_ids/0", "threats"),
+            Some(2)
+        );
+        assert_eq!(item_index("/vulnerabilities/0/flags/1/product_ids/0", "threats"), None);
+    }
+
+    #[test]
+    fn test_export_document() {
+        let vex = |category: &str| {
+            document(&json_2_0(json!({
+                "document": { "category": category },
+                "product_tree": { "full_product_names": [{ "name": "Product A", "product_id": "CSAFPID-0001" }] },
+                "vulnerabilities": [
+                    { "cve": "CVE-2024-0001", "product_status": { "known_affected": ["CSAFPID-0001", "CSAFPID-0002"] } }
+                ]
+            })))
+        };
+        let openvex = openvex::export(&vex("csaf_vex")).unwrap();
+        assert_eq!(openvex.document.statements.len(), 1);
+        // Both affected products lack an action statement and CSAFPID-0001 has neither a purl nor a CPE
+        assert_eq!(openvex.warnings.len(), 3);
+        let cyclonedx = cyclonedx::export(&vex("csaf_vex")).unwrap();
+        assert_eq!(cyclonedx.document.components.len(), 2);
+
+        assert!(matches!(openvex::export(&vex("csaf_base")), Err(VexError::NotVex(_))));
+    }
+}
@@ -0,0 +1,592 @@
+//! OpenVEX documents, see <https://github.com/openvex/spec>.
+//!
+//! Products are identified by their first purl, their CPE or, if they have neither, by their CSAF
+//! product ID. The exported document is identified by the namespace of the publisher and the
+//! tracking ID, its version is the number of revisions.
+//!
+//! The [import] takes the tracking ID from the last segment of the `@id` and the publisher from the
+//! author. Subcomponents of products cannot be expressed in CSAF and are not imported.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::CsafDocument;
+use crate::csaf::document::with_parsed;
+use crate::csaf_traits::CsafTrait;
+use crate::schema::csaf2_1::schema::{CategoryOfTheRemediation, LabelOfTheFlag};
+use crate::vex::import::{ImportOptions, VexImport, add_product, build, date_time, system_name};
+use crate::vex::{Action, Statement, Status, Vex, VexError, VexExport, Vulnerability, collect};
+
+/// The value of `@context` of OpenVEX documents
+pub const OPENVEX_CONTEXT: &str = "https://openvex.dev/ns/v0.2.0";
+
+/// An OpenVEX document
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpenVexDocument {
+    #[serde(rename = "@context")]
+    pub context: String,
+    #[serde(rename = "@id")]
+    pub id: String,
+    pub author: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+    pub timestamp: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_updated: Option<String>,
+    pub version: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tooling: Option<String>,
+    pub statements: Vec<OpenVexStatement>,
+}
+
+/// The status of products for a vulnerability
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpenVexStatement {
+    pub vulnerability: OpenVexVulnerability,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+    #[serde(default)]
+    pub products: Vec<OpenVexProduct>,
+    pub status: OpenVexStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_notes: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub justification: Option<OpenVexJustification>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub impact_statement: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action_statement: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpenVexVulnerability {
+    #[serde(rename = "@id", default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpenVexProduct {
+    #[serde(rename = "@id")]
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identifiers: Option<OpenVexIdentifiers>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subcomponents: Vec<OpenVexProduct>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpenVexIdentifiers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub purl: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpe23: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpe22: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OpenVexStatus {
+    NotAffected,
+    Affected,
+    Fixed,
+    UnderInvestigation,
+}
+
+/// Justifications of OpenVEX, which are the labels of CSAF flags
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OpenVexJustification {
+    ComponentNotPresent,
+    VulnerableCodeNotPresent,
+    VulnerableCodeNotInExecutePath,
+    VulnerableCodeCannotBeControlledByAdversary,
+    InlineMitigationsAlreadyExist,
+}
+
+impl From<LabelOfTheFlag> for OpenVexJustification {
+    fn from(label: LabelOfTheFlag) -> Self {
+        match label {
+            LabelOfTheFlag::ComponentNotPresent => OpenVexJustification::ComponentNotPresent,
+            LabelOfTheFlag::VulnerableCodeNotPresent => OpenVexJustification::VulnerableCodeNotPresent,
+            LabelOfTheFlag::VulnerableCodeNotInExecutePath => OpenVexJustification::VulnerableCodeNotInExecutePath,
+            LabelOfTheFlag::VulnerableCodeCannotBeControlledByAdversary => {
+                OpenVexJustification::VulnerableCodeCannotBeControlledByAdversary
+            },
+            LabelOfTheFlag::InlineMitigationsAlreadyExist => OpenVexJustification::InlineMitigationsAlreadyExist,
+        }
+    }
+}
+
+impl From<OpenVexJustification> for LabelOfTheFlag {
+    fn from(justification: OpenVexJustification) -> Self {
+        match justification {
+            OpenVexJustification::ComponentNotPresent => LabelOfTheFlag::ComponentNotPresent,
+            OpenVexJustification::VulnerableCodeNotPresent => LabelOfTheFlag::VulnerableCodeNotPresent,
+            OpenVexJustification::VulnerableCodeNotInExecutePath => LabelOfTheFlag::VulnerableCodeNotInExecutePath,
+            OpenVexJustification::VulnerableCodeCannotBeControlledByAdversary => {
+                LabelOfTheFlag::VulnerableCodeCannotBeControlledByAdversary
+            },
+            OpenVexJustification::InlineMitigationsAlreadyExist => LabelOfTheFlag::InlineMitigationsAlreadyExist,
+        }
+    }
+}
+
+impl From<OpenVexStatus> for Status {
+    fn from(status: OpenVexStatus) -> Self {
+        match status {
+            OpenVexStatus::Affected => Status::Affected,
+            OpenVexStatus::NotAffected => Status::NotAffected,
+            OpenVexStatus::Fixed => Status::Fixed,
+            OpenVexStatus::UnderInvestigation => Status::UnderInvestigation,
+        }
+    }
+}
+
+impl From<Status> for OpenVexStatus {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Affected => OpenVexStatus::Affected,
+            Status::NotAffected => OpenVexStatus::NotAffected,
+            Status::Fixed => OpenVexStatus::Fixed,
+            Status::UnderInvestigation => OpenVexStatus::UnderInvestigation,
+        }
+    }
+}
+
+/// Exports a CSAF VEX document as OpenVEX document, see the [module documentation](self).
+///
+/// Fails if the document is no CSAF VEX document or does not match the types of its CSAF version.
+pub fn export(document: &CsafDocument) -> Result<VexExport<OpenVexDocument>, VexError> {
+    with_parsed!(document, doc => export_parsed(doc)).map_err(|err| VexError::InvalidDocument(err.to_string()))?
+}
+
+/// Exports a parsed CSAF VEX document as OpenVEX document, see the [module documentation](self).
+pub fn export_parsed(doc: &impl CsafTrait) -> Result<VexExport<OpenVexDocument>, VexError> {
+    let mut vex = collect(doc)?;
+    let mut warned_products = BTreeSet::new();
+    let statements = std::mem::take(&mut vex.statements)
+        .into_iter()
+        .map(|statement| OpenVexStatement {
+            vulnerability: OpenVexVulnerability {
+                id: None,
+                name: statement.vulnerability.name.clone(),
+                description: None,
+                aliases: statement
+                    .vulnerability
+                    .aliases
+                    .iter()
+                    .map(|(_, alias)| alias.clone())
+                    .collect(),
+            },
+            timestamp: None,
+            products: statement
+                .product_ids
+                .iter()
+                .map(|product_id| product(&mut vex, product_id, &mut warned_products))
+                .collect(),
+            status: statement.status.into(),
+            status_notes: None,
+            justification: statement.justification.map(OpenVexJustification::from),
+            impact_statement: statement.impact_statement.clone(),
+            action_statement: statement.action_statement(),
+        })
+        .collect();
+
+    let document = OpenVexDocument {
+        context: OPENVEX_CONTEXT.to_string(),
+        id: format!("{}/{}", vex.namespace.trim_end_matches('/'), vex.tracking_id),
+        author: vex.author,
+        role: None,
+        last_updated: (vex.current_release_date != vex.initial_release_date).then_some(vex.current_release_date),
+        timestamp: vex.initial_release_date,
+        version: vex.revisions as u64,
+        tooling: None,
+        statements,
+    };
+    Ok(VexExport {
+        document,
+        warnings: vex.warnings,
+    })
+}
+
+/// Returns the OpenVEX product of a CSAF product, warning once per product about lost identifiers.
+fn product(vex: &mut Vex, product_id: &str, warned_products: &mut BTreeSet<String>) -> OpenVexProduct {
+    let Some(product) = vex.products.get(product_id).cloned() else {
+        return OpenVexProduct {
+            id: product_id.to_string(),
+            identifiers: None,
+            subcomponents: vec![],
+        };
+    };
+    let warn = warned_products.insert(product_id.to_string());
+    let (cpe23, cpe22) = match &product.cpe {
+        Some(cpe) if cpe.starts_with("cpe:2.3:") => (Some(cpe.clone()), None),
+        Some(cpe) => (None, Some(cpe.clone())),
+        None => (None, None),
+    };
+    let identifiers = OpenVexIdentifiers {
+        purl: product.purls.first().cloned(),
+        cpe23,
+        cpe22,
+    };
+    let id = match identifiers.purl.as_ref().or(product.cpe.as_ref()) {
+        Some(id) => id.clone(),
+        None => {
+            if warn {
+                vex.warn(
+                    format!(
+                        "Product '{product_id}' has neither a purl nor a CPE, its product ID is used as identifier"
+                    ),
+                    &product.path,
+                );
+            }
+            product_id.to_string()
+        },
+    };
+    if warn && product.purls.len() > 1 {
+        vex.warn(
+            format!("Only the first purl of product '{product_id}' is exported"),
+            &format!("{}/product_identification_helper/purls", product.path),
+        );
+    }
+    OpenVexProduct {
+        id,
+        identifiers: (identifiers != OpenVexIdentifiers::default()).then_some(identifiers),
+        subcomponents: vec![],
+    }
+}
+
+/// Imports an OpenVEX document as CSAF 2.1 document, see the [import module](crate::vex::import).
+pub fn import(doc: &OpenVexDocument, options: &ImportOptions) -> Result<VexImport, VexError> {
+    let initial_release_date = date_time(&doc.timestamp, "/timestamp")?;
+    let current_release_date = match &doc.last_updated {
+        Some(last_updated) => date_time(last_updated, "/last_updated")?,
+        None => initial_release_date.clone(),
+    };
+    let mut vex = Vex {
+        tracking_id: doc
+            .id
+            .trim_end_matches('/')
+            .rsplit('/')
+            .next()
+            .unwrap_or_default()
+            .to_string(),
+        author: doc.author.clone(),
+        namespace: options.publisher_namespace.clone(),
+        initial_release_date,
+        current_release_date,
+        revisions: doc.version as usize,
+        statements: Vec::new(),
+        products: BTreeMap::new(),
+        warnings: Vec::new(),
+    };
+
+    for (s_i, statement) in doc.statements.iter().enumerate() {
+        let path = format!("/statements/{s_i}");
+        let mut product_ids = Vec::new();
+        for (p_i, product) in statement.products.iter().enumerate() {
+            let product_path = format!("{path}/products/{p_i}");
+            if !product.subcomponents.is_empty() {
+                vex.warn(
+                    format!("The subcomponents of product '{}' are not imported", product.id),
+                    &format!("{product_path}/subcomponents"),
+                );
+            }
+            let identifiers = product.identifiers.clone().unwrap_or_default();
+            let id_with_prefix = |prefix: &str| product.id.starts_with(prefix).then(|| product.id.clone());
+            let purl = identifiers.purl.or_else(|| id_with_prefix("pkg:"));
+            let cpe = identifiers
+                .cpe23
+                .or(identifiers.cpe22)
+                .or_else(|| id_with_prefix("cpe:"));
+            let key = add_product(&mut vex, &product.id, purl, cpe, None, &product_path);
+            if !product_ids.contains(&key) {
+                product_ids.push(key);
+            }
+        }
+        if product_ids.is_empty() {
+            vex.warn("The statement has no products and is not imported".to_string(), &path);
+            continue;
+        }
+
+        let vulnerability = &statement.vulnerability;
+        vex.statements.push(Statement {
+            vulnerability: Vulnerability {
+                name: vulnerability.name.clone(),
+                path,
+                aliases: vulnerability
+                    .aliases
+                    .iter()
+                    .map(|alias| (system_name(alias, "OpenVEX"), alias.clone()))
+                    .collect(),
+                description: vulnerability.description.clone(),
+            },
+            product_ids,
+            status: statement.status.into(),
+            justification: statement.justification.map(LabelOfTheFlag::from),
+            impact_statement: statement.impact_statement.clone(),
+            actions: statement
+                .action_statement
+                .iter()
+                .map(|details| Action {
+                    category: CategoryOfTheRemediation::Workaround,
+                    details: details.clone(),
+                })
+                .collect(),
+            status_notes: statement.status_notes.clone(),
+        });
+    }
+    build(vex, "OpenVEX", options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_documents::csaf_2_1;
+    use crate::vex::VexWarningKind;
+    use crate::vex::tests::{assert_valid, import_options as options};
+    use serde_json::json;
+
+    #[test]
+    fn test_export() {
+        let doc = csaf_2_1(json!({
+            "document": {
+                "category": "csaf_vex",
+                "tracking": { "current_release_date": "2024-02-01T10:00:00.000Z" }
+            },
+            "product_tree": {
+                "full_product_names": [
+                    { "name": "App 1.0", "product_id": "APP-1", "product_identification_helper": { "purls": ["pkg:npm/app@1.0"] } },
+                    { "name": "App 2.0", "product_id": "APP-2", "product_identification_helper": { "cpe": "cpe:2.3:a:example:app:2.0:*:*:*:*:*:*:*" } },
+                    { "name": "App 3.0", "product_id": "APP-3" }
+                ]
+            },
+            "vulnerabilities": [{
+                "cve": "CVE-2024-0001",
+                "product_status": {
+                    "known_affected": ["APP-1"],
+                    "known_not_affected": ["APP-2"],
+                    "under_investigation": ["APP-3"]
+                },
+                "flags": [{ "label": "vulnerable_code_not_in_execute_path", "product_ids": ["APP-2"] }],
+                "threats": [{ "category": "impact", "details": "The code is never called.", "product_ids": ["APP-2"] }],
+                "remediations": [
+                    { "category": "vendor_fix", "details": "Update to 2.0.", "product_ids": ["APP-1"], "url": "https://example.com/app" },
+                    { "category": "workaround", "details": "Disable the feature.", "product_ids": ["APP-1"] }
+                ]
+            }]
+        }));
+        let export = export_parsed(&doc).unwrap();
+        assert_eq!(
+            serde_json::to_value(&export.document).unwrap(),
+            json!({
+                "@context": "https://openvex.dev/ns/v0.2.0",
+                "@id": "https://example.com/UNIT-TEST",
+                "author": "Example Company",
+                "timestamp": "2024-01-24T10:00:00.000Z",
+                "last_updated": "2024-02-01T10:00:00.000Z",
+                "version": 1,
+                "statements": [
+                    {
+                        "vulnerability": { "name": "CVE-2024-0001" },
+                        "products": [{ "@id": "pkg:npm/app@1.0", "identifiers": { "purl": "pkg:npm/app@1.0" } }],
+                        "status": "affected",
+                        "action_statement": "Update to 2.0. (https://example.com/app)\n\nDisable the feature."
+                    },
+                    {
+                        "vulnerability": { "name": "CVE-2024-0001" },
+                        "products": [{
+                            "@id": "cpe:2.3:a:example:app:2.0:*:*:*:*:*:*:*",
+                            "identifiers": { "cpe23": "cpe:2.3:a:example:app:2.0:*:*:*:*:*:*:*" }
+                        }],
+                        "status": "not_affected",
+                        "justification": "vulnerable_code_not_in_execute_path",
+                        "impact_statement": "The code is never called."
+                    },
+                    {
+                        "vulnerability": { "name": "CVE-2024-0001" },
+                        "products": [{ "@id": "APP-3" }],
+                        "status": "under_investigation"
+                    }
+                ]
+            })
+        );
+        assert_eq!(export.warnings.len(), 1);
+        assert_eq!(export.warnings[0].instance_path, "/product_tree/full_product_names/2");
+
+        let roundtrip: OpenVexDocument =
+            serde_json::from_value(serde_json::to_value(&export.document).unwrap()).unwrap();
+        assert_eq!(roundtrip, export.document);
+    }
+
+    #[test]
+    fn test_import() {
+        let doc: OpenVexDocument = serde_json::from_value(json!({
+            "@context": "https://openvex.dev/ns/v0.2.0",
+            "@id": "https://openvex.dev/docs/example/vex-9fb3463de1b57",
+            "author": "Wolfi J Inkinson",
+            "timestamp": "2023-01-08T18:02:03.647787998-06:00",
+            "last_updated": "2023-01-09T10:00:00Z",
+            "version": 2,
+            "statements": [
+                {
+                    "vulnerability": { "name": "CVE-2023-12345", "description": "Buffer overflow in the parser." },
+                    "products": [{ "@id": "pkg:apk/wolfi/git@2.39.0-r1?arch=x86_64" }],
+                    "status": "under_investigation"
+                },
+                {
+                    "vulnerability": { "name": "GHSA-xxxx-xxxx-xxxx", "aliases": ["CVE-2023-12345"] },
+                    "products": [
+                        { "@id": "pkg:apk/wolfi/git@2.39.0-r1?arch=x86_64" },
+                        {
+                            "@id": "https://example.com/products/tool",
+                            "identifiers": { "cpe23": "cpe:2.3:a:example:tool:1.0:*:*:*:*:*:*:*" },
+                            "subcomponents": [{ "@id": "pkg:golang/example.com/lib@1.0" }]
+                        }
+                    ],
+                    "status": "not_affected",
+                    "justification": "vulnerable_code_not_in_execute_path",
+                    "status_notes": "Only the CLI uses the parser."
+                },
+                {
+                    "vulnerability": { "name": "CVE-2023-12345" },
+                    "products": [{ "@id": "pkg:apk/wolfi/curl@8.0.0" }],
+                    "status": "affected",
+                    "action_statement": "Disable the parser."
+                },
+                {
+                    "vulnerability": { "name": "CVE-2023-99999" },
+                    "products": [{ "@id": "pkg:apk/wolfi/curl@8.0.0" }, { "@id": "my-product" }],
+                    "status": "not_affected"
+                },
+                {
+                    "vulnerability": { "name": "CVE-2023-99999" },
+                    "products": [{ "@id": "my-product" }],
+                    "status": "fixed"
+                }
+            ]
+        }))
+        .unwrap();
+        let import = import(&doc, &options()).unwrap();
+        assert_valid(&import.document);
+
+        let json = serde_json::to_value(&import.document).unwrap();
+        assert_eq!(json["document"]["tracking"]["id"], "vex-9fb3463de1b57");
+        assert_eq!(json["document"]["publisher"]["name"], "Wolfi J Inkinson");
+        assert_eq!(
+            json["document"]["tracking"]["initial_release_date"],
+            "2023-01-09T00:02:03.647Z"
+        );
+        assert_eq!(
+            json["document"]["tracking"]["current_release_date"],
+            "2023-01-09T10:00:00.000Z"
+        );
+        assert_eq!(json["document"]["tracking"]["version"], "2");
+        assert_eq!(
+            json["product_tree"]["full_product_names"],
+            json!([
+                {
+                    "name": "cpe:2.3:a:example:tool:1.0:*:*:*:*:*:*:*",
+                    "product_id": "CSAFPID-0001",
+                    "product_identification_helper": { "cpe": "cpe:2.3:a:example:tool:1.0:*:*:*:*:*:*:*" }
+                },
+                {
+                    "name": "my-product",
+                    "product_id": "CSAFPID-0002"
+                },
+                {
+                    "name": "wolfi/curl 8.0.0",
+                    "product_id": "CSAFPID-0003",
+                    "product_identification_helper": { "purls": ["pkg:apk/wolfi/curl@8.0.0"] }
+                },
+                {
+                    "name": "wolfi/git 2.39.0-r1",
+                    "product_id": "CSAFPID-0004",
+                    "product_identification_helper": { "purls": ["pkg:apk/wolfi/git@2.39.0-r1?arch=x86_64"] }
+                }
+            ])
+        );
+        let vulnerabilities = json["vulnerabilities"].as_array().unwrap();
+        assert_eq!(vulnerabilities.len(), 2);
+        assert_eq!(vulnerabilities[0]["cve"], "CVE-2023-12345");
+        assert_eq!(
+            vulnerabilities[0]["ids"],
+            json!([{ "system_name": "GHSA", "text": "GHSA-xxxx-xxxx-xxxx" }])
+        );
+        assert_eq!(
+            vulnerabilities[0]["product_status"],
+            json!({
+                "known_affected": ["CSAFPID-0003"],
+                "known_not_affected": ["CSAFPID-0004", "CSAFPID-0001"]
+            })
+        );
+        assert_eq!(
+            vulnerabilities[0]["notes"],
+            json!([
+                { "category": "description", "text": "Buffer overflow in the parser." },
+                {
+                    "category": "details",
+                    "product_ids": ["CSAFPID-0004", "CSAFPID-0001"],
+                    "text": "Only the CLI uses the parser."
+                }
+            ])
+        );
+        assert_eq!(
+            vulnerabilities[0]["remediations"],
+            json!([{ "category": "workaround", "details": "Disable the parser.", "product_ids": ["CSAFPID-0003"] }])
+        );
+        assert_eq!(
+            vulnerabilities[1]["product_status"],
+            json!({ "fixed": ["CSAFPID-0002"], "known_not_affected": ["CSAFPID-0003"] })
+        );
+
+        let warnings: Vec<(VexWarningKind, &str)> = import
+            .warnings
+            .iter()
+            .map(|warning| (warning.kind, warning.instance_path.as_str()))
+            .collect();
+        assert_eq!(
+            warnings,
+            vec![
+                (VexWarningKind::Lossy, "/statements/1/products/1/subcomponents"),
+                (VexWarningKind::Lossy, "/statements/3"),
+                (VexWarningKind::Lossy, "/statements/0"),
+                (VexWarningKind::Heuristic, "/statements/3"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_import_exported() {
+        let doc = csaf_2_1(json!({
+            "document": { "category": "csaf_vex" },
+            "product_tree": {
+                "full_product_names": [
+                    { "name": "App 1.0", "product_id": "APP-1", "product_identification_helper": { "purls": ["pkg:npm/app@1.0"] } },
+                    { "name": "App 2.0", "product_id": "APP-2", "product_identification_helper": { "purls": ["pkg:npm/app@2.0"] } }
+                ]
+            },
+            "vulnerabilities": [{
+                "cve": "CVE-2024-0001",
+                "notes": [{ "category": "description", "text": "Remote code execution." }],
+                "product_status": { "known_affected": ["APP-1"], "known_not_affected": ["APP-2"] },
+                "flags": [{ "label": "vulnerable_code_not_present", "product_ids": ["APP-2"] }],
+                "remediations": [{ "category": "workaround", "details": "Disable the feature.", "product_ids": ["APP-1"] }]
+            }]
+        }));
+        let export = export_parsed(&doc).unwrap();
+        let import = import(&export.document, &options()).unwrap();
+        assert_valid(&import.document);
+        assert_eq!(import.warnings, vec![]);
+
+        let reexport = super::export_parsed(&import.document).unwrap();
+        assert_eq!(reexport.document.statements, export.document.statements);
+    }
+}
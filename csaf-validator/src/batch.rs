@@ -0,0 +1,346 @@
+//! Validation of many documents at once, e.g. all advisories of a CSAF provider.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use csaf::CsafDocument;
+use csaf::csaf::loader::LoadError;
+use csaf::validation::{TestResultStatus, ValidationResult};
+use rayon::prelude::*;
+use serde::Serialize;
+
+/// Validation outcome of a single file. `Err` contains the reason why the file could not be validated.
+pub struct FileOutcome {
+    pub path: String,
+    pub result: Result<ValidationResult, String>,
+}
+
+/// A file to validate
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct InputFile {
+    pub path: PathBuf,
+    /// Whether the file was found by searching a directory, JSON files found this way which are no
+    /// CSAF documents are skipped, see [load_candidate]
+    pub searched: bool,
+}
+
+/// Collects the files to validate from the given inputs.
+///
+/// Inputs may be files, directories (searched recursively for `.json` files) or glob patterns.
+/// The returned files are sorted and free of duplicates. Whether the files found in directories are
+/// CSAF documents is only checked when they are validated, so they are read once and in parallel.
+pub fn collect_files(inputs: &[String]) -> Result<Vec<InputFile>> {
+    let mut files = Vec::new();
+    for input in inputs {
+        let path = Path::new(input);
+        if path.is_dir() {
+            let pattern = path.join("**").join("*.json");
+            files.extend(
+                glob_files(&pattern.to_string_lossy())?
+                    .into_iter()
+                    .map(|path| InputFile { path, searched: true }),
+            );
+        } else if path.exists() {
+            files.push(InputFile {
+                path: path.to_path_buf(),
+                searched: false,
+            });
+        } else {
+            let matches = glob_files(input)?;
+            if matches.is_empty() {
+                anyhow::bail!("No files found for {input}");
+            }
+            files.extend(matches.into_iter().map(|path| InputFile { path, searched: false }));
+        }
+    }
+    // Files given explicitly sort before the same files found in a directory and are kept
+    files.sort();
+    files.dedup_by(|file, kept| file.path == kept.path);
+    Ok(files)
+}
+
+fn glob_files(pattern: &str) -> Result<Vec<PathBuf>> {
+    let paths = glob::glob(pattern).with_context(|| format!("Invalid glob pattern {pattern}"))?;
+    Ok(paths.filter_map(|entry| entry.ok()).filter(|p| p.is_file()).collect())
+}
+
+/// Loads a file found in a directory with `load`, returning `None` if it is no CSAF document.
+///
+/// CSAF provider trees also contain JSON files which are not advisories, like the provider
+/// metadata, service documents or ROLIE feeds. These are recognized by lacking a top-level
+/// `document` property. Files that cannot be read or parsed are reported as load failures.
+pub fn load_candidate(path: &str, load: impl Fn(&str) -> Result<CsafDocument>) -> Result<Option<CsafDocument>> {
+    match load(path) {
+        Ok(document) if !is_csaf_candidate(document.get_json()) => Ok(None),
+        // Without a version, the document is only loaded again to tell other JSON files apart
+        Err(err)
+            if matches!(err.downcast_ref(), Some(LoadError::MissingVersion))
+                && std::fs::read_to_string(path)
+                    .ok()
+                    .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+                    .is_some_and(|json| !is_csaf_candidate(&json)) =>
+        {
+            Ok(None)
+        },
+        result => result.map(Some),
+    }
+}
+
+fn is_csaf_candidate(json: &serde_json::Value) -> bool {
+    json.as_object().is_some_and(|object| object.contains_key("document"))
+}
+
+/// Validates all files in parallel, using `jobs` threads or one per CPU if `None`.
+///
+/// `validate` returns `None` for files that are skipped, they have no outcome. The outcomes are
+/// returned in the order of the files.
+pub fn validate_files<F>(files: &[InputFile], jobs: Option<usize>, validate: F) -> Result<Vec<FileOutcome>>
+where
+    F: Fn(&InputFile) -> Result<Option<ValidationResult>> + Sync,
+{
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs.unwrap_or(0))
+        .build()
+        .context("Failed to create thread pool")?;
+
+    Ok(pool.install(|| {
+        files
+            .par_iter()
+            .filter_map(|file| {
+                let path = file.path.to_string_lossy().to_string();
+                let result = match validate(file) {
+                    Ok(Some(result)) => Ok(result),
+                    Ok(None) => return None,
+                    Err(e) => Err(format!("{e:#}")),
+                };
+                Some(FileOutcome { path, result })
+            })
+            .collect()
+    }))
+}
+
+/// Aggregated results of a batch validation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchSummary {
+    /// Number of validated files
+    pub num_files: usize,
+    /// Number of files without errors
+    pub num_passed: usize,
+    /// Number of files with errors
+    pub num_failed: usize,
+    /// Number of files that could not be loaded
+    pub num_load_failures: usize,
+    /// Tests that failed most often, with the number of files they failed for
+    pub top_failing_tests: Vec<FailingTest>,
+}
+
+/// A test and the number of files it failed for.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FailingTest {
+    pub test_id: String,
+    pub num_files: usize,
+}
+
+/// Number of failing tests listed in the summary
+const NUM_TOP_FAILING_TESTS: usize = 10;
+
+impl BatchSummary {
+    pub fn new<'a>(results: impl IntoIterator<Item = Result<&'a ValidationResult, &'a str>>) -> Self {
+        let mut summary = BatchSummary {
+            num_files: 0,
+            num_passed: 0,
+            num_failed: 0,
+            num_load_failures: 0,
+            top_failing_tests: Vec::new(),
+        };
+        let mut failing_tests: HashMap<&str, usize> = HashMap::new();
+
+        for result in results {
+            summary.num_files += 1;
+            let Ok(result) = result else {
+                summary.num_load_failures += 1;
+                continue;
+            };
+            if result.success {
+                summary.num_passed += 1;
+            } else {
+                summary.num_failed += 1;
+            }
+            for test_result in &result.test_results {
+                if let TestResultStatus::Failure { errors, .. } = &test_result.status
+                    && !errors.is_empty()
+                {
+                    *failing_tests.entry(test_result.test_id.as_str()).or_default() += 1;
+                }
+            }
+        }
+
+        let mut failing_tests: Vec<FailingTest> = failing_tests
+            .into_iter()
+            .map(|(test_id, num_files)| FailingTest {
+                test_id: test_id.to_string(),
+                num_files,
+            })
+            .collect();
+        failing_tests.sort_by(|a, b| b.num_files.cmp(&a.num_files).then_with(|| a.test_id.cmp(&b.test_id)));
+        failing_tests.truncate(NUM_TOP_FAILING_TESTS);
+        summary.top_failing_tests = failing_tests;
+        summary
+    }
+}
+
+/// Prints the result of every file and the aggregated summary to stdout.
+pub fn print_batch_result(outcomes: &[FileOutcome], summary: &BatchSummary) {
+    for outcome in outcomes {
+        match &outcome.result {
+            Ok(result) => {
+                match (result.num_errors, result.num_warnings, result.num_infos) {
+                    (0, 0, 0) => println!("✅  {}", outcome.path),
+                    (0, warnings, infos) => {
+                        println!("✅  {} ({warnings} warning(s), {infos} info(s))", outcome.path)
+                    },
+                    (errors, warnings, infos) => println!(
+                        "❌  {} ({errors} error(s), {warnings} warning(s), {infos} info(s))",
+                        outcome.path
+                    ),
+                }
+                for test_result in &result.test_results {
+                    if let TestResultStatus::Failure { errors, .. } = &test_result.status {
+                        for error in errors {
+                            let location = result
+                                .location_of(error)
+                                .map(|span| format!(" (line {}, column {})", span.line, span.column))
+                                .unwrap_or_default();
+                            println!(
+                                "      {}: {} [{}]{location}",
+                                test_result.test_id, error.message, error.instance_path
+                            );
+                        }
+                    }
+                }
+            },
+            Err(err) => println!("💥  {}: {err}", outcome.path),
+        }
+    }
+
+    println!();
+    println!(
+        "Validated {} file(s): {} passed, {} failed, {} could not be loaded",
+        summary.num_files, summary.num_passed, summary.num_failed, summary.num_load_failures
+    );
+    if !summary.top_failing_tests.is_empty() {
+        println!("\nMost frequently failing tests:");
+        for test in &summary.top_failing_tests {
+            println!("  {:10} failed for {} file(s)", test.test_id, test.num_files);
+        }
+    }
+    println!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use csaf::validation::{TestResult, ValidationError, ValidationPreset};
+
+    fn result_with_failures(test_ids: &[&str]) -> ValidationResult {
+        ValidationResult {
+            success: test_ids.is_empty(),
+            version: "2.0".to_string(),
+            preset: ValidationPreset::Basic,
+            test_results: test_ids
+                .iter()
+                .map(|id| TestResult {
+                    test_id: id.to_string(),
+                    status: TestResultStatus::Failure {
+                        errors: vec![ValidationError {
+                            message: "error".to_string(),
+                            instance_path: "".to_string(),
+                        }],
+                        warnings: vec![],
+                        infos: vec![],
+                    },
+                })
+                .collect(),
+            num_errors: test_ids.len(),
+            num_warnings: 0,
+            num_infos: 0,
+            num_not_found: 0,
+            source_locations: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_summary() {
+        let passed = result_with_failures(&[]);
+        let failed_1 = result_with_failures(&["6.1.1", "6.1.2"]);
+        let failed_2 = result_with_failures(&["6.1.2"]);
+        let summary = BatchSummary::new([Ok(&passed), Ok(&failed_1), Ok(&failed_2), Err("invalid JSON")]);
+
+        assert_eq!(summary.num_files, 4);
+        assert_eq!(summary.num_passed, 1);
+        assert_eq!(summary.num_failed, 2);
+        assert_eq!(summary.num_load_failures, 1);
+        assert_eq!(
+            summary.top_failing_tests,
+            vec![
+                FailingTest {
+                    test_id: "6.1.2".to_string(),
+                    num_files: 2
+                },
+                FailingTest {
+                    test_id: "6.1.1".to_string(),
+                    num_files: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_collect_files_from_provider_tree() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("2024")).unwrap();
+        std::fs::write(dir.path().join("provider-metadata.json"), r#"{"canonical_url": ""}"#).unwrap();
+        std::fs::write(dir.path().join("2024/b.json"), r#"{"document": {}}"#).unwrap();
+        std::fs::write(dir.path().join("2024/a.json"), r#"{"document": {}}"#).unwrap();
+        std::fs::write(dir.path().join("2024/a.json.sha512"), "").unwrap();
+
+        let files = collect_files(&[
+            dir.path().to_string_lossy().to_string(),
+            dir.path().join("2024/b.json").to_string_lossy().to_string(),
+        ])
+        .unwrap();
+        let paths: Vec<(PathBuf, bool)> = files.into_iter().map(|file| (file.path, file.searched)).collect();
+        assert_eq!(
+            paths,
+            vec![
+                (dir.path().join("2024/a.json"), true),
+                (dir.path().join("2024/b.json"), false),
+                (dir.path().join("provider-metadata.json"), true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_load_candidate() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = |name: &str| dir.path().join(name).to_string_lossy().to_string();
+        std::fs::write(path("provider-metadata.json"), r#"{"canonical_url": ""}"#).unwrap();
+        std::fs::write(path("no-version.json"), r#"{"document": {}}"#).unwrap();
+        std::fs::write(path("broken.json"), "{").unwrap();
+        let load = |path: &str| Ok(CsafDocument::load(Path::new(path))?);
+
+        assert!(load_candidate(&path("provider-metadata.json"), load).unwrap().is_none());
+        assert!(load_candidate(&path("no-version.json"), load).is_err());
+        assert!(load_candidate(&path("broken.json"), load).is_err());
+        // With a forced version, other JSON files are loaded and skipped as well
+        let load_as = |path: &str| Ok(CsafDocument::load_as(Path::new(path), "2.1")?);
+        assert!(
+            load_candidate(&path("provider-metadata.json"), load_as)
+                .unwrap()
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,70 @@
+//! The `diff` subcommand, which compares two revisions of a CSAF document.
+
+use anyhow::{Result, bail};
+use clap::{Args, ValueEnum};
+use csaf::CsafDocument;
+use csaf::diff::diff;
+use std::path::PathBuf;
+
+use crate::exit_status::ExitStatus;
+
+/// Compare two revisions of a CSAF document with the same tracking ID
+#[derive(Args, Debug)]
+pub struct DiffArgs {
+    /// Path of the older revision
+    old: PathBuf,
+
+    /// Path of the newer revision
+    new: PathBuf,
+
+    /// Format in which the differences are printed
+    #[arg(short, long, value_enum, default_value_t = DiffFormat::Text)]
+    output_format: DiffFormat,
+}
+
+/// Format in which the differences are printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DiffFormat {
+    /// Human-readable text
+    Text,
+    /// The differences as JSON
+    Json,
+}
+
+/// Prints the differences between the two revisions.
+///
+/// Errors are returned for documents with different tracking IDs, documents that cannot be loaded
+/// are reported as [`ExitStatus::LoadFailure`].
+pub fn run(args: &DiffArgs) -> Result<ExitStatus> {
+    let mut documents = Vec::new();
+    for path in [&args.old, &args.new] {
+        let loaded = CsafDocument::load(path)
+            .map_err(|err| err.to_string())
+            .and_then(|document| {
+                // The tracking ID is read from the parsed document, so this fails for invalid documents
+                document.tracking_id().map_err(str::to_string)?;
+                Ok(document)
+            });
+        match loaded {
+            Ok(document) => documents.push(document),
+            Err(err) => {
+                eprintln!("Error: failed to load {}: {err}", path.display());
+                return Ok(ExitStatus::LoadFailure);
+            },
+        }
+    }
+
+    let document_diff = match diff(&documents[0], &documents[1]) {
+        Ok(document_diff) => document_diff,
+        Err(err) => bail!(
+            "Cannot compare {} and {}: {err}",
+            args.old.display(),
+            args.new.display()
+        ),
+    };
+    match args.output_format {
+        DiffFormat::Text => print!("{document_diff}"),
+        DiffFormat::Json => println!("{}", serde_json::to_string_pretty(&document_diff)?),
+    }
+    Ok(ExitStatus::Success)
+}
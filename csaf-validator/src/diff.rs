@@ -0,0 +1,149 @@
+//! Unified diffs of documents, used to preview the changes of `--fix` with `--diff`.
+
+/// Number of unchanged lines printed before and after each change
+const CONTEXT_LINES: usize = 3;
+
+/// Maximum number of cells of the table comparing the changed lines. Larger changes are printed as
+/// removal of all old lines followed by all new lines.
+const MAX_TABLE_SIZE: usize = 10_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Line<'a> {
+    Unchanged(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Returns the unified diff between two versions of the file at `path`, empty if they are equal.
+pub fn unified_diff(path: &str, old: &str, new: &str) -> String {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    let lines = diff_lines(&old, &new);
+    let changes: Vec<usize> = (0..lines.len())
+        .filter(|&i| !matches!(lines[i], Line::Unchanged(_)))
+        .collect();
+    if changes.is_empty() {
+        return String::new();
+    }
+
+    let path = path.trim_start_matches('/');
+    let mut output = format!("--- a/{path}\n+++ b/{path}\n");
+    let mut hunk_changes = changes.as_slice();
+    while let [first, rest @ ..] = hunk_changes {
+        // Changes separated by at most twice the context belong to the same hunk
+        let len = 1 + rest
+            .iter()
+            .zip(hunk_changes)
+            .take_while(|(change, previous)| *change - *previous <= 2 * CONTEXT_LINES + 1)
+            .count();
+        let start = first.saturating_sub(CONTEXT_LINES);
+        let end = (hunk_changes[len - 1] + CONTEXT_LINES + 1).min(lines.len());
+        write_hunk(&mut output, &lines, start, end);
+        hunk_changes = &hunk_changes[len..];
+    }
+    output
+}
+
+impl Line<'_> {
+    /// Returns whether the line is part of the old or the new version.
+    fn is_in(&self, old: bool) -> bool {
+        match self {
+            Line::Unchanged(_) => true,
+            Line::Removed(_) => old,
+            Line::Added(_) => !old,
+        }
+    }
+}
+
+fn write_hunk(output: &mut String, lines: &[Line], start: usize, end: usize) {
+    let range = |old: bool| {
+        let before = lines[..start].iter().filter(|line| line.is_in(old)).count();
+        let count = lines[start..end].iter().filter(|line| line.is_in(old)).count();
+        // Empty ranges start at the line before them
+        format!("{},{count}", before + usize::from(count > 0))
+    };
+    output.push_str(&format!("@@ -{} +{} @@\n", range(true), range(false)));
+    for line in &lines[start..end] {
+        let (prefix, text) = match line {
+            Line::Unchanged(text) => (' ', text),
+            Line::Removed(text) => ('-', text),
+            Line::Added(text) => ('+', text),
+        };
+        output.push(prefix);
+        output.push_str(text);
+        output.push('\n');
+    }
+}
+
+/// Compares the lines by their longest common subsequence, after skipping the common prefix and
+/// suffix.
+fn diff_lines<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<Line<'a>> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_middle = &old[prefix..old.len() - suffix];
+    let new_middle = &new[prefix..new.len() - suffix];
+
+    let mut lines: Vec<Line> = old[..prefix].iter().map(|line| Line::Unchanged(line)).collect();
+    if old_middle.len().saturating_mul(new_middle.len()) > MAX_TABLE_SIZE {
+        lines.extend(old_middle.iter().map(|line| Line::Removed(line)));
+        lines.extend(new_middle.iter().map(|line| Line::Added(line)));
+    } else {
+        // table[i][j] is the length of the longest common subsequence of old[i..] and new[j..]
+        let (n, m) = (old_middle.len(), new_middle.len());
+        let mut table = vec![vec![0usize; m + 1]; n + 1];
+        for i in (0..n).rev() {
+            for j in (0..m).rev() {
+                table[i][j] = if old_middle[i] == new_middle[j] {
+                    table[i + 1][j + 1] + 1
+                } else {
+                    table[i + 1][j].max(table[i][j + 1])
+                };
+            }
+        }
+        let (mut i, mut j) = (0, 0);
+        while i < n || j < m {
+            if i < n && j < m && old_middle[i] == new_middle[j] {
+                lines.push(Line::Unchanged(old_middle[i]));
+                i += 1;
+                j += 1;
+            } else if j == m || (i < n && table[i + 1][j] >= table[i][j + 1]) {
+                lines.push(Line::Removed(old_middle[i]));
+                i += 1;
+            } else {
+                lines.push(Line::Added(new_middle[j]));
+                j += 1;
+            }
+        }
+    }
+    lines.extend(old[old.len() - suffix..].iter().map(|line| Line::Unchanged(line)));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unified_diff() {
+        assert_eq!(unified_diff("a.json", "1\n2\n", "1\n2\n"), "");
+
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n";
+        let new = "1\n2\nthree\n4\n5\n6\n7\n8\n9\n10\n11\n12\n13\n";
+        assert_eq!(
+            unified_diff("a.json", old, new),
+            "--- a/a.json\n+++ b/a.json\n\
+             @@ -1,6 +1,6 @@\n 1\n 2\n-3\n+three\n 4\n 5\n 6\n\
+             @@ -10,3 +10,4 @@\n 10\n 11\n 12\n+13\n"
+        );
+
+        assert_eq!(
+            unified_diff("a.json", "", "1\n"),
+            "--- a/a.json\n+++ b/a.json\n@@ -0,0 +1,1 @@\n+1\n"
+        );
+    }
+}
@@ -0,0 +1,142 @@
+//! Exit codes of the validator, depending on the validation results.
+
+use clap::ValueEnum;
+use csaf::validation::ValidationResult;
+
+/// Lowest severity of findings that lets the validation fail
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum FailOn {
+    /// Fail on errors only
+    Error,
+    /// Fail on errors and warnings
+    Warning,
+    /// Fail on errors, warnings and infos
+    Info,
+}
+
+/// Outcome of a validator run, which determines the exit code of the process.
+///
+/// The variants are ordered by precedence: if several documents are validated, the highest
+/// outcome of all documents is reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ExitStatus {
+    /// No findings that let the validation fail
+    Success,
+    /// Only infos were found (with `--fail-on info`)
+    Infos,
+    /// Warnings, but no errors were found (with `--fail-on warning` or `--fail-on info`)
+    Warnings,
+    /// Validation errors were found
+    Errors,
+    /// Tests selected with `--test-id` are unknown or not implemented
+    UnknownTests,
+    /// A document could not be loaded or parsed
+    LoadFailure,
+    /// The arguments are invalid, e.g. an unknown preset
+    InvalidArguments,
+}
+
+impl ExitStatus {
+    /// Determines the outcome of the validation of a single document.
+    ///
+    /// Tests that were not found are only taken into account if they were selected explicitly, since
+    /// presets may contain tests that are not implemented yet.
+    pub fn from_result(result: &ValidationResult, fail_on: FailOn, explicit_tests: bool) -> Self {
+        if explicit_tests && result.num_not_found > 0 {
+            ExitStatus::UnknownTests
+        } else if result.num_errors > 0 {
+            ExitStatus::Errors
+        } else if result.num_warnings > 0 && fail_on >= FailOn::Warning {
+            ExitStatus::Warnings
+        } else if result.num_infos > 0 && fail_on >= FailOn::Info {
+            ExitStatus::Infos
+        } else {
+            ExitStatus::Success
+        }
+    }
+
+    /// Returns the exit code of the process.
+    ///
+    /// Code 2 is used for invalid arguments, just like for usage errors reported by clap.
+    pub fn code(&self) -> u8 {
+        match self {
+            ExitStatus::Success => 0,
+            ExitStatus::Errors => 1,
+            ExitStatus::InvalidArguments => 2,
+            ExitStatus::Warnings => 3,
+            ExitStatus::Infos => 4,
+            ExitStatus::LoadFailure => 5,
+            ExitStatus::UnknownTests => 6,
+        }
+    }
+}
+
+impl From<ExitStatus> for std::process::ExitCode {
+    fn from(status: ExitStatus) -> Self {
+        std::process::ExitCode::from(status.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use csaf::validation::ValidationPreset;
+
+    fn result(num_errors: usize, num_warnings: usize, num_infos: usize, num_not_found: usize) -> ValidationResult {
+        ValidationResult {
+            success: num_errors == 0,
+            version: "2.0".to_string(),
+            preset: ValidationPreset::Basic,
+            test_results: vec![],
+            source_locations: Default::default(),
+            num_errors,
+            num_warnings,
+            num_infos,
+            num_not_found,
+        }
+    }
+
+    #[test]
+    fn test_from_result() {
+        let warnings_and_infos = result(0, 2, 1, 0);
+        assert_eq!(
+            ExitStatus::from_result(&warnings_and_infos, FailOn::Error, false),
+            ExitStatus::Success
+        );
+        assert_eq!(
+            ExitStatus::from_result(&warnings_and_infos, FailOn::Warning, false),
+            ExitStatus::Warnings
+        );
+        assert_eq!(
+            ExitStatus::from_result(&result(0, 0, 1, 0), FailOn::Warning, false),
+            ExitStatus::Success
+        );
+        assert_eq!(
+            ExitStatus::from_result(&result(0, 0, 1, 0), FailOn::Info, false),
+            ExitStatus::Infos
+        );
+        assert_eq!(
+            ExitStatus::from_result(&result(1, 2, 1, 0), FailOn::Info, false),
+            ExitStatus::Errors
+        );
+    }
+
+    #[test]
+    fn test_not_found_tests() {
+        let not_found = result(0, 0, 0, 1);
+        assert_eq!(
+            ExitStatus::from_result(&not_found, FailOn::Error, false),
+            ExitStatus::Success
+        );
+        assert_eq!(
+            ExitStatus::from_result(&not_found, FailOn::Error, true),
+            ExitStatus::UnknownTests
+        );
+    }
+
+    #[test]
+    fn test_precedence() {
+        let statuses = [ExitStatus::Warnings, ExitStatus::LoadFailure, ExitStatus::Errors];
+        assert_eq!(statuses.into_iter().max(), Some(ExitStatus::LoadFailure));
+    }
+}
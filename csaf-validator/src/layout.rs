@@ -0,0 +1,65 @@
+//! Serialization of fixed documents in the layout of their source file.
+
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::ser::{PrettyFormatter, Serializer};
+
+/// Serializes the JSON of a fixed document in the layout of its source.
+///
+/// Sources on a single line are written compact, all others are indented like their first indented
+/// line (with spaces or tabs). A trailing line break is kept. The document is re-serialized, so
+/// other details of the source layout, like the spaces around colons or arrays written on one line,
+/// are not kept.
+pub fn to_string_like(json: &Value, source: &str) -> serde_json::Result<String> {
+    let mut text = match indentation(source) {
+        None => serde_json::to_string(json)?,
+        Some(indent) => {
+            let mut bytes = Vec::new();
+            json.serialize(&mut Serializer::with_formatter(
+                &mut bytes,
+                PrettyFormatter::with_indent(indent.as_bytes()),
+            ))?;
+            String::from_utf8(bytes).expect("serde_json writes UTF-8")
+        },
+    };
+    if source.ends_with('\n') {
+        text.push('\n');
+    }
+    Ok(text)
+}
+
+/// Returns the indentation of the first indented line, `None` for sources on a single line.
+fn indentation(source: &str) -> Option<&str> {
+    let source = source.trim();
+    if !source.contains('\n') {
+        return None;
+    }
+    let indent = source
+        .lines()
+        .map(|line| &line[..line.len() - line.trim_start_matches([' ', '\t']).len()])
+        .find(|indent| !indent.is_empty());
+    Some(indent.unwrap_or("  "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_to_string_like() {
+        let json = json!({"document": {"title": "Fixed"}});
+        assert_eq!(
+            to_string_like(&json, r#"{"document":{"title":"Old"}}"#).unwrap(),
+            r#"{"document":{"title":"Fixed"}}"#
+        );
+        assert_eq!(
+            to_string_like(&json, "{\n    \"document\": {\n        \"title\": \"Old\"\n    }\n}\n").unwrap(),
+            "{\n    \"document\": {\n        \"title\": \"Fixed\"\n    }\n}\n"
+        );
+        assert_eq!(
+            to_string_like(&json, "{\n\t\"document\": {}\n}").unwrap(),
+            "{\n\t\"document\": {\n\t\t\"title\": \"Fixed\"\n\t}\n}"
+        );
+    }
+}
@@ -1,22 +1,50 @@
-use anyhow::{Result, bail};
-use clap::Parser;
-use csaf::csaf::loader::detect_version;
-use csaf::csaf2_0::loader::load_document as load_document_2_0;
-use csaf::csaf2_1::loader::load_document as load_document_2_1;
+use anyhow::{Context, Result, bail};
+use batch::{BatchSummary, collect_files, load_candidate, print_batch_result, validate_files};
+use clap::{Parser, Subcommand};
+use csaf::CsafDocument;
+use csaf::csaf::span::code_frame;
+use csaf::spell_check::SpellChecker;
+use csaf::successor::validate_successor;
+use csaf::url_resolver::{HttpUrlResolver, OfflineUrlResolver, UrlResolver};
 use csaf::validation::{
     TestResult,
     TestResultStatus::{Failure, NotFound, Skipped, Success},
-    Validatable, ValidationPreset, ValidationResult, validate_by_preset, validate_by_tests,
+    Validatable, ValidationError, ValidationPreset, ValidationResult,
 };
+use exit_status::{ExitStatus, FailOn};
+use output::{DocumentResult, OutputFormat, format_batch_results, format_result};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 use std::str::FromStr;
+use std::sync::Arc;
+
+mod batch;
+mod compare;
+mod diff;
+mod exit_status;
+mod layout;
+mod logger;
+mod output;
+
+/// Number of lines printed before and after the line of a finding
+const CODE_FRAME_CONTEXT_LINES: usize = 2;
 
 /// A validator for CSAF documents
 #[derive(Parser, Debug)]
-#[command(version, about, long_about = None)]
+#[command(
+    version,
+    about,
+    long_about = None,
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
 struct Args {
-    /// Path to the CSAF document to validate (not used with --web)
-    #[arg()]
-    path: Option<String>,
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Paths of CSAF documents, directories (searched recursively for JSON files) or glob patterns to validate
+    #[arg(required = true)]
+    paths: Vec<String>,
 
     /// Version of CSAF to use
     #[arg(short, long, default_value = "auto")]
@@ -29,71 +57,312 @@ struct Args {
     /// Run only the selected tests, may be specified multiple times
     #[arg(short, long, action = clap::ArgAction::Append)]
     test_id: Vec<String>,
+
+    /// Format in which the validation result is printed
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Text)]
+    output_format: OutputFormat,
+
+    /// Number of documents validated in parallel [default: number of CPUs]
+    #[arg(short, long)]
+    jobs: Option<usize>,
+
+    /// Lowest severity of findings that lets the validation fail
+    #[arg(long, value_enum, default_value_t = FailOn::Error)]
+    fail_on: FailOn,
+
+    /// Do not print the source of the document around each finding
+    #[arg(long)]
+    no_code_frame: bool,
+
+    /// Directory with Hunspell dictionaries (like en_US.aff and en_US.dic) for the spell check (6.3.8), may be specified multiple times
+    #[arg(long, action = clap::ArgAction::Append)]
+    dictionary_dir: Vec<PathBuf>,
+
+    /// File with words accepted by the spell check (6.3.8), like product names, one word per line
+    #[arg(long)]
+    spell_allowlist: Option<PathBuf>,
+
+    /// Request the URLs of the document to check that they resolve (6.3.6 and 6.3.7)
+    #[arg(long, conflicts_with = "url_dir")]
+    resolve_urls: bool,
+
+    /// Directory mirroring the URLs of the document as <DIR>/<host>/<path>, checked instead of requesting the URLs (6.3.6 and 6.3.7), may be specified multiple times
+    #[arg(long, action = clap::ArgAction::Append)]
+    url_dir: Vec<PathBuf>,
+
+    /// Fix the findings of the selected tests that can be repaired mechanically and write the documents back before validating them, re-serialized compact or with the indentation of the source
+    #[arg(long)]
+    fix: bool,
+
+    /// Print the changes of the fixes to the re-serialized documents as unified diff to stderr, the documents are only written with --fix
+    #[arg(long)]
+    diff: bool,
+
+    /// Previously published version of the document, checked to be consistently succeeded by the document (requires a single document, rejected in batch mode)
+    #[arg(long)]
+    previous: Option<String>,
+
+    /// Print more log messages to stderr, may be repeated (-v: info, -vv: debug, -vvv: trace)
+    #[arg(short, long, action = clap::ArgAction::Count)]
+    verbose: u8,
+}
+
+/// Commands besides validating documents
+#[derive(Subcommand, Debug)]
+enum Command {
+    Diff(compare::DiffArgs),
 }
 
-fn main() -> Result<()> {
+fn main() -> ExitCode {
     let args = Args::parse();
+    logger::init(args.verbose);
 
-    let path = args
-        .path
-        .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Path argument is required"))?;
+    let status = match &args.command {
+        Some(Command::Diff(diff_args)) => compare::run(diff_args),
+        None => run(&args),
+    };
+    match status {
+        Ok(status) => status.into(),
+        Err(err) => {
+            eprintln!("Error: {err:#}");
+            ExitStatus::InvalidArguments.into()
+        },
+    }
+}
 
-    validate_file(path, &args)
+/// Validates the documents given by the arguments and prints the results.
+///
+/// Errors are returned for invalid arguments only, documents that cannot be loaded are reported as
+/// [`ExitStatus::LoadFailure`].
+fn run(args: &Args) -> Result<ExitStatus> {
+    let preset = ValidationPreset::from_str(args.preset.as_str())
+        .map_err(|_| anyhow::anyhow!("Invalid validation preset: {}", args.preset))?;
+    let explicit_tests = !args.test_id.is_empty();
+    let attachments = Attachments::new(args)?;
+
+    // A single document is reported in detail, everything else is validated as a batch
+    if let [path] = args.paths.as_slice()
+        && Path::new(path).is_file()
+    {
+        let result = match validate_file(path, preset, args, &attachments) {
+            Ok(result) => result,
+            Err(err) => {
+                eprintln!("Error: failed to load {path}: {err:#}");
+                return Ok(ExitStatus::LoadFailure);
+            },
+        };
+        match format_result(path, &result, args.output_format)? {
+            Some(output) => println!("{output}"),
+            None => {
+                let source = if args.no_code_frame {
+                    None
+                } else {
+                    std::fs::read_to_string(path).ok()
+                };
+                print_validation_result(&result, source.as_deref())
+            },
+        }
+        return Ok(ExitStatus::from_result(&result, args.fail_on, explicit_tests));
+    }
+
+    if args.previous.is_some() {
+        bail!("--previous requires a single document");
+    }
+    let files = collect_files(&args.paths)?;
+    let outcomes = validate_files(&files, args.jobs, |file| {
+        let path = file.path.to_string_lossy();
+        let document = if file.searched {
+            match load_candidate(&path, |path| load_document(path, args))? {
+                Some(document) => document,
+                None => return Ok(None),
+            }
+        } else {
+            load_document(&path, args)?
+        };
+        validate_loaded_file(&path, document, preset, args, &attachments).map(Some)
+    })?;
+    if outcomes.is_empty() {
+        bail!("No CSAF documents found");
+    }
+
+    let documents: Vec<DocumentResult> = outcomes
+        .iter()
+        .map(|outcome| DocumentResult {
+            path: &outcome.path,
+            result: outcome.result.as_ref().map_err(|e| e.as_str()),
+        })
+        .collect();
+    match format_batch_results(&documents, args.output_format)? {
+        Some(output) => println!("{output}"),
+        None => print_batch_result(&outcomes, &BatchSummary::new(documents.iter().map(|d| d.result))),
+    }
+
+    Ok(documents
+        .iter()
+        .map(|document| match document.result {
+            Ok(result) => ExitStatus::from_result(result, args.fail_on, explicit_tests),
+            Err(_) => ExitStatus::LoadFailure,
+        })
+        .max()
+        .unwrap_or(ExitStatus::Success))
 }
 
-/// Try to validate a file as a CSAF document based on the specified version.
-fn validate_file(path: &str, args: &Args) -> Result<()> {
-    match if args.csaf_version == "auto" {
-        detect_version(path)?
+/// Spell checker and URL resolver attached to every validated document
+struct Attachments {
+    spell_checker: Option<Arc<SpellChecker>>,
+    url_resolver: Option<Arc<dyn UrlResolver>>,
+}
+
+impl Attachments {
+    fn new(args: &Args) -> Result<Self> {
+        Ok(Attachments {
+            spell_checker: spell_checker(args)?,
+            url_resolver: url_resolver(args),
+        })
+    }
+
+    fn attach(&self, mut document: CsafDocument) -> CsafDocument {
+        if let Some(spell_checker) = &self.spell_checker {
+            document = document.with_spell_checker(Arc::clone(spell_checker));
+        }
+        if let Some(url_resolver) = &self.url_resolver {
+            document = document.with_url_resolver(Arc::clone(url_resolver));
+        }
+        document
+    }
+}
+
+/// Returns the spell checker used by test 6.3.8, if dictionaries or an allowlist are given.
+fn spell_checker(args: &Args) -> Result<Option<Arc<SpellChecker>>> {
+    if args.dictionary_dir.is_empty() && args.spell_allowlist.is_none() {
+        return Ok(None);
+    }
+    let mut spell_checker = args
+        .dictionary_dir
+        .iter()
+        .fold(SpellChecker::new(), |spell_checker, dir| {
+            spell_checker.dictionary_dir(dir)
+        });
+    if let Some(path) = &args.spell_allowlist {
+        spell_checker = spell_checker
+            .allowlist_file(path)
+            .map_err(|err| anyhow::anyhow!("Failed to read spell check allowlist {}: {err}", path.display()))?;
+    }
+    Ok(Some(Arc::new(spell_checker)))
+}
+
+/// Returns the URL resolver used by tests 6.3.6 and 6.3.7, if URLs are requested or mirrored.
+fn url_resolver(args: &Args) -> Option<Arc<dyn UrlResolver>> {
+    if args.resolve_urls {
+        Some(Arc::new(HttpUrlResolver::new()))
+    } else if !args.url_dir.is_empty() {
+        Some(Arc::new(
+            args.url_dir
+                .iter()
+                .fold(OfflineUrlResolver::new(), |resolver, dir| resolver.directory(dir)),
+        ))
     } else {
-        args.csaf_version.clone()
+        None
     }
-    .as_str()
-    {
-        "2.0" => {
-            let document = load_document_2_0(path)?;
-            validate_document(document, "2.0", args)
-        },
-        "2.1" => {
-            let document = load_document_2_1(path)?;
-            validate_document(document, "2.1", args)
-        },
-        _ => bail!(format!("Invalid CSAF version: {}", args.csaf_version)),
+}
+
+/// Try to validate a file as a CSAF document based on the specified version.
+fn validate_file(
+    path: &str,
+    preset: ValidationPreset,
+    args: &Args,
+    attachments: &Attachments,
+) -> Result<ValidationResult> {
+    validate_loaded_file(path, load_document(path, args)?, preset, args, attachments)
+}
+
+/// Validate a CSAF document loaded from a file, fixing the file first with `--fix` or `--diff`.
+fn validate_loaded_file(
+    path: &str,
+    mut document: CsafDocument,
+    preset: ValidationPreset,
+    args: &Args,
+    attachments: &Attachments,
+) -> Result<ValidationResult> {
+    if args.fix || args.diff {
+        document = fix_file(path, document, preset, args)?;
     }
+    let document = attachments.attach(document);
+    let mut result = validate_document(&document, preset, args);
+    if let Some(previous_path) = &args.previous {
+        let previous = load_document(previous_path, args)
+            .with_context(|| format!("failed to load previous version {previous_path}"))?;
+        result.merge(validate_successor(&previous, &document, preset));
+    }
+    Ok(result)
+}
+
+/// Load a file as a CSAF document based on the specified version.
+fn load_document(path: &str, args: &Args) -> Result<CsafDocument> {
+    Ok(if args.csaf_version == "auto" {
+        CsafDocument::load(Path::new(path))?
+    } else {
+        CsafDocument::load_as(Path::new(path), &args.csaf_version)?
+    })
 }
 
-/// Validate a CSAF document of the specified version with the provided arguments.
+/// Apply the fixes for the findings of the selected tests, printing them with `--diff` and writing
+/// them with `--fix`.
 ///
-/// This prints the results of the tests on stdout.
-fn validate_document<T>(document: T, version: &str, args: &Args) -> Result<()>
-where
-    T: Validatable,
-{
-    let preset = ValidationPreset::from_str(args.preset.as_str())
-        .map_err(|_| anyhow::anyhow!("Invalid validation preset: {}", args.preset))?;
+/// Returns the document to validate, which is the written one if the file was fixed.
+fn fix_file(path: &str, document: CsafDocument, preset: ValidationPreset, args: &Args) -> Result<CsafDocument> {
+    let test_ids: Vec<&str> = if args.test_id.is_empty() {
+        document.preset_tests(&preset)
+    } else {
+        args.test_id.iter().map(|s| s.as_str()).collect()
+    };
+    let (fixed, fixes) = csaf::fix::fix(&document, &test_ids).context("failed to apply fixes")?;
+    if fixes.is_empty() {
+        return Ok(document);
+    }
+    for fix in &fixes {
+        let test = fix.test_id.as_deref().unwrap_or("fix");
+        log::info!("{path}: {test}: {} [{}]", fix.message, fix.instance_path);
+    }
+
+    // The fixed document is written compact or indented like the source, so the diff shows the fixes
+    let source = std::fs::read_to_string(path)?;
+    let fixed_json = layout::to_string_like(fixed.get_json(), &source)?;
+    if args.diff {
+        // Written to stderr in one piece, so it neither mixes with the results on stdout nor with the
+        // diffs of other documents of a batch
+        eprint!("{}", diff::unified_diff(path, &source, &fixed_json));
+    }
+    if !args.fix {
+        return Ok(document);
+    }
+    std::fs::write(path, fixed_json).with_context(|| format!("failed to write {path}"))?;
+    // The findings of the written document are located in the written file
+    load_document(path, args)
+}
 
-    let result = if !args.test_id.is_empty() {
+/// Validate a CSAF document with the provided arguments.
+fn validate_document(document: &CsafDocument, preset: ValidationPreset, args: &Args) -> ValidationResult {
+    if !args.test_id.is_empty() {
         // Individual test validation
         let test_ids: Vec<&str> = args.test_id.iter().map(|s| s.as_str()).collect();
-        validate_by_tests(&document, version, preset, &test_ids)
+        document.validate_tests(preset, &test_ids)
     } else {
         // Preset validation
-        validate_by_preset(&document, version, preset)
-    };
-
-    print_validation_result(&result);
-    Ok(())
+        document.validate(preset)
+    }
 }
 
 /// Print a validation result to stdout (for CLI use)
-pub fn print_validation_result(result: &ValidationResult) {
+///
+/// If the JSON source of the document is given, a code frame is printed for every finding.
+pub fn print_validation_result(result: &ValidationResult, source: Option<&str>) {
     println!("CSAF Version: {}", result.version);
     println!("Validating document with {:?} preset...\n", result.preset);
 
     // Print individual test results
     for test_result in &result.test_results {
-        print_test_result(test_result);
+        print_test_result(test_result, result, source);
     }
 
     // Print summary
@@ -117,7 +386,7 @@ pub fn print_validation_result(result: &ValidationResult) {
 }
 
 /// Print individual test result to stdout.
-fn print_test_result(test_result: &TestResult) {
+fn print_test_result(test_result: &TestResult, result: &ValidationResult, source: Option<&str>) {
     // Common prefix for all test statuses
     let prefix = format!("Executing Test {:10} ... ", test_result.test_id);
     print!("{prefix}");
@@ -144,15 +413,18 @@ fn print_test_result(test_result: &TestResult) {
                     "❌  {}: {} [{}]",
                     test_result.test_id, error.message, error.instance_path
                 );
+                print_location(error, result, source);
             }
             for warning in warnings {
                 println!(
                     "⚠️  {}: {} [{}]",
                     test_result.test_id, warning.message, warning.instance_path
                 );
+                print_location(warning, result, source);
             }
             for info in infos {
                 println!("💡  {}: {} [{}]", test_result.test_id, info.message, info.instance_path);
+                print_location(info, result, source);
             }
         },
         NotFound => {
@@ -165,3 +437,42 @@ fn print_test_result(test_result: &TestResult) {
         },
     }
 }
+
+/// Print the position of a finding in the document and, if the source is given, a code frame.
+fn print_location(finding: &ValidationError, result: &ValidationResult, source: Option<&str>) {
+    let Some(span) = result.location_of(finding) else {
+        return;
+    };
+    println!("    --> line {}, column {}", span.line, span.column);
+    if let Some(source) = source {
+        for line in code_frame(source, span, CODE_FRAME_CONTEXT_LINES).lines() {
+            println!("    {line}");
+        }
+        println!();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fix_keeps_compact_layout() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("advisory.json").to_string_lossy().to_string();
+        let source = r#"{"document":{"category":"csaf_base","csaf_version":"2.0","distribution":{"tlp":{"label":"WHITE"}},"publisher":{"category":"vendor","name":"Example Company","namespace":"https://example.com"},"title":"Compact","tracking":{"current_release_date":"2024-01-24T10:00:00.000Z","id":"COMPACT","initial_release_date":"2024-01-24T10:00:00.000Z","revision_history":[{"date":"2024-01-24T10:00:00.000Z","number":"1","summary":"Initial version."},{"date":"2024-01-24T10:00:00.000Z","number":"2","summary":"Update."}],"status":"final","version":"1"}}}"#;
+        std::fs::write(&path, format!("{source}\n")).unwrap();
+        let args = Args::parse_from(["csaf-validator", "--fix", "--test-id", "6.1.16", &path]);
+
+        let document = fix_file(
+            &path,
+            load_document(&path, &args).unwrap(),
+            ValidationPreset::Basic,
+            &args,
+        )
+        .unwrap();
+        assert_eq!(document.get_json()["document"]["tracking"]["version"], "2");
+        let fixed = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(fixed, source.replace(r#""version":"1""#, r#""version":"2""#) + "\n");
+    }
+}
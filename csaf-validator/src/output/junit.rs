@@ -0,0 +1,182 @@
+//! JUnit XML output, as understood by most CI systems.
+
+use std::fmt::Write;
+
+use csaf::validation::{TestResultStatus, ValidationError, ValidationResult};
+
+use crate::output::DocumentResult;
+
+/// Converts validation results into a JUnit XML report.
+///
+/// Every document becomes a test suite and every executed test a test case. Tests with errors are
+/// reported as failures, warnings and infos are included in the output of the test case. Documents
+/// that could not be loaded are reported as a test suite with a single erroneous `load` test case.
+pub fn to_junit(documents: &[DocumentResult]) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let total_tests: usize = documents
+        .iter()
+        .map(|d| d.result.map_or(1, |result| result.test_results.len()))
+        .sum();
+    let total_failures: usize = documents.iter().map(num_failures).sum();
+    let total_errors = documents.iter().filter(|d| d.result.is_err()).count();
+    let _ = writeln!(
+        xml,
+        "<testsuites name=\"csaf-validator\" tests=\"{total_tests}\" failures=\"{total_failures}\" \
+         errors=\"{total_errors}\">"
+    );
+
+    for document in documents {
+        let result = match document.result {
+            Ok(result) => result,
+            Err(error) => {
+                let _ = writeln!(
+                    xml,
+                    "  <testsuite name=\"{}\" tests=\"1\" failures=\"0\" errors=\"1\" skipped=\"0\">\n    \
+                     <testcase name=\"load\" classname=\"csaf\">\n      <error message=\"{}\"/>\n    \
+                     </testcase>\n  </testsuite>",
+                    escape(document.path),
+                    escape(error)
+                );
+                continue;
+            },
+        };
+        let skipped = result
+            .test_results
+            .iter()
+            .filter(|t| matches!(t.status, TestResultStatus::Skipped | TestResultStatus::NotFound))
+            .count();
+        let _ = writeln!(
+            xml,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"{skipped}\">",
+            escape(document.path),
+            result.test_results.len(),
+            num_failures(document),
+        );
+        let class_name = format!("csaf.{}.{}", result.version, result.preset);
+
+        for test_result in &result.test_results {
+            let _ = write!(
+                xml,
+                "    <testcase name=\"{}\" classname=\"{}\"",
+                escape(&test_result.test_id),
+                escape(&class_name)
+            );
+            match &test_result.status {
+                TestResultStatus::Success => xml.push_str("/>\n"),
+                TestResultStatus::Skipped => {
+                    xml.push_str(">\n      <skipped message=\"Test skipped\"/>\n    </testcase>\n")
+                },
+                TestResultStatus::NotFound => {
+                    xml.push_str(">\n      <skipped message=\"Test not found\"/>\n    </testcase>\n")
+                },
+                TestResultStatus::Failure {
+                    errors,
+                    warnings,
+                    infos,
+                } => {
+                    xml.push_str(">\n");
+                    if !errors.is_empty() {
+                        let _ = writeln!(
+                            xml,
+                            "      <failure message=\"{} error(s) found\" type=\"error\">{}</failure>",
+                            errors.len(),
+                            escape(&format_findings(errors, result))
+                        );
+                    }
+                    let other_findings = [warnings.as_slice(), infos.as_slice()].concat();
+                    if !other_findings.is_empty() {
+                        let _ = writeln!(
+                            xml,
+                            "      <system-out>{}</system-out>",
+                            escape(&format_findings(&other_findings, result))
+                        );
+                    }
+                    xml.push_str("    </testcase>\n");
+                },
+            }
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+/// Number of tests of a document that found errors.
+fn num_failures(document: &DocumentResult) -> usize {
+    document.result.map_or(0, |result| {
+        result
+            .test_results
+            .iter()
+            .filter(|t| matches!(&t.status, TestResultStatus::Failure { errors, .. } if !errors.is_empty()))
+            .count()
+    })
+}
+
+fn format_findings(findings: &[ValidationError], result: &ValidationResult) -> String {
+    findings
+        .iter()
+        .map(|f| match result.location_of(f) {
+            Some(span) => format!(
+                "{} [{}] (line {}, column {})",
+                f.message, f.instance_path, span.line, span.column
+            ),
+            None => format!("{} [{}]", f.message, f.instance_path),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Escapes text for use in XML attributes and text nodes.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::tests::example_result;
+
+    #[test]
+    fn test_to_junit() {
+        let result = example_result();
+        let xml = to_junit(&[DocumentResult {
+            path: "advisory.json",
+            result: Ok(&result),
+        }]);
+
+        assert!(xml.contains("<testsuites name=\"csaf-validator\" tests=\"3\" failures=\"1\" errors=\"0\">"));
+        assert!(
+            xml.contains("<testsuite name=\"advisory.json\" tests=\"3\" failures=\"1\" errors=\"0\" skipped=\"1\">")
+        );
+        assert!(xml.contains("<testcase name=\"6.1.1\" classname=\"csaf.2.0.basic\"/>"));
+        assert!(xml.contains(
+            "<failure message=\"1 error(s) found\" type=\"error\">Duplicate definition for product ID \
+             &lt;CSAFPID-0001&gt; [/product_tree/full_product_names/1/product_id]</failure>"
+        ));
+        assert!(xml.contains("<system-out>Something is odd [/document]</system-out>"));
+        assert!(xml.contains("<skipped message=\"Test skipped\"/>"));
+    }
+
+    #[test]
+    fn test_to_junit_load_failure() {
+        let xml = to_junit(&[DocumentResult {
+            path: "broken.json",
+            result: Err("expected value at line 1 column 1"),
+        }]);
+
+        assert!(xml.contains("<testsuites name=\"csaf-validator\" tests=\"1\" failures=\"0\" errors=\"1\">"));
+        assert!(xml.contains("<error message=\"expected value at line 1 column 1\"/>"));
+    }
+}
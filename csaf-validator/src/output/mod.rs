@@ -0,0 +1,117 @@
+//! Machine-readable output formats of validation results.
+
+use clap::ValueEnum;
+use csaf::validation::ValidationResult;
+use serde_json::json;
+
+use crate::batch::BatchSummary;
+
+pub mod junit;
+pub mod sarif;
+
+/// Format in which validation results are printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable text
+    Text,
+    /// The validation result as JSON
+    Json,
+    /// SARIF 2.1.0, one result per validation error
+    Sarif,
+    /// JUnit XML, one test case per executed test
+    Junit,
+}
+
+/// A validation result together with the path of the validated document.
+///
+/// `result` is `Err` with the reason if the document could not be loaded.
+pub struct DocumentResult<'a> {
+    pub path: &'a str,
+    pub result: Result<&'a ValidationResult, &'a str>,
+}
+
+/// Serializes the validation result of a single document in a machine-readable format.
+///
+/// Returns `None` for [`OutputFormat::Text`], which is printed directly.
+pub fn format_result(path: &str, result: &ValidationResult, format: OutputFormat) -> anyhow::Result<Option<String>> {
+    match format {
+        OutputFormat::Json => Ok(Some(serde_json::to_string_pretty(result)?)),
+        _ => format_batch_results(
+            &[DocumentResult {
+                path,
+                result: Ok(result),
+            }],
+            format,
+        ),
+    }
+}
+
+/// Serializes the validation results of several documents in a machine-readable format.
+///
+/// Returns `None` for [`OutputFormat::Text`], which is printed directly.
+pub fn format_batch_results(documents: &[DocumentResult], format: OutputFormat) -> anyhow::Result<Option<String>> {
+    Ok(match format {
+        OutputFormat::Text => None,
+        OutputFormat::Json => Some(serde_json::to_string_pretty(&batch_json(documents))?),
+        OutputFormat::Sarif => Some(serde_json::to_string_pretty(&sarif::to_sarif(documents))?),
+        OutputFormat::Junit => Some(junit::to_junit(documents)),
+    })
+}
+
+/// JSON representation of the results of several documents, including the aggregated summary.
+fn batch_json(documents: &[DocumentResult]) -> serde_json::Value {
+    let files: Vec<serde_json::Value> = documents
+        .iter()
+        .map(|document| match document.result {
+            Ok(result) => json!({ "path": document.path, "result": result }),
+            Err(error) => json!({ "path": document.path, "error": error }),
+        })
+        .collect();
+    json!({
+        "files": files,
+        "summary": BatchSummary::new(documents.iter().map(|d| d.result)),
+    })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use csaf::validation::{TestResult, TestResultStatus, ValidationError, ValidationPreset, ValidationResult};
+
+    /// A validation result with one successful test, one test with an error and a warning and one skipped test.
+    pub(crate) fn example_result() -> ValidationResult {
+        ValidationResult {
+            success: false,
+            version: "2.0".to_string(),
+            preset: ValidationPreset::Basic,
+            test_results: vec![
+                TestResult {
+                    test_id: "6.1.1".to_string(),
+                    status: TestResultStatus::Success,
+                },
+                TestResult {
+                    test_id: "6.1.2".to_string(),
+                    status: TestResultStatus::Failure {
+                        errors: vec![ValidationError {
+                            message: "Duplicate definition for product ID <CSAFPID-0001>".to_string(),
+                            instance_path: "/product_tree/full_product_names/1/product_id".to_string(),
+                        }],
+                        warnings: vec![ValidationError {
+                            message: "Something is odd".to_string(),
+                            instance_path: "/document".to_string(),
+                        }],
+                        infos: vec![],
+                    },
+                },
+                TestResult {
+                    test_id: "6.1.3".to_string(),
+                    status: TestResultStatus::Skipped,
+                },
+            ],
+            num_errors: 1,
+            num_warnings: 1,
+            num_infos: 0,
+            num_not_found: 0,
+            source_locations: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! SARIF 2.1.0 output, see <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>
+
+use csaf::csaf::span::Span;
+use csaf::validation::{TestResultStatus, ValidationError};
+use serde_json::{Value, json};
+
+use crate::output::DocumentResult;
+
+const SARIF_SCHEMA: &str = "https://docs.oasis-open.org/sarif/sarif/v2.1.0/errata01/os/schemas/sarif-schema-2.1.0.json";
+
+/// Converts validation results into a SARIF log with a single run.
+///
+/// Every executed test becomes a rule, every validation error, warning and info becomes a result
+/// referring to the test as its rule and to the instance path as its logical location. Documents
+/// that could not be loaded are reported as tool execution notifications.
+pub fn to_sarif(documents: &[DocumentResult]) -> Value {
+    let mut rules: Vec<String> = Vec::new();
+    let mut results: Vec<Value> = Vec::new();
+    let mut notifications: Vec<Value> = Vec::new();
+
+    for document in documents {
+        let result = match document.result {
+            Ok(result) => result,
+            Err(error) => {
+                notifications.push(json!({
+                    "level": "error",
+                    "message": { "text": error },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": document.path }
+                        }
+                    }]
+                }));
+                continue;
+            },
+        };
+        for test_result in &result.test_results {
+            let rule_index = match rules.iter().position(|id| *id == test_result.test_id) {
+                Some(index) => index,
+                None => {
+                    rules.push(test_result.test_id.clone());
+                    rules.len() - 1
+                },
+            };
+
+            if let TestResultStatus::Failure {
+                errors,
+                warnings,
+                infos,
+            } = &test_result.status
+            {
+                let levels = [("error", errors), ("warning", warnings), ("note", infos)];
+                for (level, findings) in levels {
+                    for finding in findings {
+                        results.push(sarif_result(
+                            &test_result.test_id,
+                            rule_index,
+                            level,
+                            finding,
+                            document.path,
+                            result.location_of(finding),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    let artifacts: Vec<Value> = documents
+        .iter()
+        .map(|document| json!({ "location": { "uri": document.path } }))
+        .collect();
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": env!("CARGO_PKG_NAME"),
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                    "rules": rules.iter().map(|id| json!({
+                        "id": id,
+                        "name": format!("CSAF test {id}"),
+                    })).collect::<Vec<_>>(),
+                }
+            },
+            "invocations": [{
+                "executionSuccessful": notifications.is_empty(),
+                "toolExecutionNotifications": notifications,
+            }],
+            // The columns of spans count characters, SARIF defaults to UTF-16 code units
+            "columnKind": "unicodeCodePoints",
+            "artifacts": artifacts,
+            "results": results,
+        }]
+    })
+}
+
+fn sarif_result(
+    test_id: &str,
+    rule_index: usize,
+    level: &str,
+    finding: &ValidationError,
+    path: &str,
+    span: Option<&Span>,
+) -> Value {
+    let mut physical_location = json!({ "artifactLocation": { "uri": path } });
+    if let Some(span) = span {
+        physical_location["region"] = json!({
+            "startLine": span.line,
+            "startColumn": span.column,
+            "byteOffset": span.start,
+            "byteLength": span.end - span.start,
+        });
+    }
+    json!({
+        "ruleId": test_id,
+        "ruleIndex": rule_index,
+        "level": level,
+        "message": { "text": finding.message },
+        "locations": [{
+            "physicalLocation": physical_location,
+            "logicalLocations": [{
+                "fullyQualifiedName": finding.instance_path,
+                "kind": "member"
+            }]
+        }]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::tests::example_result;
+    use csaf::csaf::span::SpanIndex;
+
+    #[test]
+    fn test_to_sarif() {
+        let mut result = example_result();
+        result.locate(&SpanIndex::new(
+            r#"{"product_tree": {"full_product_names": [{}, {"product_id": "CSAFPID-0001"}]}}"#,
+        ));
+        let sarif = to_sarif(&[DocumentResult {
+            path: "advisory.json",
+            result: Ok(&result),
+        }]);
+
+        let run = &sarif["runs"][0];
+        assert_eq!(sarif["version"], "2.1.0");
+        assert_eq!(run["columnKind"], "unicodeCodePoints");
+        assert_eq!(run["tool"]["driver"]["rules"].as_array().unwrap().len(), 3);
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0]["ruleId"], "6.1.2");
+        assert_eq!(results[0]["ruleIndex"], 1);
+        assert_eq!(results[0]["level"], "error");
+        assert_eq!(
+            results[0]["locations"][0]["logicalLocations"][0]["fullyQualifiedName"],
+            "/product_tree/full_product_names/1/product_id"
+        );
+        assert_eq!(
+            results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "advisory.json"
+        );
+        assert_eq!(
+            results[0]["locations"][0]["physicalLocation"]["region"]["startColumn"],
+            61
+        );
+        assert_eq!(results[1]["level"], "warning");
+        assert_eq!(run["invocations"][0]["executionSuccessful"], true);
+    }
+
+    #[test]
+    fn test_to_sarif_load_failure() {
+        let sarif = to_sarif(&[DocumentResult {
+            path: "broken.json",
+            result: Err("expected value at line 1 column 1"),
+        }]);
+
+        let invocation = &sarif["runs"][0]["invocations"][0];
+        assert_eq!(invocation["executionSuccessful"], false);
+        assert_eq!(
+            invocation["toolExecutionNotifications"][0]["message"]["text"],
+            "expected value at line 1 column 1"
+        );
+    }
+}